@@ -8,7 +8,7 @@ use kona_derive::PipelineErrorKind;
 use kona_driver::DriverError;
 use kona_executor::ExecutorError;
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
-use kona_proof::{CachingOracle, errors::OracleProviderError};
+use kona_proof::{CachingOracle, errors::OracleProviderError, l1::OracleDataAvailabilityRegistry};
 use kona_proof_interop::{
     BootInfo, ConsolidationError, PreState, TRANSITION_STATE_MAX_STEPS, boot::BootstrapError,
 };
@@ -55,6 +55,23 @@ pub enum FaultProofProgramError {
 /// [HintWriterClient].
 #[inline]
 pub async fn run<P, H>(oracle_client: P, hint_client: H) -> Result<(), FaultProofProgramError>
+where
+    P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
+    H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
+{
+    run_with_da_registry(oracle_client, hint_client, OracleDataAvailabilityRegistry::default())
+        .await
+}
+
+/// Executes the interop fault proof program with the given [PreimageOracleClient] and
+/// [HintWriterClient], selecting the data availability provider of the derivation pipelines from
+/// the given [OracleDataAvailabilityRegistry].
+#[inline]
+pub async fn run_with_da_registry<P, H>(
+    oracle_client: P,
+    hint_client: H,
+    da_registry: OracleDataAvailabilityRegistry<CachingOracle<P, H>>,
+) -> Result<(), FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
     H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
@@ -96,7 +113,7 @@ where
             }
 
             // If the pre-state is a super root, the first sub-problem is always selected.
-            sub_transition(oracle, boot, evm_factory).await
+            sub_transition(oracle, boot, evm_factory, &da_registry).await
         }
         PreState::TransitionState(ref transition_state) => {
             // If the claimed L2 block timestamp is less than the prestate timestamp, the
@@ -112,7 +129,7 @@ where
             // current step.
             match transition_state.step.cmp(&TRANSITION_STATE_MAX_STEPS) {
                 Ordering::Equal => consolidate_dependencies(oracle, boot, evm_factory).await,
-                Ordering::Less => sub_transition(oracle, boot, evm_factory).await,
+                Ordering::Less => sub_transition(oracle, boot, evm_factory, &da_registry).await,
                 Ordering::Greater => {
                     error!(
                         target: "client_interop",
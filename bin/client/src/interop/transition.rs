@@ -7,14 +7,16 @@ use alloy_consensus::Sealed;
 use alloy_evm::{EvmFactory, FromRecoveredTx, FromTxWithEncoded};
 use alloy_primitives::B256;
 use core::fmt::Debug;
-use kona_derive::{EthereumDataSource, PipelineError, PipelineErrorKind};
+use kona_derive::{PipelineError, PipelineErrorKind};
 use kona_driver::{Driver, DriverError};
 use kona_executor::TrieDBProvider;
 use kona_preimage::{HintWriterClient, PreimageOracleClient};
 use kona_proof::{
     CachingOracle,
     executor::KonaExecutor,
-    l1::{OracleBlobProvider, OracleL1ChainProvider, OraclePipeline},
+    l1::{
+        OracleBlobProvider, OracleDataAvailabilityRegistry, OracleL1ChainProvider, OraclePipeline,
+    },
     l2::OracleL2ChainProvider,
     sync::new_oracle_pipeline_cursor,
 };
@@ -29,10 +31,11 @@ pub(crate) async fn sub_transition<P, H, Evm>(
     oracle: Arc<CachingOracle<P, H>>,
     boot: BootInfo,
     evm_factory: Evm,
+    da_registry: &OracleDataAvailabilityRegistry<CachingOracle<P, H>>,
 ) -> Result<(), FaultProofProgramError>
 where
-    P: PreimageOracleClient + Send + Sync + Debug + Clone,
-    H: HintWriterClient + Send + Sync + Debug + Clone,
+    P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
+    H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
     Evm: EvmFactory<Spec = OpSpecId> + Send + Sync + Debug + Clone + 'static,
     <Evm as EvmFactory>::Tx: FromTxWithEncoded<OpTxEnvelope> + FromRecoveredTx<OpTxEnvelope>,
 {
@@ -102,8 +105,7 @@ where
     .await?;
     l2_provider.set_cursor(cursor.clone());
 
-    let da_provider =
-        EthereumDataSource::new_from_parts(l1_provider.clone(), beacon, &rollup_config);
+    let da_provider = da_registry.create(l1_provider.clone(), beacon, &rollup_config);
     let pipeline = OraclePipeline::new(
         rollup_config.clone(),
         cursor.clone(),
@@ -5,7 +5,7 @@ use alloc::sync::Arc;
 use alloy_consensus::Sealed;
 use alloy_primitives::B256;
 use core::fmt::Debug;
-use kona_derive::{EthereumDataSource, PipelineErrorKind};
+use kona_derive::PipelineErrorKind;
 use kona_driver::{Driver, DriverError};
 use kona_executor::{ExecutorError, TrieDBProvider};
 use kona_preimage::{CommsClient, HintWriterClient, PreimageKey, PreimageOracleClient};
@@ -13,7 +13,9 @@ use kona_proof::{
     BootInfo, CachingOracle, HintType,
     errors::OracleProviderError,
     executor::KonaExecutor,
-    l1::{OracleBlobProvider, OracleL1ChainProvider, OraclePipeline},
+    l1::{
+        OracleBlobProvider, OracleDataAvailabilityRegistry, OracleL1ChainProvider, OraclePipeline,
+    },
     l2::OracleL2ChainProvider,
     sync::new_oracle_pipeline_cursor,
 };
@@ -40,6 +42,23 @@ pub enum FaultProofProgramError {
 /// Executes the fault proof program with the given [PreimageOracleClient] and [HintWriterClient].
 #[inline]
 pub async fn run<P, H>(oracle_client: P, hint_client: H) -> Result<(), FaultProofProgramError>
+where
+    P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
+    H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
+{
+    run_with_da_registry(oracle_client, hint_client, OracleDataAvailabilityRegistry::default())
+        .await
+}
+
+/// Executes the fault proof program with the given [PreimageOracleClient] and [HintWriterClient],
+/// selecting the data availability provider of the derivation pipeline from the given
+/// [OracleDataAvailabilityRegistry].
+#[inline]
+pub async fn run_with_da_registry<P, H>(
+    oracle_client: P,
+    hint_client: H,
+    da_registry: OracleDataAvailabilityRegistry<CachingOracle<P, H>>,
+) -> Result<(), FaultProofProgramError>
 where
     P: PreimageOracleClient + Send + Sync + Debug + Clone + 'static,
     H: HintWriterClient + Send + Sync + Debug + Clone + 'static,
@@ -106,8 +125,7 @@ where
     l2_provider.set_cursor(cursor.clone());
 
     let evm_factory = FpvmOpEvmFactory::new(hint_client, oracle_client);
-    let da_provider =
-        EthereumDataSource::new_from_parts(l1_provider.clone(), beacon, &rollup_config);
+    let da_provider = da_registry.create(l1_provider.clone(), beacon, &rollup_config);
     let pipeline = OraclePipeline::new(
         rollup_config.clone(),
         cursor.clone(),
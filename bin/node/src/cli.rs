@@ -1,7 +1,10 @@
 //! Contains the node CLI.
 
 use crate::{
-    commands::{BootstoreCommand, InfoCommand, NetCommand, NodeCommand, RegistryCommand},
+    commands::{
+        BatchCommand, BootstoreCommand, DeriveCommand, InfoCommand, NetCommand, NodeCommand,
+        RegistryCommand,
+    },
     flags::{GlobalArgs, init_unified_metrics},
     version,
 };
@@ -27,6 +30,11 @@ pub enum Commands {
     Bootstore(BootstoreCommand),
     /// Get info about op chain.
     Info(InfoCommand),
+    /// Runs the derivation pipeline over a range of L1 blocks.
+    #[command(alias = "d")]
+    Derive(DeriveCommand),
+    /// Decodes the channels and batches of batcher transactions.
+    Batch(BatchCommand),
 }
 
 /// The node CLI.
@@ -61,6 +69,8 @@ impl Cli {
             Commands::Registry(ref registry) => registry.init_logs(&self.global)?,
             Commands::Bootstore(ref bootstore) => bootstore.init_logs(&self.global)?,
             Commands::Info(ref info) => info.init_logs(&self.global)?,
+            Commands::Derive(ref derive) => derive.init_logs(&self.global)?,
+            Commands::Batch(ref batch) => batch.init_logs(&self.global)?,
         }
 
         // Allow subcommands to initialize cli metrics.
@@ -76,8 +86,10 @@ impl Cli {
             Commands::Node(node) => Self::run_until_ctrl_c(node.run(&self.global)),
             Commands::Net(net) => Self::run_until_ctrl_c(net.run(&self.global)),
             Commands::Registry(registry) => registry.run(&self.global),
-            Commands::Bootstore(bootstore) => bootstore.run(&self.global),
+            Commands::Bootstore(bootstore) => Self::run_until_ctrl_c(bootstore.run(&self.global)),
             Commands::Info(info) => info.run(&self.global),
+            Commands::Derive(derive) => Self::run_until_ctrl_c(derive.run(&self.global)),
+            Commands::Batch(batch) => Self::run_until_ctrl_c(batch.run(&self.global)),
         }
     }
 
@@ -0,0 +1,237 @@
+//! Batch Subcommand
+
+use crate::flags::GlobalArgs;
+use alloy_consensus::Transaction;
+use alloy_eips::eip4844::{BYTES_PER_BLOB, Blob, IndexedBlobHash};
+use alloy_primitives::{B256, Bytes, hex};
+use alloy_provider::Provider;
+use anyhow::{Result, anyhow, bail};
+use clap::Parser;
+use kona_cli::LogConfig;
+use kona_derive::{BlobData, BlobProvider, ChainProvider};
+use kona_protocol::{BlockInfo, ChannelReassembler};
+use kona_providers_alloy::{AlloyChainProvider, OnlineBeaconClient, OnlineBlobProvider};
+use std::{
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use url::Url;
+
+/// The size of the cache used in the L1 chain provider.
+const PROVIDER_CACHE_SIZE: usize = 16;
+
+/// The `batch` Subcommand
+///
+/// The `batch` subcommand decodes the frames of batcher transactions, reassembles their channels
+/// and prints the batches they contain, along with their decoded L2 transactions.
+///
+/// Frames of a channel may be spread over several inputs, which are processed in order.
+///
+/// # Usage
+///
+/// ```sh
+/// # Decode batcher transactions by hash.
+/// kona-node batch --l1-eth-rpc <URL> --l1-beacon <URL> --tx <HASH> --tx <HASH>
+///
+/// # Decode raw calldata or blobs.
+/// kona-node batch --calldata <HEX> --blob <PATH>
+/// ```
+#[derive(Parser, Default, PartialEq, Debug, Clone)]
+#[command(about = "Decodes the channels and batches of batcher transactions")]
+pub struct BatchCommand {
+    /// Hash of an L1 batcher transaction to decode.
+    #[arg(long = "tx", requires = "l1_eth_rpc")]
+    pub txs: Vec<B256>,
+    /// Hex-encoded calldata of a batcher transaction to decode.
+    #[arg(long = "calldata")]
+    pub calldata: Vec<Bytes>,
+    /// Path of a file holding a hex-encoded blob of a batcher transaction to decode.
+    #[arg(long = "blob")]
+    pub blobs: Vec<PathBuf>,
+    /// URL of the L1 execution client RPC API, used to fetch the transactions.
+    #[arg(long, visible_alias = "l1")]
+    pub l1_eth_rpc: Option<Url>,
+    /// URL of the L1 beacon API, used to fetch the blobs of blob transactions.
+    #[arg(long, visible_alias = "l1.beacon")]
+    pub l1_beacon: Option<Url>,
+    /// Path to a custom L2 rollup configuration file.
+    #[arg(long, visible_alias = "rollup-cfg")]
+    pub l2_config_file: Option<PathBuf>,
+}
+
+impl BatchCommand {
+    /// Initializes the logging system based on global arguments.
+    pub fn init_logs(&self, args: &GlobalArgs) -> Result<()> {
+        LogConfig::new(args.log_args.clone()).init_tracing_subscriber(None)?;
+        Ok(())
+    }
+
+    /// Runs the subcommand.
+    pub async fn run(self, args: &GlobalArgs) -> Result<()> {
+        if self.txs.is_empty() && self.calldata.is_empty() && self.blobs.is_empty() {
+            bail!("At least one --tx, --calldata or --blob input is required");
+        }
+        let cfg = args.rollup_config(self.l2_config_file.as_deref())?;
+        let mut reassembler = ChannelReassembler::new();
+
+        for hash in &self.txs {
+            for (index, (block, data)) in self.fetch_tx_data(*hash).await?.into_iter().enumerate() {
+                let frames = reassembler
+                    .add_data(&data, block)
+                    .map_err(|e| anyhow!("Failed to parse the frames of tx {hash}: {e}"))?;
+                println!("tx {hash} (data {index}, L1 block {}): {frames} frame(s)", block.number);
+            }
+        }
+
+        // Raw inputs carry no inclusion block, so they are treated as included now.
+        let block = BlockInfo { timestamp: unix_now(), ..Default::default() };
+        for (index, data) in self.calldata.iter().enumerate() {
+            let frames = reassembler
+                .add_data(data, block)
+                .map_err(|e| anyhow!("Failed to parse the frames of calldata {index}: {e}"))?;
+            println!("calldata {index}: {frames} frame(s)");
+        }
+        for path in &self.blobs {
+            let data = read_blob(path)?;
+            let frames = reassembler
+                .add_data(&data, block)
+                .map_err(|e| anyhow!("Failed to parse the frames of {}: {e}", path.display()))?;
+            println!("blob {}: {frames} frame(s)", path.display());
+        }
+
+        for channel in reassembler.decode_ready(&cfg) {
+            println!("\n{channel}");
+        }
+        for channel in reassembler.pending() {
+            println!(
+                "\nIncomplete channel {} ({} frame(s), opened in L1 block {})",
+                channel.id(),
+                channel.len(),
+                channel.open_block_number()
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Fetches the batcher data of the transaction with the given hash, along with the L1 block
+    /// that included it. Blob transactions yield the data of each of their blobs.
+    async fn fetch_tx_data(&self, hash: B256) -> Result<Vec<(BlockInfo, Bytes)>> {
+        let Some(l1_eth_rpc) = &self.l1_eth_rpc else {
+            bail!("--l1-eth-rpc is required with --tx");
+        };
+        let mut chain_provider =
+            AlloyChainProvider::new_http(l1_eth_rpc.clone(), PROVIDER_CACHE_SIZE);
+
+        let block_hash = chain_provider
+            .inner
+            .get_transaction_by_hash(hash)
+            .await?
+            .ok_or_else(|| anyhow!("Transaction {hash} not found"))?
+            .block_hash
+            .ok_or_else(|| anyhow!("Transaction {hash} is pending"))?;
+        let (block, txs) = chain_provider
+            .block_info_and_transactions_by_hash(block_hash)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch L1 block {block_hash}: {e}"))?;
+
+        let position = txs
+            .iter()
+            .position(|tx| *tx.tx_hash() == hash)
+            .ok_or_else(|| anyhow!("Transaction {hash} not found in L1 block {block_hash}"))?;
+        let tx = &txs[position];
+        let Some(blob_hashes) = tx.blob_versioned_hashes() else {
+            return Ok(vec![(block, tx.input().clone())]);
+        };
+
+        let Some(l1_beacon) = &self.l1_beacon else {
+            bail!("--l1-beacon is required to fetch the blobs of blob transaction {hash}");
+        };
+        // Blobs are indexed within the block, so the blobs of the preceding txs are counted.
+        let first_index = txs[..position]
+            .iter()
+            .map(|tx| tx.blob_versioned_hashes().map_or(0, |hashes| hashes.len()))
+            .sum::<usize>();
+        let indexed = blob_hashes
+            .iter()
+            .enumerate()
+            .map(|(i, hash)| IndexedBlobHash { index: (first_index + i) as u64, hash: *hash })
+            .collect::<Vec<_>>();
+
+        let beacon = OnlineBeaconClient::new_http(l1_beacon.to_string());
+        let blobs = OnlineBlobProvider::init(beacon)
+            .await
+            .get_blobs(&block, &indexed)
+            .await
+            .map_err(|e| anyhow!("Failed to fetch the blobs of transaction {hash}: {e}"))?;
+        blobs
+            .iter()
+            .map(|blob| {
+                let data = BlobData::from_blob(blob)
+                    .decode()
+                    .map_err(|e| anyhow!("Failed to decode a blob of transaction {hash}: {e}"))?;
+                Ok((block, data))
+            })
+            .collect()
+    }
+}
+
+/// Reads a hex-encoded blob from the given file, and returns its decoded data.
+fn read_blob(path: &Path) -> Result<Bytes> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| anyhow!("Failed to read blob file {}: {e}", path.display()))?;
+    let bytes = hex::decode(contents.trim())
+        .map_err(|e| anyhow!("Failed to decode blob file {}: {e}", path.display()))?;
+    if bytes.len() != BYTES_PER_BLOB {
+        bail!(
+            "Blob file {} holds {} bytes, expected {BYTES_PER_BLOB}",
+            path.display(),
+            bytes.len()
+        );
+    }
+    BlobData::from_blob(&Blob::from_slice(&bytes))
+        .decode()
+        .map_err(|e| anyhow!("Failed to decode blob file {}: {e}", path.display()))
+}
+
+/// Returns the current unix timestamp, in seconds.
+fn unix_now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_batch_cli_raw_inputs() {
+        let args = BatchCommand::parse_from([
+            "batch",
+            "--calldata",
+            "0x00aabb",
+            "--blob",
+            "blob.hex",
+            "--blob",
+            "other.hex",
+        ]);
+        assert_eq!(args.calldata, vec![Bytes::from_static(&[0x00, 0xaa, 0xbb])]);
+        assert_eq!(args.blobs, vec![PathBuf::from("blob.hex"), PathBuf::from("other.hex")]);
+        assert!(args.txs.is_empty());
+    }
+
+    #[test]
+    fn test_batch_cli_tx_requires_rpc() {
+        let hash = B256::with_last_byte(1).to_string();
+        assert!(BatchCommand::try_parse_from(["batch", "--tx", hash.as_str()]).is_err());
+
+        let args = BatchCommand::try_parse_from([
+            "batch",
+            "--tx",
+            hash.as_str(),
+            "--l1-eth-rpc",
+            "http://localhost:8545",
+        ])
+        .unwrap();
+        assert_eq!(args.txs, vec![B256::with_last_byte(1)]);
+    }
+}
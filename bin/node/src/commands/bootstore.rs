@@ -1,19 +1,31 @@
 //! Bootstore Subcommand
 
 use crate::flags::GlobalArgs;
-use clap::Parser;
+use anyhow::{Context, bail};
+use clap::{Parser, Subcommand, ValueEnum};
+use discv5::{
+    ConfigBuilder, Discv5, Enr, ListenConfig,
+    enr::{CombinedKey, NodeId},
+};
+use futures::future::join_all;
 use kona_cli::LogConfig;
-use kona_peers::BootStore;
-use std::path::PathBuf;
+use kona_peers::{BootNode, BootStore, EnrValidation, NodeRecord};
+use std::{
+    net::Ipv4Addr,
+    path::{Path, PathBuf},
+    str::FromStr,
+    time::Duration,
+};
 
 /// The `bootstore` Subcommand
 ///
 /// The `bootstore` subcommand can be used to interact with local bootstores.
+/// Without a subcommand, it prints information about the bootstore.
 ///
 /// # Usage
 ///
 /// ```sh
-/// kona-node bootstore [FLAGS] [OPTIONS]
+/// kona-node bootstore [FLAGS] [OPTIONS] [SUBCOMMAND]
 /// ```
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Utility tool to interact with local bootstores")]
@@ -25,6 +37,74 @@ pub struct BootstoreCommand {
     /// The directory to store the bootstore.
     #[arg(long = "p2p.bootstore", env = "KONA_NODE_P2P_BOOTSTORE")]
     pub bootstore: Option<PathBuf>,
+    /// The operation to run on the bootstore of the chain configured with `--l2-chain-id`.
+    #[command(subcommand)]
+    pub command: Option<BootstoreSubcommand>,
+}
+
+/// Operations on a bootstore.
+#[derive(Subcommand, PartialEq, Debug, Clone)]
+pub enum BootstoreSubcommand {
+    /// Adds ENRs to the bootstore.
+    Add {
+        /// The ENRs to add.
+        #[arg(required = true)]
+        enrs: Vec<Enr>,
+    },
+    /// Removes nodes from the bootstore, given their ENR or hex-encoded node id.
+    #[command(alias = "rm")]
+    Remove {
+        /// The ENRs or node ids to remove.
+        #[arg(required = true)]
+        nodes: Vec<String>,
+    },
+    /// Pings every node in the bootstore over discv5 and removes those that do not respond.
+    Prune {
+        /// The time to wait for the nodes to respond, in seconds.
+        #[arg(long, default_value = "5")]
+        timeout: u64,
+        /// The port used by the discovery service sending the pings. Defaults to a random port.
+        #[arg(long = "disc-port", default_value = "0")]
+        disc_port: u16,
+        /// Only prints the unreachable nodes, without removing them.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Merges other bootstore files into the bootstore.
+    Merge {
+        /// Paths to the `bootstore.json` files to merge.
+        #[arg(required = true)]
+        paths: Vec<PathBuf>,
+    },
+    /// Imports nodes from a text file holding one ENR or enode per line.
+    ///
+    /// The ENRs of enodes are requested from the nodes over discv5, since enodes do not carry the
+    /// OP Stack ENR entry.
+    Import {
+        /// The file to import nodes from. Empty lines and lines starting with `#` are ignored.
+        path: PathBuf,
+        /// The port used by the discovery service resolving enodes. Defaults to a random port.
+        #[arg(long = "disc-port", default_value = "0")]
+        disc_port: u16,
+    },
+    /// Exports the nodes of the bootstore as text, one node per line.
+    Export {
+        /// The format of the exported nodes.
+        #[arg(long, value_enum, default_value_t = NodeFormat::Enr)]
+        format: NodeFormat,
+        /// The file to write the nodes to. Defaults to stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
+    },
+}
+
+/// The text format of an exported node.
+#[derive(ValueEnum, PartialEq, Eq, Debug, Clone, Copy)]
+pub enum NodeFormat {
+    /// The base64-encoded `enr:` format.
+    Enr,
+    /// The `enode://` URL format.
+    Enode,
 }
 
 impl BootstoreCommand {
@@ -35,13 +115,113 @@ impl BootstoreCommand {
     }
 
     /// Runs the subcommand.
-    pub fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
-        println!("--------------------------");
-        if self.all {
-            self.all()?;
-        } else {
-            self.info(args.l2_chain_id.into())?;
+    pub async fn run(self, args: &GlobalArgs) -> anyhow::Result<()> {
+        let chain_id = args.l2_chain_id.into();
+        let Some(command) = self.command.clone() else {
+            println!("--------------------------");
+            if self.all {
+                self.all()?;
+            } else {
+                self.info(chain_id)?;
+            }
+            return Ok(());
+        };
+
+        let mut store = BootStore::from_chain_id(chain_id, self.bootstore.clone(), vec![]);
+        match command {
+            BootstoreSubcommand::Add { enrs } => {
+                let added = add_valid(&mut store, enrs, chain_id);
+                println!("Added {added} ENRs");
+            }
+            BootstoreSubcommand::Remove { nodes } => {
+                let ids = nodes
+                    .iter()
+                    .map(|node| parse_node_id(node))
+                    .collect::<anyhow::Result<Vec<_>>>()?;
+                let removed = store.retain(|enr| !ids.contains(&enr.node_id()));
+                println!("Removed {removed} ENRs");
+            }
+            BootstoreSubcommand::Prune { timeout, disc_port, dry_run } => {
+                let mut disc = start_discv5(disc_port).await?;
+                let pings = store.peers.iter().cloned().map(|enr| {
+                    let ping = disc.send_ping(enr.clone());
+                    async move {
+                        let reachable =
+                            tokio::time::timeout(Duration::from_secs(timeout), ping).await;
+                        (enr, matches!(reachable, Ok(Ok(_))))
+                    }
+                });
+                let unreachable = join_all(pings)
+                    .await
+                    .into_iter()
+                    .filter_map(|(enr, reachable)| (!reachable).then_some(enr))
+                    .collect::<Vec<_>>();
+                disc.shutdown();
+
+                for enr in &unreachable {
+                    println!("Unreachable: {enr}");
+                }
+                if dry_run {
+                    println!("Found {} unreachable ENRs", unreachable.len());
+                    return Ok(());
+                }
+                let removed = store.retain(|enr| !unreachable.contains(enr));
+                println!("Removed {removed} unreachable ENRs");
+            }
+            BootstoreSubcommand::Merge { paths } => {
+                let mut merged = 0;
+                for path in paths {
+                    if !path.exists() {
+                        bail!("Bootstore {} does not exist", path.display());
+                    }
+                    let other = BootStore::from_file(&path);
+                    merged += add_valid(&mut store, other.peers, chain_id);
+                }
+                println!("Merged {merged} ENRs");
+            }
+            BootstoreSubcommand::Import { path, disc_port } => {
+                let nodes = read_nodes(&path)?;
+                let mut enrs = Vec::with_capacity(nodes.len());
+                let mut disc = None;
+                for node in nodes {
+                    match node {
+                        BootNode::Enr(enr) => enrs.push(enr),
+                        BootNode::Enode(addr) => {
+                            if disc.is_none() {
+                                disc = Some(start_discv5(disc_port).await?);
+                            }
+                            let disc = disc.as_ref().expect("discv5 service started above");
+                            match disc.request_enr(addr.clone()).await {
+                                Ok(enr) => enrs.push(enr),
+                                Err(e) => println!("Failed to request ENR of {addr}: {e:?}"),
+                            }
+                        }
+                    }
+                }
+                if let Some(mut disc) = disc {
+                    disc.shutdown();
+                }
+                let imported = add_valid(&mut store, enrs, chain_id);
+                println!("Imported {imported} ENRs");
+            }
+            BootstoreSubcommand::Export { format, output } => {
+                let lines = store
+                    .peers
+                    .iter()
+                    .filter_map(|enr| format_node(enr, format))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                match output {
+                    Some(path) => std::fs::write(&path, lines + "\n")
+                        .with_context(|| format!("Failed to write {}", path.display()))?,
+                    None => println!("{lines}"),
+                }
+                return Ok(());
+            }
         }
+
+        store.write_to_file().context("Failed to write bootstore")?;
+        println!("Bootstore {} now holds {} ENRs", store.path.display(), store.len());
         Ok(())
     }
 
@@ -67,3 +247,144 @@ impl BootstoreCommand {
         Ok(())
     }
 }
+
+/// Adds the ENRs that are valid for the given chain id to the store, returning the number of
+/// ENRs added.
+fn add_valid(store: &mut BootStore, enrs: impl IntoIterator<Item = Enr>, chain_id: u64) -> usize {
+    let len = store.len();
+    let valid = enrs.into_iter().filter(|enr| {
+        let validation = EnrValidation::validate(enr, chain_id);
+        if validation.is_invalid() {
+            println!("Skipping ENR ({validation}): {enr}");
+        }
+        validation.is_valid()
+    });
+    store.merge(valid.collect::<Vec<_>>());
+    store.len() - len
+}
+
+/// Parses a node id from either an ENR or a hex-encoded node id.
+fn parse_node_id(node: &str) -> anyhow::Result<NodeId> {
+    if node.starts_with("enr:") {
+        let enr = Enr::from_str(node).map_err(|e| anyhow::anyhow!("Invalid ENR {node}: {e}"))?;
+        return Ok(enr.node_id());
+    }
+    NodeId::parse(&alloy_primitives::hex::decode(node)?)
+        .map_err(|e| anyhow::anyhow!("Invalid node id {node}: {e}"))
+}
+
+/// Parses a node in either the ENR or enode format.
+fn parse_node(line: &str) -> anyhow::Result<BootNode> {
+    if line.starts_with("enode://") {
+        let record = NodeRecord::from_str(line)?;
+        return Ok(BootNode::from_unsigned(record)?);
+    }
+    let enr = Enr::from_str(line).map_err(|e| anyhow::anyhow!("Invalid ENR {line}: {e}"))?;
+    Ok(BootNode::Enr(enr))
+}
+
+/// Reads the nodes listed in a text file, one per line.
+fn read_nodes(path: &Path) -> anyhow::Result<Vec<BootNode>> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(parse_node)
+        .collect()
+}
+
+/// Formats an ENR in the given [`NodeFormat`].
+///
+/// Returns `None` if the ENR cannot be represented as an enode.
+fn format_node(enr: &Enr, format: NodeFormat) -> Option<String> {
+    match format {
+        NodeFormat::Enr => Some(enr.to_base64()),
+        NodeFormat::Enode => NodeRecord::from_enr(enr).map(|record| record.to_string()),
+    }
+}
+
+/// Starts a temporary discv5 service, used to ping nodes and request their ENRs.
+async fn start_discv5(port: u16) -> anyhow::Result<Discv5> {
+    let key = CombinedKey::generate_secp256k1();
+    let enr = Enr::builder().build(&key)?;
+    let config = ConfigBuilder::new(ListenConfig::Ipv4 { ip: Ipv4Addr::UNSPECIFIED, port }).build();
+    let mut disc = Discv5::new(enr, key, config).map_err(|e| anyhow::anyhow!(e))?;
+    disc.start().await.map_err(|e| anyhow::anyhow!("Failed to start discv5: {e:?}"))?;
+    Ok(disc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENODE: &str = "enode://2bd2e657bb3c8efffb8ff6db9071d9eb7be70d7c6d7d980ff80fc93b2629675c5f750bc0a5ef27cd788c2e491b8795a7e9a4a6e72178c14acc6753c0e5d77ae4@34.65.205.244:30305";
+
+    fn enr() -> Enr {
+        let key = CombinedKey::generate_secp256k1();
+        Enr::builder().ip4([10, 0, 0, 1].into()).tcp4(9222).udp4(9223).build(&key).unwrap()
+    }
+
+    #[test]
+    fn test_bootstore_subcommands() {
+        let args = BootstoreCommand::parse_from(["test"]);
+        assert_eq!(args.command, None);
+
+        let enr = enr();
+        let args = BootstoreCommand::parse_from(["test", "add", &enr.to_base64()]);
+        assert_eq!(args.command, Some(BootstoreSubcommand::Add { enrs: vec![enr] }));
+
+        let args = BootstoreCommand::parse_from(["test", "export", "--format", "enode"]);
+        assert_eq!(
+            args.command,
+            Some(BootstoreSubcommand::Export { format: NodeFormat::Enode, output: None })
+        );
+
+        let args = BootstoreCommand::parse_from(["test", "prune", "--dry-run"]);
+        assert_eq!(
+            args.command,
+            Some(BootstoreSubcommand::Prune { timeout: 5, disc_port: 0, dry_run: true })
+        );
+
+        assert!(BootstoreCommand::try_parse_from(["test", "add"]).is_err());
+        assert!(BootstoreCommand::try_parse_from(["test", "add", "not-an-enr"]).is_err());
+    }
+
+    #[test]
+    fn test_parse_node_id() {
+        let enr = enr();
+        assert_eq!(parse_node_id(&enr.to_base64()).unwrap(), enr.node_id());
+        assert_eq!(
+            parse_node_id(&alloy_primitives::hex::encode(enr.node_id().raw())).unwrap(),
+            enr.node_id()
+        );
+        assert!(parse_node_id("0xdead").is_err());
+    }
+
+    #[test]
+    fn test_read_nodes() {
+        let enr = enr();
+        let dir = std::env::temp_dir().join("kona-bootstore-import");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("nodes.txt");
+        std::fs::write(&path, format!("# bootnodes\n{}\n\n{ENODE}\n", enr.to_base64())).unwrap();
+
+        let nodes = read_nodes(&path).unwrap();
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0], BootNode::Enr(enr));
+        assert!(matches!(nodes[1], BootNode::Enode(_)));
+    }
+
+    #[test]
+    fn test_format_node() {
+        let enr = enr();
+        let formatted = format_node(&enr, NodeFormat::Enr).unwrap();
+        assert_eq!(parse_node(&formatted).unwrap(), BootNode::Enr(enr.clone()));
+
+        let enode = format_node(&enr, NodeFormat::Enode).unwrap();
+        assert!(enode.starts_with("enode://"));
+        assert!(enode.ends_with("@10.0.0.1:9222?discport=9223"));
+        assert!(matches!(parse_node(&enode).unwrap(), BootNode::Enode(_)));
+    }
+}
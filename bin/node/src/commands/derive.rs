@@ -0,0 +1,388 @@
+//! Derive Subcommand
+
+use crate::flags::GlobalArgs;
+use alloy_provider::RootProvider;
+use anyhow::{Result, anyhow, bail};
+use clap::Parser;
+use kona_cli::LogConfig;
+use kona_derive::{
+    ActivationSignal, ChainProvider, EthereumDataSource, L2ChainProvider, OriginProvider, Pipeline,
+    PipelineBuilder, PipelineError, PipelineErrorKind, ResetError, ResetSignal, SignalReceiver,
+    StatefulAttributesBuilder, StepResult,
+};
+use kona_genesis::RollupConfig;
+use kona_protocol::{BatchValidationProvider, OpAttributesWithParent};
+use kona_providers_alloy::{
+    AlloyChainProvider, AlloyL2ChainProvider, DerivationRecording, OnlineBeaconClient,
+    OnlineBlobProvider, RecordedBlobProvider, RecordedChainProvider, RecordedL2ChainProvider,
+};
+use op_alloy_network::Optimism;
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+use tracing::{debug, info, warn};
+use url::Url;
+
+/// The size of the cache used in the providers of the upstream RPCs.
+const PROVIDER_CACHE_SIZE: usize = 1024;
+
+/// The file of a recording directory holding the rollup configuration.
+const ROLLUP_CONFIG_FILE: &str = "rollup.json";
+
+/// The `derive` Subcommand
+///
+/// The `derive` subcommand runs the derivation pipeline over a range of L1 blocks, without a
+/// running node, and outputs the derived payload attributes as JSON.
+///
+/// The chain data is either fetched from RPCs, or replayed from a recording directory captured
+/// with `--record`. After each derived payload, the safe head is advanced to the canonical L2
+/// block at the next height.
+///
+/// # Usage
+///
+/// ```sh
+/// # Derive from RPCs, recording the chain data.
+/// kona-node derive --l1-eth-rpc <URL> --l1-beacon <URL> --l2-provider-rpc <URL> \
+///     --l2-safe-head <NUMBER> --l1-end <NUMBER> --record <DIR>
+///
+/// # Replay the recording offline.
+/// kona-node derive --recording <DIR> --l2-safe-head <NUMBER> --l1-end <NUMBER>
+/// ```
+#[derive(Parser, Default, PartialEq, Debug, Clone)]
+#[command(about = "Runs the derivation pipeline over a range of L1 blocks")]
+pub struct DeriveCommand {
+    /// URL of the L1 execution client RPC API.
+    #[arg(
+        long,
+        visible_alias = "l1",
+        requires_all = ["l1_beacon", "l2_provider_rpc"],
+        required_unless_present = "recording"
+    )]
+    pub l1_eth_rpc: Option<Url>,
+    /// URL of the L1 beacon API.
+    #[arg(long, visible_alias = "l1.beacon", requires = "l1_eth_rpc")]
+    pub l1_beacon: Option<Url>,
+    /// URL of the L2 execution client RPC API.
+    #[arg(long, visible_alias = "l2.provider", requires = "l1_eth_rpc")]
+    pub l2_provider_rpc: Option<Url>,
+    /// Directory of a recording to replay the chain data from, instead of fetching it from RPCs.
+    #[arg(long, conflicts_with_all = ["l1_eth_rpc", "record"])]
+    pub recording: Option<PathBuf>,
+    /// Directory to record the chain data read by the pipeline to, for later offline replays.
+    #[arg(long, requires = "l1_eth_rpc")]
+    pub record: Option<PathBuf>,
+    /// Path to a custom L2 rollup configuration file. Defaults to the rollup configuration of the
+    /// recording, if any, and to the rollup configuration from the registry otherwise.
+    #[arg(long, visible_alias = "rollup-cfg")]
+    pub l2_config_file: Option<PathBuf>,
+    /// Number of the L2 safe head to derive from.
+    #[arg(long)]
+    pub l2_safe_head: u64,
+    /// Number of the first L1 block to read. Defaults to the L1 origin of the L2 safe head.
+    #[arg(long)]
+    pub l1_start: Option<u64>,
+    /// Number of the last L1 block to read.
+    #[arg(long)]
+    pub l1_end: u64,
+    /// Path of the file to write the derived payload attributes to, as a JSON array. Without it,
+    /// the payload attributes are printed to stdout, one JSON object per line.
+    #[arg(long, short = 'o')]
+    pub output: Option<PathBuf>,
+}
+
+impl DeriveCommand {
+    /// Initializes the logging system based on global arguments.
+    pub fn init_logs(&self, args: &GlobalArgs) -> Result<()> {
+        LogConfig::new(args.log_args.clone()).init_tracing_subscriber(None)?;
+        Ok(())
+    }
+
+    /// Runs the subcommand.
+    pub async fn run(self, args: &GlobalArgs) -> Result<()> {
+        let cfg = Arc::new(self.rollup_config(args)?);
+
+        let recording = match &self.recording {
+            Some(dir) => DerivationRecording::load(dir)?,
+            None => DerivationRecording::default(),
+        };
+        let recording = Arc::new(Mutex::new(recording));
+
+        let (chain_upstream, blob_upstream, l2_upstream) = match &self.l1_eth_rpc {
+            Some(l1_eth_rpc) => {
+                let (Some(l1_beacon), Some(l2_provider_rpc)) =
+                    (&self.l1_beacon, &self.l2_provider_rpc)
+                else {
+                    bail!("--l1-beacon and --l2-provider-rpc are required with --l1-eth-rpc");
+                };
+                let beacon = OnlineBeaconClient::new_http(l1_beacon.to_string());
+                (
+                    Some(AlloyChainProvider::new_http(l1_eth_rpc.clone(), PROVIDER_CACHE_SIZE)),
+                    Some(OnlineBlobProvider::init(beacon).await),
+                    Some(AlloyL2ChainProvider::new(
+                        RootProvider::<Optimism>::new_http(l2_provider_rpc.clone()),
+                        cfg.clone(),
+                        PROVIDER_CACHE_SIZE,
+                    )),
+                )
+            }
+            None => (None, None, None),
+        };
+        let mut chain_provider = RecordedChainProvider::new(recording.clone(), chain_upstream);
+        let blob_provider = RecordedBlobProvider::new(recording.clone(), blob_upstream);
+        let mut l2_provider =
+            RecordedL2ChainProvider::new(recording.clone(), cfg.clone(), l2_upstream);
+
+        let derived =
+            self.derive(cfg.clone(), &mut chain_provider, blob_provider, &mut l2_provider).await;
+
+        // The chain data read so far is recorded even if derivation failed, so that the failure
+        // can be replayed offline.
+        if let Some(dir) = &self.record {
+            match (&derived, store_recording(dir, &recording, &cfg)) {
+                (Ok(_), Err(e)) => return Err(e),
+                (Err(_), Err(e)) => warn!(target: "derive", "Failed to record chain data: {e}"),
+                _ => info!(target: "derive", dir = %dir.display(), "Recorded chain data"),
+            }
+        }
+
+        let derived = derived?;
+        info!(target: "derive", count = derived.len(), "Derived payload attributes");
+
+        if let Some(path) = &self.output {
+            let file = File::create(path)
+                .map_err(|e| anyhow!("Failed to create output file {}: {e}", path.display()))?;
+            serde_json::to_writer_pretty(file, &derived)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the polled derivation pipeline from the L2 safe head until the L1 end block, and
+    /// returns the derived payload attributes.
+    async fn derive(
+        &self,
+        cfg: Arc<RollupConfig>,
+        chain_provider: &mut RecordedChainProvider,
+        blob_provider: RecordedBlobProvider,
+        l2_provider: &mut RecordedL2ChainProvider,
+    ) -> Result<Vec<OpAttributesWithParent>> {
+        let mut safe_head = l2_provider.l2_block_info_by_number(self.l2_safe_head).await?;
+        let l1_start = self.l1_start.unwrap_or(safe_head.l1_origin.number);
+        if l1_start > self.l1_end {
+            bail!("L1 start block {l1_start} is after the L1 end block {}", self.l1_end);
+        }
+        let l1_origin = chain_provider.block_info_by_number(l1_start).await?;
+
+        let attributes = StatefulAttributesBuilder::new(
+            cfg.clone(),
+            l2_provider.clone(),
+            chain_provider.clone(),
+        );
+        let dap = EthereumDataSource::new_from_parts(chain_provider.clone(), blob_provider, &cfg);
+        let mut pipeline = PipelineBuilder::new()
+            .rollup_config(cfg.clone())
+            .dap_source(dap)
+            .l2_chain_provider(l2_provider.clone())
+            .chain_provider(chain_provider.clone())
+            .builder(attributes)
+            .origin(l1_origin)
+            .build_polled();
+
+        let system_config =
+            l2_provider.system_config_by_number(safe_head.block_info.number, cfg.clone()).await?;
+        pipeline
+            .signal(
+                ResetSignal {
+                    l2_safe_head: safe_head,
+                    l1_origin,
+                    system_config: Some(system_config),
+                }
+                .signal(),
+            )
+            .await?;
+        info!(
+            target: "derive",
+            safe_head = safe_head.block_info.number,
+            l1_start,
+            l1_end = self.l1_end,
+            "Deriving payload attributes"
+        );
+
+        let mut derived = Vec::new();
+        loop {
+            let origin = pipeline.origin().ok_or(PipelineError::MissingOrigin.crit())?;
+            if origin.number > self.l1_end {
+                break;
+            }
+
+            match pipeline.step(safe_head).await {
+                StepResult::PreparedAttributes => {
+                    let Some(attributes) = pipeline.next() else {
+                        continue;
+                    };
+                    if self.output.is_none() {
+                        println!("{}", serde_json::to_string(&attributes)?);
+                    }
+                    derived.push(attributes);
+
+                    // Without an execution client to build the payload, the safe head advances
+                    // to the canonical L2 block at the next height.
+                    safe_head = l2_provider
+                        .l2_block_info_by_number(safe_head.block_info.number + 1)
+                        .await?;
+                }
+                StepResult::AdvancedOrigin => {
+                    debug!(target: "derive", l1_block = origin.number, "Advanced origin");
+                }
+                StepResult::OriginAdvanceErr(e) | StepResult::StepFailed(e) => match e {
+                    // The L1 blocks after the end block may not exist, or may not be recorded.
+                    PipelineErrorKind::Temporary(PipelineError::Provider(e))
+                        if origin.number >= self.l1_end =>
+                    {
+                        info!(target: "derive", "Reached the L1 end block: {e}");
+                        break;
+                    }
+                    PipelineErrorKind::Temporary(PipelineError::Provider(e))
+                        if self.recording.is_some() =>
+                    {
+                        bail!("The recording is missing chain data: {e}");
+                    }
+                    PipelineErrorKind::Temporary(_) => continue,
+                    PipelineErrorKind::Reset(ResetError::HoloceneActivation) => {
+                        let system_config = l2_provider
+                            .system_config_by_number(safe_head.block_info.number, cfg.clone())
+                            .await?;
+                        pipeline
+                            .signal(
+                                ActivationSignal {
+                                    l2_safe_head: safe_head,
+                                    l1_origin: origin,
+                                    system_config: Some(system_config),
+                                }
+                                .signal(),
+                            )
+                            .await?;
+                    }
+                    e => bail!("Derivation failed at L1 block {}: {e}", origin.number),
+                },
+            }
+        }
+
+        Ok(derived)
+    }
+
+    /// Returns the L2 rollup config, from the config file, the recording or the registry.
+    fn rollup_config(&self, args: &GlobalArgs) -> Result<RollupConfig> {
+        let recorded =
+            self.recording.as_ref().map(|dir| dir.join(ROLLUP_CONFIG_FILE)).filter(|p| p.exists());
+        args.rollup_config(self.l2_config_file.as_ref().or(recorded.as_ref()).map(PathBuf::as_path))
+    }
+}
+
+/// Stores the recorded chain data and the rollup configuration in the given directory.
+fn store_recording(
+    dir: &Path,
+    recording: &Mutex<DerivationRecording>,
+    cfg: &RollupConfig,
+) -> Result<()> {
+    let recording = recording.lock().map_err(|_| anyhow!("Recording lock poisoned"))?;
+    recording.store(dir)?;
+    write_rollup_config(&dir.join(ROLLUP_CONFIG_FILE), cfg)
+}
+
+/// Writes the rollup configuration to the given path.
+fn write_rollup_config(path: &Path, cfg: &RollupConfig) -> Result<()> {
+    let file = File::create(path)
+        .map_err(|e| anyhow!("Failed to create rollup config file {}: {e}", path.display()))?;
+    serde_json::to_writer_pretty(file, cfg)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_derive_cli_rpc() {
+        let args = DeriveCommand::parse_from([
+            "derive",
+            "--l1-eth-rpc",
+            "http://localhost:8545",
+            "--l1-beacon",
+            "http://localhost:5052",
+            "--l2-provider-rpc",
+            "http://localhost:9545",
+            "--l2-safe-head",
+            "100",
+            "--l1-end",
+            "20",
+            "--record",
+            "/tmp/recording",
+        ]);
+        assert_eq!(args.l2_safe_head, 100);
+        assert_eq!(args.l1_start, None);
+        assert_eq!(args.l1_end, 20);
+        assert_eq!(args.record, Some(PathBuf::from("/tmp/recording")));
+        assert_eq!(args.recording, None);
+    }
+
+    #[test]
+    fn test_derive_cli_recording() {
+        let args = DeriveCommand::parse_from([
+            "derive",
+            "--recording",
+            "/tmp/recording",
+            "--l2-safe-head",
+            "100",
+            "--l1-start",
+            "10",
+            "--l1-end",
+            "20",
+            "-o",
+            "/tmp/attributes.json",
+        ]);
+        assert_eq!(args.recording, Some(PathBuf::from("/tmp/recording")));
+        assert_eq!(args.l1_start, Some(10));
+        assert_eq!(args.output, Some(PathBuf::from("/tmp/attributes.json")));
+        assert_eq!(args.l1_eth_rpc, None);
+    }
+
+    #[test]
+    fn test_derive_cli_invalid_sources() {
+        // Neither RPCs nor a recording.
+        assert!(
+            DeriveCommand::try_parse_from(["derive", "--l2-safe-head", "1", "--l1-end", "2"])
+                .is_err()
+        );
+        // The L1 RPC requires the beacon API and the L2 RPC.
+        assert!(
+            DeriveCommand::try_parse_from([
+                "derive",
+                "--l1-eth-rpc",
+                "http://localhost:8545",
+                "--l2-safe-head",
+                "1",
+                "--l1-end",
+                "2",
+            ])
+            .is_err()
+        );
+        // Recording requires RPCs.
+        assert!(
+            DeriveCommand::try_parse_from([
+                "derive",
+                "--recording",
+                "/tmp/recording",
+                "--record",
+                "/tmp/other",
+                "--l2-safe-head",
+                "1",
+                "--l1-end",
+                "2",
+            ])
+            .is_err()
+        );
+    }
+}
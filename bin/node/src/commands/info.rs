@@ -1,9 +1,12 @@
 //! Info Subcommand
 
 use crate::flags::GlobalArgs;
+use anyhow::bail;
 use clap::Parser;
 use kona_cli::LogConfig;
+use kona_genesis::RollupConfig;
 use kona_registry::{OPCHAINS, ROLLUP_CONFIGS};
+use std::path::PathBuf;
 use tracing::info;
 
 /// The `info` Subcommand
@@ -15,10 +18,28 @@ use tracing::info;
 /// ```sh
 /// kona-node info
 /// ```
-
+///
+/// With `--check-config`, the rollup config is validated instead, and the command fails if the
+/// config has errors. This is meant to lint configs in CI, before deploying them.
+///
+/// ```sh
+/// kona-node info --check-config --l2-config-file rollup.json
+/// ```
 #[derive(Parser, Default, PartialEq, Debug, Clone)]
 #[command(about = "Runs the information stack for the kona-node.")]
-pub struct InfoCommand;
+pub struct InfoCommand {
+    /// Validates the rollup config and reports its warnings and errors, exiting with an error if
+    /// the config is invalid.
+    #[arg(long)]
+    pub check_config: bool,
+    /// Path to a custom L2 rollup configuration file to check. Defaults to the rollup
+    /// configuration from the registry.
+    #[arg(long, visible_alias = "rollup-cfg", requires = "check_config")]
+    pub l2_config_file: Option<PathBuf>,
+    /// Treats the warnings of the config check as errors.
+    #[arg(long, requires = "check_config")]
+    pub deny_warnings: bool,
+}
 
 impl InfoCommand {
     /// Initializes the logging system based on global arguments.
@@ -29,6 +50,10 @@ impl InfoCommand {
 
     /// Runs the information stack for the kona-node.
     pub fn run(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        if self.check_config {
+            return self.check_config(args);
+        }
+
         info!(target: "node_info", "Running info command");
 
         let op_chain_config = OPCHAINS.get(&args.l2_chain_id.id()).expect("No Chain config found");
@@ -46,4 +71,64 @@ impl InfoCommand {
 
         Ok(())
     }
+
+    /// Validates the rollup config, against the config of the same chain in the superchain
+    /// registry if the chain is registered.
+    fn check_config(&self, args: &GlobalArgs) -> anyhow::Result<()> {
+        let config = args.rollup_config(self.l2_config_file.as_deref())?;
+        let report = match Self::registered_config(&config) {
+            Some(registered) => config.validate_against(registered),
+            None => config.validate(),
+        };
+
+        for warning in &report.warnings {
+            println!("warning: {warning}");
+        }
+        for error in &report.errors {
+            println!("error: {error}");
+        }
+
+        let (warnings, errors) = (report.warnings.len(), report.errors.len());
+        if errors > 0 || (self.deny_warnings && warnings > 0) {
+            bail!("Rollup config check failed with {errors} error(s) and {warnings} warning(s)");
+        }
+        println!("Rollup config check passed with {warnings} warning(s)");
+        Ok(())
+    }
+
+    /// Returns the registered config of the chain the given config is for.
+    ///
+    /// The chain is identified by its L2 genesis hash, so that a config using the genesis of a
+    /// registered chain with another chain ID is matched to it. Configs of chains with an
+    /// unregistered genesis are matched by their L2 chain ID.
+    fn registered_config(config: &RollupConfig) -> Option<&'static RollupConfig> {
+        ROLLUP_CONFIGS
+            .values()
+            .find(|registered| registered.genesis.l2.hash == config.genesis.l2.hash)
+            .or_else(|| ROLLUP_CONFIGS.get(&config.l2_chain_id.id()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flags::GlobalArgs;
+
+    #[test]
+    fn test_registry_configs_pass_check() {
+        for chain_id in [10, 8453, 11155420, 84532] {
+            let args = GlobalArgs { l2_chain_id: chain_id.into(), ..Default::default() };
+            let command = InfoCommand { check_config: true, ..Default::default() };
+            assert!(command.run(&args).is_ok(), "config check failed for chain {chain_id}");
+        }
+    }
+
+    #[test]
+    fn test_registered_config_matches_genesis() {
+        let mut config = ROLLUP_CONFIGS.get(&10).unwrap().clone();
+        config.l2_chain_id = 11.into();
+        let registered = InfoCommand::registered_config(&config).unwrap();
+        assert_eq!(registered.l2_chain_id.id(), 10);
+        assert!(!config.validate_against(registered).is_valid());
+    }
 }
@@ -14,3 +14,9 @@ pub use net::NetCommand;
 
 mod registry;
 pub use registry::RegistryCommand;
+
+mod derive;
+pub use derive::DeriveCommand;
+
+mod batch;
+pub use batch::BatchCommand;
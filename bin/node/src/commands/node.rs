@@ -9,12 +9,17 @@ use anyhow::{Result, bail};
 use backon::{ExponentialBuilder, Retryable};
 use clap::Parser;
 use kona_cli::{LogConfig, metrics_args::MetricsArgs};
+use kona_engine::SecondaryEngineConfig;
 use kona_genesis::RollupConfig;
-use kona_node_service::{NodeMode, RollupNode, RollupNodeService};
+use kona_node_service::{
+    DerivationCheckpointConfig, DerivationEventRecorder, NodeMode, RollupNode, RollupNodeService,
+    SequencerConfig,
+};
+use kona_providers_alloy::PrefetchConfig;
 use kona_registry::scr_rollup_config_by_alloy_ident;
 use op_alloy_provider::ext::engine::OpEngineApi;
 use serde_json::from_reader;
-use std::{fs::File, path::PathBuf, sync::Arc};
+use std::{fs::File, path::PathBuf, sync::Arc, time::Duration};
 use strum::IntoEnumIterator;
 use tracing::{debug, error, info};
 use url::Url;
@@ -58,10 +63,56 @@ pub struct NodeCommand {
     /// This MUST be a valid path to a file containing the hex-encoded JWT secret.
     #[arg(long, visible_alias = "l2.jwt-secret", env = "KONA_NODE_L2_ENGINE_AUTH")]
     pub l2_engine_jwt_secret: Option<PathBuf>,
+    /// URL of the engine API endpoint of a secondary L2 execution client.
+    /// The `engine_newPayload` and `engine_forkchoiceUpdated` calls made to the primary execution
+    /// client are mirrored to it, and divergences between both clients are reported.
+    #[arg(long, visible_alias = "l2.secondary", env = "KONA_NODE_L2_SECONDARY_ENGINE_RPC")]
+    pub l2_secondary_engine_rpc: Option<Url>,
+    /// JWT secret for the auth-rpc endpoint of the secondary execution client.
+    /// Defaults to the JWT secret of the primary execution client.
+    #[arg(
+        long,
+        visible_alias = "l2.secondary.jwt-secret",
+        env = "KONA_NODE_L2_SECONDARY_ENGINE_AUTH",
+        requires = "l2_secondary_engine_rpc"
+    )]
+    pub l2_secondary_engine_jwt_secret: Option<PathBuf>,
     /// Path to a custom L2 rollup configuration file
     /// (overrides the default rollup configuration from the registry)
     #[arg(long, visible_alias = "rollup-cfg", env = "KONA_NODE_ROLLUP_CONFIG")]
     pub l2_config_file: Option<PathBuf>,
+    /// Path of the file the derivation pipeline is periodically checkpointed to.
+    /// On restart, derivation resumes from the checkpoint if it is still consistent with L1 and
+    /// the safe head, instead of re-reading L1 from the safe head's L1 origin.
+    #[arg(long = "derivation.checkpoint", env = "KONA_NODE_DERIVATION_CHECKPOINT")]
+    pub derivation_checkpoint: Option<PathBuf>,
+    /// The minimum interval between two derivation pipeline checkpoints, in seconds.
+    #[arg(
+        long = "derivation.checkpoint-interval",
+        default_value_t = 60,
+        env = "KONA_NODE_DERIVATION_CHECKPOINT_INTERVAL"
+    )]
+    pub derivation_checkpoint_interval: u64,
+    /// Path of the file indexing the derived L2 blocks awaiting finalization by L1 origin.
+    /// The index survives restarts and resets, so that L2 blocks are finalized as soon as their
+    /// L1 origin is finalized, including right after a restart.
+    #[arg(long = "finality.index", env = "KONA_NODE_FINALITY_INDEX")]
+    pub finality_index: Option<PathBuf>,
+    /// Path of the file the derivation pipeline events are appended to, one JSON object per line.
+    /// The events record every frame, channel and batch accepted or dropped by the pipeline.
+    #[arg(long = "derivation.events", env = "KONA_NODE_DERIVATION_EVENTS")]
+    pub derivation_events: Option<PathBuf>,
+    /// The number of L1 blocks the derivation pipeline data is prefetched ahead, concurrently.
+    /// Prefetching speeds up derivation when catching up with L1. Set to 0 to disable it.
+    #[arg(long = "l1.prefetch-depth", default_value_t = 8, env = "KONA_NODE_L1_PREFETCH_DEPTH")]
+    pub l1_prefetch_depth: u64,
+    /// The maximum size of the prefetched L1 data, in MiB.
+    #[arg(
+        long = "l1.prefetch-max-mib",
+        default_value_t = 256,
+        env = "KONA_NODE_L1_PREFETCH_MAX_MIB"
+    )]
+    pub l1_prefetch_max_mib: usize,
     /// P2P CLI arguments.
     #[command(flatten)]
     pub p2p_flags: P2PArgs,
@@ -84,7 +135,13 @@ impl Default for NodeCommand {
             l2_engine_rpc: Url::parse("http://localhost:8551").unwrap(),
             l2_provider_rpc: Url::parse("http://localhost:8545").unwrap(),
             l2_engine_jwt_secret: None,
+            l2_secondary_engine_rpc: None,
+            l2_secondary_engine_jwt_secret: None,
             l2_config_file: None,
+            derivation_checkpoint: None,
+            derivation_checkpoint_interval: 60,
+            finality_index: None,
+            derivation_events: None,
             node_mode: NodeMode::Validator,
             p2p_flags: P2PArgs::default(),
             rpc_flags: RpcArgs::default(),
@@ -195,6 +252,7 @@ impl NodeCommand {
         args.metrics.enabled.then(|| init_rollup_config_metrics(&cfg));
 
         let jwt_secret = self.validate_jwt(&cfg).await?;
+        let secondary_engine = self.secondary_engine_config(jwt_secret)?;
 
         let supervisor_rpc_config =
             match (self.supervisor_flags.as_rpc_config(), self.supervisor_flags.rpc_enabled) {
@@ -205,6 +263,27 @@ impl NodeCommand {
 
         self.p2p_flags.check_ports()?;
         let p2p_config = self.p2p_flags.config(&cfg, args, Some(self.l1_eth_rpc.clone())).await?;
+        // State changes made through the admin API are persisted by the sequencer.
+        let sequencer_config = SequencerConfig {
+            admin_state_path: self.rpc_flags.admin_persistence.clone(),
+            ..self.sequencer_flags.config()?
+        };
+        let derivation_checkpoint = self.derivation_checkpoint_config();
+        let l1_prefetch = self.l1_prefetch_config();
+        let finality_index = self.finality_index.clone();
+        let derivation_events = self
+            .derivation_events
+            .as_ref()
+            .map(|path| {
+                DerivationEventRecorder::create(path).map_err(|e| {
+                    anyhow::anyhow!(
+                        "Failed to open derivation events file {}: {}",
+                        path.display(),
+                        e
+                    )
+                })
+            })
+            .transpose()?;
         let rpc_config = self.rpc_flags.into();
 
         info!(
@@ -216,24 +295,29 @@ impl NodeCommand {
             info!(target: "rollup_node", "{hf}");
         }
 
-        RollupNode::builder(cfg)
+        let mut builder = RollupNode::builder(cfg)
             .with_mode(self.node_mode)
             .with_jwt_secret(jwt_secret)
             .with_l1_provider_rpc_url(self.l1_eth_rpc)
             .with_l1_beacon_api_url(self.l1_beacon)
             .with_l2_provider_rpc_url(self.l2_provider_rpc)
             .with_l2_engine_rpc_url(self.l2_engine_rpc)
+            .with_secondary_engine(secondary_engine)
             .with_p2p_config(p2p_config)
             .with_rpc_config(rpc_config)
             .with_supervisor_rpc_config(supervisor_rpc_config.unwrap_or_default())
-            .with_sequencer_config(self.sequencer_flags.config())
-            .build()
-            .start()
-            .await
-            .map_err(|e| {
-                error!(target: "rollup_node", "Failed to start rollup node service: {e}");
-                anyhow::anyhow!("{}", e)
-            })?;
+            .with_sequencer_config(sequencer_config)
+            .with_derivation_checkpoint(derivation_checkpoint)
+            .with_l1_prefetch(l1_prefetch)
+            .with_finality_index(finality_index);
+        if let Some(recorder) = derivation_events {
+            builder = builder.with_derivation_event_sink(Arc::new(recorder));
+        }
+
+        builder.build().start().await.map_err(|e| {
+            error!(target: "rollup_node", "Failed to start rollup node service: {e}");
+            anyhow::anyhow!("{}", e)
+        })?;
 
         Ok(())
     }
@@ -269,6 +353,45 @@ impl NodeCommand {
         Self::default_jwt_secret()
     }
 
+    /// Returns the [`SecondaryEngineConfig`] if a secondary execution client is configured.
+    ///
+    /// The JWT secret of the primary execution client is used, unless a JWT secret file is
+    /// specified for the secondary one.
+    pub fn secondary_engine_config(
+        &self,
+        primary_jwt_secret: JwtSecret,
+    ) -> Result<Option<SecondaryEngineConfig>> {
+        let Some(url) = self.l2_secondary_engine_rpc.clone() else {
+            return Ok(None);
+        };
+
+        let jwt_secret = match &self.l2_secondary_engine_jwt_secret {
+            Some(path) => {
+                let secret = std::fs::read_to_string(path).map_err(|e| {
+                    anyhow::anyhow!("Failed to read secondary engine JWT secret: {}", e)
+                })?;
+                JwtSecret::from_hex(secret)
+                    .map_err(|e| anyhow::anyhow!("Invalid secondary engine JWT secret: {}", e))?
+            }
+            None => primary_jwt_secret,
+        };
+
+        Ok(Some(SecondaryEngineConfig { url, jwt_secret }))
+    }
+
+    /// Returns the [`DerivationCheckpointConfig`], if derivation pipeline checkpoints are enabled.
+    pub fn derivation_checkpoint_config(&self) -> Option<DerivationCheckpointConfig> {
+        self.derivation_checkpoint.clone().map(|path| DerivationCheckpointConfig {
+            path,
+            interval: Duration::from_secs(self.derivation_checkpoint_interval),
+        })
+    }
+
+    /// Returns the [`PrefetchConfig`] of the L1 data read by the derivation pipeline.
+    pub const fn l1_prefetch_config(&self) -> PrefetchConfig {
+        PrefetchConfig { depth: self.l1_prefetch_depth, max_bytes: self.l1_prefetch_max_mib << 20 }
+    }
+
     /// Uses the current directory to attempt to read
     /// the JWT secret from a file named `jwt.hex`.
     /// If the file is not found, it will return `None`.
@@ -355,4 +478,126 @@ mod tests {
         .unwrap_err();
         assert!(err.to_string().contains("--l2-provider-rpc"));
     }
+
+    #[test]
+    fn test_node_cli_secondary_engine() {
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert_eq!(args.l2_secondary_engine_rpc, None);
+        assert!(args.secondary_engine_config(JwtSecret::random()).unwrap().is_none());
+
+        let args = NodeCommand::parse_from(
+            ["node", "--l2.secondary", "http://localhost:9551"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        let jwt_secret = JwtSecret::random();
+        let config = args.secondary_engine_config(jwt_secret).unwrap().unwrap();
+        assert_eq!(config.url, Url::parse("http://localhost:9551").unwrap());
+        assert_eq!(config.jwt_secret, jwt_secret);
+    }
+
+    #[test]
+    fn test_node_cli_secondary_engine_jwt_secret() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secondary.hex");
+        let secret = JwtSecret::random();
+        std::fs::write(&path, alloy_primitives::hex::encode(secret.as_bytes())).unwrap();
+
+        let args = NodeCommand::parse_from(
+            [
+                "node",
+                "--l2.secondary",
+                "http://localhost:9551",
+                "--l2.secondary.jwt-secret",
+                path.to_str().unwrap(),
+            ]
+            .iter()
+            .chain(default_flags().iter())
+            .copied(),
+        );
+        let config = args.secondary_engine_config(JwtSecret::random()).unwrap().unwrap();
+        assert_eq!(config.jwt_secret, secret);
+    }
+
+    #[test]
+    fn test_node_cli_secondary_engine_jwt_secret_requires_url() {
+        let err = NodeCommand::try_parse_from(
+            ["node", "--l2.secondary.jwt-secret", "secondary.hex"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("--l2-secondary-engine-rpc"));
+    }
+
+    #[test]
+    fn test_node_cli_derivation_checkpoint() {
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert_eq!(args.derivation_checkpoint_config(), None);
+
+        let args = NodeCommand::parse_from(
+            [
+                "node",
+                "--derivation.checkpoint",
+                "/tmp/derivation.json",
+                "--derivation.checkpoint-interval",
+                "30",
+            ]
+            .iter()
+            .chain(default_flags().iter())
+            .copied(),
+        );
+        assert_eq!(
+            args.derivation_checkpoint_config(),
+            Some(DerivationCheckpointConfig {
+                path: PathBuf::from("/tmp/derivation.json"),
+                interval: Duration::from_secs(30),
+            })
+        );
+    }
+
+    #[test]
+    fn test_node_cli_finality_index() {
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert_eq!(args.finality_index, None);
+
+        let args = NodeCommand::parse_from(
+            ["node", "--finality.index", "/tmp/finality.json"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert_eq!(args.finality_index, Some(PathBuf::from("/tmp/finality.json")));
+    }
+
+    #[test]
+    fn test_node_cli_derivation_events() {
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert_eq!(args.derivation_events, None);
+
+        let args = NodeCommand::parse_from(
+            ["node", "--derivation.events", "/tmp/events.jsonl"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert_eq!(args.derivation_events, Some(PathBuf::from("/tmp/events.jsonl")));
+    }
+
+    #[test]
+    fn test_node_cli_l1_prefetch() {
+        let args = NodeCommand::parse_from(["node"].iter().chain(default_flags().iter()).copied());
+        assert_eq!(args.l1_prefetch_config(), PrefetchConfig { depth: 8, max_bytes: 256 << 20 });
+
+        let args = NodeCommand::parse_from(
+            ["node", "--l1.prefetch-depth", "0", "--l1.prefetch-max-mib", "64"]
+                .iter()
+                .chain(default_flags().iter())
+                .copied(),
+        );
+        assert_eq!(args.l1_prefetch_config(), PrefetchConfig { depth: 0, max_bytes: 64 << 20 });
+        assert!(!args.l1_prefetch_config().is_enabled());
+    }
 }
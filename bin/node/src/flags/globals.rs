@@ -4,7 +4,8 @@ use alloy_primitives::Address;
 use clap::Parser;
 use kona_cli::{log::LogArgs, metrics_args::MetricsArgs};
 use kona_genesis::RollupConfig;
-use kona_registry::OPCHAINS;
+use kona_registry::{OPCHAINS, scr_rollup_config_by_alloy_ident};
+use std::{fs::File, path::Path};
 
 /// Global arguments for the CLI.
 #[derive(Parser, Default, Clone, Debug)]
@@ -39,6 +40,27 @@ impl GlobalArgs {
         self.override_args.apply(config)
     }
 
+    /// Loads the rollup config from the given file, or from the superchain registry for the l2
+    /// chain id if no file is given, and applies the overrides to it.
+    pub fn rollup_config(&self, path: Option<&Path>) -> anyhow::Result<RollupConfig> {
+        let config = match path {
+            Some(path) => {
+                let file = File::open(path).map_err(|e| {
+                    anyhow::anyhow!("Failed to open l2 config file {}: {e}", path.display())
+                })?;
+                serde_json::from_reader(file)
+                    .map_err(|e| anyhow::anyhow!("Failed to parse l2 config: {e}"))?
+            }
+            None => scr_rollup_config_by_alloy_ident(&self.l2_chain_id)
+                .ok_or(anyhow::anyhow!(
+                    "Failed to find l2 config for chain ID {}",
+                    self.l2_chain_id
+                ))?
+                .clone(),
+        };
+        Ok(self.apply_overrides(config))
+    }
+
     /// Returns the signer [`Address`] from the rollup config for the given l2 chain id.
     pub fn genesis_signer(&self) -> anyhow::Result<Address> {
         let id = self.l2_chain_id;
@@ -18,5 +18,8 @@ pub use metrics::init_unified_metrics;
 mod sequencer;
 pub use sequencer::SequencerArgs;
 
+mod signer;
+pub use signer::SignerArgs;
+
 mod supervisor;
 pub use supervisor::SupervisorArgs;
@@ -4,7 +4,7 @@
 //!
 //! [op-node]: https://github.com/ethereum-optimism/optimism/blob/develop/op-node/flags/p2p_flags.go
 
-use crate::flags::GlobalArgs;
+use crate::flags::{GlobalArgs, SignerArgs};
 use alloy_primitives::B256;
 use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
@@ -12,13 +12,13 @@ use anyhow::Result;
 use clap::Parser;
 use discv5::{Enr, enr::k256};
 use kona_genesis::RollupConfig;
-use kona_node_service::NetworkConfig;
-use kona_p2p::{GaterConfig, LocalNode};
+use kona_node_service::{BlockSigner, LocalBlockSigner, NetworkConfig, RemoteBlockSigner};
+use kona_p2p::{GaterConfig, LocalNode, PublishRetryConfig, TransportConfig, TransportSecurity};
 use kona_peers::{PeerMonitoring, PeerScoreLevel};
 use kona_sources::RuntimeLoader;
 use libp2p::identity::Keypair;
 use std::{
-    net::{IpAddr, SocketAddr},
+    net::{IpAddr, Ipv6Addr, SocketAddr},
     num::ParseIntError,
     path::PathBuf,
     str::FromStr,
@@ -76,6 +76,19 @@ pub struct P2PArgs {
     /// UDP port to bind Discv5 to. Same as TCP port if left 0.
     #[arg(long = "p2p.listen.udp", default_value = "9223", env = "KONA_NODE_P2P_LISTEN_UDP_PORT")]
     pub listen_udp_port: u16,
+    /// UDP port to bind the LibP2P QUIC transport to. QUIC is disabled if not set.
+    ///
+    /// Must differ from the discovery UDP port.
+    #[arg(long = "p2p.listen.quic", env = "KONA_NODE_P2P_LISTEN_QUIC_PORT")]
+    pub listen_quic_port: Option<u16>,
+    /// IPv6 address to additionally bind LibP2P to, for dual-stack listening.
+    /// The TCP and QUIC ports are the same as for the `p2p.listen.ip` address.
+    #[arg(long = "p2p.listen.ip6", env = "KONA_NODE_P2P_LISTEN_IP6")]
+    pub listen_ip6: Option<Ipv6Addr>,
+    /// The security protocol used to encrypt LibP2P TCP connections.
+    /// Can be one of: noise or tls. QUIC connections always use TLS.
+    #[arg(long = "p2p.security", default_value = "noise", env = "KONA_NODE_P2P_SECURITY")]
+    pub security: TransportSecurity,
     /// Low-tide peer count. The node actively searches for new peer connections if below this
     /// amount.
     #[arg(long = "p2p.peers.lo", default_value = "20", env = "KONA_NODE_P2P_PEERS_LO")]
@@ -212,8 +225,35 @@ pub struct P2PArgs {
     pub discovery_randomize: Option<u64>,
 
     /// An optional flag to specify the private key of the sequencer, used to sign unsafe blocks.
-    #[arg(long = "p2p.sequencer.key", env = "KONA_NODE_P2P_SEQUENCER_KEY")]
+    #[arg(
+        long = "p2p.sequencer.key",
+        env = "KONA_NODE_P2P_SEQUENCER_KEY",
+        conflicts_with = "signer_endpoint"
+    )]
     pub sequencer_key: Option<B256>,
+
+    /// Remote signer CLI arguments, used to sign unsafe blocks without holding the sequencer key.
+    #[command(flatten)]
+    pub signer: SignerArgs,
+
+    /// The maximum number of times the publication of an unsafe block is retried when it fails
+    /// with a transient error, such as not having enough peers in the gossip mesh.
+    /// Retries are disabled if set to 0.
+    #[arg(
+        long = "p2p.publish.retries",
+        default_value = "5",
+        env = "KONA_NODE_P2P_PUBLISH_RETRIES"
+    )]
+    pub publish_retries: u32,
+
+    /// The interval in milliseconds between two attempts to publish an unsafe block.
+    #[arg(
+        long = "p2p.publish.retry-interval",
+        default_value = "250",
+        env = "KONA_NODE_P2P_PUBLISH_RETRY_INTERVAL",
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    pub publish_retry_interval: u64,
 }
 
 impl Default for P2PArgs {
@@ -400,12 +440,25 @@ impl P2PArgs {
         let mut gossip_address = libp2p::Multiaddr::from(self.listen_ip);
         gossip_address.push(libp2p::multiaddr::Protocol::Tcp(self.listen_tcp_port));
 
-        let local_signer = self
-            .sequencer_key
-            .as_ref()
-            .map(PrivateKeySigner::from_bytes)
-            .transpose()?
-            .map(|s| s.with_chain_id(Some(args.l2_chain_id.into())));
+        if self.listen_quic_port.is_some_and(|port| port != 0 && port == self.listen_udp_port) {
+            anyhow::bail!("The QUIC port must differ from the discovery UDP port");
+        }
+        let transport = TransportConfig {
+            security: self.security,
+            quic_port: self.listen_quic_port,
+            ipv6: self.listen_ip6,
+        };
+
+        let block_signer = match self.signer.config() {
+            Some(remote) => Some(Arc::new(RemoteBlockSigner::new(remote)?) as Arc<dyn BlockSigner>),
+            None => self
+                .sequencer_key
+                .as_ref()
+                .map(PrivateKeySigner::from_bytes)
+                .transpose()?
+                .map(|s| s.with_chain_id(Some(args.l2_chain_id.into())))
+                .map(|s| Arc::new(LocalBlockSigner::new(s)) as Arc<dyn BlockSigner>),
+        };
 
         Ok(NetworkConfig {
             discovery_config,
@@ -413,6 +466,7 @@ impl P2PArgs {
             discovery_address,
             discovery_randomize: self.discovery_randomize.map(Duration::from_secs),
             gossip_address,
+            transport,
             keypair,
             unsafe_block_signer: self.unsafe_block_signer(config, args, l1_rpc).await?,
             gossip_config,
@@ -426,7 +480,11 @@ impl P2PArgs {
             },
             bootnodes: self.bootnodes,
             rollup_config: config.clone(),
-            local_signer,
+            block_signer,
+            publish_retry: PublishRetryConfig {
+                max_retries: self.publish_retries,
+                retry_interval: Duration::from_millis(self.publish_retry_interval),
+            },
         })
     }
 
@@ -455,7 +513,7 @@ impl P2PArgs {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alloy_primitives::b256;
+    use alloy_primitives::{address, b256};
     use clap::Parser;
 
     /// A mock command that uses the P2PArgs.
@@ -516,6 +574,29 @@ mod tests {
         assert_eq!(args.p2p.discovery_randomize, None);
     }
 
+    #[test]
+    fn test_p2p_args_transport() {
+        let args = MockCommand::parse_from(["test"]);
+        assert_eq!(args.p2p.listen_quic_port, None);
+        assert_eq!(args.p2p.listen_ip6, None);
+        assert_eq!(args.p2p.security, TransportSecurity::Noise);
+
+        let args = MockCommand::parse_from([
+            "test",
+            "--p2p.listen.quic",
+            "9224",
+            "--p2p.listen.ip6",
+            "::",
+            "--p2p.security",
+            "tls",
+        ]);
+        assert_eq!(args.p2p.listen_quic_port, Some(9224));
+        assert_eq!(args.p2p.listen_ip6, Some(Ipv6Addr::UNSPECIFIED));
+        assert_eq!(args.p2p.security, TransportSecurity::Tls);
+
+        assert!(MockCommand::try_parse_from(["test", "--p2p.security", "plaintext"]).is_err());
+    }
+
     #[test]
     fn test_p2p_args_no_discovery() {
         let args = MockCommand::parse_from(["test", "--p2p.no-discovery"]);
@@ -550,6 +631,74 @@ mod tests {
         assert_eq!(args.p2p.sequencer_key, Some(key));
     }
 
+    #[test]
+    fn test_p2p_args_remote_signer() {
+        let args = MockCommand::parse_from(["test"]);
+        assert_eq!(args.p2p.signer.config(), None);
+
+        let args = MockCommand::parse_from([
+            "test",
+            "--signer.endpoint",
+            "https://signer.local:8080",
+            "--signer.address",
+            "0x000000000000000000000000000000000000dead",
+            "--signer.tls.cert",
+            "tls/tls.crt",
+            "--signer.tls.key",
+            "tls/tls.key",
+        ]);
+        let config = args.p2p.signer.config().unwrap();
+        assert_eq!(config.endpoint.as_str(), "https://signer.local:8080/");
+        assert_eq!(config.address, address!("0x000000000000000000000000000000000000dead"));
+        assert_eq!(config.ca_cert, None);
+        assert_eq!(config.client_cert.unwrap().key, PathBuf::from("tls/tls.key"));
+        assert_eq!(config.timeout, Duration::from_secs(10));
+
+        // The signer address is required alongside the endpoint.
+        assert!(
+            MockCommand::try_parse_from(["test", "--signer.endpoint", "https://signer.local"])
+                .is_err()
+        );
+
+        // The remote signer cannot be used alongside a local sequencer key.
+        assert!(
+            MockCommand::try_parse_from([
+                "test",
+                "--signer.endpoint",
+                "https://signer.local",
+                "--signer.address",
+                "0x000000000000000000000000000000000000dead",
+                "--p2p.sequencer.key",
+                "bcc617ea05150ff60490d3c6058630ba94ae9f12a02a87efd291349ca0e54e0a",
+            ])
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_p2p_args_publish_retries() {
+        let args = MockCommand::parse_from(["test"]);
+        assert_eq!(args.p2p.publish_retries, kona_p2p::DEFAULT_PUBLISH_MAX_RETRIES);
+        assert_eq!(
+            Duration::from_millis(args.p2p.publish_retry_interval),
+            kona_p2p::DEFAULT_PUBLISH_RETRY_INTERVAL
+        );
+
+        let args = MockCommand::parse_from([
+            "test",
+            "--p2p.publish.retries",
+            "0",
+            "--p2p.publish.retry-interval",
+            "1000",
+        ]);
+        assert_eq!(args.p2p.publish_retries, 0);
+        assert_eq!(args.p2p.publish_retry_interval, 1000);
+
+        assert!(
+            MockCommand::try_parse_from(["test", "--p2p.publish.retry-interval", "0"]).is_err()
+        );
+    }
+
     #[test]
     fn test_p2p_args_listen_ip() {
         let args = MockCommand::parse_from(["test", "--p2p.listen.ip", "127.0.0.1"]);
@@ -3,7 +3,7 @@
 //! Flags for configuring the RPC server.
 
 use clap::Parser;
-use kona_rpc::RpcBuilder;
+use kona_rpc::{ReadinessConfig, RpcBuilder, SequencerHealthConfig};
 use std::{
     net::{IpAddr, SocketAddr},
     path::PathBuf,
@@ -37,6 +37,78 @@ pub struct RpcArgs {
     /// Enables development RPC endpoints for engine state introspection
     #[arg(long = "rpc.dev-enabled", default_value = "false", env = "KONA_NODE_RPC_DEV_ENABLED")]
     pub dev_enabled: bool,
+    /// Enables the development RPC endpoints that drop engine tasks and reset the engine.
+    /// Requires `--rpc.dev-enabled`.
+    #[arg(
+        long = "rpc.dev-admin-enabled",
+        default_value = "false",
+        requires = "dev_enabled",
+        env = "KONA_NODE_RPC_DEV_ADMIN_ENABLED"
+    )]
+    pub dev_admin_enabled: bool,
+    /// The maximum age of the unsafe head, in seconds on top of one block time, before
+    /// `optimism_sequencerHealth` reports the sequencer as unhealthy.
+    #[arg(
+        long = "rpc.health.unsafe-interval",
+        default_value = "10",
+        env = "KONA_NODE_RPC_HEALTH_UNSAFE_INTERVAL"
+    )]
+    pub health_unsafe_interval: u64,
+    /// The maximum age of the safe head, in seconds, before `optimism_sequencerHealth` reports
+    /// the sequencer as unhealthy. The safe head is not checked if set to 0.
+    #[arg(
+        long = "rpc.health.safe-interval",
+        default_value = "0",
+        env = "KONA_NODE_RPC_HEALTH_SAFE_INTERVAL"
+    )]
+    pub health_safe_interval: u64,
+    /// The minimum number of connected gossip peers for `optimism_sequencerHealth` to report the
+    /// sequencer as healthy.
+    #[arg(
+        long = "rpc.health.min-peer-count",
+        default_value = "1",
+        env = "KONA_NODE_RPC_HEALTH_MIN_PEER_COUNT"
+    )]
+    pub health_min_peer_count: u64,
+    /// Serve the `/healthz` liveness and `/readyz` readiness HTTP probes on the RPC server.
+    #[arg(
+        long = "rpc.probes.enabled",
+        default_value = "false",
+        env = "KONA_NODE_RPC_PROBES_ENABLED"
+    )]
+    pub probes_enabled: bool,
+    /// The maximum age of the unsafe head, in seconds, before `/readyz` reports the node as not
+    /// ready.
+    #[arg(
+        long = "rpc.probes.max-unsafe-head-age",
+        default_value = "60",
+        env = "KONA_NODE_RPC_PROBES_MAX_UNSAFE_HEAD_AGE"
+    )]
+    pub probes_max_unsafe_head_age: u64,
+    /// The maximum age of the latest L1 head, in seconds, before `/readyz` reports the node as not
+    /// ready.
+    #[arg(
+        long = "rpc.probes.max-l1-head-age",
+        default_value = "60",
+        env = "KONA_NODE_RPC_PROBES_MAX_L1_HEAD_AGE"
+    )]
+    pub probes_max_l1_head_age: u64,
+    /// The maximum number of L1 blocks between the latest L1 head and the L1 origin of the safe
+    /// head before `/readyz` reports the node as not ready. Derivation progress is not checked if
+    /// set to 0.
+    #[arg(
+        long = "rpc.probes.max-derivation-lag",
+        default_value = "300",
+        env = "KONA_NODE_RPC_PROBES_MAX_DERIVATION_LAG"
+    )]
+    pub probes_max_derivation_lag: u64,
+    /// The minimum number of connected gossip peers for `/readyz` to report the node as ready.
+    #[arg(
+        long = "rpc.probes.min-peer-count",
+        default_value = "1",
+        env = "KONA_NODE_RPC_PROBES_MIN_PEER_COUNT"
+    )]
+    pub probes_min_peer_count: u64,
 }
 
 impl Default for RpcArgs {
@@ -59,6 +131,19 @@ impl From<RpcArgs> for Option<RpcBuilder> {
             admin_persistence: args.admin_persistence,
             ws_enabled: args.ws_enabled,
             dev_enabled: args.dev_enabled,
+            dev_admin_enabled: args.dev_admin_enabled,
+            health: SequencerHealthConfig {
+                unsafe_interval: args.health_unsafe_interval,
+                safe_interval: (args.health_safe_interval > 0).then_some(args.health_safe_interval),
+                min_peer_count: args.health_min_peer_count,
+            },
+            probes: args.probes_enabled.then(|| ReadinessConfig {
+                max_unsafe_head_age: args.probes_max_unsafe_head_age,
+                max_l1_head_age: args.probes_max_l1_head_age,
+                max_derivation_lag: (args.probes_max_derivation_lag > 0)
+                    .then_some(args.probes_max_derivation_lag),
+                min_peer_count: args.probes_min_peer_count,
+            }),
         })
     }
 }
@@ -77,6 +162,13 @@ mod tests {
     #[case::disable_rpc_alias(&["--rpc.port", "8743"], |args: &mut RpcArgs| { args.listen_port = 8743; })]
     #[case::disable_rpc(&["--rpc.enable-admin"], |args: &mut RpcArgs| { args.enable_admin = true; })]
     #[case::disable_rpc(&["--rpc.admin-state", "/"], |args: &mut RpcArgs| { args.admin_persistence = Some(PathBuf::from("/")); })]
+    #[case::dev_admin(&["--rpc.dev-enabled", "--rpc.dev-admin-enabled"], |args: &mut RpcArgs| { args.dev_enabled = true; args.dev_admin_enabled = true; })]
+    #[case::health_unsafe_interval(&["--rpc.health.unsafe-interval", "20"], |args: &mut RpcArgs| { args.health_unsafe_interval = 20; })]
+    #[case::health_safe_interval(&["--rpc.health.safe-interval", "1200"], |args: &mut RpcArgs| { args.health_safe_interval = 1200; })]
+    #[case::health_min_peer_count(&["--rpc.health.min-peer-count", "3"], |args: &mut RpcArgs| { args.health_min_peer_count = 3; })]
+    #[case::probes_enabled(&["--rpc.probes.enabled"], |args: &mut RpcArgs| { args.probes_enabled = true; })]
+    #[case::probes_max_unsafe_head_age(&["--rpc.probes.max-unsafe-head-age", "30"], |args: &mut RpcArgs| { args.probes_max_unsafe_head_age = 30; })]
+    #[case::probes_max_derivation_lag(&["--rpc.probes.max-derivation-lag", "0"], |args: &mut RpcArgs| { args.probes_max_derivation_lag = 0; })]
     fn test_parse_rpc_args(#[case] args: &[&str], #[case] mutate: impl Fn(&mut RpcArgs)) {
         let args = [&["kona-node"], args].concat();
         let cli = RpcArgs::parse_from(args);
@@ -84,4 +176,38 @@ mod tests {
         mutate(&mut expected);
         assert_eq!(cli, expected);
     }
+
+    #[test]
+    fn test_health_config() {
+        let builder = Option::<RpcBuilder>::from(RpcArgs::default()).unwrap();
+        assert_eq!(builder.health, SequencerHealthConfig::default());
+
+        let args = RpcArgs::parse_from(["kona-node", "--rpc.health.safe-interval", "1200"]);
+        let builder = Option::<RpcBuilder>::from(args).unwrap();
+        assert_eq!(builder.health.safe_interval, Some(1200));
+    }
+
+    #[test]
+    fn test_probes_config() {
+        let builder = Option::<RpcBuilder>::from(RpcArgs::default()).unwrap();
+        assert_eq!(builder.probes, None);
+
+        let args = RpcArgs::parse_from([
+            "kona-node",
+            "--rpc.probes.enabled",
+            "--rpc.probes.max-derivation-lag",
+            "0",
+        ]);
+        let probes = Option::<RpcBuilder>::from(args).unwrap().probes.unwrap();
+        assert_eq!(probes, ReadinessConfig { max_derivation_lag: None, ..Default::default() });
+
+        // The probes are served by the RPC server.
+        let args = RpcArgs::parse_from(["kona-node", "--rpc.disabled", "--rpc.probes.enabled"]);
+        assert!(Option::<RpcBuilder>::from(args).is_none());
+    }
+
+    #[test]
+    fn test_dev_admin_requires_dev() {
+        assert!(RpcArgs::try_parse_from(["kona-node", "--rpc.dev-admin-enabled"]).is_err());
+    }
 }
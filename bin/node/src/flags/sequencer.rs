@@ -4,9 +4,17 @@
 //!
 //! [op-node]: https://github.com/ethereum-optimism/optimism/blob/develop/op-node/flags/flags.go#L233-L265
 
+use anyhow::{Context, bail};
 use clap::Parser;
-use kona_node_service::SequencerConfig;
-use std::{num::ParseIntError, time::Duration};
+use kona_node_service::{
+    ForcedTransaction, PayloadAttributesHookConfig, PayloadAttributesHookKind, SequencerConfig,
+};
+use std::{
+    fs::File,
+    num::ParseIntError,
+    path::{Path, PathBuf},
+    time::Duration,
+};
 use url::Url;
 
 /// Sequencer CLI Flags
@@ -44,6 +52,8 @@ pub struct SequencerArgs {
     pub recover: bool,
 
     /// Conductor service rpc endpoint. Providing this value will enable the conductor service.
+    /// The sequencer stops when it loses the conductor leadership, and is started by the
+    /// conductor through `admin_startSequencer` when it gains it.
     #[arg(long = "conductor.rpc", env = "KONA_NODE_CONDUCTOR_RPC")]
     pub conductor_rpc: Option<Url>,
 
@@ -55,6 +65,28 @@ pub struct SequencerArgs {
         value_parser = |arg: &str| -> Result<Duration, ParseIntError> {Ok(Duration::from_secs(arg.parse()?))}
     )]
     pub conductor_rpc_timeout: Duration,
+
+    /// Comma-separated list of the payload attributes hooks applied, in order, to the blocks
+    /// built by the sequencer. Supported hooks are: "recovery", "gas-limit", "forced-txs".
+    #[arg(
+        long = "sequencer.hooks",
+        value_delimiter = ',',
+        default_value = "recovery",
+        env = "KONA_NODE_SEQUENCER_HOOKS"
+    )]
+    pub hooks: Vec<PayloadAttributesHookKind>,
+
+    /// The gas limit of the blocks built by the sequencer, used by the "gas-limit" hook. Not
+    /// applied to the blocks whose system config gas limit differs, as verifiers would derive
+    /// them with the system config gas limit.
+    #[arg(long = "sequencer.gas-limit", env = "KONA_NODE_SEQUENCER_GAS_LIMIT")]
+    pub gas_limit: Option<u64>,
+
+    /// Path to a JSON file listing the transactions forced into the blocks built by the
+    /// sequencer, used by the "forced-txs" hook. Each entry is an object with the `block` number
+    /// and the EIP-2718 encoded signed `transaction`.
+    #[arg(long = "sequencer.forced-txs", env = "KONA_NODE_SEQUENCER_FORCED_TXS")]
+    pub forced_txs: Option<PathBuf>,
 }
 
 impl Default for SequencerArgs {
@@ -67,11 +99,137 @@ impl Default for SequencerArgs {
 
 impl SequencerArgs {
     /// Creates a [`SequencerConfig`] from the [`SequencerArgs`].
-    pub fn config(&self) -> SequencerConfig {
-        SequencerConfig {
+    pub fn config(&self) -> anyhow::Result<SequencerConfig> {
+        Ok(SequencerConfig {
             sequencer_stopped: self.stopped,
             sequencer_recovery_mode: self.recover,
             conductor_rpc_url: self.conductor_rpc.clone(),
+            max_safe_lag: self.max_safe_lag,
+            l1_confs: self.l1_confs,
+            // Configured through the `rpc.admin-state` flag.
+            admin_state_path: None,
+            hooks: self.hooks()?,
+        })
+    }
+
+    /// Returns the configurations of the payload attributes hooks, in order.
+    pub fn hooks(&self) -> anyhow::Result<Vec<PayloadAttributesHookConfig>> {
+        self.hooks
+            .iter()
+            .map(|kind| match kind {
+                PayloadAttributesHookKind::Recovery => Ok(PayloadAttributesHookConfig::Recovery),
+                PayloadAttributesHookKind::GasLimit => {
+                    let gas_limit = self
+                        .gas_limit
+                        .context("The gas-limit hook requires --sequencer.gas-limit")?;
+                    Ok(PayloadAttributesHookConfig::GasLimit(gas_limit))
+                }
+                PayloadAttributesHookKind::ForcedTxs => {
+                    let path = self
+                        .forced_txs
+                        .as_ref()
+                        .context("The forced-txs hook requires --sequencer.forced-txs")?;
+                    Ok(PayloadAttributesHookConfig::ForcedTransactions(Self::load_forced_txs(
+                        path,
+                    )?))
+                }
+            })
+            .collect()
+    }
+
+    /// Loads the [`ForcedTransaction`]s from the JSON file at the given path.
+    fn load_forced_txs(path: &Path) -> anyhow::Result<Vec<ForcedTransaction>> {
+        let file = File::open(path)
+            .with_context(|| format!("Failed to open forced transactions file {path:?}"))?;
+        let transactions: Vec<ForcedTransaction> = serde_json::from_reader(file)
+            .with_context(|| format!("Failed to parse forced transactions file {path:?}"))?;
+
+        if let Some(tx) = transactions.iter().find(|tx| !tx.is_valid()) {
+            bail!("Invalid forced transaction for block {}: {}", tx.block, tx.transaction);
         }
+        Ok(transactions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// A mock command that uses the SequencerArgs.
+    #[derive(Parser, Debug, Clone)]
+    #[command(about = "Mock command")]
+    struct MockCommand {
+        /// Sequencer CLI Flags
+        #[clap(flatten)]
+        pub sequencer: SequencerArgs,
+    }
+
+    #[test]
+    fn test_sequencer_hooks_default() {
+        let args = MockCommand::parse_from(["test"]);
+        assert_eq!(args.sequencer.hooks, vec![PayloadAttributesHookKind::Recovery]);
+        assert_eq!(
+            args.sequencer.config().unwrap().hooks,
+            vec![PayloadAttributesHookConfig::Recovery]
+        );
+    }
+
+    #[test]
+    fn test_sequencer_hooks_gas_limit() {
+        let args = MockCommand::parse_from([
+            "test",
+            "--sequencer.hooks",
+            "gas-limit,recovery",
+            "--sequencer.gas-limit",
+            "60000000",
+        ]);
+        assert_eq!(
+            args.sequencer.hooks().unwrap(),
+            vec![
+                PayloadAttributesHookConfig::GasLimit(60_000_000),
+                PayloadAttributesHookConfig::Recovery
+            ]
+        );
+
+        let args = MockCommand::parse_from(["test", "--sequencer.hooks", "gas-limit"]);
+        assert!(args.sequencer.hooks().is_err());
+    }
+
+    #[test]
+    fn test_sequencer_hooks_forced_txs() {
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"[{{"block":10,"transaction":"0x02aa"}}]"#).unwrap();
+
+        let args = MockCommand::parse_from([
+            "test",
+            "--sequencer.hooks",
+            "forced-txs",
+            "--sequencer.forced-txs",
+            file.path().to_str().unwrap(),
+        ]);
+        let PayloadAttributesHookConfig::ForcedTransactions(txs) =
+            &args.sequencer.hooks().unwrap()[0]
+        else {
+            panic!("expected forced transactions hook");
+        };
+        assert_eq!(txs.len(), 1);
+        assert_eq!(txs[0].block, 10);
+
+        let mut file = tempfile::NamedTempFile::new().unwrap();
+        write!(file, r#"[{{"block":10,"transaction":"0x7eaa"}}]"#).unwrap();
+        let args = MockCommand::parse_from([
+            "test",
+            "--sequencer.hooks",
+            "forced-txs",
+            "--sequencer.forced-txs",
+            file.path().to_str().unwrap(),
+        ]);
+        assert!(args.sequencer.hooks().is_err());
+    }
+
+    #[test]
+    fn test_sequencer_hooks_unknown() {
+        assert!(MockCommand::try_parse_from(["test", "--sequencer.hooks", "unknown"]).is_err());
     }
 }
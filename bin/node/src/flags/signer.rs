@@ -0,0 +1,70 @@
+//! Remote Signer CLI Flags
+//!
+//! These are based on the signer flags from the [`op-node`][op-node] CLI.
+//!
+//! [op-node]: https://github.com/ethereum-optimism/optimism/blob/develop/op-service/signer/cli.go
+
+use alloy_primitives::Address;
+use clap::Parser;
+use kona_node_service::{ClientCert, RemoteSignerConfig};
+use std::{num::ParseIntError, path::PathBuf, time::Duration};
+use url::Url;
+
+/// Remote Signer CLI Flags
+#[derive(Parser, Clone, Debug, PartialEq, Eq)]
+pub struct SignerArgs {
+    /// The op-signer endpoint used to sign unsafe blocks. Providing this value replaces the local
+    /// sequencer key.
+    #[arg(
+        long = "signer.endpoint",
+        env = "KONA_NODE_SIGNER_ENDPOINT",
+        requires = "signer_address"
+    )]
+    pub signer_endpoint: Option<Url>,
+
+    /// The address of the key held by the remote signer.
+    #[arg(long = "signer.address", env = "KONA_NODE_SIGNER_ADDRESS", requires = "signer_endpoint")]
+    pub signer_address: Option<Address>,
+
+    /// Path to a PEM-encoded CA certificate used to verify the remote signer.
+    #[arg(long = "signer.tls.ca", env = "KONA_NODE_SIGNER_TLS_CA")]
+    pub tls_ca: Option<PathBuf>,
+
+    /// Path to the PEM-encoded client certificate used to authenticate against the remote
+    /// signer.
+    #[arg(long = "signer.tls.cert", env = "KONA_NODE_SIGNER_TLS_CERT", requires = "tls_key")]
+    pub tls_cert: Option<PathBuf>,
+
+    /// Path to the PEM-encoded private key of the client certificate.
+    #[arg(long = "signer.tls.key", env = "KONA_NODE_SIGNER_TLS_KEY", requires = "tls_cert")]
+    pub tls_key: Option<PathBuf>,
+
+    /// Remote signer request timeout, in seconds.
+    #[arg(
+        long = "signer.timeout",
+        default_value = "10",
+        env = "KONA_NODE_SIGNER_TIMEOUT",
+        value_parser = |arg: &str| -> Result<Duration, ParseIntError> {Ok(Duration::from_secs(arg.parse()?))}
+    )]
+    pub signer_timeout: Duration,
+}
+
+impl SignerArgs {
+    /// Creates a [`RemoteSignerConfig`] from the [`SignerArgs`], if a remote signer is configured.
+    pub fn config(&self) -> Option<RemoteSignerConfig> {
+        let (endpoint, address) = self.signer_endpoint.clone().zip(self.signer_address)?;
+        let client_cert = self
+            .tls_cert
+            .clone()
+            .zip(self.tls_key.clone())
+            .map(|(cert, key)| ClientCert { cert, key });
+
+        Some(RemoteSignerConfig {
+            endpoint,
+            address,
+            ca_cert: self.tls_ca.clone(),
+            client_cert,
+            timeout: self.signer_timeout,
+        })
+    }
+}
@@ -0,0 +1,199 @@
+//! Engine API capability negotiation.
+//!
+//! The engine api method versions used by the [`EngineClient`] are selected from the hardforks
+//! active at the timestamp of each call. Before calling them, the rollup node exchanges
+//! capabilities with the execution layer through `engine_exchangeCapabilities`, so that an
+//! execution client that does not support the methods required by an upcoming hardfork is caught
+//! before the hardfork activates.
+//!
+//! [`EngineClient`]: crate::EngineClient
+
+use crate::{EngineForkchoiceVersion, EngineGetPayloadVersion, EngineNewPayloadVersion};
+use alloy_transport::{RpcError, TransportErrorKind};
+use kona_genesis::RollupConfig;
+use std::collections::HashSet;
+use thiserror::Error;
+
+/// The engine api methods that may be called by the rollup node, advertised to the execution
+/// layer through `engine_exchangeCapabilities`.
+pub const ENGINE_CAPABILITIES: &[&str] = &[
+    "engine_forkchoiceUpdatedV2",
+    "engine_forkchoiceUpdatedV3",
+    "engine_newPayloadV2",
+    "engine_newPayloadV3",
+    "engine_newPayloadV4",
+    "engine_getPayloadV2",
+    "engine_getPayloadV3",
+    "engine_getPayloadV4",
+];
+
+/// Returns the engine api methods required by the rollup node at the given timestamp.
+pub fn required_engine_methods(cfg: &RollupConfig, timestamp: u64) -> [&'static str; 3] {
+    [
+        EngineForkchoiceVersion::from_cfg(cfg, timestamp).method(),
+        EngineNewPayloadVersion::from_cfg(cfg, timestamp).method(),
+        EngineGetPayloadVersion::from_cfg(cfg, timestamp).method(),
+    ]
+}
+
+/// Engine api methods required from a scheduled hardfork on, which the execution layer does not
+/// support.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingForkCapabilities {
+    /// The name of the hardfork.
+    pub fork: &'static str,
+    /// The activation timestamp of the hardfork.
+    pub activation: u64,
+    /// The missing engine api methods.
+    pub methods: Vec<&'static str>,
+}
+
+/// The result of the validation of the execution layer capabilities against the hardforks of a
+/// [`RollupConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EngineCapabilityReport {
+    /// The engine api methods required by the active hardforks which the execution layer does not
+    /// support.
+    pub missing_active: Vec<&'static str>,
+    /// The engine api methods required by the scheduled hardforks which the execution layer does
+    /// not support, in hardfork activation order.
+    pub missing_upcoming: Vec<MissingForkCapabilities>,
+}
+
+impl EngineCapabilityReport {
+    /// Validates the capabilities supported by the execution layer against the methods required by
+    /// the hardforks active at `now`, and by the hardforks scheduled after `now`.
+    pub fn new(cfg: &RollupConfig, supported: &[String], now: u64) -> Self {
+        let supported = supported.iter().map(String::as_str).collect::<HashSet<_>>();
+        let mut reported = HashSet::new();
+        let mut missing = |timestamp| {
+            required_engine_methods(cfg, timestamp)
+                .into_iter()
+                .filter(|method| !supported.contains(method) && reported.insert(*method))
+                .collect::<Vec<_>>()
+        };
+
+        let missing_active = missing(now);
+
+        let mut upcoming = cfg
+            .hardforks
+            .iter()
+            .filter_map(|(fork, time)| time.filter(|time| *time > now).map(|time| (fork, time)))
+            .collect::<Vec<_>>();
+        upcoming.sort_by_key(|(_, time)| *time);
+        let missing_upcoming = upcoming
+            .into_iter()
+            .filter_map(|(fork, activation)| {
+                let methods = missing(activation);
+                (!methods.is_empty()).then_some(MissingForkCapabilities {
+                    fork,
+                    activation,
+                    methods,
+                })
+            })
+            .collect();
+
+        Self { missing_active, missing_upcoming }
+    }
+
+    /// Returns `true` if the execution layer supports every method required by the active and
+    /// scheduled hardforks.
+    pub fn is_ok(&self) -> bool {
+        self.missing_active.is_empty() && self.missing_upcoming.is_empty()
+    }
+}
+
+/// An error returned by the engine api capability check.
+#[derive(Error, Debug)]
+pub enum EngineCapabilitiesError {
+    /// The `engine_exchangeCapabilities` call failed.
+    #[error("Failed to exchange engine api capabilities: {0}")]
+    Rpc(#[from] RpcError<TransportErrorKind>),
+    /// The execution layer does not support the methods required by the active hardforks.
+    #[error(
+        "The execution layer does not support the engine api methods required by the active hardforks: {}. Upgrade the execution client",
+        .0.join(", ")
+    )]
+    MissingActive(Vec<&'static str>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_genesis::HardForkConfig;
+
+    fn config() -> RollupConfig {
+        RollupConfig {
+            hardforks: HardForkConfig {
+                ecotone_time: Some(100),
+                holocene_time: Some(200),
+                isthmus_time: Some(300),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    fn supported(methods: &[&str]) -> Vec<String> {
+        methods.iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn test_required_engine_methods() {
+        let cfg = config();
+        assert_eq!(
+            required_engine_methods(&cfg, 0),
+            ["engine_forkchoiceUpdatedV2", "engine_newPayloadV2", "engine_getPayloadV2"]
+        );
+        assert_eq!(
+            required_engine_methods(&cfg, 100),
+            ["engine_forkchoiceUpdatedV3", "engine_newPayloadV3", "engine_getPayloadV3"]
+        );
+        assert_eq!(
+            required_engine_methods(&cfg, 300),
+            ["engine_forkchoiceUpdatedV3", "engine_newPayloadV4", "engine_getPayloadV4"]
+        );
+    }
+
+    #[test]
+    fn test_report_all_supported() {
+        let report = EngineCapabilityReport::new(&config(), &supported(ENGINE_CAPABILITIES), 0);
+        assert!(report.is_ok());
+    }
+
+    #[test]
+    fn test_report_missing_upcoming() {
+        let supported = supported(&[
+            "engine_forkchoiceUpdatedV3",
+            "engine_newPayloadV3",
+            "engine_getPayloadV3",
+        ]);
+        let report = EngineCapabilityReport::new(&config(), &supported, 150);
+
+        assert!(report.missing_active.is_empty());
+        assert_eq!(
+            report.missing_upcoming,
+            vec![MissingForkCapabilities {
+                fork: "Isthmus",
+                activation: 300,
+                methods: vec!["engine_newPayloadV4", "engine_getPayloadV4"],
+            }]
+        );
+        assert!(!report.is_ok());
+    }
+
+    #[test]
+    fn test_report_missing_active() {
+        let supported = supported(&[
+            "engine_forkchoiceUpdatedV2",
+            "engine_newPayloadV2",
+            "engine_getPayloadV2",
+            "engine_forkchoiceUpdatedV3",
+        ]);
+        let report = EngineCapabilityReport::new(&config(), &supported, 150);
+
+        assert_eq!(report.missing_active, vec!["engine_newPayloadV3", "engine_getPayloadV3"]);
+        assert_eq!(report.missing_upcoming.len(), 1);
+        assert_eq!(report.missing_upcoming[0].fork, "Isthmus");
+    }
+}
@@ -1,6 +1,9 @@
 //! An Engine API Client.
 
-use crate::Metrics;
+use crate::{
+    ENGINE_CAPABILITIES, EngineCapabilitiesError, EngineCapabilityReport, Metrics, SecondaryEngine,
+    secondary::MirroredCall,
+};
 use alloy_eips::eip1898::BlockNumberOrTag;
 use alloy_network::{AnyNetwork, Network};
 use alloy_primitives::{B256, BlockHash, Bytes};
@@ -47,7 +50,8 @@ pub enum EngineClientError {
     BlockInfoDecodeError(#[from] FromBlockError),
 }
 /// A Hyper HTTP client with a JWT authentication layer.
-type HyperAuthClient<B = Full<Bytes>> = HyperClient<B, AuthService<Client<HttpConnector, B>>>;
+pub(crate) type HyperAuthClient<B = Full<Bytes>> =
+    HyperClient<B, AuthService<Client<HttpConnector, B>>>;
 
 /// An external engine api client
 #[derive(Debug, Deref, Clone)]
@@ -61,11 +65,13 @@ pub struct EngineClient {
     l1_provider: RootProvider,
     /// The [RollupConfig] for the chain used to timestamp which version of the engine api to use.
     cfg: Arc<RollupConfig>,
+    /// The optional secondary execution layer, verifying the calls made to the engine.
+    secondary: Option<SecondaryEngine>,
 }
 
 impl EngineClient {
     /// Creates a new RPC client for the given address and JWT secret.
-    fn rpc_client<T: Network>(addr: Url, jwt: JwtSecret) -> RootProvider<T> {
+    pub(crate) fn rpc_client<T: Network>(addr: Url, jwt: JwtSecret) -> RootProvider<T> {
         let hyper_client = Client::builder(TokioExecutor::new()).build_http::<Full<Bytes>>();
         let auth_layer = AuthLayer::new(jwt);
         let service = ServiceBuilder::new().layer(auth_layer).service(hyper_client);
@@ -88,7 +94,19 @@ impl EngineClient {
         let l2_provider = Self::rpc_client::<Optimism>(l2_rpc, jwt);
         let l1_provider = RootProvider::new_http(l1_rpc);
 
-        Self { engine, l2_provider, l1_provider, cfg }
+        Self { engine, l2_provider, l1_provider, cfg, secondary: None }
+    }
+
+    /// Mirrors the `engine_newPayload` and `engine_forkchoiceUpdated` calls made by this client to
+    /// the given [`SecondaryEngine`].
+    pub fn with_secondary(mut self, secondary: SecondaryEngine) -> Self {
+        self.secondary = Some(secondary);
+        self
+    }
+
+    /// Returns a reference to the [`SecondaryEngine`], if any.
+    pub const fn secondary(&self) -> Option<&SecondaryEngine> {
+        self.secondary.as_ref()
     }
 
     /// Returns a reference to the inner L2 [`RootProvider`].
@@ -114,6 +132,28 @@ impl EngineClient {
         Ok(<RootProvider<Optimism>>::get_block_by_number(&self.l2_provider, numtag).full().await?)
     }
 
+    /// Exchanges engine api capabilities with the execution layer, and validates them against the
+    /// methods required by the hardforks of the [`RollupConfig`] that are active at `now`, or
+    /// scheduled after `now`.
+    ///
+    /// Returns an [`EngineCapabilitiesError::MissingActive`] error if a method required by an
+    /// active hardfork is not supported. Methods missing for scheduled hardforks are reported in
+    /// the returned [`EngineCapabilityReport`].
+    pub async fn check_capabilities(
+        &self,
+        now: u64,
+    ) -> Result<EngineCapabilityReport, EngineCapabilitiesError> {
+        let supported = self
+            .exchange_capabilities(ENGINE_CAPABILITIES.iter().map(ToString::to_string).collect())
+            .await?;
+
+        let report = EngineCapabilityReport::new(&self.cfg, &supported, now);
+        if !report.missing_active.is_empty() {
+            return Err(EngineCapabilitiesError::MissingActive(report.missing_active));
+        }
+        Ok(report)
+    }
+
     /// Fetches the [L2BlockInfo] by [BlockNumberOrTag].
     pub async fn l2_block_info_by_label(
         &self,
@@ -134,12 +174,17 @@ impl OpEngineApi<AnyNetwork, Http<HyperAuthClient>> for EngineClient {
         &self,
         payload: ExecutionPayloadInputV2,
     ) -> TransportResult<PayloadStatus> {
+        let mirrored = self.secondary.as_ref().map(|_| payload.clone());
         let call = <RootProvider<AnyNetwork> as OpEngineApi<
             AnyNetwork,
             Http<HyperAuthClient>,
         >>::new_payload_v2(&self.engine, payload);
 
-        record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await
+        let status = record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await?;
+        if let (Some(secondary), Some(payload)) = (&self.secondary, mirrored) {
+            secondary.mirror(MirroredCall::NewPayloadV2 { payload, primary: status.clone() });
+        }
+        Ok(status)
     }
 
     async fn new_payload_v3(
@@ -147,12 +192,21 @@ impl OpEngineApi<AnyNetwork, Http<HyperAuthClient>> for EngineClient {
         payload: ExecutionPayloadV3,
         parent_beacon_block_root: B256,
     ) -> TransportResult<PayloadStatus> {
+        let mirrored = self.secondary.as_ref().map(|_| payload.clone());
         let call = <RootProvider<AnyNetwork> as OpEngineApi<
             AnyNetwork,
             Http<HyperAuthClient>,
         >>::new_payload_v3(&self.engine, payload, parent_beacon_block_root);
 
-        record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await
+        let status = record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await?;
+        if let (Some(secondary), Some(payload)) = (&self.secondary, mirrored) {
+            secondary.mirror(MirroredCall::NewPayloadV3 {
+                payload,
+                parent_beacon_block_root,
+                primary: status.clone(),
+            });
+        }
+        Ok(status)
     }
 
     async fn new_payload_v4(
@@ -160,12 +214,21 @@ impl OpEngineApi<AnyNetwork, Http<HyperAuthClient>> for EngineClient {
         payload: OpExecutionPayloadV4,
         parent_beacon_block_root: B256,
     ) -> TransportResult<PayloadStatus> {
+        let mirrored = self.secondary.as_ref().map(|_| payload.clone());
         let call = <RootProvider<AnyNetwork> as OpEngineApi<
             AnyNetwork,
             Http<HyperAuthClient>,
         >>::new_payload_v4(&self.engine, payload, parent_beacon_block_root);
 
-        record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await
+        let status = record_call_time(call, Metrics::NEW_PAYLOAD_METHOD).await?;
+        if let (Some(secondary), Some(payload)) = (&self.secondary, mirrored) {
+            secondary.mirror(MirroredCall::NewPayloadV4 {
+                payload,
+                parent_beacon_block_root,
+                primary: status.clone(),
+            });
+        }
+        Ok(status)
     }
 
     async fn fork_choice_updated_v2(
@@ -178,7 +241,14 @@ impl OpEngineApi<AnyNetwork, Http<HyperAuthClient>> for EngineClient {
             Http<HyperAuthClient>,
         >>::fork_choice_updated_v2(&self.engine, fork_choice_state, payload_attributes);
 
-        record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await
+        let updated = record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await?;
+        if let Some(secondary) = &self.secondary {
+            secondary.mirror(MirroredCall::ForkchoiceUpdatedV2 {
+                state: fork_choice_state,
+                primary: updated.payload_status.clone(),
+            });
+        }
+        Ok(updated)
     }
 
     async fn fork_choice_updated_v3(
@@ -191,7 +261,14 @@ impl OpEngineApi<AnyNetwork, Http<HyperAuthClient>> for EngineClient {
             Http<HyperAuthClient>,
         >>::fork_choice_updated_v3(&self.engine, fork_choice_state, payload_attributes);
 
-        record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await
+        let updated = record_call_time(call, Metrics::FORKCHOICE_UPDATE_METHOD).await?;
+        if let Some(secondary) = &self.secondary {
+            secondary.mirror(MirroredCall::ForkchoiceUpdatedV3 {
+                state: fork_choice_state,
+                primary: updated.payload_status.clone(),
+            });
+        }
+        Ok(updated)
     }
 
     async fn get_payload_v2(
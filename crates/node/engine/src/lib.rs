@@ -13,8 +13,9 @@ mod task_queue;
 pub use task_queue::{
     BuildTask, BuildTaskError, ConsolidateTask, ConsolidateTaskError, Engine, EngineBuildError,
     EngineResetError, EngineTask, EngineTaskError, EngineTaskErrorSeverity, EngineTaskErrors,
-    EngineTaskExt, FinalizeTask, FinalizeTaskError, InsertTask, InsertTaskError, SynchronizeTask,
-    SynchronizeTaskError,
+    EngineTaskExt, EngineTaskFailure, EngineTaskInfo, EngineTaskKind, EngineTaskQueueCommand,
+    EngineTaskQueueHandle, EngineTaskQueueSnapshot, FinalizeTask, FinalizeTaskError, InsertTask,
+    InsertTaskError, MAX_RECENT_TASK_ERRORS, SynchronizeTask, SynchronizeTaskError,
 };
 
 mod attributes;
@@ -23,6 +24,17 @@ pub use attributes::{AttributesMatch, AttributesMismatch};
 mod client;
 pub use client::{EngineClient, EngineClientError};
 
+mod capabilities;
+pub use capabilities::{
+    ENGINE_CAPABILITIES, EngineCapabilitiesError, EngineCapabilityReport, MissingForkCapabilities,
+    required_engine_methods,
+};
+
+mod secondary;
+pub use secondary::{
+    PayloadStatusComparison, SecondaryEngine, SecondaryEngineConfig, SecondaryEngineStats,
+};
+
 mod versions;
 pub use versions::{EngineForkchoiceVersion, EngineGetPayloadVersion, EngineNewPayloadVersion};
 
@@ -43,6 +43,10 @@ impl Metrics {
     /// `engine_getPayloadV<N>` label.
     pub const GET_PAYLOAD_METHOD: &str = "engine_getPayload";
 
+    /// Identifier for the counter that tracks the outcomes of the calls mirrored to the secondary
+    /// execution layer.
+    pub const ENGINE_SECONDARY_COMPARISON: &str = "kona_node_engine_secondary_comparison";
+
     /// Identifier for the counter that tracks the number of times the engine has been reset.
     pub const ENGINE_RESET_COUNT: &str = "kona_node_engine_reset_count";
 
@@ -74,6 +78,13 @@ impl Metrics {
             "Engine method request duration"
         );
 
+        // Secondary execution layer comparisons
+        metrics::describe_counter!(
+            Self::ENGINE_SECONDARY_COMPARISON,
+            metrics::Unit::Count,
+            "Outcomes of the engine calls mirrored to the secondary execution layer"
+        );
+
         // Engine reset counter
         metrics::describe_counter!(
             Self::ENGINE_RESET_COUNT,
@@ -99,6 +110,16 @@ impl Metrics {
         kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::FINALIZE_TASK_LABEL, 0);
         kona_macros::set!(counter, Self::ENGINE_TASK_FAILURE, Self::FORKCHOICE_TASK_LABEL, 0);
 
+        // Secondary execution layer divergences
+        for method in [Self::NEW_PAYLOAD_METHOD, Self::FORKCHOICE_UPDATE_METHOD] {
+            metrics::counter!(
+                Self::ENGINE_SECONDARY_COMPARISON,
+                "method" => method,
+                "result" => "diverged"
+            )
+            .absolute(0);
+        }
+
         // Engine reset count
         kona_macros::set!(counter, Self::ENGINE_RESET_COUNT, 0);
     }
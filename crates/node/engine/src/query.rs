@@ -5,9 +5,11 @@ use alloy_provider::Provider;
 use alloy_transport::{RpcError, TransportErrorKind};
 use kona_genesis::RollupConfig;
 use kona_protocol::{L2BlockInfo, OutputRoot, Predeploys};
-use tokio::sync::oneshot::Sender;
+use tokio::sync::{mpsc, oneshot::Sender};
 
-use crate::{EngineClient, EngineClientError, EngineState};
+use crate::{
+    EngineClient, EngineClientError, EngineState, EngineTaskQueueHandle, EngineTaskQueueSnapshot,
+};
 
 /// The type of data that can be requested from the engine.
 pub type EngineQuerySender = tokio::sync::mpsc::Sender<EngineQueries>;
@@ -33,6 +35,19 @@ pub enum EngineQueries {
     QueueLengthReceiver(Sender<tokio::sync::watch::Receiver<usize>>),
     /// Dev API: Returns the number of tasks in the engine queue.
     TaskQueueLength(Sender<usize>),
+    /// Dev API: Returns a snapshot of the engine task queue, with the metadata of the queued tasks
+    /// and the recent task errors.
+    TaskQueue(Sender<EngineTaskQueueSnapshot>),
+    /// Dev API: Drops a task from the engine queue.
+    DropTask {
+        /// The id of the task to drop.
+        id: u64,
+        /// A channel to send back whether the task was queued.
+        sender: Sender<bool>,
+    },
+    /// Dev API: Resets the engine. The task being retried, if any, is interrupted so that the
+    /// reset is processed.
+    Reset(Sender<()>),
 }
 
 /// An error that can occur when querying the engine.
@@ -53,6 +68,9 @@ pub enum EngineQueriesError {
     /// Impossible to retrieve L2 withdrawals root from state.
     #[error("Impossible to retrieve L2 withdrawals root from state. {0}")]
     FailedToRetrieveWithdrawalsRoot(#[from] RpcError<TransportErrorKind>),
+    /// The reset request channel was closed unexpectedly.
+    #[error("Reset request channel closed unexpectedly")]
+    ResetChannelClosed,
 }
 
 impl EngineQueries {
@@ -61,6 +79,8 @@ impl EngineQueries {
         self,
         state_recv: &tokio::sync::watch::Receiver<EngineState>,
         queue_length_recv: &tokio::sync::watch::Receiver<usize>,
+        task_queue: &EngineTaskQueueHandle,
+        reset_request_tx: &mpsc::Sender<()>,
         client: &Arc<EngineClient>,
         rollup_config: &Arc<RollupConfig>,
     ) -> Result<(), EngineQueriesError> {
@@ -127,6 +147,25 @@ impl EngineQueries {
                 }
                 Ok(())
             }
+            Self::TaskQueue(sender) => sender
+                .send(task_queue.snapshot())
+                .map_err(|_| EngineQueriesError::OutputChannelClosed),
+            Self::DropTask { id, sender } => {
+                let queued = task_queue.drop_task(id);
+                warn!(target: "engine", id, queued, "Received request to drop engine task");
+                sender.send(queued).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
+            Self::Reset(sender) => {
+                warn!(target: "engine", "Received engine reset request");
+                reset_request_tx
+                    .send(())
+                    .await
+                    .map_err(|_| EngineQueriesError::ResetChannelClosed)?;
+                // Stop retrying the current task, if any, so that the engine actor processes the
+                // reset.
+                task_queue.interrupt();
+                sender.send(()).map_err(|_| EngineQueriesError::OutputChannelClosed)
+            }
         }
     }
 }
@@ -0,0 +1,454 @@
+//! A secondary execution layer, verifying the engine api calls made to the primary one.
+//!
+//! The secondary execution layer receives a copy of every `engine_newPayload` and
+//! `engine_forkchoiceUpdated` call that succeeded on the primary execution layer, and its payload
+//! statuses are compared against the primary's. Mirrored calls are queued and sent by a background
+//! task, so that a slow or unavailable secondary never affects the primary path.
+
+use crate::{EngineClient, Metrics, client::HyperAuthClient};
+use alloy_network::AnyNetwork;
+use alloy_primitives::B256;
+use alloy_provider::RootProvider;
+use alloy_rpc_types_engine::{
+    ExecutionPayloadInputV2, ExecutionPayloadV3, ForkchoiceState, JwtSecret, PayloadStatus,
+    PayloadStatusEnum,
+};
+use alloy_transport_http::Http;
+use derive_more::Display;
+use op_alloy_provider::ext::engine::OpEngineApi;
+use op_alloy_rpc_types_engine::OpExecutionPayloadV4;
+use tokio::sync::{mpsc, watch};
+use url::Url;
+
+/// The maximum number of calls queued for the secondary execution layer. Calls are dropped when
+/// the queue is full.
+const MIRROR_QUEUE_CAPACITY: usize = 1024;
+
+/// The configuration of a [`SecondaryEngine`].
+#[derive(Debug, Clone)]
+pub struct SecondaryEngineConfig {
+    /// The engine api url of the secondary execution layer.
+    pub url: Url,
+    /// The JWT secret of the engine api of the secondary execution layer.
+    pub jwt_secret: JwtSecret,
+}
+
+/// The outcome of the comparison of the payload statuses returned by the primary and secondary
+/// execution layers for the same call.
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq)]
+pub enum PayloadStatusComparison {
+    /// Both execution layers returned the same status.
+    #[display("match")]
+    Match,
+    /// One of the execution layers is syncing, or accepted the payload without validating it, so
+    /// the statuses cannot be compared.
+    #[display("lagging")]
+    Lagging,
+    /// The execution layers disagree on the validity of the payload, or on the latest valid
+    /// block hash.
+    #[display("diverged")]
+    Diverged,
+}
+
+impl PayloadStatusComparison {
+    /// Compares the [`PayloadStatus`]es returned by the primary and secondary execution layers.
+    pub fn new(primary: &PayloadStatus, secondary: &PayloadStatus) -> Self {
+        match (&primary.status, &secondary.status) {
+            (PayloadStatusEnum::Syncing | PayloadStatusEnum::Accepted, _) |
+            (_, PayloadStatusEnum::Syncing | PayloadStatusEnum::Accepted) => Self::Lagging,
+            (PayloadStatusEnum::Valid, PayloadStatusEnum::Valid) => {
+                if primary.latest_valid_hash == secondary.latest_valid_hash {
+                    Self::Match
+                } else {
+                    Self::Diverged
+                }
+            }
+            (PayloadStatusEnum::Invalid { .. }, PayloadStatusEnum::Invalid { .. }) => Self::Match,
+            _ => Self::Diverged,
+        }
+    }
+}
+
+/// Counters of the outcomes of the calls mirrored to a [`SecondaryEngine`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SecondaryEngineStats {
+    /// The number of calls for which both execution layers returned the same status.
+    pub matched: u64,
+    /// The number of calls for which the statuses could not be compared.
+    pub lagging: u64,
+    /// The number of calls for which the execution layers diverged.
+    pub diverged: u64,
+    /// The number of calls that failed on the secondary execution layer.
+    pub errors: u64,
+    /// The number of calls dropped because the queue of mirrored calls was full.
+    pub dropped: u64,
+}
+
+/// An engine api call mirrored to the secondary execution layer, along with the status returned
+/// by the primary execution layer.
+#[derive(Debug)]
+pub(crate) enum MirroredCall {
+    /// An `engine_newPayloadV2` call.
+    NewPayloadV2 {
+        /// The payload.
+        payload: ExecutionPayloadInputV2,
+        /// The status returned by the primary.
+        primary: PayloadStatus,
+    },
+    /// An `engine_newPayloadV3` call.
+    NewPayloadV3 {
+        /// The payload.
+        payload: ExecutionPayloadV3,
+        /// The parent beacon block root.
+        parent_beacon_block_root: B256,
+        /// The status returned by the primary.
+        primary: PayloadStatus,
+    },
+    /// An `engine_newPayloadV4` call.
+    NewPayloadV4 {
+        /// The payload.
+        payload: OpExecutionPayloadV4,
+        /// The parent beacon block root.
+        parent_beacon_block_root: B256,
+        /// The status returned by the primary.
+        primary: PayloadStatus,
+    },
+    /// An `engine_forkchoiceUpdatedV2` call, without payload attributes.
+    ForkchoiceUpdatedV2 {
+        /// The forkchoice state.
+        state: ForkchoiceState,
+        /// The status returned by the primary.
+        primary: PayloadStatus,
+    },
+    /// An `engine_forkchoiceUpdatedV3` call, without payload attributes.
+    ForkchoiceUpdatedV3 {
+        /// The forkchoice state.
+        state: ForkchoiceState,
+        /// The status returned by the primary.
+        primary: PayloadStatus,
+    },
+}
+
+impl MirroredCall {
+    /// Returns the metrics label of the method.
+    const fn method(&self) -> &'static str {
+        match self {
+            Self::NewPayloadV2 { .. } | Self::NewPayloadV3 { .. } | Self::NewPayloadV4 { .. } => {
+                Metrics::NEW_PAYLOAD_METHOD
+            }
+            Self::ForkchoiceUpdatedV2 { .. } | Self::ForkchoiceUpdatedV3 { .. } => {
+                Metrics::FORKCHOICE_UPDATE_METHOD
+            }
+        }
+    }
+
+    /// Returns the hash of the new payload, or of the forkchoice head.
+    const fn block_hash(&self) -> B256 {
+        match self {
+            Self::NewPayloadV2 { payload, .. } => payload.execution_payload.block_hash,
+            Self::NewPayloadV3 { payload, .. } => payload.payload_inner.payload_inner.block_hash,
+            Self::NewPayloadV4 { payload, .. } => {
+                payload.payload_inner.payload_inner.payload_inner.block_hash
+            }
+            Self::ForkchoiceUpdatedV2 { state, .. } | Self::ForkchoiceUpdatedV3 { state, .. } => {
+                state.head_block_hash
+            }
+        }
+    }
+}
+
+/// A secondary execution layer, receiving a copy of the `engine_newPayload` and
+/// `engine_forkchoiceUpdated` calls made to the primary execution layer.
+///
+/// Divergences between the payload statuses of both execution layers are logged as errors and
+/// recorded in the [`Metrics::ENGINE_SECONDARY_COMPARISON`] counter. They never affect the
+/// primary path.
+#[derive(Debug, Clone)]
+pub struct SecondaryEngine {
+    /// The queue of mirrored calls.
+    calls: mpsc::Sender<MirroredCall>,
+    /// The outcomes of the mirrored calls.
+    stats: watch::Receiver<SecondaryEngineStats>,
+    /// The sender of the outcomes, used to count dropped calls.
+    stats_tx: watch::Sender<SecondaryEngineStats>,
+}
+
+impl SecondaryEngine {
+    /// Spawns the task that mirrors calls to the secondary execution layer described by the
+    /// [`SecondaryEngineConfig`].
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn spawn(config: SecondaryEngineConfig) -> Self {
+        let provider = EngineClient::rpc_client::<AnyNetwork>(config.url, config.jwt_secret);
+        let (calls, calls_rx) = mpsc::channel(MIRROR_QUEUE_CAPACITY);
+        let (stats_tx, stats) = watch::channel(SecondaryEngineStats::default());
+
+        tokio::spawn(Self::run(provider, calls_rx, stats_tx.clone()));
+
+        Self { calls, stats, stats_tx }
+    }
+
+    /// Returns a receiver of the [`SecondaryEngineStats`].
+    pub fn stats(&self) -> watch::Receiver<SecondaryEngineStats> {
+        self.stats.clone()
+    }
+
+    /// Queues a call to be mirrored to the secondary execution layer.
+    pub(crate) fn mirror(&self, call: MirroredCall) {
+        let method = call.method();
+        if self.calls.try_send(call).is_err() {
+            warn!(target: "engine::secondary", method, "Secondary execution layer queue is full, dropping call");
+            kona_macros::inc!(
+                counter,
+                Metrics::ENGINE_SECONDARY_COMPARISON,
+                "method" => method,
+                "result" => "dropped"
+            );
+            self.stats_tx.send_modify(|stats| stats.dropped += 1);
+        }
+    }
+
+    /// Sends the mirrored calls to the secondary execution layer, and compares its responses
+    /// with the primary's.
+    async fn run(
+        provider: RootProvider<AnyNetwork>,
+        mut calls: mpsc::Receiver<MirroredCall>,
+        stats: watch::Sender<SecondaryEngineStats>,
+    ) {
+        while let Some(call) = calls.recv().await {
+            let method = call.method();
+            let block_hash = call.block_hash();
+
+            let (primary, secondary) =
+                match call {
+                    MirroredCall::NewPayloadV2 { payload, primary } => (
+                        primary,
+                        <RootProvider<AnyNetwork> as OpEngineApi<
+                            AnyNetwork,
+                            Http<HyperAuthClient>,
+                        >>::new_payload_v2(&provider, payload)
+                        .await,
+                    ),
+                    MirroredCall::NewPayloadV3 { payload, parent_beacon_block_root, primary } => (
+                        primary,
+                        <RootProvider<AnyNetwork> as OpEngineApi<
+                            AnyNetwork,
+                            Http<HyperAuthClient>,
+                        >>::new_payload_v3(
+                            &provider, payload, parent_beacon_block_root
+                        )
+                        .await,
+                    ),
+                    MirroredCall::NewPayloadV4 { payload, parent_beacon_block_root, primary } => (
+                        primary,
+                        <RootProvider<AnyNetwork> as OpEngineApi<
+                            AnyNetwork,
+                            Http<HyperAuthClient>,
+                        >>::new_payload_v4(
+                            &provider, payload, parent_beacon_block_root
+                        )
+                        .await,
+                    ),
+                    MirroredCall::ForkchoiceUpdatedV2 { state, primary } => (
+                        primary,
+                        <RootProvider<AnyNetwork> as OpEngineApi<
+                            AnyNetwork,
+                            Http<HyperAuthClient>,
+                        >>::fork_choice_updated_v2(&provider, state, None)
+                        .await
+                        .map(|updated| updated.payload_status),
+                    ),
+                    MirroredCall::ForkchoiceUpdatedV3 { state, primary } => (
+                        primary,
+                        <RootProvider<AnyNetwork> as OpEngineApi<
+                            AnyNetwork,
+                            Http<HyperAuthClient>,
+                        >>::fork_choice_updated_v3(&provider, state, None)
+                        .await
+                        .map(|updated| updated.payload_status),
+                    ),
+                };
+
+            let secondary = match secondary {
+                Ok(secondary) => secondary,
+                Err(err) => {
+                    warn!(target: "engine::secondary", method, %block_hash, ?err, "Secondary execution layer call failed");
+                    kona_macros::inc!(
+                        counter,
+                        Metrics::ENGINE_SECONDARY_COMPARISON,
+                        "method" => method,
+                        "result" => "error"
+                    );
+                    stats.send_modify(|stats| stats.errors += 1);
+                    continue;
+                }
+            };
+
+            let comparison = PayloadStatusComparison::new(&primary, &secondary);
+            match comparison {
+                PayloadStatusComparison::Match => {
+                    trace!(target: "engine::secondary", method, %block_hash, "Secondary execution layer agrees with the primary");
+                    stats.send_modify(|stats| stats.matched += 1);
+                }
+                PayloadStatusComparison::Lagging => {
+                    debug!(
+                        target: "engine::secondary",
+                        method,
+                        %block_hash,
+                        primary = %primary.status,
+                        secondary = %secondary.status,
+                        "Secondary execution layer status cannot be compared with the primary"
+                    );
+                    stats.send_modify(|stats| stats.lagging += 1);
+                }
+                PayloadStatusComparison::Diverged => {
+                    error!(
+                        target: "engine::secondary",
+                        method,
+                        %block_hash,
+                        primary = %primary.status,
+                        primary_latest_valid_hash = ?primary.latest_valid_hash,
+                        secondary = %secondary.status,
+                        secondary_latest_valid_hash = ?secondary.latest_valid_hash,
+                        "Secondary execution layer diverged from the primary"
+                    );
+                    stats.send_modify(|stats| stats.diverged += 1);
+                }
+            }
+            kona_macros::inc!(
+                counter,
+                Metrics::ENGINE_SECONDARY_COMPARISON,
+                "method" => method,
+                "result" => comparison.to_string()
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rpc_types_engine::ForkchoiceUpdated;
+    use jsonrpsee::{
+        RpcModule,
+        server::{Server, ServerHandle},
+        types::ErrorObjectOwned,
+    };
+    use kona_genesis::RollupConfig;
+    use std::{net::SocketAddr, sync::Arc};
+
+    fn valid(hash: B256) -> PayloadStatus {
+        PayloadStatus::new(PayloadStatusEnum::Valid, Some(hash))
+    }
+
+    fn invalid(hash: B256) -> PayloadStatus {
+        PayloadStatus::new(
+            PayloadStatusEnum::Invalid { validation_error: "bad block".to_string() },
+            Some(hash),
+        )
+    }
+
+    /// Spawns a mock execution layer answering `engine_forkchoiceUpdatedV3` with the given status.
+    async fn mock_el(status: PayloadStatus) -> (Url, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr: SocketAddr = server.local_addr().unwrap();
+
+        let mut module = RpcModule::new(status);
+        module
+            .register_method("engine_forkchoiceUpdatedV3", |_, status, _| {
+                Ok::<_, ErrorObjectOwned>(ForkchoiceUpdated::new(status.clone()))
+            })
+            .unwrap();
+
+        (format!("http://{addr}").parse().unwrap(), server.start(module))
+    }
+
+    /// Returns an [`EngineClient`] backed by mock primary and secondary execution layers, along
+    /// with the handles of the mock servers.
+    async fn client(
+        primary: PayloadStatus,
+        secondary: PayloadStatus,
+    ) -> (EngineClient, [ServerHandle; 2]) {
+        let (primary_url, primary_handle) = mock_el(primary).await;
+        let (secondary_url, secondary_handle) = mock_el(secondary).await;
+
+        let jwt_secret = JwtSecret::random();
+        let dummy: Url = "http://127.0.0.1:1".parse().unwrap();
+        let client = EngineClient::new_http(
+            primary_url,
+            dummy.clone(),
+            dummy,
+            Arc::new(RollupConfig::default()),
+            jwt_secret,
+        )
+        .with_secondary(SecondaryEngine::spawn(SecondaryEngineConfig {
+            url: secondary_url,
+            jwt_secret,
+        }));
+        (client, [primary_handle, secondary_handle])
+    }
+
+    #[test]
+    fn test_payload_status_comparison() {
+        let hash = B256::repeat_byte(1);
+        let other = B256::repeat_byte(2);
+
+        assert_eq!(
+            PayloadStatusComparison::new(&valid(hash), &valid(hash)),
+            PayloadStatusComparison::Match
+        );
+        assert_eq!(
+            PayloadStatusComparison::new(&invalid(hash), &invalid(other)),
+            PayloadStatusComparison::Match
+        );
+        assert_eq!(
+            PayloadStatusComparison::new(&valid(hash), &valid(other)),
+            PayloadStatusComparison::Diverged
+        );
+        assert_eq!(
+            PayloadStatusComparison::new(&valid(hash), &invalid(hash)),
+            PayloadStatusComparison::Diverged
+        );
+        assert_eq!(
+            PayloadStatusComparison::new(
+                &valid(hash),
+                &PayloadStatus::from_status(PayloadStatusEnum::Syncing)
+            ),
+            PayloadStatusComparison::Lagging
+        );
+        assert_eq!(
+            PayloadStatusComparison::new(
+                &PayloadStatus::from_status(PayloadStatusEnum::Accepted),
+                &invalid(hash)
+            ),
+            PayloadStatusComparison::Lagging
+        );
+    }
+
+    #[tokio::test]
+    async fn test_secondary_engine_detects_divergence() {
+        let hash = B256::repeat_byte(1);
+        let (client, _handles) = client(valid(hash), invalid(hash)).await;
+
+        let updated =
+            client.fork_choice_updated_v3(ForkchoiceState::default(), None).await.unwrap();
+        assert_eq!(updated.payload_status, valid(hash));
+
+        let mut stats = client.secondary().unwrap().stats();
+        let stats = *stats.wait_for(|stats| stats.diverged == 1).await.unwrap();
+        assert_eq!(stats, SecondaryEngineStats { diverged: 1, ..Default::default() });
+    }
+
+    #[tokio::test]
+    async fn test_secondary_engine_matches() {
+        let hash = B256::repeat_byte(1);
+        let (client, _handles) = client(valid(hash), valid(hash)).await;
+
+        client.fork_choice_updated_v3(ForkchoiceState::default(), None).await.unwrap();
+        client.fork_choice_updated_v3(ForkchoiceState::default(), None).await.unwrap();
+
+        let mut stats = client.secondary().unwrap().stats();
+        let stats = *stats.wait_for(|stats| stats.matched == 2).await.unwrap();
+        assert_eq!(stats, SecondaryEngineStats { matched: 2, ..Default::default() });
+    }
+}
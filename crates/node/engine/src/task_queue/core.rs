@@ -1,9 +1,12 @@
 //! The [`Engine`] is a task queue that receives and executes [`EngineTask`]s.
 
-use super::EngineTaskExt;
+use super::{
+    EngineTaskExt,
+    introspection::{QueuedTask, TaskQueueTracker},
+};
 use crate::{
     EngineClient, EngineState, EngineSyncStateUpdate, EngineTask, EngineTaskError,
-    EngineTaskErrorSeverity, Metrics, SynchronizeTask, SynchronizeTaskError,
+    EngineTaskErrorSeverity, EngineTaskQueueHandle, Metrics, SynchronizeTask, SynchronizeTaskError,
     task_queue::EngineTaskErrors,
 };
 use alloy_provider::Provider;
@@ -29,6 +32,9 @@ use tokio::sync::watch::Sender;
 /// Tasks within the queue are also considered fallible. If they fail with a temporary error,
 /// they are not popped from the queue, the error is returned, and they are retried on the
 /// next call to [`Engine::drain`].
+///
+/// The queued tasks, their failed attempts and the recent task errors can be inspected through an
+/// [`EngineTaskQueueHandle`], which can also be used to drop a stuck task.
 #[derive(Debug)]
 pub struct Engine {
     /// The state of the engine.
@@ -38,7 +44,9 @@ pub struct Engine {
     /// A sender that can be used to notify the engine actor of task queue length changes.
     task_queue_length: Sender<usize>,
     /// The task queue.
-    tasks: BinaryHeap<EngineTask>,
+    tasks: BinaryHeap<QueuedTask>,
+    /// Tracks the metadata of the queued tasks.
+    tracker: TaskQueueTracker,
 }
 
 impl Engine {
@@ -48,7 +56,13 @@ impl Engine {
         state_sender: Sender<EngineState>,
         task_queue_length: Sender<usize>,
     ) -> Self {
-        Self { state: initial_state, state_sender, task_queue_length, tasks: BinaryHeap::default() }
+        Self {
+            state: initial_state,
+            state_sender,
+            task_queue_length,
+            tasks: BinaryHeap::default(),
+            tracker: TaskQueueTracker::default(),
+        }
     }
 
     /// Returns a reference to the inner [`EngineState`].
@@ -66,11 +80,17 @@ impl Engine {
         self.task_queue_length.subscribe()
     }
 
+    /// Returns an [`EngineTaskQueueHandle`] that can be used to inspect and control the task
+    /// queue.
+    pub fn task_queue_handle(&self) -> EngineTaskQueueHandle {
+        self.tracker.handle()
+    }
+
     /// Enqueues a new [`EngineTask`] for execution.
     /// Updates the queue length and notifies listeners of the change.
     pub fn enqueue(&mut self, task: EngineTask) {
-        self.tasks.push(task);
-        self.task_queue_length.send_replace(self.tasks.len());
+        self.tasks.push(self.tracker.queue(task));
+        self.queue_updated();
     }
 
     /// Resets the engine by finding a plausible sync starting point via
@@ -147,28 +167,81 @@ impl Engine {
     /// Clears the task queue.
     pub fn clear(&mut self) {
         self.tasks.clear();
+        self.tracker.clear();
+        self.queue_updated();
     }
 
     /// Attempts to drain the queue by executing all [`EngineTask`]s in-order. If any task returns
     /// an error along the way, it is not popped from the queue (in case it must be retried) and
     /// the error is returned.
+    ///
+    /// Tasks requested to be dropped through the [`EngineTaskQueueHandle`] are removed before
+    /// their next execution attempt, including the task being retried.
     pub async fn drain(&mut self) -> Result<(), EngineTaskErrors> {
+        self.tracker.clear_interrupt();
+
         // Drain tasks in order of priority, halting on errors for a retry to be attempted.
-        while let Some(task) = self.tasks.peek() {
-            // Execute the task
-            task.execute(&mut self.state).await?;
+        loop {
+            self.drop_requested_tasks();
+
+            let Some(queued) = self.tasks.peek() else {
+                break;
+            };
+
+            // Execute the task, recording its failed attempts.
+            let result = queued
+                .task
+                .execute_with(&mut self.state, |err| {
+                    self.tracker.record_failure(queued, err);
+                    self.tracker.publish(&self.tasks);
+                    self.tracker.should_retry(queued.id)
+                })
+                .await;
+            if let Err(err) = result {
+                if self.tracker.is_dropped(queued.id) {
+                    continue;
+                }
+                return Err(err);
+            }
 
             // Update the state and notify the engine actor.
             self.state_sender.send_replace(self.state);
 
             // Pop the task from the queue now that it's been executed.
-            self.tasks.pop();
+            if let Some(queued) = self.tasks.pop() {
+                self.tracker.remove(queued.id);
+            }
 
-            self.task_queue_length.send_replace(self.tasks.len());
+            self.queue_updated();
         }
 
         Ok(())
     }
+
+    /// Removes the tasks requested to be dropped through the [`EngineTaskQueueHandle`] from the
+    /// queue.
+    fn drop_requested_tasks(&mut self) {
+        let dropped = self.tracker.take_dropped();
+        if dropped.is_empty() {
+            return;
+        }
+
+        self.tasks.retain(|queued| {
+            let keep = !dropped.contains(&queued.id);
+            if !keep {
+                warn!(target: "engine", id = queued.id, kind = %queued.task.kind(), "Dropping engine task");
+            }
+            keep
+        });
+        dropped.into_iter().for_each(|id| self.tracker.remove(id));
+        self.queue_updated();
+    }
+
+    /// Notifies listeners of a change of the task queue.
+    fn queue_updated(&self) {
+        self.task_queue_length.send_replace(self.tasks.len());
+        self.tracker.publish(&self.tasks);
+    }
 }
 
 /// An error occurred while attempting to reset the [`Engine`].
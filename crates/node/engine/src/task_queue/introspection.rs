@@ -0,0 +1,451 @@
+//! Introspection of the [`Engine`] task queue, used to debug and recover stalled engines.
+//!
+//! [`Engine`]: crate::Engine
+
+use crate::{EngineTask, EngineTaskError, EngineTaskErrorSeverity, EngineTaskErrors};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::{mpsc, watch};
+
+/// The maximum number of task failures kept in [`EngineTaskQueueSnapshot::recent_errors`].
+pub const MAX_RECENT_TASK_ERRORS: usize = 32;
+
+/// The kind of an [`EngineTask`].
+#[derive(Debug, Display, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EngineTaskKind {
+    /// An [`EngineTask::Insert`] task.
+    #[display("insert")]
+    Insert,
+    /// An [`EngineTask::Build`] task.
+    #[display("build")]
+    Build,
+    /// An [`EngineTask::Consolidate`] task.
+    #[display("consolidate")]
+    Consolidate,
+    /// An [`EngineTask::Finalize`] task.
+    #[display("finalize")]
+    Finalize,
+}
+
+/// A failed execution attempt of a queued [`EngineTask`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineTaskFailure {
+    /// The id of the task that failed.
+    pub task_id: u64,
+    /// The kind of the task that failed.
+    pub kind: EngineTaskKind,
+    /// The severity of the error.
+    pub severity: EngineTaskErrorSeverity,
+    /// The error message.
+    pub error: String,
+    /// The unix timestamp of the failure, in seconds.
+    pub timestamp: u64,
+}
+
+/// The metadata of a queued [`EngineTask`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineTaskInfo {
+    /// The id of the task, unique for the lifetime of the [`Engine`].
+    ///
+    /// [`Engine`]: crate::Engine
+    pub id: u64,
+    /// The kind of the task.
+    pub kind: EngineTaskKind,
+    /// The unix timestamp at which the task was enqueued, in seconds.
+    pub enqueued_at: u64,
+    /// The number of failed execution attempts of the task.
+    pub attempts: u64,
+    /// The last error returned by the task, if any.
+    pub last_error: Option<EngineTaskFailure>,
+}
+
+/// A snapshot of the [`Engine`] task queue.
+///
+/// [`Engine`]: crate::Engine
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EngineTaskQueueSnapshot {
+    /// The queued tasks, in execution order. The first task is the head of the queue.
+    pub tasks: Vec<EngineTaskInfo>,
+    /// The most recent task failures, oldest first. At most [`MAX_RECENT_TASK_ERRORS`] failures
+    /// are kept.
+    pub recent_errors: Vec<EngineTaskFailure>,
+}
+
+/// A command sent to the [`Engine`] task queue through an [`EngineTaskQueueHandle`].
+///
+/// [`Engine`]: crate::Engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EngineTaskQueueCommand {
+    /// Drops the task with the given id before its next execution attempt.
+    Drop(u64),
+    /// Stops retrying the task being executed, handing control back to the engine actor. The task
+    /// stays in the queue.
+    Interrupt,
+}
+
+/// A handle to inspect and control the [`Engine`] task queue from outside of the engine actor.
+///
+/// The handle does not need the engine actor to be responsive: snapshots are published by the
+/// [`Engine`] as the queue changes, and commands are applied between two execution attempts of a
+/// task, which allows recovering from a task that is retried forever.
+///
+/// [`Engine`]: crate::Engine
+#[derive(Debug, Clone)]
+pub struct EngineTaskQueueHandle {
+    /// The latest snapshot of the task queue.
+    snapshot: watch::Receiver<EngineTaskQueueSnapshot>,
+    /// The channel used to send commands to the task queue.
+    commands: mpsc::UnboundedSender<EngineTaskQueueCommand>,
+}
+
+impl EngineTaskQueueHandle {
+    /// Returns the latest [`EngineTaskQueueSnapshot`].
+    pub fn snapshot(&self) -> EngineTaskQueueSnapshot {
+        self.snapshot.borrow().clone()
+    }
+
+    /// Requests the task with the given id to be dropped from the queue.
+    ///
+    /// Returns `false` if no task with this id is queued.
+    pub fn drop_task(&self, id: u64) -> bool {
+        let queued = self.snapshot.borrow().tasks.iter().any(|task| task.id == id);
+        queued && self.commands.send(EngineTaskQueueCommand::Drop(id)).is_ok()
+    }
+
+    /// Requests the engine to stop retrying the task being executed.
+    ///
+    /// Returns `false` if the [`Engine`] was dropped.
+    ///
+    /// [`Engine`]: crate::Engine
+    pub fn interrupt(&self) -> bool {
+        self.commands.send(EngineTaskQueueCommand::Interrupt).is_ok()
+    }
+}
+
+/// An [`EngineTask`] in the [`Engine`] task queue, along with its metadata.
+///
+/// Queued tasks are ordered by their [`EngineTask`] first, and then by enqueue order, so that
+/// tasks of a shared variant are executed in FIFO order.
+///
+/// [`Engine`]: crate::Engine
+#[derive(Debug)]
+pub(crate) struct QueuedTask {
+    /// The id of the task.
+    pub(crate) id: u64,
+    /// The unix timestamp at which the task was enqueued, in seconds.
+    pub(crate) enqueued_at: u64,
+    /// The task.
+    pub(crate) task: EngineTask,
+}
+
+impl PartialEq for QueuedTask {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedTask {}
+
+impl PartialOrd for QueuedTask {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedTask {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Lower ids were enqueued first, and must be popped first from the max-heap.
+        self.task.cmp(&other.task).then_with(|| other.id.cmp(&self.id))
+    }
+}
+
+/// Tracks the metadata of the queued tasks, publishes [`EngineTaskQueueSnapshot`]s and receives
+/// [`EngineTaskQueueCommand`]s.
+#[derive(Debug)]
+pub(crate) struct TaskQueueTracker {
+    /// The id of the next enqueued task.
+    next_id: u64,
+    /// The number of failed attempts and the last failure of the tasks that failed at least once.
+    failures: HashMap<u64, (u64, EngineTaskFailure)>,
+    /// The most recent task failures.
+    recent_errors: VecDeque<EngineTaskFailure>,
+    /// The ids of the tasks requested to be dropped.
+    dropped: HashSet<u64>,
+    /// Whether the task being executed was requested to stop retrying.
+    interrupted: bool,
+    /// The sender of the task queue snapshots.
+    snapshot: watch::Sender<EngineTaskQueueSnapshot>,
+    /// A sender of commands, handed out to the [`EngineTaskQueueHandle`]s.
+    commands_tx: mpsc::UnboundedSender<EngineTaskQueueCommand>,
+    /// The receiver of commands.
+    commands_rx: mpsc::UnboundedReceiver<EngineTaskQueueCommand>,
+}
+
+impl Default for TaskQueueTracker {
+    fn default() -> Self {
+        let (commands_tx, commands_rx) = mpsc::unbounded_channel();
+        Self {
+            next_id: 0,
+            failures: HashMap::new(),
+            recent_errors: VecDeque::with_capacity(MAX_RECENT_TASK_ERRORS),
+            dropped: HashSet::new(),
+            interrupted: false,
+            snapshot: watch::Sender::new(EngineTaskQueueSnapshot::default()),
+            commands_tx,
+            commands_rx,
+        }
+    }
+}
+
+impl TaskQueueTracker {
+    /// Returns a new [`EngineTaskQueueHandle`].
+    pub(crate) fn handle(&self) -> EngineTaskQueueHandle {
+        EngineTaskQueueHandle {
+            snapshot: self.snapshot.subscribe(),
+            commands: self.commands_tx.clone(),
+        }
+    }
+
+    /// Wraps the task in a [`QueuedTask`], assigning it a new id.
+    pub(crate) fn queue(&mut self, task: EngineTask) -> QueuedTask {
+        let id = self.next_id;
+        self.next_id += 1;
+        QueuedTask { id, enqueued_at: unix_timestamp(), task }
+    }
+
+    /// Records a failed execution attempt of the given task.
+    pub(crate) fn record_failure(&mut self, queued: &QueuedTask, err: &EngineTaskErrors) {
+        let failure = EngineTaskFailure {
+            task_id: queued.id,
+            kind: queued.task.kind(),
+            severity: err.severity(),
+            error: err.to_string(),
+            timestamp: unix_timestamp(),
+        };
+
+        if self.recent_errors.len() == MAX_RECENT_TASK_ERRORS {
+            self.recent_errors.pop_front();
+        }
+        self.recent_errors.push_back(failure.clone());
+
+        let entry = self.failures.entry(queued.id).or_insert_with(|| (0, failure.clone()));
+        entry.0 += 1;
+        entry.1 = failure;
+    }
+
+    /// Applies the pending [`EngineTaskQueueCommand`]s, and returns whether the task with the
+    /// given id should be retried after a temporary error.
+    pub(crate) fn should_retry(&mut self, id: u64) -> bool {
+        self.poll_commands();
+        if self.dropped.contains(&id) {
+            return false;
+        }
+        !std::mem::take(&mut self.interrupted)
+    }
+
+    /// Returns whether the task with the given id was requested to be dropped.
+    pub(crate) fn is_dropped(&self, id: u64) -> bool {
+        self.dropped.contains(&id)
+    }
+
+    /// Applies the pending [`EngineTaskQueueCommand`]s, and takes the ids of the tasks to drop.
+    pub(crate) fn take_dropped(&mut self) -> HashSet<u64> {
+        self.poll_commands();
+        std::mem::take(&mut self.dropped)
+    }
+
+    /// Discards the interrupt requests received while the queue was not drained.
+    pub(crate) fn clear_interrupt(&mut self) {
+        self.poll_commands();
+        self.interrupted = false;
+    }
+
+    /// Forgets the metadata of a task that left the queue.
+    pub(crate) fn remove(&mut self, id: u64) {
+        self.failures.remove(&id);
+        self.dropped.remove(&id);
+    }
+
+    /// Forgets the metadata of all tasks. The failure history is kept.
+    pub(crate) fn clear(&mut self) {
+        self.failures.clear();
+        self.dropped.clear();
+    }
+
+    /// Publishes a new [`EngineTaskQueueSnapshot`] of the given queue.
+    pub(crate) fn publish(&self, tasks: &BinaryHeap<QueuedTask>) {
+        let mut queued = tasks.iter().collect::<Vec<_>>();
+        queued.sort_unstable_by(|a, b| b.cmp(a));
+
+        let tasks = queued
+            .into_iter()
+            .map(|queued| {
+                let (attempts, last_error) = self
+                    .failures
+                    .get(&queued.id)
+                    .map_or((0, None), |(attempts, failure)| (*attempts, Some(failure.clone())));
+                EngineTaskInfo {
+                    id: queued.id,
+                    kind: queued.task.kind(),
+                    enqueued_at: queued.enqueued_at,
+                    attempts,
+                    last_error,
+                }
+            })
+            .collect();
+
+        self.snapshot.send_replace(EngineTaskQueueSnapshot {
+            tasks,
+            recent_errors: self.recent_errors.iter().cloned().collect(),
+        });
+    }
+
+    /// Receives the pending [`EngineTaskQueueCommand`]s.
+    fn poll_commands(&mut self) {
+        while let Ok(command) = self.commands_rx.try_recv() {
+            match command {
+                EngineTaskQueueCommand::Drop(id) => {
+                    self.dropped.insert(id);
+                }
+                EngineTaskQueueCommand::Interrupt => self.interrupted = true,
+            }
+        }
+    }
+}
+
+/// Returns the current unix timestamp, in seconds.
+fn unix_timestamp() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ConsolidateTask, EngineClient, FinalizeTask, FinalizeTaskError};
+    use alloy_rpc_types_engine::JwtSecret;
+    use kona_genesis::RollupConfig;
+    use kona_protocol::OpAttributesWithParent;
+    use std::sync::Arc;
+
+    fn client() -> Arc<EngineClient> {
+        let url: url::Url = "http://127.0.0.1:8551".parse().unwrap();
+        Arc::new(EngineClient::new_http(
+            url.clone(),
+            url.clone(),
+            url,
+            Arc::new(RollupConfig::default()),
+            JwtSecret::random(),
+        ))
+    }
+
+    fn finalize(client: &Arc<EngineClient>, block_number: u64) -> EngineTask {
+        EngineTask::Finalize(FinalizeTask::new(
+            client.clone(),
+            Arc::new(RollupConfig::default()),
+            block_number,
+        ))
+    }
+
+    fn consolidate(client: &Arc<EngineClient>) -> EngineTask {
+        EngineTask::Consolidate(ConsolidateTask::new(
+            client.clone(),
+            Arc::new(RollupConfig::default()),
+            OpAttributesWithParent::new(
+                Default::default(),
+                Default::default(),
+                Default::default(),
+                false,
+            ),
+            true,
+        ))
+    }
+
+    #[test]
+    fn test_queued_tasks_fifo_within_priority() {
+        let client = client();
+        let mut tracker = TaskQueueTracker::default();
+        let mut heap = BinaryHeap::new();
+        heap.push(tracker.queue(finalize(&client, 1)));
+        heap.push(tracker.queue(finalize(&client, 2)));
+        heap.push(tracker.queue(consolidate(&client)));
+        heap.push(tracker.queue(finalize(&client, 3)));
+
+        let order = std::iter::from_fn(|| heap.pop().map(|queued| queued.id)).collect::<Vec<_>>();
+        assert_eq!(order, vec![2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn test_tracker_snapshot() {
+        let client = client();
+        let mut tracker = TaskQueueTracker::default();
+        let handle = tracker.handle();
+
+        let mut heap = BinaryHeap::new();
+        heap.push(tracker.queue(finalize(&client, 1)));
+        heap.push(tracker.queue(consolidate(&client)));
+
+        let err = EngineTaskErrors::Finalize(FinalizeTaskError::BlockNotSafe);
+        let head = heap.peek().unwrap();
+        tracker.record_failure(head, &err);
+        tracker.record_failure(head, &err);
+        tracker.publish(&heap);
+
+        let snapshot = handle.snapshot();
+        assert_eq!(snapshot.tasks.len(), 2);
+        assert_eq!(snapshot.tasks[0].id, 1);
+        assert_eq!(snapshot.tasks[0].kind, EngineTaskKind::Consolidate);
+        assert_eq!(snapshot.tasks[0].attempts, 2);
+        assert_eq!(snapshot.tasks[0].last_error.as_ref().unwrap().error, err.to_string());
+        assert_eq!(snapshot.tasks[1].kind, EngineTaskKind::Finalize);
+        assert_eq!(snapshot.tasks[1].attempts, 0);
+        assert_eq!(snapshot.recent_errors.len(), 2);
+    }
+
+    #[test]
+    fn test_tracker_recent_errors_bounded() {
+        let client = client();
+        let mut tracker = TaskQueueTracker::default();
+        let queued = tracker.queue(finalize(&client, 1));
+        let err = EngineTaskErrors::Finalize(FinalizeTaskError::BlockNotSafe);
+        for _ in 0..MAX_RECENT_TASK_ERRORS + 5 {
+            tracker.record_failure(&queued, &err);
+        }
+        assert_eq!(tracker.recent_errors.len(), MAX_RECENT_TASK_ERRORS);
+        assert_eq!(tracker.failures[&queued.id].0, MAX_RECENT_TASK_ERRORS as u64 + 5);
+    }
+
+    #[test]
+    fn test_tracker_commands() {
+        let client = client();
+        let mut tracker = TaskQueueTracker::default();
+        let handle = tracker.handle();
+        let mut heap = BinaryHeap::new();
+        heap.push(tracker.queue(finalize(&client, 1)));
+        tracker.publish(&heap);
+
+        // Unknown tasks cannot be dropped.
+        assert!(!handle.drop_task(7));
+        assert!(tracker.should_retry(0));
+
+        // Interrupts stop a single retry loop.
+        assert!(handle.interrupt());
+        assert!(!tracker.should_retry(0));
+        assert!(tracker.should_retry(0));
+
+        // Dropped tasks are never retried.
+        assert!(handle.drop_task(0));
+        assert!(!tracker.should_retry(0));
+        assert!(tracker.is_dropped(0));
+        assert_eq!(tracker.take_dropped(), HashSet::from([0]));
+        assert!(!tracker.is_dropped(0));
+    }
+}
@@ -3,5 +3,11 @@
 mod core;
 pub use core::{Engine, EngineResetError};
 
+mod introspection;
+pub use introspection::{
+    EngineTaskFailure, EngineTaskInfo, EngineTaskKind, EngineTaskQueueCommand,
+    EngineTaskQueueHandle, EngineTaskQueueSnapshot, MAX_RECENT_TASK_ERRORS,
+};
+
 mod tasks;
 pub use tasks::*;
@@ -4,17 +4,20 @@
 
 use super::{BuildTask, ConsolidateTask, FinalizeTask, InsertTask};
 use crate::{
-    BuildTaskError, ConsolidateTaskError, EngineState, FinalizeTaskError, InsertTaskError,
+    BuildTaskError, ConsolidateTaskError, EngineState, EngineTaskKind, FinalizeTaskError,
+    InsertTaskError,
 };
 use async_trait::async_trait;
 use derive_more::Display;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 use thiserror::Error;
 
 /// The severity of an engine task error.
 ///
 /// This is used to determine how to handle the error when draining the engine task queue.
-#[derive(Debug, PartialEq, Eq, Display, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Display, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum EngineTaskErrorSeverity {
     /// The error is temporary and the task is retried.
     #[display("temporary")]
@@ -109,6 +112,68 @@ impl EngineTask {
         Ok(())
     }
 
+    /// Returns the [`EngineTaskKind`] of the task.
+    pub const fn kind(&self) -> EngineTaskKind {
+        match self {
+            Self::Insert(_) => EngineTaskKind::Insert,
+            Self::Build(_) => EngineTaskKind::Build,
+            Self::Consolidate(_) => EngineTaskKind::Consolidate,
+            Self::Finalize(_) => EngineTaskKind::Finalize,
+        }
+    }
+
+    /// Executes the task, retrying on temporary errors.
+    ///
+    /// `on_error` is called with every error returned by the task. After a temporary error, the
+    /// task is only retried if `on_error` returns `true`, otherwise the error is returned.
+    pub(crate) async fn execute_with<F>(
+        &self,
+        state: &mut EngineState,
+        mut on_error: F,
+    ) -> Result<(), EngineTaskErrors>
+    where
+        F: FnMut(&EngineTaskErrors) -> bool + Send,
+    {
+        // Retry the task until it succeeds or a critical error occurs.
+        while let Err(e) = self.execute_inner(state).await {
+            let severity = e.severity();
+
+            kona_macros::inc!(
+                counter,
+                crate::Metrics::ENGINE_TASK_FAILURE,
+                self.task_metrics_label() => severity.to_string()
+            );
+
+            let retry = on_error(&e);
+            match severity {
+                EngineTaskErrorSeverity::Temporary if retry => {
+                    trace!(target: "engine", "{e}");
+                    continue;
+                }
+                EngineTaskErrorSeverity::Temporary => {
+                    warn!(target: "engine", "{e}. Stopped retrying the {} task", self.kind());
+                    return Err(e);
+                }
+                EngineTaskErrorSeverity::Critical => {
+                    error!(target: "engine", "{e}");
+                    return Err(e);
+                }
+                EngineTaskErrorSeverity::Reset => {
+                    warn!(target: "engine", "Engine requested derivation reset");
+                    return Err(e);
+                }
+                EngineTaskErrorSeverity::Flush => {
+                    warn!(target: "engine", "Engine requested derivation flush");
+                    return Err(e);
+                }
+            }
+        }
+
+        kona_macros::inc!(counter, crate::Metrics::ENGINE_TASK_SUCCESS, self.task_metrics_label());
+
+        Ok(())
+    }
+
     const fn task_metrics_label(&self) -> &'static str {
         match self {
             Self::Insert(_) => crate::Metrics::INSERT_TASK_LABEL,
@@ -181,38 +246,6 @@ impl EngineTaskExt for EngineTask {
     type Error = EngineTaskErrors;
 
     async fn execute(&self, state: &mut EngineState) -> Result<(), Self::Error> {
-        // Retry the task until it succeeds or a critical error occurs.
-        while let Err(e) = self.execute_inner(state).await {
-            let severity = e.severity();
-
-            kona_macros::inc!(
-                counter,
-                crate::Metrics::ENGINE_TASK_FAILURE,
-                self.task_metrics_label() => severity.to_string()
-            );
-
-            match severity {
-                EngineTaskErrorSeverity::Temporary => {
-                    trace!(target: "engine", "{e}");
-                    continue;
-                }
-                EngineTaskErrorSeverity::Critical => {
-                    error!(target: "engine", "{e}");
-                    return Err(e);
-                }
-                EngineTaskErrorSeverity::Reset => {
-                    warn!(target: "engine", "Engine requested derivation reset");
-                    return Err(e);
-                }
-                EngineTaskErrorSeverity::Flush => {
-                    warn!(target: "engine", "Engine requested derivation flush");
-                    return Err(e);
-                }
-            }
-        }
-
-        kona_macros::inc!(counter, crate::Metrics::ENGINE_TASK_SUCCESS, self.task_metrics_label());
-
-        Ok(())
+        self.execute_with(state, |_| true).await
     }
 }
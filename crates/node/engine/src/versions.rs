@@ -28,6 +28,14 @@ impl EngineForkchoiceVersion {
             Self::V2
         }
     }
+
+    /// Returns the name of the engine api method.
+    pub const fn method(&self) -> &'static str {
+        match self {
+            Self::V2 => "engine_forkchoiceUpdatedV2",
+            Self::V3 => "engine_forkchoiceUpdatedV3",
+        }
+    }
 }
 
 /// The method version for the `engine_newPayload` api.
@@ -55,6 +63,15 @@ impl EngineNewPayloadVersion {
             Self::V2
         }
     }
+
+    /// Returns the name of the engine api method.
+    pub const fn method(&self) -> &'static str {
+        match self {
+            Self::V2 => "engine_newPayloadV2",
+            Self::V3 => "engine_newPayloadV3",
+            Self::V4 => "engine_newPayloadV4",
+        }
+    }
 }
 
 /// The method version for the `engine_getPayload` api.
@@ -82,4 +99,13 @@ impl EngineGetPayloadVersion {
             Self::V2
         }
     }
+
+    /// Returns the name of the engine api method.
+    pub const fn method(&self) -> &'static str {
+        match self {
+            Self::V2 => "engine_getPayloadV2",
+            Self::V3 => "engine_getPayloadV3",
+            Self::V4 => "engine_getPayloadV4",
+        }
+    }
 }
@@ -5,13 +5,13 @@ use derive_more::Debug;
 use discv5::{Config, Discv5, Enr, enr::NodeId};
 use kona_peers::{BootNode, BootNodes, BootStore, EnrValidation, enr_to_multiaddr};
 use libp2p::Multiaddr;
-use std::path::PathBuf;
+use std::{net::SocketAddr, path::PathBuf};
 use tokio::{
-    sync::mpsc::channel,
+    sync::mpsc::{Sender, channel},
     time::{Duration, sleep},
 };
 
-use crate::{Discv5Builder, Discv5Handler, HandlerRequest, LocalNode};
+use crate::{DiscoveryStats, Discv5Builder, Discv5Handler, HandlerRequest, LocalNode};
 
 /// The [`Discv5Driver`] drives the discovery service.
 ///
@@ -48,6 +48,8 @@ pub struct Discv5Driver {
     /// The frequency at which to remove random nodes from the discovery table.
     /// This is not enabled (`None`) by default.
     pub remove_interval: Option<Duration>,
+    /// Statistics about the ENRs seen by the discovery service.
+    pub stats: DiscoveryStats,
 }
 
 impl Discv5Driver {
@@ -78,6 +80,7 @@ impl Discv5Driver {
             forward: true,
             remove_interval: None,
             store_interval: Duration::from_secs(60),
+            stats: DiscoveryStats::default(),
         }
     }
 
@@ -228,12 +231,71 @@ impl Discv5Driver {
             return;
         }
         for enr in self.store.valid_peers_with_chain_id(self.chain_id) {
-            if let Err(e) = enr_sender.send(enr.clone()).await {
-                debug!(target: "discovery", "Failed to forward enr: {:?}", e);
+            match enr_sender.send(enr.clone()).await {
+                Ok(()) => self.stats.forwarded += 1,
+                Err(e) => debug!(target: "discovery", "Failed to forward enr: {:?}", e),
             }
         }
     }
 
+    /// Validates an [`Enr`] found through discovery and forwards it to the enr receiver to be
+    /// dialed if it advertises the OP Stack ENR entry of our chain.
+    ///
+    /// The `source` labels where the ENR was found, for metrics.
+    fn handle_enr(&mut self, enr: Enr, source: &'static str, enr_sender: &Sender<Enr>) {
+        let validation = EnrValidation::validate(&enr, self.chain_id);
+        self.stats.record(&validation);
+        kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_ENR_FOUND, "source" => source);
+
+        if validation.is_invalid() {
+            trace!(target: "discovery", %validation, source, "Filtered out ENR: {:?}", enr);
+            kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_ENR_FILTERED, "reason" => validation.label());
+            return;
+        }
+
+        debug!(target: "discovery", source, "Valid ENR found, forwarding to swarm: {:?}", enr);
+        kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_EVENT, "type" => source);
+        kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_ENR_FORWARDED);
+        self.stats.forwarded += 1;
+        self.store.add_enr(enr.clone());
+
+        let sender = enr_sender.clone();
+        tokio::spawn(async move {
+            if let Err(e) = sender.send(enr).await {
+                debug!(target: "discovery", "Failed to send enr: {:?}", e);
+            }
+        });
+    }
+
+    /// Updates the TCP socket of the local [`Enr`] after discv5 observed a new external address
+    /// for the node, for example after a NAT change.
+    ///
+    /// Discv5 only updates the UDP socket of the local [`Enr`] and bumps its sequence number.
+    /// Keeping the TCP socket in sync ensures that peers fetching the new record dial the
+    /// swarm on the new address.
+    fn update_local_socket(&mut self, addr: SocketAddr) {
+        let local_enr = self.disc.local_enr();
+        let Some(tcp_port) = local_enr.tcp4().or_else(|| local_enr.tcp6()) else {
+            warn!(target: "discovery", %addr, "Local ENR has no TCP port, skipping ENR update");
+            return;
+        };
+
+        let tcp_socket = SocketAddr::new(addr.ip(), tcp_port);
+        if !self.disc.update_local_enr_socket(tcp_socket, true) {
+            warn!(target: "discovery", %tcp_socket, "Failed to update the local ENR TCP socket");
+            return;
+        }
+
+        self.stats.local_enr_updates += 1;
+        kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_LOCAL_ENR_UPDATE);
+        info!(
+            target: "discovery",
+            %addr,
+            seq = self.disc.local_enr().seq(),
+            "Published local ENR with updated external address"
+        );
+    }
+
     /// Spawns a new [`Discv5`] discovery service in a new tokio task.
     ///
     /// Returns a [`Discv5Handler`] to communicate with the spawned task.
@@ -241,6 +303,7 @@ impl Discv5Driver {
         let chain_id = self.chain_id;
         let (req_sender, mut req_recv) = channel::<HandlerRequest>(1024);
         let (enr_sender, enr_recv) = channel::<Enr>(1024);
+        let (found_sender, mut found_recv) = channel::<Enr>(1024);
 
         tokio::spawn(async move {
             let remove = self.remove_interval.is_some();
@@ -305,6 +368,11 @@ impl Discv5Driver {
                                         warn!(target: "discovery", "Failed to send peer count: {:?}", e);
                                     }
                                 }
+                                HandlerRequest::Stats(tx) => {
+                                    if let Err(e) = tx.send(self.stats.clone()) {
+                                        warn!(target: "discovery", "Failed to send stats: {:?}", e);
+                                    }
+                                }
                                 HandlerRequest::LocalEnr(tx) => {
                                     let enr = self.disc.local_enr().clone();
                                     if let Err(e) = tx.send(enr.clone()) {
@@ -358,60 +426,36 @@ impl Discv5Driver {
                         };
                         match event {
                             discv5::Event::Discovered(enr) => {
-                                if EnrValidation::validate(&enr, chain_id).is_valid() {
-                                    debug!(target: "discovery", "Valid ENR discovered, forwarding to swarm: {:?}", enr);
-                                    kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_EVENT, "type" => "discovered");
-                                    self.store.add_enr(enr.clone());
-                                    let sender = enr_sender.clone();
-                                    tokio::spawn(async move {
-                                        if let Err(e) = sender.send(enr).await {
-                                            debug!(target: "discovery", "Failed to send enr: {:?}", e);
-                                        }
-                                    });
-                                }
+                                self.handle_enr(enr, "discovered", &enr_sender);
                             }
                             discv5::Event::SessionEstablished(enr, addr) => {
-                                if EnrValidation::validate(&enr, chain_id).is_valid() {
-                                    debug!(target: "discovery", "Session established with valid ENR, forwarding to swarm. Address: {:?}, ENR: {:?}", addr, enr);
-                                    kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_EVENT, "type" => "session_established");
-                                    self.store.add_enr(enr.clone());
-                                    let sender = enr_sender.clone();
-                                    tokio::spawn(async move {
-                                        if let Err(e) = sender.send(enr).await {
-                                            debug!(target: "discovery", "Failed to send enr: {:?}", e);
-                                        }
-                                    });
-                                }
+                                trace!(target: "discovery", ?addr, "Session established");
+                                self.handle_enr(enr, "session_established", &enr_sender);
                             }
                             discv5::Event::UnverifiableEnr { enr, .. } => {
-                                if EnrValidation::validate(&enr, chain_id).is_valid() {
-                                    debug!(target: "discovery", "Valid ENR discovered, forwarding to swarm: {:?}", enr);
-                                    kona_macros::inc!(gauge, crate::Metrics::DISCOVERY_EVENT, "type" => "unverifiable_enr");
-                                    self.store.add_enr(enr.clone());
-                                    let sender = enr_sender.clone();
-                                    tokio::spawn(async move {
-                                        if let Err(e) = sender.send(enr).await {
-                                            debug!(target: "discovery", "Failed to send enr: {:?}", e);
-                                        }
-                                    });
-                                }
-
+                                self.handle_enr(enr, "unverifiable_enr", &enr_sender);
+                            }
+                            discv5::Event::SocketUpdated(addr) => {
+                                self.update_local_socket(addr);
                             }
                             _ => {}
                         }
                     }
+                    Some(enr) = found_recv.recv() => {
+                        self.handle_enr(enr, "find_node", &enr_sender);
+                    }
                     _ = interval.tick() => {
                         let id = NodeId::random();
                         trace!(target: "discovery", "Finding random node: {}", id);
                         kona_macros::inc!(gauge, crate::Metrics::FIND_NODE_REQUEST, "find_node" => "find_node");
                         let fut = self.disc.find_node(id);
-                        let enr_sender = enr_sender.clone();
+                        let found_sender = found_sender.clone();
                         tokio::spawn(async move {
                             match fut.await {
                                 Ok(nodes) => {
-                                    let enrs = nodes.into_iter().filter(|node| EnrValidation::validate(node, chain_id).is_valid());
-                                    for enr in enrs {
-                                        _ = enr_sender.send(enr).await;
+                                    // Found nodes are validated by the driver before being forwarded.
+                                    for enr in nodes {
+                                        _ = found_sender.send(enr).await;
                                     }
                                 }
                                 Err(err) => {
@@ -429,6 +473,14 @@ impl Discv5Driver {
                         debug!(target: "discovery", "Bootstore ENRs stored in {:?}", elapsed);
                         kona_macros::record!(histogram, crate::Metrics::ENR_STORE_TIME, "store_time", "store_time", elapsed.as_secs_f64());
                         kona_macros::set!(gauge, crate::Metrics::DISCOVERY_PEER_COUNT, self.disc.connected_peers() as f64);
+                        debug!(
+                            target: "discovery",
+                            found = self.stats.found,
+                            filtered = self.stats.filtered_total(),
+                            forwarded = self.stats.forwarded,
+                            "Discovery stats: {:?}",
+                            self.stats.filtered
+                        );
                     }
                     _ = removal_interval.tick() => {
                         if remove {
@@ -460,6 +512,7 @@ mod tests {
         handler::NodeContact,
     };
     use kona_genesis::{OP_MAINNET_CHAIN_ID, OP_SEPOLIA_CHAIN_ID};
+    use kona_peers::OpStackEnr;
 
     use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 
@@ -481,6 +534,69 @@ mod tests {
         assert_eq!(handle.chain_id, OP_SEPOLIA_CHAIN_ID);
     }
 
+    fn op_stack_enr(chain_id: Option<u64>) -> Enr {
+        use alloy_rlp::Encodable;
+
+        let key = CombinedKey::generate_secp256k1();
+        let mut builder = Enr::builder();
+        if let Some(chain_id) = chain_id {
+            let mut opstack_data = Vec::new();
+            OpStackEnr::from_chain_id(chain_id).encode(&mut opstack_data);
+            builder.add_value_rlp(OpStackEnr::OP_CL_KEY, opstack_data.into());
+        }
+        builder.ip4(Ipv4Addr::LOCALHOST).tcp4(9000).udp4(9001).build(&key).unwrap()
+    }
+
+    fn test_driver(ip: IpAddr, tcp_port: u16, udp_port: u16) -> Discv5Driver {
+        let CombinedKey::Secp256k1(secret_key) = CombinedKey::generate_secp256k1() else {
+            unreachable!()
+        };
+
+        let socket = SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0);
+        let mut discovery = Discv5Driver::builder(
+            LocalNode::new(secret_key, ip, tcp_port, udp_port),
+            OP_SEPOLIA_CHAIN_ID,
+            ConfigBuilder::new(socket.into()).build(),
+        )
+        .build()
+        .expect("Failed to build discovery service");
+        discovery.store.path = std::env::temp_dir().join("filter-bootstore.json");
+        discovery
+    }
+
+    #[tokio::test]
+    async fn test_discv5_driver_filters_enrs() {
+        let mut discovery = test_driver(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0, 0);
+        let (enr_sender, mut enr_recv) = channel(16);
+
+        let valid = op_stack_enr(Some(OP_SEPOLIA_CHAIN_ID));
+        discovery.handle_enr(valid.clone(), "discovered", &enr_sender);
+        discovery.handle_enr(op_stack_enr(Some(OP_MAINNET_CHAIN_ID)), "discovered", &enr_sender);
+        discovery.handle_enr(op_stack_enr(None), "find_node", &enr_sender);
+
+        assert_eq!(enr_recv.recv().await.unwrap(), valid);
+        assert!(enr_recv.try_recv().is_err());
+
+        assert_eq!(discovery.stats.found, 3);
+        assert_eq!(discovery.stats.forwarded, 1);
+        assert_eq!(discovery.stats.filtered.get("invalid_chain_id"), Some(&1));
+        assert_eq!(discovery.stats.filtered.get("missing_key"), Some(&1));
+    }
+
+    #[tokio::test]
+    async fn test_discv5_driver_updates_local_socket() {
+        let mut discovery = test_driver(IpAddr::V4(Ipv4Addr::LOCALHOST), 9000, 9001);
+        let seq = discovery.disc.local_enr().seq();
+
+        discovery.update_local_socket(SocketAddr::new(Ipv4Addr::new(10, 0, 0, 1).into(), 9001));
+
+        let local_enr = discovery.disc.local_enr();
+        assert_eq!(local_enr.ip4(), Some(Ipv4Addr::new(10, 0, 0, 1)));
+        assert_eq!(local_enr.tcp4(), Some(9000));
+        assert!(local_enr.seq() > seq);
+        assert_eq!(discovery.stats.local_enr_updates, 1);
+    }
+
     #[tokio::test]
     async fn test_online_discv5_driver_bootstrap_testnet() {
         // Use a test directory to make sure bootstore
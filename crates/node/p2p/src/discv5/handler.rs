@@ -5,6 +5,8 @@ use libp2p::Multiaddr;
 use std::{collections::HashSet, string::String, sync::Arc, time::Duration};
 use tokio::sync::mpsc::Sender;
 
+use crate::DiscoveryStats;
+
 /// A request from the [`Discv5Handler`] to the spawned [`discv5::Discv5`] service.
 #[derive(Debug)]
 pub enum HandlerRequest {
@@ -12,6 +14,8 @@ pub enum HandlerRequest {
     Metrics(tokio::sync::oneshot::Sender<Metrics>),
     /// Returns the number of connected peers.
     PeerCount(tokio::sync::oneshot::Sender<usize>),
+    /// Requests the [`DiscoveryStats`] of the discovery service.
+    Stats(tokio::sync::oneshot::Sender<DiscoveryStats>),
     /// Request for the [`discv5::Discv5`] service to call [`discv5::Discv5::add_enr`] with the
     /// specified [`Enr`].
     AddEnr(Enr),
@@ -142,4 +146,18 @@ impl Discv5Handler {
         });
         rx
     }
+
+    /// Blocking request for the [`DiscoveryStats`] of the discovery service.
+    ///
+    /// Returns `None` if the request could not be sent or received.
+    pub fn stats(&self) -> tokio::sync::oneshot::Receiver<DiscoveryStats> {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            if let Err(e) = sender.send(HandlerRequest::Stats(tx)).await {
+                warn!(target: "discovery", err = ?e, "Failed to send stats request");
+            }
+        });
+        rx
+    }
 }
@@ -9,5 +9,8 @@ pub use error::Discv5BuilderError;
 mod driver;
 pub use driver::Discv5Driver;
 
+mod stats;
+pub use stats::DiscoveryStats;
+
 mod handler;
 pub use handler::{Discv5Handler, HandlerRequest};
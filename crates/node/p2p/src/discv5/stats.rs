@@ -0,0 +1,77 @@
+//! Statistics about the ENRs seen by the discovery service.
+
+use kona_peers::EnrValidation;
+use std::collections::BTreeMap;
+
+/// Statistics about the ENRs seen by the [`Discv5Driver`].
+///
+/// These are useful to understand why a node does not find peers, for example on a private
+/// devnet where all discovered nodes advertise a different chain id.
+///
+/// [`Discv5Driver`]: crate::Discv5Driver
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiscoveryStats {
+    /// The number of ENRs found through discovery, before filtering.
+    pub found: u64,
+    /// The number of ENRs filtered out, keyed by the [`EnrValidation::label`] of the failure.
+    pub filtered: BTreeMap<String, u64>,
+    /// The number of ENRs forwarded to the swarm to be dialed.
+    pub forwarded: u64,
+    /// The number of times the local ENR was updated with a new external address.
+    pub local_enr_updates: u64,
+}
+
+impl DiscoveryStats {
+    /// Records a found ENR and the outcome of its validation.
+    pub fn record(&mut self, validation: &EnrValidation) {
+        self.found += 1;
+        if validation.is_invalid() {
+            *self.filtered.entry(validation.label().into()).or_default() += 1;
+        }
+    }
+
+    /// Returns the total number of filtered ENRs.
+    pub fn filtered_total(&self) -> u64 {
+        self.filtered.values().sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_peers::OpStackEnrError;
+
+    #[test]
+    fn test_discovery_stats_record() {
+        let mut stats = DiscoveryStats::default();
+        stats.record(&EnrValidation::Valid);
+        stats.record(&EnrValidation::InvalidChainId(11));
+        stats.record(&EnrValidation::InvalidChainId(12));
+        stats.record(&EnrValidation::ConversionError(OpStackEnrError::MissingKey));
+
+        assert_eq!(stats.found, 4);
+        assert_eq!(stats.filtered_total(), 3);
+        assert_eq!(stats.filtered.get("invalid_chain_id"), Some(&2));
+        assert_eq!(stats.filtered.get("missing_key"), Some(&1));
+        assert_eq!(stats.filtered.get("valid"), None);
+    }
+
+    #[test]
+    fn test_discovery_stats_serde() {
+        let mut stats = DiscoveryStats { forwarded: 1, local_enr_updates: 2, ..Default::default() };
+        stats.record(&EnrValidation::InvalidChainId(11));
+
+        let json = serde_json::to_value(&stats).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "found": 1,
+                "filtered": { "invalid_chain_id": 1 },
+                "forwarded": 1,
+                "localEnrUpdates": 2,
+            })
+        );
+        assert_eq!(serde_json::from_value::<DiscoveryStats>(json).unwrap(), stats);
+    }
+}
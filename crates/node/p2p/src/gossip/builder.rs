@@ -3,15 +3,13 @@
 use alloy_primitives::Address;
 use kona_genesis::RollupConfig;
 use kona_peers::{PeerMonitoring, PeerScoreLevel};
-use libp2p::{
-    Multiaddr, StreamProtocol, SwarmBuilder, gossipsub::Config, identity::Keypair,
-    noise::Config as NoiseConfig, tcp::Config as TcpConfig, yamux::Config as YamuxConfig,
-};
+use libp2p::{Multiaddr, StreamProtocol, SwarmBuilder, gossipsub::Config, identity::Keypair};
 use std::time::Duration;
 use tokio::sync::watch::{self};
 
 use crate::{
-    Behaviour, BlockHandler, GossipDriver, GossipDriverBuilderError, gossip::gater::GaterConfig,
+    Behaviour, BlockHandler, GossipDriver, GossipDriverBuilderError, PublishQueue,
+    PublishRetryConfig, TransportConfig, gossip::gater::GaterConfig,
 };
 
 /// A builder for the [`GossipDriver`].
@@ -38,6 +36,10 @@ pub struct GossipDriverBuilder {
     gater_config: Option<GaterConfig>,
     /// Topic scoring. Disabled by default.
     topic_scoring: bool,
+    /// The configuration for retrying failed unsafe block publications.
+    publish_retry: Option<PublishRetryConfig>,
+    /// The transports of the swarm.
+    transport: TransportConfig,
 }
 
 impl GossipDriverBuilder {
@@ -59,6 +61,8 @@ impl GossipDriverBuilder {
             gater_config: None,
             rollup_config,
             topic_scoring: false,
+            publish_retry: None,
+            transport: TransportConfig::default(),
         }
     }
 
@@ -68,6 +72,18 @@ impl GossipDriverBuilder {
         self
     }
 
+    /// Sets the [`PublishRetryConfig`] used to retry failed unsafe block publications.
+    pub const fn with_publish_retry(mut self, config: PublishRetryConfig) -> Self {
+        self.publish_retry = Some(config);
+        self
+    }
+
+    /// Sets the [`TransportConfig`] of the swarm.
+    pub const fn with_transport(mut self, transport: TransportConfig) -> Self {
+        self.transport = transport;
+        self
+    }
+
     /// Sets the [`RollupConfig`] for the network.
     /// This is used to determine the topic to publish to.
     pub fn with_rollup_config(mut self, rollup_config: RollupConfig) -> Self {
@@ -196,16 +212,17 @@ impl GossipDriverBuilder {
 
         // Build the swarm.
         debug!(target: "gossip", "Building Swarm with Peer ID: {}", keypair.public().to_peer_id());
+        info!(
+            target: "gossip",
+            "TRANSPORT: [Security: {}] [QUIC: {:?}] [IPv6: {:?}]",
+            self.transport.security,
+            self.transport.quic_port,
+            self.transport.ipv6
+        );
+        let transport = self.transport.build(&keypair)?;
         let swarm = SwarmBuilder::with_existing_identity(keypair)
             .with_tokio()
-            .with_tcp(
-                TcpConfig::default().nodelay(true),
-                |i: &Keypair| {
-                    debug!(target: "gossip", "Noise Config Peer ID: {}", i.public().to_peer_id());
-                    NoiseConfig::new(i)
-                },
-                YamuxConfig::default,
-            )
+            .with_other_transport(|_| transport)
             .map_err(|_| GossipDriverBuilderError::TcpError)?
             .with_behaviour(|_| behaviour)
             .map_err(|_| GossipDriverBuilderError::WithBehaviourError)?
@@ -215,6 +232,11 @@ impl GossipDriverBuilder {
         let gater_config = self.gater_config.take().unwrap_or_default();
         let gate = crate::ConnectionGater::new(gater_config);
 
-        Ok((GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate), signer_tx))
+        let additional_addrs = self.transport.listen_addrs(&addr).split_off(1);
+        let mut driver = GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate);
+        driver.additional_addrs = additional_addrs;
+        driver.publish_queue = PublishQueue::new(self.publish_retry.unwrap_or_default());
+
+        Ok((driver, signer_tx))
     }
 }
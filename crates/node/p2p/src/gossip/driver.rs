@@ -23,7 +23,8 @@ use tokio::sync::Mutex;
 
 use crate::{
     Behaviour, BlockHandler, ConnectionGate, ConnectionGater, Event, GossipDriverBuilder, Handler,
-    PublishError,
+    PendingPublish, PublishError, PublishQueue,
+    gossip::publisher::{error_label, is_retryable},
 };
 
 /// A driver for a [`Swarm`] instance.
@@ -37,6 +38,8 @@ pub struct GossipDriver<G: ConnectionGate> {
     pub swarm: Swarm<Behaviour>,
     /// A [`Multiaddr`] to listen on.
     pub addr: Multiaddr,
+    /// Additional [`Multiaddr`]s to listen on, such as IPv6 or QUIC addresses.
+    pub additional_addrs: Vec<Multiaddr>,
     /// The [`BlockHandler`].
     pub handler: BlockHandler,
     /// A [`libp2p_stream::Control`] instance. Can be used to control the sync request/response
@@ -61,6 +64,8 @@ pub struct GossipDriver<G: ConnectionGate> {
     pub connection_gate: G,
     /// Tracks ping times for peers.
     pub ping: Arc<Mutex<HashMap<PeerId, Duration>>>,
+    /// Unsafe block publications waiting to be retried.
+    pub publish_queue: PublishQueue,
 }
 
 impl<G> GossipDriver<G>
@@ -89,6 +94,7 @@ where
         Self {
             swarm,
             addr,
+            additional_addrs: Default::default(),
             handler,
             peerstore: Default::default(),
             peer_monitoring: None,
@@ -97,6 +103,7 @@ where
             sync_protocol: Some(sync_protocol),
             connection_gate: gate,
             ping: Arc::new(Mutex::new(Default::default())),
+            publish_queue: Default::default(),
         }
     }
 
@@ -111,7 +118,8 @@ where
     /// ## Returns
     ///
     /// Returns the [`MessageId`] of the published message or a [`PublishError`]
-    /// if the message could not be published.
+    /// if the message could not be published. If the publication failed because of a transient
+    /// error, it is queued to be retried (see [`Self::retry_publishes`]).
     pub fn publish(
         &mut self,
         selector: impl FnOnce(&BlockHandler) -> IdentTopic,
@@ -121,11 +129,101 @@ where
             return Ok(None);
         };
         let topic = selector(&self.handler);
-        let topic_hash = topic.hash();
-        let data = self.handler.encode(topic, payload)?;
-        let id = self.swarm.behaviour_mut().gossipsub.publish(topic_hash, data)?;
-        kona_macros::inc!(gauge, crate::Metrics::UNSAFE_BLOCK_PUBLISHED);
-        Ok(Some(id))
+        let payload_hash = payload.payload_hash;
+        let data = self.handler.encode(topic.clone(), payload)?;
+        self.try_publish(PendingPublish { topic, payload_hash, data, attempts: 0 })
+    }
+
+    /// Publishes a signed unsafe block to gossip, selecting the versioned block topic from the
+    /// hardfork schedule and the payload timestamp.
+    pub fn publish_block(
+        &mut self,
+        payload: OpNetworkPayloadEnvelope,
+    ) -> Result<Option<MessageId>, PublishError> {
+        let timestamp = payload.payload.timestamp();
+        self.publish(|handler| handler.topic(timestamp), Some(payload))
+    }
+
+    /// Attempts to publish all unsafe blocks waiting in the [`PublishQueue`], oldest first.
+    ///
+    /// Publications that fail again with a transient error are re-queued until they exhaust their
+    /// retries.
+    pub fn retry_publishes(&mut self) {
+        for pending in self.publish_queue.take() {
+            kona_macros::inc!(gauge, crate::Metrics::UNSAFE_BLOCK_PUBLISH_RETRY);
+            let payload_hash = pending.payload_hash;
+            match self.try_publish(pending) {
+                Ok(id) => {
+                    info!(target: "gossip", ?id, hash = ?payload_hash, "Published unsafe block after retry")
+                }
+                Err(err) => {
+                    debug!(target: "gossip", ?err, hash = ?payload_hash, "Retry to publish unsafe block failed")
+                }
+            }
+        }
+    }
+
+    /// Publishes an encoded unsafe block, queuing it for a retry if the publication failed with a
+    /// transient error.
+    fn try_publish(
+        &mut self,
+        mut pending: PendingPublish,
+    ) -> Result<Option<MessageId>, PublishError> {
+        let version = self.handler.topic_version(&pending.topic.hash()).unwrap_or("unknown");
+        pending.attempts += 1;
+
+        let result = self
+            .swarm
+            .behaviour_mut()
+            .gossipsub
+            .publish(pending.topic.hash(), pending.data.clone());
+
+        let err = match result {
+            Ok(id) => {
+                kona_macros::inc!(gauge, crate::Metrics::UNSAFE_BLOCK_PUBLISHED, "topic" => version);
+                return Ok(Some(id));
+            }
+            // The block was already published (for instance by a previous attempt that we
+            // considered failed). There is nothing left to do.
+            Err(libp2p::gossipsub::PublishError::Duplicate) => {
+                debug!(target: "gossip", hash = ?pending.payload_hash, "Unsafe block already published");
+                return Ok(None);
+            }
+            Err(err) => err,
+        };
+
+        kona_macros::inc!(
+            gauge,
+            crate::Metrics::UNSAFE_BLOCK_PUBLISH_ERROR,
+            "topic" => version,
+            "reason" => error_label(&err)
+        );
+
+        if is_retryable(&err) && self.publish_queue.can_retry(&pending) {
+            warn!(
+                target: "gossip",
+                ?err,
+                hash = ?pending.payload_hash,
+                attempts = pending.attempts,
+                mesh_peers = self.swarm.behaviour().gossipsub.mesh_peers(&pending.topic.hash()).count(),
+                "Failed to publish unsafe block, scheduling a retry"
+            );
+            if let Some(evicted) = self.publish_queue.push(pending) {
+                error!(target: "gossip", hash = ?evicted.payload_hash, "Publish retry queue is full, dropping unsafe block");
+                kona_macros::inc!(gauge, crate::Metrics::UNSAFE_BLOCK_PUBLISH_DROPPED, "reason" => "queue_full");
+            }
+        } else {
+            error!(
+                target: "gossip",
+                ?err,
+                hash = ?pending.payload_hash,
+                attempts = pending.attempts,
+                "Failed to publish unsafe block, dropping it"
+            );
+            kona_macros::inc!(gauge, crate::Metrics::UNSAFE_BLOCK_PUBLISH_DROPPED, "reason" => error_label(&err));
+        }
+
+        Err(err.into())
     }
 
     /// Handles the sync request/response protocol.
@@ -182,14 +280,23 @@ where
     /// Starts the libp2p Swarm.
     ///
     /// - Starts the sync request/response protocol handler.
-    /// - Tells the swarm to listen on the given [`Multiaddr`].
+    /// - Tells the swarm to listen on the given [`Multiaddr`] and on the additional addresses.
     ///
     /// Waits for the swarm to start listen before returning and connecting to peers.
     pub async fn start(&mut self) -> Result<(), TransportError<std::io::Error>> {
         // Start the sync request/response protocol handler.
         self.sync_protocol_handler();
 
-        match self.swarm.listen_on(self.addr.clone()) {
+        let addrs = std::iter::once(self.addr.clone()).chain(self.additional_addrs.clone());
+        for addr in addrs {
+            self.listen(addr).await?;
+        }
+        Ok(())
+    }
+
+    /// Tells the swarm to listen on the given [`Multiaddr`] and waits for it to start listening.
+    async fn listen(&mut self, addr: Multiaddr) -> Result<(), TransportError<std::io::Error>> {
+        match self.swarm.listen_on(addr.clone()) {
             Ok(id) => loop {
                 if let SwarmEvent::NewListenAddr { address, listener_id } =
                     self.swarm.select_next_some().await
@@ -201,7 +308,7 @@ where
                 }
             },
             Err(err) => {
-                error!(target: "gossip", "Fail to listen on {}: {err}", self.addr);
+                error!(target: "gossip", "Fail to listen on {addr}: {err}");
                 return Err(err);
             }
         }
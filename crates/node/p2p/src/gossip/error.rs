@@ -34,6 +34,12 @@ pub enum GossipDriverBuilderError {
     /// A TCP error.
     #[error("TCP error")]
     TcpError,
+    /// An error when generating the noise keys of the node.
+    #[error("error configuring noise security")]
+    NoiseError,
+    /// An error when generating the TLS certificate of the node.
+    #[error("error configuring TLS security")]
+    TlsError,
     /// An error when setting the behaviour on the swarm builder.
     #[error("error setting behaviour on swarm builder")]
     WithBehaviourError,
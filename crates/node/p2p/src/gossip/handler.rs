@@ -117,6 +117,17 @@ impl BlockHandler {
         }
     }
 
+    /// Returns the version label of the given block topic, if it is one of the handler's topics.
+    pub fn topic_version(&self, topic: &TopicHash) -> Option<&'static str> {
+        match topic {
+            hash if *hash == self.blocks_v1_topic.hash() => Some("v1"),
+            hash if *hash == self.blocks_v2_topic.hash() => Some("v2"),
+            hash if *hash == self.blocks_v3_topic.hash() => Some("v3"),
+            hash if *hash == self.blocks_v4_topic.hash() => Some("v4"),
+            _ => None,
+        }
+    }
+
     /// Encodes a [`OpNetworkPayloadEnvelope`] into a byte array
     /// based on the specified topic.
     pub fn encode(
@@ -190,6 +201,33 @@ mod tests {
         assert!(matches!(handler.handle(message).0, MessageAcceptance::Accept));
     }
 
+    #[test]
+    fn test_topic_selection_follows_hardforks() {
+        let (_, signer) = tokio::sync::watch::channel(Address::ZERO);
+        let handler = BlockHandler::new(
+            RollupConfig {
+                l2_chain_id: Chain::optimism_mainnet(),
+                hardforks: kona_genesis::HardForkConfig {
+                    canyon_time: Some(10),
+                    ecotone_time: Some(20),
+                    isthmus_time: Some(30),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            signer,
+        );
+
+        let cases = [(0, "v1"), (10, "v2"), (19, "v2"), (20, "v3"), (29, "v3"), (30, "v4")];
+        for (timestamp, version) in cases {
+            let topic = handler.topic(timestamp);
+            assert_eq!(handler.topic_version(&topic.hash()), Some(version));
+        }
+
+        let unknown = IdentTopic::new("/optimism/10/4/blocks");
+        assert_eq!(handler.topic_version(&unknown.hash()), None);
+    }
+
     /// This payload has a wrong hash so the signature won't be valid.
     #[test]
     fn test_invalid_decode_payload_hash() {
@@ -24,6 +24,9 @@ pub use gater::{
 mod builder;
 pub use builder::GossipDriverBuilder;
 
+mod transport;
+pub use transport::{TransportConfig, TransportSecurity};
+
 mod error;
 pub use error::{DialError, GossipDriverBuilderError, HandlerEncodeError, PublishError};
 
@@ -36,6 +39,12 @@ pub use handler::{BlockHandler, Handler};
 mod driver;
 pub use driver::GossipDriver;
 
+mod publisher;
+pub use publisher::{
+    DEFAULT_PUBLISH_MAX_RETRIES, DEFAULT_PUBLISH_RETRY_INTERVAL, MAX_PENDING_PUBLISHES,
+    PendingPublish, PublishQueue, PublishRetryConfig, is_retryable,
+};
+
 mod block_validity;
 pub use block_validity::BlockInvalidError;
 
@@ -0,0 +1,179 @@
+//! Retry queue for unsafe block publications.
+
+use libp2p::gossipsub::{IdentTopic, PublishError as GossipsubPublishError};
+use op_alloy_rpc_types_engine::PayloadHash;
+use std::{collections::VecDeque, time::Duration};
+
+/// The default number of times a failed unsafe block publication is retried.
+pub const DEFAULT_PUBLISH_MAX_RETRIES: u32 = 5;
+
+/// The default interval between two attempts to publish an unsafe block.
+pub const DEFAULT_PUBLISH_RETRY_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The maximum number of unsafe blocks waiting to be republished.
+///
+/// Once the queue is full, the oldest pending publication is dropped to make room for the new one,
+/// since a newer unsafe block is always more valuable to the network than an older one.
+pub const MAX_PENDING_PUBLISHES: usize = 64;
+
+/// Configuration for retrying failed unsafe block publications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PublishRetryConfig {
+    /// The maximum number of retries after the first failed attempt. Retries are disabled if set
+    /// to `0`.
+    pub max_retries: u32,
+    /// The interval between two publication attempts.
+    pub retry_interval: Duration,
+}
+
+impl Default for PublishRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: DEFAULT_PUBLISH_MAX_RETRIES,
+            retry_interval: DEFAULT_PUBLISH_RETRY_INTERVAL,
+        }
+    }
+}
+
+/// An encoded unsafe block that failed to be published and is waiting to be retried.
+#[derive(Debug, Clone)]
+pub struct PendingPublish {
+    /// The topic the block is published on.
+    pub topic: IdentTopic,
+    /// The hash of the published payload.
+    pub payload_hash: PayloadHash,
+    /// The encoded payload envelope.
+    pub data: Vec<u8>,
+    /// The number of publication attempts made so far.
+    pub attempts: u32,
+}
+
+/// A bounded queue of unsafe blocks waiting to be republished.
+#[derive(Debug, Default)]
+pub struct PublishQueue {
+    /// The [`PublishRetryConfig`].
+    pub config: PublishRetryConfig,
+    /// The pending publications, oldest first.
+    pending: VecDeque<PendingPublish>,
+}
+
+impl PublishQueue {
+    /// Creates a new, empty [`PublishQueue`].
+    pub const fn new(config: PublishRetryConfig) -> Self {
+        Self { config, pending: VecDeque::new() }
+    }
+
+    /// Returns `true` if there are no pending publications.
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Returns the number of pending publications.
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Returns `true` if the given publication may be attempted again.
+    pub const fn can_retry(&self, pending: &PendingPublish) -> bool {
+        pending.attempts <= self.config.max_retries
+    }
+
+    /// Enqueues a publication to be retried.
+    ///
+    /// Returns the publication that was evicted to make room for the new one, if the queue was
+    /// full.
+    pub fn push(&mut self, pending: PendingPublish) -> Option<PendingPublish> {
+        let evicted = (self.pending.len() >= MAX_PENDING_PUBLISHES)
+            .then(|| self.pending.pop_front())
+            .flatten();
+        self.pending.push_back(pending);
+        evicted
+    }
+
+    /// Takes all pending publications out of the queue.
+    pub fn take(&mut self) -> VecDeque<PendingPublish> {
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Returns `true` if the [`GossipsubPublishError`] is transient and the publication should be
+/// retried.
+///
+/// This is the case when the node does not have enough peers in the mesh for the topic yet, or
+/// when the outbound queues of all peers are full.
+pub const fn is_retryable(err: &GossipsubPublishError) -> bool {
+    matches!(
+        err,
+        GossipsubPublishError::NoPeersSubscribedToTopic | GossipsubPublishError::AllQueuesFull(_)
+    )
+}
+
+/// Returns a short label for the [`GossipsubPublishError`], used in metrics.
+pub(crate) const fn error_label(err: &GossipsubPublishError) -> &'static str {
+    match err {
+        GossipsubPublishError::Duplicate => "duplicate",
+        GossipsubPublishError::SigningError(_) => "signing",
+        GossipsubPublishError::NoPeersSubscribedToTopic => "insufficient_peers",
+        GossipsubPublishError::MessageTooLarge => "message_too_large",
+        GossipsubPublishError::TransformFailed(_) => "transform_failed",
+        GossipsubPublishError::AllQueuesFull(_) => "queues_full",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+
+    fn pending(attempts: u32) -> PendingPublish {
+        PendingPublish {
+            topic: IdentTopic::new("/optimism/10/3/blocks"),
+            payload_hash: PayloadHash(B256::ZERO),
+            data: vec![attempts as u8],
+            attempts,
+        }
+    }
+
+    #[test]
+    fn test_publish_queue_evicts_oldest() {
+        let mut queue = PublishQueue::new(PublishRetryConfig::default());
+        for i in 0..MAX_PENDING_PUBLISHES {
+            assert!(queue.push(pending(i as u32)).is_none());
+        }
+        assert_eq!(queue.len(), MAX_PENDING_PUBLISHES);
+
+        let evicted = queue.push(pending(u32::MAX)).unwrap();
+        assert_eq!(evicted.attempts, 0);
+        assert_eq!(queue.len(), MAX_PENDING_PUBLISHES);
+
+        let pending = queue.take();
+        assert!(queue.is_empty());
+        assert_eq!(pending.front().unwrap().attempts, 1);
+        assert_eq!(pending.back().unwrap().attempts, u32::MAX);
+    }
+
+    #[test]
+    fn test_publish_queue_can_retry() {
+        let queue = PublishQueue::new(PublishRetryConfig {
+            max_retries: 2,
+            retry_interval: Duration::from_millis(10),
+        });
+        assert!(queue.can_retry(&pending(1)));
+        assert!(queue.can_retry(&pending(2)));
+        assert!(!queue.can_retry(&pending(3)));
+
+        let queue = PublishQueue::new(PublishRetryConfig {
+            max_retries: 0,
+            retry_interval: Duration::from_millis(10),
+        });
+        assert!(!queue.can_retry(&pending(1)));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable(&GossipsubPublishError::NoPeersSubscribedToTopic));
+        assert!(is_retryable(&GossipsubPublishError::AllQueuesFull(8)));
+        assert!(!is_retryable(&GossipsubPublishError::Duplicate));
+        assert!(!is_retryable(&GossipsubPublishError::MessageTooLarge));
+    }
+}
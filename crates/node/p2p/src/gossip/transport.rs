@@ -0,0 +1,188 @@
+//! Transport configuration for the gossip [`Swarm`].
+//!
+//! [`Swarm`]: libp2p::Swarm
+
+use derive_more::{Display, FromStr};
+use futures::future::Either;
+use libp2p::{
+    Multiaddr, PeerId, Transport,
+    core::{muxing::StreamMuxerBox, transport::Boxed, upgrade::Version},
+    identity::Keypair,
+    multiaddr::Protocol,
+    noise, quic, tcp, tls, yamux,
+};
+use std::net::{IpAddr, Ipv6Addr};
+
+use crate::GossipDriverBuilderError;
+
+/// The security protocol used to authenticate and encrypt TCP connections.
+///
+/// QUIC connections are always secured with TLS 1.3, regardless of this setting.
+#[derive(Debug, FromStr, Display, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TransportSecurity {
+    /// The [noise] protocol. This is what the `op-node` uses.
+    ///
+    /// [noise]: https://github.com/libp2p/specs/tree/master/noise
+    #[default]
+    Noise,
+    /// The [libp2p TLS] handshake.
+    ///
+    /// [libp2p TLS]: https://github.com/libp2p/specs/blob/master/tls/tls.md
+    Tls,
+}
+
+/// The transports the gossip [`Swarm`] listens on and dials with.
+///
+/// By default, only TCP secured with [`TransportSecurity::Noise`] is used, on the single address
+/// the gossip driver is configured with.
+///
+/// [`Swarm`]: libp2p::Swarm
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TransportConfig {
+    /// The security protocol of TCP connections.
+    pub security: TransportSecurity,
+    /// If set, the swarm also listens for QUIC connections on this UDP port.
+    pub quic_port: Option<u16>,
+    /// If set, the swarm also listens on this IPv6 address, in addition to the IPv4 gossip
+    /// address.
+    pub ipv6: Option<Ipv6Addr>,
+}
+
+impl TransportConfig {
+    /// Returns the [`Multiaddr`]s to listen on, given the TCP gossip address.
+    ///
+    /// The gossip address is always the first element. It is followed by its IPv6 counterpart if
+    /// [`Self::ipv6`] is set, and by QUIC addresses for each listened IP if [`Self::quic_port`] is
+    /// set.
+    pub fn listen_addrs(&self, gossip_addr: &Multiaddr) -> Vec<Multiaddr> {
+        let mut ips = Vec::with_capacity(2);
+        let mut tcp_port = None;
+        for protocol in gossip_addr.iter() {
+            match protocol {
+                Protocol::Ip4(ip) => ips.push(IpAddr::V4(ip)),
+                Protocol::Ip6(ip) => ips.push(IpAddr::V6(ip)),
+                Protocol::Tcp(port) => tcp_port = Some(port),
+                _ => {}
+            }
+        }
+
+        let mut addrs = vec![gossip_addr.clone()];
+        if let Some(ipv6) = self.ipv6 {
+            if !ips.contains(&IpAddr::V6(ipv6)) {
+                ips.push(IpAddr::V6(ipv6));
+                if let Some(port) = tcp_port {
+                    addrs.push(Multiaddr::from(ipv6).with(Protocol::Tcp(port)));
+                }
+            }
+        }
+        if let Some(port) = self.quic_port {
+            addrs
+                .extend(ips.into_iter().map(|ip| {
+                    Multiaddr::from(ip).with(Protocol::Udp(port)).with(Protocol::QuicV1)
+                }));
+        }
+        addrs
+    }
+
+    /// Builds the libp2p transport for the given [`Keypair`].
+    ///
+    /// TCP connections are secured with the configured [`TransportSecurity`] and multiplexed with
+    /// yamux. The QUIC transport is only added if a [`Self::quic_port`] is configured.
+    pub(crate) fn build(
+        &self,
+        keypair: &Keypair,
+    ) -> Result<Boxed<(PeerId, StreamMuxerBox)>, GossipDriverBuilderError> {
+        let tcp = tcp::tokio::Transport::new(tcp::Config::default().nodelay(true))
+            .upgrade(Version::V1Lazy);
+        let tcp = match self.security {
+            TransportSecurity::Noise => tcp
+                .authenticate(
+                    noise::Config::new(keypair)
+                        .map_err(|_| GossipDriverBuilderError::NoiseError)?,
+                )
+                .multiplex(yamux::Config::default())
+                .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+                .boxed(),
+            TransportSecurity::Tls => tcp
+                .authenticate(
+                    tls::Config::new(keypair).map_err(|_| GossipDriverBuilderError::TlsError)?,
+                )
+                .multiplex(yamux::Config::default())
+                .map(|(peer_id, muxer), _| (peer_id, StreamMuxerBox::new(muxer)))
+                .boxed(),
+        };
+
+        if self.quic_port.is_none() {
+            return Ok(tcp);
+        }
+
+        let quic = quic::tokio::Transport::new(quic::Config::new(keypair))
+            .map(|(peer_id, conn), _| (peer_id, StreamMuxerBox::new(conn)));
+        Ok(quic.or_transport(tcp).map(|either, _| Either::into_inner(either)).boxed())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn tcp_addr(ip: IpAddr, port: u16) -> Multiaddr {
+        Multiaddr::from(ip).with(Protocol::Tcp(port))
+    }
+
+    fn quic_addr(ip: IpAddr, port: u16) -> Multiaddr {
+        Multiaddr::from(ip).with(Protocol::Udp(port)).with(Protocol::QuicV1)
+    }
+
+    #[test]
+    fn test_transport_security_from_str() {
+        assert_eq!("noise".parse::<TransportSecurity>().unwrap(), TransportSecurity::Noise);
+        assert_eq!("tls".parse::<TransportSecurity>().unwrap(), TransportSecurity::Tls);
+        assert!("plaintext".parse::<TransportSecurity>().is_err());
+    }
+
+    #[test]
+    fn test_listen_addrs_default() {
+        let addr = tcp_addr(Ipv4Addr::UNSPECIFIED.into(), 9222);
+        assert_eq!(TransportConfig::default().listen_addrs(&addr), vec![addr]);
+    }
+
+    #[test]
+    fn test_listen_addrs_dual_stack_quic() {
+        let addr = tcp_addr(Ipv4Addr::UNSPECIFIED.into(), 9222);
+        let config = TransportConfig {
+            quic_port: Some(9224),
+            ipv6: Some(Ipv6Addr::UNSPECIFIED),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            config.listen_addrs(&addr),
+            vec![
+                addr,
+                tcp_addr(Ipv6Addr::UNSPECIFIED.into(), 9222),
+                quic_addr(Ipv4Addr::UNSPECIFIED.into(), 9224),
+                quic_addr(Ipv6Addr::UNSPECIFIED.into(), 9224),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_listen_addrs_ipv6_gossip_address() {
+        let addr = tcp_addr(Ipv6Addr::LOCALHOST.into(), 9222);
+        let config = TransportConfig { ipv6: Some(Ipv6Addr::LOCALHOST), ..Default::default() };
+        assert_eq!(config.listen_addrs(&addr), vec![addr]);
+    }
+
+    #[tokio::test]
+    async fn test_build_transports() {
+        let keypair = Keypair::generate_secp256k1();
+        for security in [TransportSecurity::Noise, TransportSecurity::Tls] {
+            for quic_port in [None, Some(0)] {
+                let config = TransportConfig { security, quic_port, ..Default::default() };
+                assert!(config.build(&keypair).is_ok());
+            }
+        }
+    }
+}
@@ -22,14 +22,17 @@ pub use rpc::{
 mod gossip;
 pub use gossip::{
     Behaviour, BehaviourError, BlockHandler, BlockInvalidError, ConnectionGate, ConnectionGater,
-    DEFAULT_MESH_D, DEFAULT_MESH_DHI, DEFAULT_MESH_DLAZY, DEFAULT_MESH_DLO, DialError, DialInfo,
-    Event, GLOBAL_VALIDATE_THROTTLE, GOSSIP_HEARTBEAT, GaterConfig, GossipDriver,
-    GossipDriverBuilder, GossipDriverBuilderError, Handler, HandlerEncodeError, MAX_GOSSIP_SIZE,
-    MAX_OUTBOUND_QUEUE, MAX_VALIDATE_QUEUE, MIN_GOSSIP_SIZE, PEER_SCORE_INSPECT_FREQUENCY,
-    PublishError, SEEN_MESSAGES_TTL, default_config, default_config_builder,
+    DEFAULT_MESH_D, DEFAULT_MESH_DHI, DEFAULT_MESH_DLAZY, DEFAULT_MESH_DLO,
+    DEFAULT_PUBLISH_MAX_RETRIES, DEFAULT_PUBLISH_RETRY_INTERVAL, DialError, DialInfo, Event,
+    GLOBAL_VALIDATE_THROTTLE, GOSSIP_HEARTBEAT, GaterConfig, GossipDriver, GossipDriverBuilder,
+    GossipDriverBuilderError, Handler, HandlerEncodeError, MAX_GOSSIP_SIZE, MAX_OUTBOUND_QUEUE,
+    MAX_PENDING_PUBLISHES, MAX_VALIDATE_QUEUE, MIN_GOSSIP_SIZE, PEER_SCORE_INSPECT_FREQUENCY,
+    PendingPublish, PublishError, PublishQueue, PublishRetryConfig, SEEN_MESSAGES_TTL,
+    TransportConfig, TransportSecurity, default_config, default_config_builder, is_retryable,
 };
 
 mod discv5;
 pub use discv5::{
-    Discv5Builder, Discv5BuilderError, Discv5Driver, Discv5Handler, HandlerRequest, LocalNode,
+    DiscoveryStats, Discv5Builder, Discv5BuilderError, Discv5Driver, Discv5Handler, HandlerRequest,
+    LocalNode,
 };
@@ -17,6 +17,15 @@ impl Metrics {
     /// Identifier for the gauge that tracks unsafe blocks published.
     pub const UNSAFE_BLOCK_PUBLISHED: &str = "kona_node_unsafe_block_published";
 
+    /// Identifier for the gauge that tracks failed attempts to publish unsafe blocks.
+    pub const UNSAFE_BLOCK_PUBLISH_ERROR: &str = "kona_node_unsafe_block_publish_errors";
+
+    /// Identifier for the gauge that tracks retried unsafe block publications.
+    pub const UNSAFE_BLOCK_PUBLISH_RETRY: &str = "kona_node_unsafe_block_publish_retries";
+
+    /// Identifier for the gauge that tracks unsafe blocks dropped without being published.
+    pub const UNSAFE_BLOCK_PUBLISH_DROPPED: &str = "kona_node_unsafe_block_publish_dropped";
+
     /// Identifier for the gauge that tracks the number of connected peers.
     pub const GOSSIP_PEER_COUNT: &str = "kona_node_swarm_peer_count";
 
@@ -29,6 +38,18 @@ impl Metrics {
     /// Identifier for discv5 events.
     pub const DISCOVERY_EVENT: &str = "kona_node_discovery_events";
 
+    /// Identifier for the gauge that tracks ENRs found through discovery, by source.
+    pub const DISCOVERY_ENR_FOUND: &str = "kona_node_discovery_enrs_found";
+
+    /// Identifier for the gauge that tracks ENRs filtered out by discovery, by reason.
+    pub const DISCOVERY_ENR_FILTERED: &str = "kona_node_discovery_enrs_filtered";
+
+    /// Identifier for the gauge that tracks ENRs forwarded by discovery to be dialed.
+    pub const DISCOVERY_ENR_FORWARDED: &str = "kona_node_discovery_enrs_forwarded";
+
+    /// Identifier for the gauge that tracks updates of the local ENR's external address.
+    pub const DISCOVERY_LOCAL_ENR_UPDATE: &str = "kona_node_discovery_local_enr_updates";
+
     /// Counter for the number of FIND_NODE requests.
     pub const FIND_NODE_REQUEST: &str = "kona_node_find_node_requests";
 
@@ -75,7 +96,35 @@ impl Metrics {
             Self::UNSAFE_BLOCK_PUBLISHED,
             "Number of OpNetworkPayloadEnvelope gossipped out through the libp2p Swarm"
         );
+        metrics::describe_gauge!(
+            Self::UNSAFE_BLOCK_PUBLISH_ERROR,
+            "Number of failed attempts to gossip an OpNetworkPayloadEnvelope"
+        );
+        metrics::describe_gauge!(
+            Self::UNSAFE_BLOCK_PUBLISH_RETRY,
+            "Number of retried attempts to gossip an OpNetworkPayloadEnvelope"
+        );
+        metrics::describe_gauge!(
+            Self::UNSAFE_BLOCK_PUBLISH_DROPPED,
+            "Number of OpNetworkPayloadEnvelope dropped without being gossipped"
+        );
         metrics::describe_gauge!(Self::DISCOVERY_EVENT, "Events received by the discv5 service");
+        metrics::describe_gauge!(
+            Self::DISCOVERY_ENR_FOUND,
+            "ENRs found by the discv5 service, before filtering"
+        );
+        metrics::describe_gauge!(
+            Self::DISCOVERY_ENR_FILTERED,
+            "ENRs filtered out by the discv5 service because of an invalid OP Stack entry"
+        );
+        metrics::describe_gauge!(
+            Self::DISCOVERY_ENR_FORWARDED,
+            "ENRs forwarded by the discv5 service to the swarm to be dialed"
+        );
+        metrics::describe_gauge!(
+            Self::DISCOVERY_LOCAL_ENR_UPDATE,
+            "Updates of the local ENR after a change of the node's external address"
+        );
         metrics::describe_histogram!(
             Self::ENR_STORE_TIME,
             "Observations of elapsed time to store ENRs in the on-disk bootstore"
@@ -117,6 +166,7 @@ impl Metrics {
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_peers", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_peerStats", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_discoveryTable", 0);
+        kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_discoveryStats", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_blockPeer", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_listBlockedPeers", 0);
         kona_macros::set!(gauge, Self::RPC_CALLS, "method", "opp2p_blockAddr", 0);
@@ -142,12 +192,25 @@ impl Metrics {
         kona_macros::set!(gauge, Self::DIAL_PEER_ERROR, 0);
 
         // Unsafe Blocks
-        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_PUBLISHED, 0);
+        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_PUBLISHED, "topic", "v1", 0);
+        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_PUBLISHED, "topic", "v2", 0);
+        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_PUBLISHED, "topic", "v3", 0);
+        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_PUBLISHED, "topic", "v4", 0);
+        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_PUBLISH_RETRY, 0);
 
         // Discovery Event
         kona_macros::set!(gauge, Self::DISCOVERY_EVENT, "type", "discovered", 0);
         kona_macros::set!(gauge, Self::DISCOVERY_EVENT, "type", "session_established", 0);
         kona_macros::set!(gauge, Self::DISCOVERY_EVENT, "type", "unverifiable_enr", 0);
+        kona_macros::set!(gauge, Self::DISCOVERY_EVENT, "type", "find_node", 0);
+
+        // Discovery ENR filtering
+        kona_macros::set!(gauge, Self::DISCOVERY_ENR_FILTERED, "reason", "missing_key", 0);
+        kona_macros::set!(gauge, Self::DISCOVERY_ENR_FILTERED, "reason", "decode_error", 0);
+        kona_macros::set!(gauge, Self::DISCOVERY_ENR_FILTERED, "reason", "invalid_version", 0);
+        kona_macros::set!(gauge, Self::DISCOVERY_ENR_FILTERED, "reason", "invalid_chain_id", 0);
+        kona_macros::set!(gauge, Self::DISCOVERY_ENR_FORWARDED, 0);
+        kona_macros::set!(gauge, Self::DISCOVERY_LOCAL_ENR_UPDATE, 0);
 
         // Peer Counts
         kona_macros::set!(gauge, Self::GOSSIP_PEER_COUNT, 0);
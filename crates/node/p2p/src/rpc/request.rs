@@ -7,7 +7,7 @@ use std::{
     sync::Arc,
 };
 
-use crate::{Discv5Handler, GossipDriver, GossipScores};
+use crate::{DiscoveryStats, Discv5Handler, GossipDriver, GossipScores};
 use alloy_primitives::map::foldhash::fast::RandomState;
 use discv5::{
     enr::{NodeId, k256::ecdsa},
@@ -31,6 +31,9 @@ pub enum P2pRpcRequest {
     PeerInfo(Sender<PeerInfo>),
     /// Dumps the node's discovery table from the [`crate::Discv5Driver`].
     DiscoveryTable(Sender<Vec<String>>),
+    /// Returns the [`DiscoveryStats`] of the [`crate::Discv5Driver`]: the ENRs found, filtered
+    /// and forwarded to be dialed.
+    DiscoveryStats(Sender<DiscoveryStats>),
     /// Returns the current peer count for both the
     /// - Discovery Service ([`crate::Discv5Driver`])
     /// - Gossip Service ([`crate::GossipDriver`])
@@ -115,6 +118,7 @@ impl P2pRpcRequest {
         match self {
             Self::PeerCount(s) => Self::handle_peer_count(s, gossip, disc),
             Self::DiscoveryTable(s) => Self::handle_discovery_table(s, disc),
+            Self::DiscoveryStats(s) => Self::handle_discovery_stats(s, disc),
             Self::PeerInfo(s) => Self::handle_peer_info(s, gossip, disc),
             Self::Peers { out, connected } => Self::handle_peers(out, connected, gossip, disc),
             Self::DisconnectPeer { peer_id } => Self::disconnect_peer(peer_id, gossip),
@@ -228,6 +232,23 @@ impl P2pRpcRequest {
         });
     }
 
+    fn handle_discovery_stats(sender: Sender<DiscoveryStats>, disc: &Discv5Handler) {
+        let stats = disc.stats();
+        tokio::spawn(async move {
+            let stats = match stats.await {
+                Ok(stats) => stats,
+                Err(e) => {
+                    warn!(target: "p2p_rpc", "Failed to receive discovery stats: {:?}", e);
+                    return;
+                }
+            };
+
+            if let Err(e) = sender.send(stats) {
+                warn!(target: "p2p_rpc", "Failed to send discovery stats through response channel: {:?}", e);
+            }
+        });
+    }
+
     fn handle_peers<G: ConnectionGate>(
         sender: Sender<PeerDump>,
         connected: bool,
@@ -3,7 +3,10 @@
 use alloy_chains::Chain;
 use alloy_primitives::Address;
 use kona_genesis::RollupConfig;
-use kona_p2p::{Behaviour, BlockHandler, ConnectionGater, GaterConfig, GossipDriver};
+use kona_p2p::{
+    Behaviour, BlockHandler, ConnectionGater, GaterConfig, GossipDriver, GossipDriverBuilder,
+    TransportConfig,
+};
 use libp2p::{Multiaddr, StreamProtocol, SwarmBuilder, identity::Keypair, multiaddr::Protocol};
 use std::{net::Ipv4Addr, time::Duration};
 
@@ -59,3 +62,23 @@ pub(crate) fn gossip_driver(port: u16) -> GossipDriver<ConnectionGater> {
 
     GossipDriver::new(swarm, addr, handler, sync_handler, sync_protocol, gate)
 }
+
+/// Helper function to create a new gossip driver instance listening on the given [`Multiaddr`],
+/// with the given [`TransportConfig`].
+pub(crate) fn gossip_driver_with_transport(
+    addr: Multiaddr,
+    transport: TransportConfig,
+) -> GossipDriver<ConnectionGater> {
+    let rollup_config =
+        RollupConfig { l2_chain_id: Chain::optimism_mainnet(), ..Default::default() };
+    let (driver, _) = GossipDriverBuilder::new(
+        rollup_config,
+        Address::default(),
+        addr,
+        Keypair::generate_secp256k1(),
+    )
+    .with_transport(transport)
+    .build()
+    .expect("builds gossip driver");
+    driver
+}
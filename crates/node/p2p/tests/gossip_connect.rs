@@ -2,6 +2,14 @@
 
 mod common;
 
+use futures::StreamExt;
+use kona_p2p::{ConnectionGater, GossipDriver, TransportConfig, TransportSecurity};
+use libp2p::{Multiaddr, multiaddr::Protocol, swarm::SwarmEvent};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    time::Duration,
+};
+
 #[tokio::test]
 async fn test_unknown_peer_connect_fails() {
     let mut driver = common::gossip_driver(4003);
@@ -25,3 +33,92 @@ async fn test_connect_to_peer() {
     assert!(driver.swarm.dial(driver_2.addr).is_ok());
     assert_eq!(driver.connected_peers(), 0);
 }
+
+/// Dials `addr` from a driver using the `dialer` transport and waits until both drivers see the
+/// connection established, or until one of them reports a connection error.
+async fn connects(
+    mut dialer: GossipDriver<ConnectionGater>,
+    mut listener: GossipDriver<ConnectionGater>,
+    addr: Multiaddr,
+) -> bool {
+    assert!(dialer.start().await.is_ok());
+    assert!(listener.start().await.is_ok());
+    dialer.swarm.dial(addr).expect("dials listener");
+
+    let (mut dialer_connected, mut listener_connected) = (false, false);
+    let result = tokio::time::timeout(Duration::from_secs(10), async {
+        while !(dialer_connected && listener_connected) {
+            tokio::select! {
+                event = dialer.swarm.select_next_some() => match event {
+                    SwarmEvent::ConnectionEstablished { .. } => dialer_connected = true,
+                    SwarmEvent::OutgoingConnectionError { .. } => return false,
+                    _ => {}
+                },
+                event = listener.swarm.select_next_some() => match event {
+                    SwarmEvent::ConnectionEstablished { .. } => listener_connected = true,
+                    SwarmEvent::IncomingConnectionError { .. } => return false,
+                    _ => {}
+                },
+            }
+        }
+        true
+    })
+    .await;
+    result.unwrap_or(false)
+}
+
+fn tcp(ip: IpAddr, port: u16) -> Multiaddr {
+    Multiaddr::from(ip).with(Protocol::Tcp(port))
+}
+
+fn quic(ip: IpAddr, port: u16) -> Multiaddr {
+    Multiaddr::from(ip).with(Protocol::Udp(port)).with(Protocol::QuicV1)
+}
+
+/// Connects two drivers over every combination of transport, security and IP version.
+#[tokio::test]
+async fn test_connect_transport_combinations() {
+    let ipv4 = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let ipv6 = IpAddr::V6(Ipv6Addr::LOCALHOST);
+
+    let mut port = 4020;
+    for security in [TransportSecurity::Noise, TransportSecurity::Tls] {
+        for (ip, use_quic) in [(ipv4, false), (ipv6, false), (ipv4, true), (ipv6, true)] {
+            let (dialer_port, listener_port) = (port, port + 1);
+            port += 2;
+
+            let transport = TransportConfig {
+                security,
+                quic_port: use_quic.then_some(listener_port),
+                ipv6: Some(Ipv6Addr::LOCALHOST),
+            };
+            let dialer = common::gossip_driver_with_transport(
+                tcp(ipv4, dialer_port),
+                TransportConfig { quic_port: use_quic.then_some(dialer_port), ..transport },
+            );
+            let listener =
+                common::gossip_driver_with_transport(tcp(ipv4, listener_port), transport);
+
+            let addr = if use_quic { quic(ip, listener_port) } else { tcp(ip, listener_port) };
+            assert!(
+                connects(dialer, listener, addr.clone()).await,
+                "failed to connect over {addr} with {security} security"
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_connect_security_mismatch_fails() {
+    let ip = IpAddr::V4(Ipv4Addr::LOCALHOST);
+    let dialer = common::gossip_driver_with_transport(
+        tcp(ip, 4040),
+        TransportConfig { security: TransportSecurity::Noise, ..Default::default() },
+    );
+    let listener = common::gossip_driver_with_transport(
+        tcp(ip, 4041),
+        TransportConfig { security: TransportSecurity::Tls, ..Default::default() },
+    );
+
+    assert!(!connects(dialer, listener, tcp(ip, 4041)).await);
+}
@@ -43,6 +43,17 @@ impl EnrValidation {
     pub const fn is_invalid(&self) -> bool {
         !self.is_valid()
     }
+
+    /// Returns a short label describing the validation outcome, used in metrics and statistics.
+    pub const fn label(&self) -> &'static str {
+        match self {
+            Self::ConversionError(OpStackEnrError::MissingKey) => "missing_key",
+            Self::ConversionError(OpStackEnrError::DecodeError(_)) => "decode_error",
+            Self::ConversionError(OpStackEnrError::InvalidVersion(_)) => "invalid_version",
+            Self::InvalidChainId(_) => "invalid_chain_id",
+            Self::Valid => "valid",
+        }
+    }
 }
 
 /// The unique L2 network identifier
@@ -160,6 +171,20 @@ mod tests {
         op_stack_enr.encode(&mut op_stack_bytes);
         enr.insert_raw_rlp(OpStackEnr::OP_CL_KEY, op_stack_bytes.into(), &key).unwrap();
         assert!(EnrValidation::validate(&enr, 10).is_invalid());
+        assert_eq!(EnrValidation::validate(&enr, 10).label(), "invalid_version");
+    }
+
+    #[test]
+    fn test_enr_validation_labels() {
+        let key = CombinedKey::generate_secp256k1();
+        let mut enr = Enr::builder().build(&key).unwrap();
+        assert_eq!(EnrValidation::validate(&enr, 10).label(), "missing_key");
+
+        let mut op_stack_bytes = Vec::new();
+        OpStackEnr::from_chain_id(10).encode(&mut op_stack_bytes);
+        enr.insert_raw_rlp(OpStackEnr::OP_CL_KEY, op_stack_bytes.into(), &key).unwrap();
+        assert_eq!(EnrValidation::validate(&enr, 10).label(), "valid");
+        assert_eq!(EnrValidation::validate(&enr, 11).label(), "invalid_chain_id");
     }
 
     #[test]
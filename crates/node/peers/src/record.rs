@@ -12,6 +12,10 @@ use core::{
     num::ParseIntError,
     str::FromStr,
 };
+use discv5::{
+    Enr,
+    enr::{CombinedPublicKey, k256::elliptic_curve::sec1::ToEncodedPoint},
+};
 
 /// Represents an ENR in discovery.
 ///
@@ -80,6 +84,25 @@ impl NodeRecord {
         Self { address: ip_addr, tcp_port, udp_port, id }
     }
 
+    /// Creates a new record from the sockets advertised in an [`Enr`], preferring IPv4.
+    ///
+    /// Returns `None` if the [`Enr`] does not advertise a TCP socket, or if its public key is not
+    /// a secp256k1 key.
+    pub fn from_enr(enr: &Enr) -> Option<Self> {
+        let CombinedPublicKey::Secp256k1(public_key) = enr.public_key() else {
+            return None;
+        };
+        let id = PeerId::from_slice(&public_key.to_encoded_point(false).as_bytes()[1..]);
+
+        if let Some(tcp) = enr.tcp4_socket() {
+            let udp_port = enr.udp4().unwrap_or(tcp.port());
+            return Some(Self::new_with_ports((*tcp.ip()).into(), tcp.port(), Some(udp_port), id));
+        }
+        let tcp = enr.tcp6_socket()?;
+        let udp_port = enr.udp6().unwrap_or(tcp.port());
+        Some(Self::new_with_ports((*tcp.ip()).into(), tcp.port(), Some(udp_port), id))
+    }
+
     /// The TCP socket address of this node
     #[must_use]
     pub const fn tcp_addr(&self) -> SocketAddr {
@@ -196,6 +219,23 @@ mod tests {
         assert_eq!(url, &format!("{node}"));
     }
 
+    #[test]
+    fn test_node_record_from_enr() {
+        let key = discv5::enr::CombinedKey::generate_secp256k1();
+        let enr =
+            Enr::builder().ip4([10, 3, 58, 6].into()).tcp4(30303).udp4(30301).build(&key).unwrap();
+        let node = NodeRecord::from_enr(&enr).unwrap();
+        assert_eq!(node.tcp_addr(), "10.3.58.6:30303".parse().unwrap());
+        assert_eq!(node.udp_port, 30301);
+
+        // The enode roundtrips to the same record.
+        assert_eq!(node.to_string().parse::<NodeRecord>().unwrap(), node);
+
+        // ENRs without a TCP socket cannot be converted.
+        let enr = Enr::builder().ip4([10, 3, 58, 6].into()).udp4(30301).build(&key).unwrap();
+        assert!(NodeRecord::from_enr(&enr).is_none());
+    }
+
     #[test]
     fn test_node_display_discport() {
         let url = "enode://6f8a80d14311c39f35f516fa664deaaaa13e85b2f7493f37f6144d86991ec012937307647bd3b9a82abe2974e1407241d54947bbb39763a4cac9f77166ad92a0@10.3.58.6:30303?discport=30301";
@@ -103,6 +103,17 @@ impl BootStore {
         peers.into_iter().for_each(|peer| self.add_rotate(peer));
     }
 
+    /// Removes the peers for which the predicate returns `false`.
+    ///
+    /// Returns the number of removed peers. As with [`BootStore::merge`], the change is not
+    /// written to disk until [`BootStore::write_to_file`] is called. Note that
+    /// [`BootStore::sync`] would merge the removed peers back from disk.
+    pub fn retain(&mut self, f: impl FnMut(&Enr) -> bool) -> usize {
+        let len = self.peers.len();
+        self.peers.retain(f);
+        len - self.peers.len()
+    }
+
     /// Syncs the [`BootStore`] with the contents on disk.
     pub fn sync(&mut self) {
         let _ = self.peers();
@@ -111,8 +122,8 @@ impl BootStore {
         }
     }
 
-    /// Writes the store to disk.
-    fn write_to_file(&mut self) -> Result<(), std::io::Error> {
+    /// Writes the store to disk, overwriting its previous contents.
+    pub fn write_to_file(&mut self) -> Result<(), std::io::Error> {
         // If the directory does not exist, create it.
         if let Some(parent) = self.path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -202,3 +213,28 @@ impl BootStore {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use discv5::enr::CombinedKey;
+
+    fn random_enr() -> Enr {
+        Enr::builder().build(&CombinedKey::generate_secp256k1()).unwrap()
+    }
+
+    #[test]
+    fn test_bootstore_retain_and_write() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("bootstore.json");
+
+        let (first, second) = (random_enr(), random_enr());
+        let mut store = BootStore::from_file(&path);
+        store.merge([first.clone(), second.clone()]);
+        assert_eq!(store.retain(|enr| enr != &first), 1);
+        store.write_to_file().unwrap();
+
+        let store = BootStore::from_file(&path);
+        assert_eq!(store.peers, VecDeque::from([second]));
+    }
+}
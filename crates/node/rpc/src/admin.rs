@@ -1,11 +1,12 @@
 //! Admin RPC Module
 
 use crate::AdminApiServer;
+use alloy_eips::BlockNumHash;
 use alloy_primitives::B256;
 use async_trait::async_trait;
 use jsonrpsee::{
     core::RpcResult,
-    types::{ErrorCode, ErrorObject},
+    types::{ErrorCode, ErrorObject, ErrorObjectOwned, error::CALL_EXECUTION_FAILED_CODE},
 };
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 use tokio::sync::oneshot;
@@ -15,10 +16,12 @@ use tokio::sync::oneshot;
 pub enum SequencerAdminQuery {
     /// A query to check if the sequencer is active.
     SequencerActive(oneshot::Sender<bool>),
-    /// A query to start the sequencer.
-    StartSequencer,
-    /// A query to stop the sequencer.
-    StopSequencer(oneshot::Sender<B256>),
+    /// A query for the [`SequencerStatus`], including the safety limits in force.
+    SequencerStatus(oneshot::Sender<SequencerStatus>),
+    /// A query to start the sequencer on top of the given unsafe head hash.
+    StartSequencer(B256, oneshot::Sender<Result<(), SequencerAdminError>>),
+    /// A query to stop the sequencer, returning the hash of the unsafe head.
+    StopSequencer(oneshot::Sender<Result<B256, SequencerAdminError>>),
     /// A query to check if the conductor is enabled.
     ConductorEnabled(oneshot::Sender<bool>),
     /// A query to set the recover mode.
@@ -27,6 +30,63 @@ pub enum SequencerAdminQuery {
     OverrideLeader,
 }
 
+/// An error returned by the sequencer when handling an admin query.
+///
+/// The error messages match the ones of the `op-node`, which failover tooling relies on.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum SequencerAdminError {
+    /// The sequencer is already running.
+    #[error("sequencer already running")]
+    AlreadyActive,
+    /// The sequencer is not running.
+    #[error("sequencer not running")]
+    NotActive,
+    /// The unsafe head of the node does not match the expected block hash.
+    #[error("block hash does not match: head {local}, received {expected}")]
+    UnsafeHeadMismatch {
+        /// The block hash the sequencer was asked to start on.
+        expected: B256,
+        /// The hash of the local unsafe head.
+        local: B256,
+    },
+    /// The block signer is unavailable, blocks built by the sequencer could not be gossipped.
+    #[error("block signer is unavailable")]
+    SignerUnavailable,
+    /// The new sequencer state could not be persisted.
+    #[error("failed to persist sequencer state: {0}")]
+    Persistence(String),
+}
+
+impl From<SequencerAdminError> for ErrorObjectOwned {
+    fn from(err: SequencerAdminError) -> Self {
+        ErrorObject::owned(CALL_EXECUTION_FAILED_CODE, err.to_string(), None::<()>)
+    }
+}
+
+/// The status of the sequencer, returned by the `admin_sequencerStatus` RPC method.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SequencerStatus {
+    /// Whether the sequencer is active.
+    pub active: bool,
+    /// Whether the sequencer is in recovery mode.
+    pub recovery_mode: bool,
+    /// Whether the conductor is enabled.
+    pub conductor_enabled: bool,
+    /// The unsafe head the sequencer builds on.
+    pub unsafe_head: BlockNumHash,
+    /// The safe head.
+    pub safe_head: BlockNumHash,
+    /// The current L1 origin of the sequencer, if selected.
+    pub l1_origin: Option<BlockNumHash>,
+    /// The maximum number of L2 blocks between the unsafe and safe heads. Disabled if `0`.
+    pub max_safe_lag: u64,
+    /// Whether block production is stalled because the max safe lag was reached.
+    pub safe_lag_exceeded: bool,
+    /// The number of L1 confirmations required before adopting a new L1 origin.
+    pub l1_confs: u64,
+}
+
 /// The query types to the network actor for the admin api.
 #[derive(Debug)]
 pub enum NetworkAdminQuery {
@@ -76,16 +136,33 @@ impl AdminApiServer for AdminRpc {
         rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
 
-    async fn admin_start_sequencer(&self) -> RpcResult<()> {
+    async fn admin_sequencer_status(&self) -> RpcResult<SequencerStatus> {
         // If the sequencer is not enabled (mode runs in validator mode), return an error.
         let Some(ref sequencer_sender) = self.sequencer_sender else {
             return Err(ErrorObject::from(ErrorCode::MethodNotFound));
         };
 
+        let (tx, rx) = oneshot::channel();
         sequencer_sender
-            .send(SequencerAdminQuery::StartSequencer)
+            .send(SequencerAdminQuery::SequencerStatus(tx))
             .await
-            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn admin_start_sequencer(&self, unsafe_head: B256) -> RpcResult<()> {
+        // If the sequencer is not enabled (mode runs in validator mode), return an error.
+        let Some(ref sequencer_sender) = self.sequencer_sender else {
+            return Err(ErrorObject::from(ErrorCode::MethodNotFound));
+        };
+
+        let (tx, rx) = oneshot::channel();
+
+        sequencer_sender
+            .send(SequencerAdminQuery::StartSequencer(unsafe_head, tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(Into::into)
     }
 
     async fn admin_stop_sequencer(&self) -> RpcResult<B256> {
@@ -100,7 +177,7 @@ impl AdminApiServer for AdminRpc {
             .send(SequencerAdminQuery::StopSequencer(tx))
             .await
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
-        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?.map_err(Into::into)
     }
 
     async fn admin_conductor_enabled(&self) -> RpcResult<bool> {
@@ -142,3 +219,43 @@ impl AdminApiServer for AdminRpc {
             .map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequencer_status_serde() {
+        let status = SequencerStatus {
+            active: true,
+            max_safe_lag: 100,
+            safe_lag_exceeded: true,
+            l1_confs: 4,
+            ..Default::default()
+        };
+        let json = serde_json::to_value(status).unwrap();
+        assert_eq!(json["maxSafeLag"], 100);
+        assert_eq!(json["safeLagExceeded"], true);
+        assert_eq!(json["l1Confs"], 4);
+        assert_eq!(json["l1Origin"], serde_json::Value::Null);
+        assert_eq!(serde_json::from_value::<SequencerStatus>(json).unwrap(), status);
+    }
+
+    #[test]
+    fn test_sequencer_admin_error_message() {
+        let err = SequencerAdminError::UnsafeHeadMismatch {
+            expected: B256::repeat_byte(0x01),
+            local: B256::repeat_byte(0x02),
+        };
+        let obj = ErrorObjectOwned::from(err);
+        assert_eq!(obj.code(), CALL_EXECUTION_FAILED_CODE);
+        assert_eq!(
+            obj.message(),
+            format!(
+                "block hash does not match: head {}, received {}",
+                B256::repeat_byte(0x02),
+                B256::repeat_byte(0x01)
+            )
+        );
+    }
+}
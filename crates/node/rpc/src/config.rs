@@ -1,5 +1,6 @@
 //! Contains the RPC Configuration.
 
+use crate::{ReadinessConfig, SequencerHealthConfig};
 use std::{net::SocketAddr, path::PathBuf};
 
 /// The RPC configuration.
@@ -18,6 +19,14 @@ pub struct RpcBuilder {
     pub ws_enabled: bool,
     /// Enable development RPC endpoints
     pub dev_enabled: bool,
+    /// Enable the development RPC endpoints that alter the engine task queue. Only effective if
+    /// [`Self::dev_enabled`] is set.
+    pub dev_admin_enabled: bool,
+    /// The thresholds of the sequencer health checks served to op-conductor.
+    pub health: SequencerHealthConfig,
+    /// The thresholds of the readiness checks of the liveness and readiness HTTP probes, served
+    /// by the RPC server. The probes are not served if [`None`].
+    pub probes: Option<ReadinessConfig>,
 }
 
 impl RpcBuilder {
@@ -31,6 +40,11 @@ impl RpcBuilder {
         self.dev_enabled
     }
 
+    /// Returns whether the development RPC endpoints that alter the engine task queue are enabled
+    pub const fn dev_admin_enabled(&self) -> bool {
+        self.dev_enabled && self.dev_admin_enabled
+    }
+
     /// Returns the socket address of the [`RpcBuilder`].
     pub const fn socket(&self) -> SocketAddr {
         self.socket
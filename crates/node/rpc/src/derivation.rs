@@ -0,0 +1,41 @@
+//! Contains the system config history types and the queries about the derivation pipeline.
+
+use alloy_eips::BlockNumHash;
+use kona_genesis::{SystemConfig, SystemConfigUpdate};
+use tokio::sync::oneshot::Sender;
+
+/// A system config update applied by the derivation pipeline.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemConfigHistoryEntry {
+    /// The L1 block whose receipts contained the updates.
+    pub l1_origin: BlockNumHash,
+    /// The first L2 block the updates take effect at, i.e. the first L2 block with the L1
+    /// origin as its epoch.
+    ///
+    /// This is `None` until such an L2 block is derived.
+    pub l2_block: Option<u64>,
+    /// The updates applied, in log order.
+    ///
+    /// This is empty for the entry recording the system config the pipeline was reset with.
+    pub updates: Vec<SystemConfigUpdate>,
+    /// The system config after the updates were applied.
+    pub system_config: SystemConfig,
+}
+
+/// A sender for derivation queries.
+pub type DerivationQuerySender = tokio::sync::mpsc::Sender<DerivationQueries>;
+
+/// The queries about the derivation pipeline, answered off the derivation actor.
+#[derive(Debug)]
+pub enum DerivationQueries {
+    /// Get the history of the system config updates, in L1 order.
+    SystemConfigHistory(Sender<Vec<SystemConfigHistoryEntry>>),
+    /// Get the system config in effect at the given L2 block.
+    SystemConfigAt {
+        /// The L2 block number.
+        block: u64,
+        /// The sender of the system config, or `None` if it cannot be determined.
+        sender: Sender<Option<SystemConfig>>,
+    },
+}
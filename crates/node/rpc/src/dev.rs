@@ -1,7 +1,8 @@
 //! Development RPC API for exposing internal engine state and task queue information.
 //!
 //! This module provides development and debugging endpoints that allow introspection
-//! of the engine's internal state, task queue, and operations.
+//! of the engine's internal state, task queue, and operations, as well as endpoints to recover
+//! a stalled engine task queue.
 
 use async_trait::async_trait;
 use jsonrpsee::{
@@ -9,9 +10,10 @@ use jsonrpsee::{
     core::{RpcResult, SubscriptionResult},
     types::ErrorCode,
 };
-use kona_engine::{EngineQueries, EngineQuerySender};
+use kona_engine::{EngineQueries, EngineQuerySender, EngineTaskQueueSnapshot};
+use tokio::sync::oneshot;
 
-use crate::DevEngineApiServer;
+use crate::{DevEngineAdminApiServer, DevEngineApiServer};
 use jsonrpsee::core::to_json_raw_value;
 
 /// Implementation of the development RPC API.
@@ -45,6 +47,30 @@ impl DevEngineRpc {
         query_rx.await.map_err(|_| jsonrpsee::core::SubscriptionError::from("Internal error. Failed to receive engine task receiver query. The engine query handler is likely closed."))
     }
 
+    /// Sends a query to the engine and waits for its response.
+    async fn query<T>(
+        &self,
+        query: impl FnOnce(oneshot::Sender<T>) -> EngineQueries,
+    ) -> RpcResult<T> {
+        let (query_tx, query_rx) = oneshot::channel();
+
+        self.engine_query_sender.send(query(query_tx)).await.map_err(|_| {
+            jsonrpsee::types::ErrorObjectOwned::owned(
+                ErrorCode::InternalError.code(),
+                "Engine query channel closed",
+                None::<()>,
+            )
+        })?;
+
+        query_rx.await.map_err(|_| {
+            jsonrpsee::types::ErrorObjectOwned::owned(
+                ErrorCode::InternalError.code(),
+                "Failed to receive engine query response",
+                None::<()>,
+            )
+        })
+    }
+
     async fn send_queue_length_update(
         sink: &SubscriptionSink,
         queue_length: &usize,
@@ -91,24 +117,21 @@ impl DevEngineApiServer for DevEngineRpc {
     }
 
     async fn dev_task_queue_length(&self) -> RpcResult<usize> {
-        let (query_tx, query_rx) = tokio::sync::oneshot::channel();
+        self.query(EngineQueries::TaskQueueLength).await
+    }
 
-        self.engine_query_sender.send(EngineQueries::TaskQueueLength(query_tx)).await.map_err(
-            |_| {
-                jsonrpsee::types::ErrorObjectOwned::owned(
-                    ErrorCode::InternalError.code(),
-                    "Engine query channel closed",
-                    None::<()>,
-                )
-            },
-        )?;
+    async fn dev_task_queue(&self) -> RpcResult<EngineTaskQueueSnapshot> {
+        self.query(EngineQueries::TaskQueue).await
+    }
+}
 
-        query_rx.await.map_err(|_| {
-            jsonrpsee::types::ErrorObjectOwned::owned(
-                ErrorCode::InternalError.code(),
-                "Failed to receive task queue length",
-                None::<()>,
-            )
-        })
+#[async_trait]
+impl DevEngineAdminApiServer for DevEngineRpc {
+    async fn dev_drop_task(&self, id: u64) -> RpcResult<bool> {
+        self.query(|sender| EngineQueries::DropTask { id, sender }).await
+    }
+
+    async fn dev_reset_engine(&self) -> RpcResult<()> {
+        self.query(EngineQueries::Reset).await
     }
 }
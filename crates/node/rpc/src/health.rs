@@ -0,0 +1,236 @@
+//! Sequencer health reporting for op-conductor.
+//!
+//! The checks mirror the [health monitor] of op-conductor, which polls the sync status and peer
+//! count of its sequencer to decide whether leadership should be transferred.
+//!
+//! [health monitor]: https://github.com/ethereum-optimism/optimism/blob/develop/op-conductor/health/monitor.go
+
+use alloy_eips::BlockNumHash;
+use async_trait::async_trait;
+use jsonrpsee::{
+    core::RpcResult,
+    types::{ErrorCode, ErrorObject},
+};
+use kona_engine::{EngineQueries, EngineQuerySender, EngineState};
+use kona_p2p::P2pRpcRequest;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{HealthApiServer, query::query};
+
+/// The thresholds of the sequencer health checks.
+///
+/// Like in op-conductor, the safe head is not checked by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SequencerHealthConfig {
+    /// The maximum age of the unsafe head, in seconds, on top of one block time.
+    pub unsafe_interval: u64,
+    /// The maximum age of the safe head, in seconds. The safe head is not checked if [`None`].
+    pub safe_interval: Option<u64>,
+    /// The minimum number of connected gossip peers.
+    pub min_peer_count: u64,
+}
+
+impl Default for SequencerHealthConfig {
+    fn default() -> Self {
+        Self { unsafe_interval: 10, safe_interval: None, min_peer_count: 1 }
+    }
+}
+
+/// A failed sequencer health check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SequencerHealthFailure {
+    /// The execution layer is still syncing.
+    Syncing,
+    /// The unsafe head is older than the unsafe interval.
+    UnsafeHeadStale,
+    /// The safe head is older than the safe interval.
+    SafeHeadStale,
+    /// Not enough gossip peers are connected.
+    NotEnoughPeers,
+}
+
+/// The health of the sequencer, as reported by the `optimism_sequencerHealth` RPC method.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SequencerHealth {
+    /// Whether every health check passed.
+    pub healthy: bool,
+    /// The failed health checks.
+    pub failures: Vec<SequencerHealthFailure>,
+    /// The unsafe head.
+    pub unsafe_head: BlockNumHash,
+    /// The age of the unsafe head, in seconds.
+    pub unsafe_head_age: u64,
+    /// The safe head.
+    pub safe_head: BlockNumHash,
+    /// The age of the safe head, in seconds.
+    pub safe_head_age: u64,
+    /// Whether the execution layer finished syncing.
+    pub el_sync_finished: bool,
+    /// The number of connected gossip peers.
+    pub peer_count: u64,
+}
+
+impl SequencerHealth {
+    /// Checks the health of the sequencer at the unix timestamp `now`, given the block time of the
+    /// chain, the [`EngineState`] and the number of connected gossip peers.
+    pub fn check(
+        config: &SequencerHealthConfig,
+        now: u64,
+        block_time: u64,
+        state: &EngineState,
+        peer_count: u64,
+    ) -> Self {
+        let unsafe_head = state.sync_state.unsafe_head().block_info;
+        let safe_head = state.sync_state.safe_head().block_info;
+        let unsafe_head_age = now.saturating_sub(unsafe_head.timestamp);
+        let safe_head_age = now.saturating_sub(safe_head.timestamp);
+
+        let mut failures = Vec::new();
+        if !state.el_sync_finished {
+            failures.push(SequencerHealthFailure::Syncing);
+        }
+        // Like op-conductor, allow the unsafe head to drift by one block.
+        if unsafe_head_age > config.unsafe_interval + block_time {
+            failures.push(SequencerHealthFailure::UnsafeHeadStale);
+        }
+        if config.safe_interval.is_some_and(|interval| safe_head_age > interval) {
+            failures.push(SequencerHealthFailure::SafeHeadStale);
+        }
+        if peer_count < config.min_peer_count {
+            failures.push(SequencerHealthFailure::NotEnoughPeers);
+        }
+
+        Self {
+            healthy: failures.is_empty(),
+            failures,
+            unsafe_head: unsafe_head.id(),
+            unsafe_head_age,
+            safe_head: safe_head.id(),
+            safe_head_age,
+            el_sync_finished: state.el_sync_finished,
+            peer_count,
+        }
+    }
+}
+
+/// HealthRpc
+///
+/// This is a server implementation of [`crate::HealthApiServer`].
+#[derive(Debug)]
+pub struct HealthRpc {
+    /// The channel to send [`EngineQueries`]s.
+    pub engine_sender: EngineQuerySender,
+    /// The channel to send [`P2pRpcRequest`]s.
+    pub p2p_sender: tokio::sync::mpsc::Sender<P2pRpcRequest>,
+    /// The health check thresholds.
+    pub config: SequencerHealthConfig,
+}
+
+impl HealthRpc {
+    /// Constructs a new [`HealthRpc`].
+    pub const fn new(
+        engine_sender: EngineQuerySender,
+        p2p_sender: tokio::sync::mpsc::Sender<P2pRpcRequest>,
+        config: SequencerHealthConfig,
+    ) -> Self {
+        Self { engine_sender, p2p_sender, config }
+    }
+
+    /// Sends a query built with the given constructor, and awaits its response.
+    async fn query<Q, T>(
+        sender: &tokio::sync::mpsc::Sender<Q>,
+        build: impl FnOnce(tokio::sync::oneshot::Sender<T>) -> Q,
+    ) -> RpcResult<T> {
+        query(sender, build).await.ok_or_else(|| ErrorObject::from(ErrorCode::InternalError))
+    }
+}
+
+#[async_trait]
+impl HealthApiServer for HealthRpc {
+    async fn op_sequencer_health(&self) -> RpcResult<SequencerHealth> {
+        kona_macros::inc!(gauge, crate::RollupRpc::RPC_IDENT, "method" => "optimism_sequencerHealth");
+
+        let (config, state, (_, peer_count)) = tokio::try_join!(
+            Self::query(&self.engine_sender, EngineQueries::Config),
+            Self::query(&self.engine_sender, EngineQueries::State),
+            Self::query(&self.p2p_sender, P2pRpcRequest::PeerCount),
+        )?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        Ok(SequencerHealth::check(&self.config, now, config.block_time, &state, peer_count as u64))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kona_engine::{EngineSyncState, EngineSyncStateUpdate};
+    use kona_protocol::{BlockInfo, L2BlockInfo};
+
+    fn state(unsafe_time: u64, safe_time: u64, el_sync_finished: bool) -> EngineState {
+        let head = |number, timestamp| L2BlockInfo {
+            block_info: BlockInfo { number, timestamp, ..Default::default() },
+            ..Default::default()
+        };
+        let sync_state = EngineSyncState::default().apply_update(EngineSyncStateUpdate {
+            unsafe_head: Some(head(10, unsafe_time)),
+            safe_head: Some(head(5, safe_time)),
+            ..Default::default()
+        });
+        EngineState { sync_state, el_sync_finished, ..Default::default() }
+    }
+
+    #[test]
+    fn test_sequencer_healthy() {
+        let health = SequencerHealth::check(
+            &SequencerHealthConfig::default(),
+            1_000,
+            2,
+            &state(990, 0, true),
+            3,
+        );
+        assert!(health.healthy);
+        assert!(health.failures.is_empty());
+        assert_eq!(health.unsafe_head_age, 10);
+        assert_eq!(health.safe_head_age, 1_000);
+        assert_eq!(health.unsafe_head.number, 10);
+        assert_eq!(health.peer_count, 3);
+    }
+
+    #[test]
+    fn test_sequencer_unhealthy() {
+        let config = SequencerHealthConfig {
+            unsafe_interval: 10,
+            safe_interval: Some(600),
+            min_peer_count: 2,
+        };
+        let health = SequencerHealth::check(&config, 1_000, 2, &state(987, 0, false), 1);
+        assert!(!health.healthy);
+        assert_eq!(
+            health.failures,
+            vec![
+                SequencerHealthFailure::Syncing,
+                SequencerHealthFailure::UnsafeHeadStale,
+                SequencerHealthFailure::SafeHeadStale,
+                SequencerHealthFailure::NotEnoughPeers,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_sequencer_health_serde() {
+        let health = SequencerHealth::check(
+            &SequencerHealthConfig::default(),
+            1_000,
+            2,
+            &state(990, 0, true),
+            0,
+        );
+        let json = serde_json::to_value(&health).unwrap();
+        assert_eq!(json["healthy"], false);
+        assert_eq!(json["failures"][0], "notEnoughPeers");
+        assert_eq!(json["unsafeHeadAge"], 10);
+    }
+}
@@ -1,6 +1,8 @@
 //! The Optimism RPC API using `jsonrpsee`
 
-use crate::{OutputResponse, SafeHeadResponse};
+use crate::{
+    OutputResponse, SafeHeadResponse, SequencerHealth, SequencerStatus, SystemConfigHistoryEntry,
+};
 use alloy_eips::BlockNumberOrTag;
 use alloy_primitives::B256;
 use core::net::IpAddr;
@@ -9,9 +11,10 @@ use jsonrpsee::{
     core::{RpcResult, SubscriptionResult},
     proc_macros::rpc,
 };
-use kona_genesis::RollupConfig;
+use kona_engine::EngineTaskQueueSnapshot;
+use kona_genesis::{RollupConfig, SystemConfig};
 use kona_interop::{ExecutingDescriptor, SafetyLevel};
-use kona_p2p::{PeerCount, PeerDump, PeerInfo, PeerStats};
+use kona_p2p::{DiscoveryStats, PeerCount, PeerDump, PeerInfo, PeerStats};
 use kona_protocol::SyncStatus;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
 
@@ -51,6 +54,28 @@ pub trait RollupNodeApi {
     /// Get the software version.
     #[method(name = "version")]
     async fn op_version(&self) -> RpcResult<String>;
+
+    /// Get the history of the system config updates applied by the derivation pipeline, along
+    /// with the L1 block they were included in and the L2 block they take effect at.
+    #[method(name = "systemConfigHistory")]
+    async fn op_system_config_history(&self) -> RpcResult<Vec<SystemConfigHistoryEntry>>;
+
+    /// Get the system config in effect at an L2 block.
+    #[method(name = "systemConfigAtBlock")]
+    async fn op_system_config_at_block(
+        &self,
+        block_number: BlockNumberOrTag,
+    ) -> RpcResult<Option<SystemConfig>>;
+}
+
+/// The health of the sequencer, polled by op-conductor.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "optimism"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "optimism"))]
+pub trait HealthApi {
+    /// Get the health of the sequencer: the freshness of the unsafe and safe heads, the peer
+    /// count and the sync status of the execution layer.
+    #[method(name = "sequencerHealth")]
+    async fn op_sequencer_health(&self) -> RpcResult<SequencerHealth>;
 }
 
 /// The opp2p namespace handles peer interactions.
@@ -77,6 +102,11 @@ pub trait OpP2PApi {
     #[method(name = "discoveryTable")]
     async fn opp2p_discovery_table(&self) -> RpcResult<Vec<String>>;
 
+    /// Returns the number of ENRs found by discovery, filtered out by reason, and forwarded to be
+    /// dialed.
+    #[method(name = "discoveryStats")]
+    async fn opp2p_discovery_stats(&self) -> RpcResult<DiscoveryStats>;
+
     /// Blocks the given peer
     #[method(name = "blockPeer")]
     async fn opp2p_block_peer(&self, peer: String) -> RpcResult<()>;
@@ -184,6 +214,28 @@ pub trait DevEngineApi {
     /// Get the current number of tasks in the engine queue.
     #[method(name = "taskQueueLength")]
     async fn dev_task_queue_length(&self) -> RpcResult<usize>;
+
+    /// Get the tasks in the engine queue, in execution order, with their failed attempts and the
+    /// most recent task errors.
+    #[method(name = "taskQueue")]
+    async fn dev_task_queue(&self) -> RpcResult<EngineTaskQueueSnapshot>;
+}
+
+/// Development RPC API to recover a stalled engine task queue.
+///
+/// These endpoints alter the engine task queue, and are only served when explicitly enabled.
+#[cfg_attr(not(feature = "client"), rpc(server, namespace = "dev"))]
+#[cfg_attr(feature = "client", rpc(server, client, namespace = "dev"))]
+#[async_trait]
+pub trait DevEngineAdminApi {
+    /// Drops the engine task with the given id from the queue, before its next execution
+    /// attempt. Returns `false` if no task with this id is queued.
+    #[method(name = "dropTask")]
+    async fn dev_drop_task(&self, id: u64) -> RpcResult<bool>;
+
+    /// Resets the engine to a plausible sync starting point, clearing the task queue.
+    #[method(name = "resetEngine")]
+    async fn dev_reset_engine(&self) -> RpcResult<()>;
 }
 
 /// The admin namespace for the consensus node.
@@ -199,11 +251,16 @@ pub trait AdminApi {
     #[method(name = "sequencerActive")]
     async fn admin_sequencer_active(&self) -> RpcResult<bool>;
 
-    /// Starts the sequencer.
+    /// Returns the status of the sequencer, including the safety limits in force.
+    #[method(name = "sequencerStatus")]
+    async fn admin_sequencer_status(&self) -> RpcResult<SequencerStatus>;
+
+    /// Starts the sequencer on top of the given unsafe head. Fails if the unsafe head of the node
+    /// does not match the given block hash.
     #[method(name = "startSequencer")]
-    async fn admin_start_sequencer(&self) -> RpcResult<()>;
+    async fn admin_start_sequencer(&self, unsafe_head: B256) -> RpcResult<()>;
 
-    /// Stops the sequencer.
+    /// Stops the sequencer, returning the hash of the unsafe head.
     #[method(name = "stopSequencer")]
     async fn admin_stop_sequencer(&self) -> RpcResult<B256>;
 
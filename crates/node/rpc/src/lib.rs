@@ -10,7 +10,9 @@
 extern crate tracing;
 
 mod admin;
-pub use admin::{AdminRpc, NetworkAdminQuery, SequencerAdminQuery};
+pub use admin::{
+    AdminRpc, NetworkAdminQuery, SequencerAdminError, SequencerAdminQuery, SequencerStatus,
+};
 
 mod config;
 pub use config::RpcBuilder;
@@ -29,13 +31,22 @@ pub use response::SafeHeadResponse;
 mod output;
 pub use output::OutputResponse;
 
+mod health;
+pub use health::{HealthRpc, SequencerHealth, SequencerHealthConfig, SequencerHealthFailure};
+
+mod probes;
+pub use probes::{ProbeLayer, ProbeService, Readiness, ReadinessConfig, ReadinessFailure};
+
+mod query;
+
 mod dev;
 pub use dev::DevEngineRpc;
 
 mod jsonrpsee;
 pub use jsonrpsee::{
-    AdminApiServer, DevEngineApiServer, MinerApiExtServer, OpAdminApiServer, OpP2PApiServer,
-    RollupNodeApiServer, SupervisorEventsServer, WsServer,
+    AdminApiServer, DevEngineAdminApiServer, DevEngineApiServer, HealthApiServer,
+    MinerApiExtServer, OpAdminApiServer, OpP2PApiServer, RollupNodeApiServer,
+    SupervisorEventsServer, WsServer,
 };
 
 #[cfg(feature = "reqwest")]
@@ -57,6 +68,9 @@ pub use rollup::RollupRpc;
 mod l1_watcher;
 pub use l1_watcher::{L1State, L1WatcherQueries, L1WatcherQuerySender};
 
+mod derivation;
+pub use derivation::{DerivationQueries, DerivationQuerySender, SystemConfigHistoryEntry};
+
 mod ws;
 pub use ws::WsRPC;
 
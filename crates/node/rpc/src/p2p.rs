@@ -11,7 +11,7 @@ use jsonrpsee::{
     core::RpcResult,
     types::{ErrorCode, ErrorObject},
 };
-use kona_p2p::{P2pRpcRequest, PeerCount, PeerDump, PeerInfo, PeerStats};
+use kona_p2p::{DiscoveryStats, P2pRpcRequest, PeerCount, PeerDump, PeerInfo, PeerStats};
 use std::{net::IpAddr, str::FromStr};
 
 use crate::{OpP2PApiServer, net::P2pRpc};
@@ -79,6 +79,17 @@ impl OpP2PApiServer for P2pRpc {
         rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
     }
 
+    async fn opp2p_discovery_stats(&self) -> RpcResult<DiscoveryStats> {
+        kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_discoveryStats");
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        self.sender
+            .send(P2pRpcRequest::DiscoveryStats(tx))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        rx.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
     async fn opp2p_block_peer(&self, peer_id: String) -> RpcResult<()> {
         kona_macros::inc!(gauge, kona_p2p::Metrics::RPC_CALLS, "method" => "opp2p_blockPeer");
         let id = libp2p::PeerId::from_str(&peer_id)
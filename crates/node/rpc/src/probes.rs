@@ -0,0 +1,493 @@
+//! Liveness and readiness HTTP probes.
+//!
+//! The probes are served as plain HTTP `GET` endpoints by the RPC server, through the
+//! [`ProbeLayer`] HTTP middleware, so that orchestrators such as Kubernetes can tell a running
+//! node apart from a node that follows the chain:
+//!
+//! - `/healthz` succeeds as long as the node actors answer queries.
+//! - `/readyz` succeeds only if the node is synced, following L1 and deriving the safe chain.
+//!
+//! Failed probes are answered with `503 Service Unavailable`. The probes are only served if the
+//! RPC server is enabled.
+
+use alloy_eips::BlockNumHash;
+use http::{HeaderValue, Method, StatusCode, header::CONTENT_TYPE};
+use jsonrpsee::server::{HttpBody, HttpRequest, HttpResponse};
+use kona_engine::{EngineQueries, EngineQuerySender, EngineState};
+use kona_p2p::P2pRpcRequest;
+use kona_protocol::BlockInfo;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+use tokio::sync::{mpsc, oneshot};
+use tower::{Layer, Service};
+
+use crate::{HealthzResponse, L1WatcherQueries, L1WatcherQuerySender, query::query};
+
+/// The maximum time spent answering a query to a node actor.
+const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// The thresholds of the readiness checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadinessConfig {
+    /// The maximum age of the unsafe head, in seconds.
+    pub max_unsafe_head_age: u64,
+    /// The maximum age of the latest L1 head seen by the L1 watcher, in seconds.
+    pub max_l1_head_age: u64,
+    /// The maximum number of L1 blocks between the latest L1 head and the L1 origin of the safe
+    /// head. Derivation progress is not checked if [`None`].
+    pub max_derivation_lag: Option<u64>,
+    /// The minimum number of connected gossip peers.
+    pub min_peer_count: u64,
+}
+
+impl Default for ReadinessConfig {
+    fn default() -> Self {
+        Self {
+            max_unsafe_head_age: 60,
+            max_l1_head_age: 60,
+            max_derivation_lag: Some(300),
+            min_peer_count: 1,
+        }
+    }
+}
+
+/// A failed readiness check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ReadinessFailure {
+    /// The execution layer is still syncing.
+    Syncing,
+    /// The unsafe head is older than the maximum unsafe head age.
+    UnsafeHeadStale,
+    /// The L1 watcher has not seen any L1 head yet.
+    L1HeadUnknown,
+    /// The latest L1 head is older than the maximum L1 head age.
+    L1HeadStale,
+    /// The L1 origin of the safe head lags too far behind the latest L1 head.
+    DerivationLagging,
+    /// Not enough gossip peers are connected.
+    NotEnoughPeers,
+}
+
+/// The readiness of the node, as reported by the `/readyz` probe.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Readiness {
+    /// Whether every readiness check passed.
+    pub ready: bool,
+    /// The failed readiness checks.
+    pub failures: Vec<ReadinessFailure>,
+    /// Whether the execution layer finished syncing.
+    pub el_sync_finished: bool,
+    /// The unsafe head.
+    pub unsafe_head: BlockNumHash,
+    /// The age of the unsafe head, in seconds.
+    pub unsafe_head_age: u64,
+    /// The safe head.
+    pub safe_head: BlockNumHash,
+    /// The L1 origin of the safe head.
+    pub safe_head_l1_origin: BlockNumHash,
+    /// The latest L1 head seen by the L1 watcher.
+    pub l1_head: Option<BlockNumHash>,
+    /// The age of the latest L1 head, in seconds.
+    pub l1_head_age: Option<u64>,
+    /// The number of L1 blocks between the latest L1 head and the L1 origin of the safe head.
+    pub derivation_lag: Option<u64>,
+    /// The number of connected gossip peers.
+    pub peer_count: u64,
+}
+
+impl Readiness {
+    /// Checks the readiness of the node at the unix timestamp `now`, given the [`EngineState`],
+    /// the latest L1 head seen by the L1 watcher and the number of connected gossip peers.
+    pub fn check(
+        config: &ReadinessConfig,
+        now: u64,
+        state: &EngineState,
+        l1_head: Option<&BlockInfo>,
+        peer_count: u64,
+    ) -> Self {
+        let unsafe_head = state.sync_state.unsafe_head().block_info;
+        let safe_head = state.sync_state.safe_head();
+        let unsafe_head_age = now.saturating_sub(unsafe_head.timestamp);
+        let l1_head_age = l1_head.map(|head| now.saturating_sub(head.timestamp));
+        let derivation_lag =
+            l1_head.map(|head| head.number.saturating_sub(safe_head.l1_origin.number));
+
+        let mut failures = Vec::new();
+        if !state.el_sync_finished {
+            failures.push(ReadinessFailure::Syncing);
+        }
+        if unsafe_head_age > config.max_unsafe_head_age {
+            failures.push(ReadinessFailure::UnsafeHeadStale);
+        }
+        match l1_head_age {
+            None => failures.push(ReadinessFailure::L1HeadUnknown),
+            Some(age) if age > config.max_l1_head_age => {
+                failures.push(ReadinessFailure::L1HeadStale)
+            }
+            Some(_) => {}
+        }
+        if config.max_derivation_lag.zip(derivation_lag).is_some_and(|(max, lag)| lag > max) {
+            failures.push(ReadinessFailure::DerivationLagging);
+        }
+        if peer_count < config.min_peer_count {
+            failures.push(ReadinessFailure::NotEnoughPeers);
+        }
+
+        Self {
+            ready: failures.is_empty(),
+            failures,
+            el_sync_finished: state.el_sync_finished,
+            unsafe_head: unsafe_head.id(),
+            unsafe_head_age,
+            safe_head: safe_head.block_info.id(),
+            safe_head_l1_origin: safe_head.l1_origin,
+            l1_head: l1_head.map(BlockInfo::id),
+            l1_head_age,
+            derivation_lag,
+            peer_count,
+        }
+    }
+}
+
+/// A response to a probe request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProbeResponse {
+    /// The HTTP status.
+    status: StatusCode,
+    /// The JSON body.
+    body: String,
+}
+
+impl ProbeResponse {
+    /// Creates a [`ProbeResponse`] from a probe outcome, encoding the body as JSON.
+    fn json(ok: bool, body: &impl serde::Serialize) -> Self {
+        Self {
+            status: if ok { StatusCode::OK } else { StatusCode::SERVICE_UNAVAILABLE },
+            body: serde_json::to_string(body).unwrap_or_default(),
+        }
+    }
+
+    /// Creates a [`ProbeResponse`] with an empty body.
+    const fn empty(status: StatusCode) -> Self {
+        Self { status, body: String::new() }
+    }
+
+    /// Converts the [`ProbeResponse`] into an HTTP response.
+    fn into_http(self) -> HttpResponse {
+        let mut response = HttpResponse::new(HttpBody::from(self.body));
+        *response.status_mut() = self.status;
+        response.headers_mut().insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        response
+    }
+}
+
+/// A probe served by the [`ProbeLayer`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Probe {
+    /// The `/healthz` liveness probe.
+    Liveness,
+    /// The `/readyz` readiness probe.
+    Readiness,
+}
+
+impl Probe {
+    /// Returns the probe requested by the given HTTP request, if any.
+    fn from_request(request: &HttpRequest) -> Option<Self> {
+        if request.method() != Method::GET {
+            return None;
+        }
+
+        match request.uri().path() {
+            "/healthz" => Some(Self::Liveness),
+            "/readyz" => Some(Self::Readiness),
+            _ => None,
+        }
+    }
+}
+
+/// The node actors queried by the probes.
+#[derive(Debug, Clone)]
+struct Probes {
+    /// The channel to send [`EngineQueries`]s.
+    engine_sender: EngineQuerySender,
+    /// The channel to send [`L1WatcherQueries`]s.
+    l1_watcher_sender: L1WatcherQuerySender,
+    /// The channel to send [`P2pRpcRequest`]s.
+    p2p_sender: mpsc::Sender<P2pRpcRequest>,
+    /// The thresholds of the readiness checks.
+    config: ReadinessConfig,
+}
+
+impl Probes {
+    /// Answers the given probe.
+    async fn answer(&self, probe: Probe) -> ProbeResponse {
+        match probe {
+            Probe::Liveness => self.liveness().await,
+            Probe::Readiness => self.readiness().await,
+        }
+    }
+
+    /// The node is live as long as its engine actor answers queries.
+    async fn liveness(&self) -> ProbeResponse {
+        let alive = Self::query(&self.engine_sender, EngineQueries::State).await.is_some();
+        let response = HealthzResponse { version: std::env!("CARGO_PKG_VERSION").to_string() };
+        ProbeResponse::json(alive, &response)
+    }
+
+    /// Checks the [`Readiness`] of the node.
+    async fn readiness(&self) -> ProbeResponse {
+        let (state, l1_state, peer_count) = tokio::join!(
+            Self::query(&self.engine_sender, EngineQueries::State),
+            Self::query(&self.l1_watcher_sender, L1WatcherQueries::L1State),
+            Self::query(&self.p2p_sender, P2pRpcRequest::PeerCount),
+        );
+        let Some(state) = state else {
+            return ProbeResponse::empty(StatusCode::SERVICE_UNAVAILABLE);
+        };
+        let l1_head = l1_state.and_then(|l1_state| l1_state.current_l1);
+        let peer_count = peer_count.map_or(0, |(_, connected)| connected as u64);
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        let readiness = Readiness::check(&self.config, now, &state, l1_head.as_ref(), peer_count);
+        ProbeResponse::json(readiness.ready, &readiness)
+    }
+
+    /// Queries a node actor, giving up if it does not answer in time.
+    async fn query<Q, T>(
+        sender: &mpsc::Sender<Q>,
+        build: impl FnOnce(oneshot::Sender<T>) -> Q,
+    ) -> Option<T> {
+        tokio::time::timeout(PROBE_TIMEOUT, query(sender, build)).await.ok()?
+    }
+}
+
+/// An HTTP middleware of the RPC server, serving the liveness and readiness probes of the node.
+///
+/// Requests other than the probes are passed on to the RPC server.
+#[derive(Debug, Clone)]
+pub struct ProbeLayer {
+    /// The node actors queried by the probes.
+    probes: Probes,
+}
+
+impl ProbeLayer {
+    /// Constructs a new [`ProbeLayer`].
+    pub const fn new(
+        engine_sender: EngineQuerySender,
+        l1_watcher_sender: L1WatcherQuerySender,
+        p2p_sender: mpsc::Sender<P2pRpcRequest>,
+        config: ReadinessConfig,
+    ) -> Self {
+        Self { probes: Probes { engine_sender, l1_watcher_sender, p2p_sender, config } }
+    }
+}
+
+impl<S> Layer<S> for ProbeLayer {
+    type Service = ProbeService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ProbeService { inner, probes: self.probes.clone() }
+    }
+}
+
+/// The [`Service`] built by the [`ProbeLayer`].
+#[derive(Debug, Clone)]
+pub struct ProbeService<S> {
+    /// The RPC service.
+    inner: S,
+    /// The node actors queried by the probes.
+    probes: Probes,
+}
+
+impl<S> Service<HttpRequest> for ProbeService<S>
+where
+    S: Service<HttpRequest, Response = HttpResponse>,
+    S::Future: Send + 'static,
+{
+    type Response = HttpResponse;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: HttpRequest) -> Self::Future {
+        let Some(probe) = Probe::from_request(&request) else {
+            return Box::pin(self.inner.call(request));
+        };
+
+        let probes = self.probes.clone();
+        Box::pin(async move { Ok(probes.answer(probe).await.into_http()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::L1State;
+    use kona_engine::{EngineSyncState, EngineSyncStateUpdate};
+    use kona_protocol::L2BlockInfo;
+
+    fn state(unsafe_time: u64, safe_l1_origin: u64, el_sync_finished: bool) -> EngineState {
+        let sync_state = EngineSyncState::default().apply_update(EngineSyncStateUpdate {
+            unsafe_head: Some(L2BlockInfo {
+                block_info: BlockInfo { number: 10, timestamp: unsafe_time, ..Default::default() },
+                ..Default::default()
+            }),
+            safe_head: Some(L2BlockInfo {
+                block_info: BlockInfo { number: 5, ..Default::default() },
+                l1_origin: BlockNumHash { number: safe_l1_origin, ..Default::default() },
+                ..Default::default()
+            }),
+            ..Default::default()
+        });
+        EngineState { sync_state, el_sync_finished, ..Default::default() }
+    }
+
+    fn l1_head(number: u64, timestamp: u64) -> BlockInfo {
+        BlockInfo { number, timestamp, ..Default::default() }
+    }
+
+    #[test]
+    fn test_node_ready() {
+        let readiness = Readiness::check(
+            &ReadinessConfig::default(),
+            1_000,
+            &state(990, 100, true),
+            Some(&l1_head(110, 988)),
+            3,
+        );
+        assert!(readiness.ready);
+        assert!(readiness.failures.is_empty());
+        assert_eq!(readiness.unsafe_head_age, 10);
+        assert_eq!(readiness.l1_head_age, Some(12));
+        assert_eq!(readiness.derivation_lag, Some(10));
+    }
+
+    #[test]
+    fn test_node_not_ready() {
+        let config = ReadinessConfig {
+            max_unsafe_head_age: 10,
+            max_l1_head_age: 24,
+            max_derivation_lag: Some(5),
+            min_peer_count: 2,
+        };
+        let readiness =
+            Readiness::check(&config, 1_000, &state(980, 100, false), Some(&l1_head(110, 970)), 1);
+        assert!(!readiness.ready);
+        assert_eq!(
+            readiness.failures,
+            vec![
+                ReadinessFailure::Syncing,
+                ReadinessFailure::UnsafeHeadStale,
+                ReadinessFailure::L1HeadStale,
+                ReadinessFailure::DerivationLagging,
+                ReadinessFailure::NotEnoughPeers,
+            ]
+        );
+
+        // Derivation progress is not checked without a maximum lag.
+        let config = ReadinessConfig { max_derivation_lag: None, ..config };
+        let readiness = Readiness::check(&config, 1_000, &state(995, 0, true), None, 2);
+        assert_eq!(readiness.failures, vec![ReadinessFailure::L1HeadUnknown]);
+        assert_eq!(readiness.derivation_lag, None);
+    }
+
+    /// Sends a raw HTTP request to the given address, returning the status code and body.
+    async fn request(
+        addr: std::net::SocketAddr,
+        method: &str,
+        path: &str,
+        body: &str,
+    ) -> (u16, String) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let request = format!(
+            "{method} {path} HTTP/1.1\r\nHost: localhost\r\nContent-Type: application/json\r\n\
+             Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        );
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+
+        let status = response.split_whitespace().nth(1).unwrap().parse().unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap_or_default().to_string();
+        (status, body)
+    }
+
+    #[tokio::test]
+    async fn test_probe_layer() {
+        let (engine_sender, mut engine_rx) = mpsc::channel(8);
+        let (l1_watcher_sender, mut l1_watcher_rx) = mpsc::channel(8);
+        let (p2p_sender, mut p2p_rx) = mpsc::channel(8);
+
+        tokio::spawn(async move {
+            while let Some(query) = engine_rx.recv().await {
+                if let EngineQueries::State(tx) = query {
+                    let _ = tx.send(state(0, 0, true));
+                }
+            }
+        });
+        tokio::spawn(async move {
+            while let Some(query) = l1_watcher_rx.recv().await {
+                if let L1WatcherQueries::L1State(tx) = query {
+                    let _ = tx.send(L1State {
+                        current_l1: None,
+                        current_l1_finalized: None,
+                        head_l1: None,
+                        safe_l1: None,
+                        finalized_l1: None,
+                    });
+                }
+            }
+        });
+        tokio::spawn(async move {
+            while let Some(request) = p2p_rx.recv().await {
+                if let P2pRpcRequest::PeerCount(tx) = request {
+                    let _ = tx.send((None, 0));
+                }
+            }
+        });
+
+        let layer = ProbeLayer::new(
+            engine_sender,
+            l1_watcher_sender,
+            p2p_sender,
+            ReadinessConfig::default(),
+        );
+        let server = jsonrpsee::server::Server::builder()
+            .set_http_middleware(tower::ServiceBuilder::new().layer(layer))
+            .build("127.0.0.1:0")
+            .await
+            .unwrap();
+        let addr = server.local_addr().unwrap();
+        let handle = server.start(jsonrpsee::RpcModule::new(()));
+
+        let (status, body) = request(addr, "GET", "/healthz", "").await;
+        assert_eq!(status, 200);
+        assert!(body.contains("version"));
+
+        // The unsafe head is stale and the L1 head is unknown.
+        let (status, body) = request(addr, "GET", "/readyz?verbose=1", "").await;
+        assert_eq!(status, 503);
+        let readiness: Readiness = serde_json::from_str(&body).unwrap();
+        assert!(readiness.failures.contains(&ReadinessFailure::L1HeadUnknown));
+
+        // Other requests are answered by the RPC server.
+        let call = r#"{"jsonrpc":"2.0","id":1,"method":"optimism_syncStatus"}"#;
+        let (status, body) = request(addr, "POST", "/", call).await;
+        assert_eq!(status, 200);
+        assert!(body.contains("Method not found"));
+
+        handle.stop().unwrap();
+    }
+}
@@ -0,0 +1,14 @@
+//! Queries to the node actors.
+
+use tokio::sync::{mpsc, oneshot};
+
+/// Sends a query built with the given constructor to a node actor, and awaits its response.
+/// Returns [`None`] if the actor is gone or drops the query.
+pub(crate) async fn query<Q, T>(
+    sender: &mpsc::Sender<Q>,
+    build: impl FnOnce(oneshot::Sender<T>) -> Q,
+) -> Option<T> {
+    let (tx, rx) = oneshot::channel();
+    sender.send(build(tx)).await.ok()?;
+    rx.await.ok()
+}
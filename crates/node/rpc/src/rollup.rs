@@ -9,11 +9,12 @@ use jsonrpsee::{
     types::{ErrorCode, ErrorObject},
 };
 use kona_engine::{EngineQueries, EngineQuerySender, EngineState};
-use kona_genesis::RollupConfig;
+use kona_genesis::{RollupConfig, SystemConfig};
 use kona_protocol::SyncStatus;
 
 use crate::{
-    L1State, L1WatcherQueries, OutputResponse, RollupNodeApiServer, SafeHeadResponse,
+    DerivationQueries, DerivationQuerySender, L1State, L1WatcherQueries, OutputResponse,
+    RollupNodeApiServer, SafeHeadResponse, SystemConfigHistoryEntry,
     l1_watcher::L1WatcherQuerySender,
 };
 
@@ -26,6 +27,8 @@ pub struct RollupRpc {
     pub engine_sender: EngineQuerySender,
     /// The channel to send [`crate::L1WatcherQueries`]s.
     pub l1_watcher_sender: L1WatcherQuerySender,
+    /// The channel to send [`crate::DerivationQueries`]s.
+    pub derivation_sender: DerivationQuerySender,
 }
 
 impl RollupRpc {
@@ -36,8 +39,32 @@ impl RollupRpc {
     pub const fn new(
         engine_sender: EngineQuerySender,
         l1_watcher_sender: L1WatcherQuerySender,
+        derivation_sender: DerivationQuerySender,
     ) -> Self {
-        Self { engine_sender, l1_watcher_sender }
+        Self { engine_sender, l1_watcher_sender, derivation_sender }
+    }
+
+    /// Resolves the [`BlockNumberOrTag`] to an L2 block number, using the engine state for tags.
+    async fn l2_block_number(&self, block: BlockNumberOrTag) -> RpcResult<u64> {
+        match block {
+            BlockNumberOrTag::Number(number) => return Ok(number),
+            BlockNumberOrTag::Earliest => return Ok(0),
+            _ => {}
+        }
+
+        let (state_send, state_recv) = tokio::sync::oneshot::channel();
+        self.engine_sender
+            .send(EngineQueries::State(state_send))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+        let state = state_recv.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        let head = match block {
+            BlockNumberOrTag::Safe => state.sync_state.safe_head(),
+            BlockNumberOrTag::Finalized => state.sync_state.finalized_head(),
+            _ => state.sync_state.unsafe_head(),
+        };
+        Ok(head.block_info.number)
     }
 
     // Important note: we zero-out the fields that can't be derived yet to follow op-node's
@@ -142,6 +169,34 @@ impl RollupNodeApiServer for RollupRpc {
         Ok(rollup_config_recv.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))?)
     }
 
+    async fn op_system_config_history(&self) -> RpcResult<Vec<SystemConfigHistoryEntry>> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_systemConfigHistory");
+
+        let (history_send, history_recv) = tokio::sync::oneshot::channel();
+        self.derivation_sender
+            .send(DerivationQueries::SystemConfigHistory(history_send))
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        history_recv.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
+    async fn op_system_config_at_block(
+        &self,
+        block_number: BlockNumberOrTag,
+    ) -> RpcResult<Option<SystemConfig>> {
+        kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_systemConfigAtBlock");
+
+        let block = self.l2_block_number(block_number).await?;
+        let (config_send, config_recv) = tokio::sync::oneshot::channel();
+        self.derivation_sender
+            .send(DerivationQueries::SystemConfigAt { block, sender: config_send })
+            .await
+            .map_err(|_| ErrorObject::from(ErrorCode::InternalError))?;
+
+        config_recv.await.map_err(|_| ErrorObject::from(ErrorCode::InternalError))
+    }
+
     async fn op_version(&self) -> RpcResult<String> {
         kona_macros::inc!(gauge, Self::RPC_IDENT, "method" => "op_version");
 
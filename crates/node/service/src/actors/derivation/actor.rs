@@ -2,19 +2,25 @@
 
 use std::sync::Arc;
 
+use super::{
+    DerivationCheckpointConfig, DerivationCheckpointer, SystemConfigHistory,
+    SystemConfigQueryServer,
+};
 use crate::{InteropMode, Metrics, NodeActor, actors::CancellableContext};
 use alloy_provider::RootProvider;
 use async_trait::async_trait;
 use kona_derive::{
-    ActivationSignal, Pipeline, PipelineError, PipelineErrorKind, ResetError, ResetSignal, Signal,
-    SignalReceiver, StepResult,
+    ActivationSignal, DerivationEventSink, Pipeline, PipelineCheckpoint, PipelineError,
+    PipelineErrorKind, ResetError, ResetSignal, Signal, SignalReceiver, StageCheckpoint,
+    StepResult,
 };
 use kona_genesis::RollupConfig;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use kona_providers_alloy::{
-    AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlineBlobProvider,
-    OnlinePipeline,
+    AlloyChainProvider, AlloyL2ChainProvider, L1Prefetcher, OnlineBeaconClient, OnlineBlobProvider,
+    OnlineDataAvailabilityRegistry, OnlinePipeline, PrefetchConfig,
 };
+use kona_rpc::{DerivationQueries, DerivationQuerySender};
 use op_alloy_network::Optimism;
 use thiserror::Error;
 use tokio::{
@@ -62,6 +68,8 @@ where
     ///
     /// Specs: <https://specs.optimism.io/protocol/derivation.html#l1-sync-payload-attributes-processing>
     derivation_signal_rx: mpsc::Receiver<Signal>,
+    /// The receiver for the [`DerivationQueries`] sent by the RPC.
+    derivation_queries_rx: mpsc::Receiver<DerivationQueries>,
 }
 
 /// The state for the derivation actor.
@@ -78,6 +86,16 @@ where
     /// A flag indicating whether or not derivation is waiting for a signal. When waiting for a
     /// signal, derivation cannot process any incoming events.
     pub waiting_for_signal: bool,
+    /// The optional checkpointer of the derivation pipeline, used to resume derivation from the
+    /// last checkpoint after a restart.
+    pub checkpointer: Option<DerivationCheckpointer>,
+    /// The optional history of the system config updates applied by the pipeline.
+    pub system_config_history: Option<SystemConfigHistory>,
+    /// The optional server of the [`DerivationQueries`], spawned off the actor when it starts.
+    pub system_config_queries: Option<SystemConfigQueryServer>,
+    /// The optional prefetcher of the L1 data read by the pipeline, invalidated when the pipeline
+    /// is reset or an L1 reorg is detected.
+    pub prefetcher: Option<L1Prefetcher>,
 }
 
 /// The size of the cache used in the derivation pipeline's providers.
@@ -87,7 +105,7 @@ const DERIVATION_PROVIDER_CACHE_SIZE: usize = 1024;
 #[async_trait]
 pub trait PipelineBuilder: Send + Sync + 'static {
     /// The type of pipeline to build.
-    type Pipeline: Pipeline + SignalReceiver + Send + Sync + 'static;
+    type Pipeline: Pipeline + SignalReceiver + StageCheckpoint + Send + Sync + 'static;
 
     /// Builds the derivation pipeline.
     async fn build(self) -> DerivationState<Self::Pipeline>;
@@ -106,6 +124,14 @@ pub struct DerivationBuilder {
     pub rollup_config: Arc<RollupConfig>,
     /// The interop mode.
     pub interop_mode: InteropMode,
+    /// The optional derivation pipeline checkpoint configuration.
+    pub checkpoint: Option<DerivationCheckpointConfig>,
+    /// The optional sink of the derivation pipeline events.
+    pub event_sink: Option<Arc<dyn DerivationEventSink>>,
+    /// The configuration of the L1 data prefetching.
+    pub prefetch: PrefetchConfig,
+    /// The registry the data availability provider of the pipeline is selected from.
+    pub da_registry: OnlineDataAvailabilityRegistry,
 }
 
 #[async_trait]
@@ -122,22 +148,48 @@ impl PipelineBuilder for DerivationBuilder {
             DERIVATION_PROVIDER_CACHE_SIZE,
         );
 
+        // Record the system config updates, alongside the configured event sink.
+        let system_config_history = SystemConfigHistory::new();
+        let event_sink = Some(system_config_history.sink(self.event_sink));
+        let system_config_queries = SystemConfigQueryServer::new(
+            system_config_history.clone(),
+            self.l2_provider.clone(),
+            self.rollup_config.clone(),
+        );
+
+        let blob_provider = OnlineBlobProvider::init(self.l1_beacon.clone()).await;
+        let prefetcher = L1Prefetcher::new(
+            self.prefetch,
+            self.l1_provider.clone(),
+            blob_provider.clone(),
+            &self.rollup_config,
+        );
         let pipeline = match self.interop_mode {
             InteropMode::Polled => OnlinePipeline::new_polled(
                 self.rollup_config.clone(),
-                OnlineBlobProvider::init(self.l1_beacon.clone()).await,
+                blob_provider,
                 l1_derivation_provider,
                 l2_derivation_provider,
+                &self.da_registry,
+                &prefetcher,
+                event_sink,
             ),
             InteropMode::Indexed => OnlinePipeline::new_indexed(
                 self.rollup_config.clone(),
-                OnlineBlobProvider::init(self.l1_beacon.clone()).await,
+                blob_provider,
                 l1_derivation_provider,
                 l2_derivation_provider,
+                &self.da_registry,
+                &prefetcher,
+                event_sink,
             ),
         };
 
         DerivationState::new(pipeline)
+            .with_checkpointer(self.checkpoint.map(DerivationCheckpointer::new))
+            .with_system_config_history(system_config_history)
+            .with_system_config_queries(system_config_queries)
+            .with_prefetcher(prefetcher)
     }
 }
 
@@ -157,6 +209,8 @@ pub struct DerivationInboundChannels {
     /// This channel should be used by the engine actor to send [`Signal`]s to the derivation
     /// pipeline. The signals are received by `DerivationActor::derivation_signal_rx`.
     pub derivation_signal_tx: mpsc::Sender<Signal>,
+    /// A sender for the [`DerivationQueries`] served by the [`SystemConfigQueryServer`].
+    pub derivation_queries_tx: DerivationQuerySender,
 }
 
 /// The communication context used by the derivation actor.
@@ -179,27 +233,121 @@ impl CancellableContext for DerivationContext {
 
 impl<P> DerivationState<P>
 where
-    P: Pipeline + SignalReceiver,
+    P: Pipeline + SignalReceiver + StageCheckpoint,
 {
     /// Creates a new instance of the [DerivationState].
     pub const fn new(pipeline: P) -> Self {
-        Self { pipeline, derivation_idle: true, waiting_for_signal: false }
+        Self {
+            pipeline,
+            derivation_idle: true,
+            waiting_for_signal: false,
+            checkpointer: None,
+            system_config_history: None,
+            system_config_queries: None,
+            prefetcher: None,
+        }
+    }
+
+    /// Sets the [`DerivationCheckpointer`] of the [DerivationState].
+    pub fn with_checkpointer(self, checkpointer: Option<DerivationCheckpointer>) -> Self {
+        Self { checkpointer, ..self }
+    }
+
+    /// Sets the [`SystemConfigHistory`] of the [DerivationState].
+    ///
+    /// The history must be recording the events of the pipeline, see
+    /// [`SystemConfigHistory::sink`].
+    pub fn with_system_config_history(self, history: SystemConfigHistory) -> Self {
+        Self { system_config_history: Some(history), ..self }
+    }
+
+    /// Sets the [`SystemConfigQueryServer`] of the [DerivationState].
+    pub fn with_system_config_queries(self, queries: SystemConfigQueryServer) -> Self {
+        Self { system_config_queries: Some(queries), ..self }
+    }
+
+    /// Sets the [`L1Prefetcher`] of the L1 data read by the pipeline of the [DerivationState].
+    pub fn with_prefetcher(self, prefetcher: L1Prefetcher) -> Self {
+        Self { prefetcher: Some(prefetcher), ..self }
+    }
+
+    /// Drops the L1 data prefetched ahead of the pipeline, which may no longer be canonical.
+    fn invalidate_prefetched(&self) {
+        if let Some(prefetcher) = &self.prefetcher {
+            prefetcher.invalidate();
+        }
     }
 
     /// Handles a [`Signal`] received over the derivation signal receiver channel.
     async fn signal(&mut self, signal: Signal) {
         if let Signal::Reset(ResetSignal { l1_origin, .. }) = signal {
             kona_macros::set!(counter, Metrics::DERIVATION_L1_ORIGIN, l1_origin.number);
+            self.invalidate_prefetched();
         }
 
         match self.pipeline.signal(signal).await {
             Ok(_) => info!(target: "derivation", ?signal, "[SIGNAL] Executed Successfully"),
             Err(e) => {
-                error!(target: "derivation", ?e, ?signal, "Failed to signal derivation pipeline")
+                error!(target: "derivation", ?e, ?signal, "Failed to signal derivation pipeline");
+                return;
+            }
+        }
+
+        if let Signal::Reset(ResetSignal { l2_safe_head, system_config, .. }) = signal {
+            if let Some(history) = &self.system_config_history {
+                history.reset(l2_safe_head, system_config);
             }
+            self.restore_checkpoint(l2_safe_head, signal).await;
         }
     }
 
+    /// Restores the checkpoint loaded on startup after the first pipeline reset, if it was taken at
+    /// the L2 safe head the pipeline was reset to.
+    ///
+    /// If the checkpoint cannot be restored, e.g. because its L1 origin was reorged out, the
+    /// pipeline is reset again and derivation resumes from the reset origin.
+    async fn restore_checkpoint(&mut self, l2_safe_head: L2BlockInfo, reset: Signal) {
+        let Some(checkpoint) = self
+            .checkpointer
+            .as_mut()
+            .and_then(|checkpointer| checkpointer.take_startup_checkpoint(&l2_safe_head))
+        else {
+            return;
+        };
+
+        match self.pipeline.restore(&checkpoint).await {
+            Ok(()) => info!(
+                target: "derivation",
+                l1_origin = checkpoint.origin.number,
+                channels = checkpoint.channels.len(),
+                "Restored derivation pipeline checkpoint"
+            ),
+            Err(e) => {
+                warn!(target: "derivation", ?e, "Failed to restore derivation pipeline checkpoint");
+                if let Err(e) = self.pipeline.signal(reset).await {
+                    error!(target: "derivation", ?e, "Failed to reset derivation pipeline");
+                }
+            }
+        }
+    }
+
+    /// Checkpoints the derivation pipeline if a checkpoint is due.
+    ///
+    /// Must only be called when derivation is idle, so that all derived attributes were consumed
+    /// and the pipeline state is consistent with the given L2 safe head.
+    async fn checkpoint(&mut self, l2_safe_head: L2BlockInfo) {
+        let Some(checkpointer) = self.checkpointer.as_mut().filter(|c| c.is_due()) else {
+            return;
+        };
+
+        let mut checkpoint = PipelineCheckpoint { l2_safe_head, ..Default::default() };
+        if let Err(e) = self.pipeline.checkpoint(&mut checkpoint) {
+            warn!(target: "derivation", ?e, "Failed to checkpoint derivation pipeline");
+            return;
+        }
+        checkpointer.store(checkpoint).await;
+    }
+
     /// Attempts to step the derivation pipeline forward as much as possible in order to produce the
     /// next safe payload.
     async fn produce_next_attributes(
@@ -268,6 +416,7 @@ where
                                     );
 
                                     kona_macros::inc!(counter, Metrics::L1_REORG_COUNT);
+                                    self.invalidate_prefetched();
                                 }
                                 // send the `reset` signal to the engine actor only when interop is
                                 // not active.
@@ -362,6 +511,10 @@ where
                 Err(DerivationError::Yield) => {
                     // Yield until more data is available.
                     self.derivation_idle = true;
+                    if !self.waiting_for_signal {
+                        let l2_safe_head = *engine_l2_safe_head.borrow();
+                        self.checkpoint(l2_safe_head).await;
+                    }
                     return Ok(());
                 }
                 Err(e) => {
@@ -369,6 +522,16 @@ where
                 }
             };
 
+        // Resolve the L2 block the pending system config updates take effect at.
+        if let Some(history) = &self.system_config_history {
+            match payload_attrs.l1_block_info() {
+                Some(l1_info) => history.resolve(l1_info.id().number, payload_attrs.block_number()),
+                None => {
+                    warn!(target: "derivation", "Derived attributes without an L1 info deposit")
+                }
+            }
+        }
+
         // Mark derivation as busy.
         self.derivation_idle = false;
 
@@ -396,12 +559,14 @@ where
             watch::channel(L2BlockInfo::default());
         let (el_sync_complete_tx, el_sync_complete_rx) = oneshot::channel();
         let (derivation_signal_tx, derivation_signal_rx) = mpsc::channel(16);
+        let (derivation_queries_tx, derivation_queries_rx) = mpsc::channel(1024);
         let actor = Self {
             state,
             l1_head_updates: l1_head_updates_rx,
             engine_l2_safe_head: engine_l2_safe_head_rx,
             el_sync_complete_rx,
             derivation_signal_rx,
+            derivation_queries_rx,
         };
 
         (
@@ -410,6 +575,7 @@ where
                 engine_l2_safe_head_tx,
                 el_sync_complete_tx,
                 derivation_signal_tx,
+                derivation_queries_tx,
             },
             actor,
         )
@@ -440,6 +606,15 @@ where
     ) -> Result<(), Self::Error> {
         let mut state = self.state.build().await;
 
+        // Serve the queries off the actor, so that they never wait on the pipeline. Without a
+        // server, the queries are rejected.
+        match state.system_config_queries.take() {
+            Some(server) => {
+                tokio::spawn(server.run(self.derivation_queries_rx, cancellation.clone()));
+            }
+            None => drop(self.derivation_queries_rx),
+        }
+
         loop {
             select! {
                 biased;
@@ -0,0 +1,225 @@
+//! Persistence of the derivation pipeline state across restarts.
+
+use crate::fs::write_atomic;
+use kona_derive::PipelineCheckpoint;
+use kona_protocol::L2BlockInfo;
+use std::{path::PathBuf, time::Duration};
+use tokio::{task::JoinHandle, time::Instant};
+
+/// The configuration of the derivation pipeline checkpoints.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationCheckpointConfig {
+    /// The path of the checkpoint file.
+    pub path: PathBuf,
+    /// The minimum interval between two checkpoints.
+    pub interval: Duration,
+}
+
+/// An error produced when reading or writing a [`PipelineCheckpoint`].
+#[derive(Debug, thiserror::Error)]
+pub enum DerivationCheckpointError {
+    /// The checkpoint file could not be read or written.
+    #[error("Failed to access the derivation checkpoint file {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    /// The checkpoint could not be encoded, or the checkpoint file is malformed.
+    #[error("Invalid derivation checkpoint file {0}: {1}")]
+    Json(PathBuf, serde_json::Error),
+}
+
+/// Reads and writes the [`PipelineCheckpoint`] to a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DerivationCheckpointStore {
+    /// The path of the checkpoint file.
+    path: PathBuf,
+}
+
+impl DerivationCheckpointStore {
+    /// Creates a new [`DerivationCheckpointStore`] backed by the file at the given path.
+    pub const fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Loads the [`PipelineCheckpoint`], returning [`None`] if the checkpoint file does not exist.
+    pub fn load(&self) -> Result<Option<PipelineCheckpoint>, DerivationCheckpointError> {
+        let contents = match std::fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(DerivationCheckpointError::Io(self.path.clone(), err)),
+        };
+
+        serde_json::from_slice(&contents)
+            .map(Some)
+            .map_err(|err| DerivationCheckpointError::Json(self.path.clone(), err))
+    }
+
+    /// Stores the [`PipelineCheckpoint`], atomically replacing the previous one.
+    pub fn store(&self, checkpoint: &PipelineCheckpoint) -> Result<(), DerivationCheckpointError> {
+        let contents = serde_json::to_vec(checkpoint)
+            .map_err(|err| DerivationCheckpointError::Json(self.path.clone(), err))?;
+        write_atomic(&self.path, &contents)
+            .map_err(|err| DerivationCheckpointError::Io(self.path.clone(), err))
+    }
+}
+
+/// Periodically checkpoints the derivation pipeline, and holds the checkpoint loaded on startup
+/// until the pipeline is first reset.
+#[derive(Debug)]
+pub struct DerivationCheckpointer {
+    /// The checkpoint file.
+    store: DerivationCheckpointStore,
+    /// The minimum interval between two checkpoints.
+    interval: Duration,
+    /// The time of the last checkpoint.
+    last_checkpoint: Option<Instant>,
+    /// The checkpoint loaded on startup, if it has not been consumed by a reset yet.
+    startup_checkpoint: Option<PipelineCheckpoint>,
+    /// The write of the last checkpoint, if it was not awaited yet.
+    pending_store: Option<JoinHandle<()>>,
+}
+
+impl DerivationCheckpointer {
+    /// Creates a new [`DerivationCheckpointer`], loading the checkpoint stored by the previous
+    /// run, if any.
+    pub fn new(config: DerivationCheckpointConfig) -> Self {
+        let store = DerivationCheckpointStore::new(config.path);
+        let startup_checkpoint = store.load().unwrap_or_else(|err| {
+            warn!(target: "derivation", ?err, "Failed to load derivation checkpoint, ignoring it");
+            None
+        });
+        if let Some(checkpoint) = &startup_checkpoint {
+            info!(
+                target: "derivation",
+                l2_safe_head = checkpoint.l2_safe_head.block_info.number,
+                l1_origin = checkpoint.origin.number,
+                "Loaded derivation checkpoint"
+            );
+        }
+
+        Self {
+            store,
+            interval: config.interval,
+            last_checkpoint: None,
+            startup_checkpoint,
+            pending_store: None,
+        }
+    }
+
+    /// Takes the checkpoint loaded on startup if it was taken at the given L2 safe head.
+    ///
+    /// Only the first reset after startup may restore the checkpoint, later calls always return
+    /// [`None`].
+    pub fn take_startup_checkpoint(
+        &mut self,
+        l2_safe_head: &L2BlockInfo,
+    ) -> Option<PipelineCheckpoint> {
+        self.startup_checkpoint.take().filter(|checkpoint| {
+            checkpoint.l2_safe_head.block_info.hash == l2_safe_head.block_info.hash
+        })
+    }
+
+    /// Returns whether a new checkpoint is due.
+    pub fn is_due(&self) -> bool {
+        self.last_checkpoint.is_none_or(|last| last.elapsed() >= self.interval)
+    }
+
+    /// Stores the [`PipelineCheckpoint`] on the blocking thread pool.
+    ///
+    /// The checkpoint is encoded and written without blocking the derivation actor, but the
+    /// previous write is awaited first so that writes land in order.
+    pub async fn store(&mut self, checkpoint: PipelineCheckpoint) {
+        if let Some(pending_store) = self.pending_store.take() {
+            pending_store.await.ok();
+        }
+
+        self.last_checkpoint = Some(Instant::now());
+        let store = self.store.clone();
+        self.pending_store =
+            Some(tokio::task::spawn_blocking(move || match store.store(&checkpoint) {
+                Ok(()) => debug!(
+                    target: "derivation",
+                    l1_origin = checkpoint.origin.number,
+                    channels = checkpoint.channels.len(),
+                    "Checkpointed derivation pipeline"
+                ),
+                Err(e) => warn!(target: "derivation", ?e, "Failed to store derivation checkpoint"),
+            }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use kona_derive::ChannelCheckpoint;
+    use kona_protocol::BlockInfo;
+
+    fn checkpoint(hash: B256) -> PipelineCheckpoint {
+        PipelineCheckpoint {
+            l2_safe_head: L2BlockInfo {
+                block_info: BlockInfo { number: 10, hash, ..Default::default() },
+                ..Default::default()
+            },
+            origin: BlockInfo { number: 5, ..Default::default() },
+            channels: vec![ChannelCheckpoint {
+                id: [0xaa; 16].into(),
+                frames: vec![vec![0x01, 0x02].into()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_derivation_checkpoint_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = DerivationCheckpointStore::new(dir.path().join("derivation.json"));
+        assert_eq!(store.load().unwrap(), None);
+
+        let checkpoint = checkpoint(B256::repeat_byte(0xaa));
+        store.store(&checkpoint).unwrap();
+        assert_eq!(store.load().unwrap(), Some(checkpoint));
+    }
+
+    #[test]
+    fn test_derivation_checkpoint_malformed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("derivation.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        assert!(matches!(
+            DerivationCheckpointStore::new(path).load(),
+            Err(DerivationCheckpointError::Json(..))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_derivation_checkpointer() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("derivation.json");
+        let stored = checkpoint(B256::repeat_byte(0xaa));
+        DerivationCheckpointStore::new(path.clone()).store(&stored).unwrap();
+
+        let config = DerivationCheckpointConfig { path, interval: Duration::from_secs(60) };
+        let mut checkpointer = DerivationCheckpointer::new(config.clone());
+        assert!(checkpointer.is_due());
+        assert_eq!(
+            checkpointer.take_startup_checkpoint(&stored.l2_safe_head),
+            Some(stored.clone())
+        );
+        assert_eq!(checkpointer.take_startup_checkpoint(&stored.l2_safe_head), None);
+
+        // A checkpoint taken at another safe head is discarded.
+        let mut checkpointer = DerivationCheckpointer::new(config);
+        let other = checkpoint(B256::repeat_byte(0xbb));
+        assert_eq!(checkpointer.take_startup_checkpoint(&other.l2_safe_head), None);
+        assert_eq!(checkpointer.take_startup_checkpoint(&stored.l2_safe_head), None);
+
+        checkpointer.store(other.clone()).await;
+        assert!(!checkpointer.is_due());
+        checkpointer.pending_store.take().unwrap().await.unwrap();
+        assert_eq!(
+            DerivationCheckpointStore::new(dir.path().join("derivation.json")).load().unwrap(),
+            Some(other)
+        );
+    }
+}
@@ -0,0 +1,191 @@
+//! Recording of the derivation pipeline events.
+
+use kona_derive::{DerivationEvent, DerivationEventSink};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufWriter, Write},
+    path::{Path, PathBuf},
+    sync::mpsc::{self, RecvTimeoutError, SyncSender, TrySendError},
+    thread::JoinHandle,
+    time::{Duration, Instant},
+};
+
+/// The interval at which the recorded events are flushed to the events file.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The maximum number of events waiting to be written. Events are dropped while the buffer is
+/// full, rather than stalling the derivation pipeline.
+const EVENT_BUFFER: usize = 4096;
+
+/// A [`DerivationEventSink`] that appends the [`DerivationEvent`]s to a file, one JSON object per
+/// line.
+///
+/// Events are handed over to a writer thread, which flushes them to the file every second, so that
+/// the file can be tailed while the node is running. The remaining events are flushed when the
+/// recorder is dropped.
+#[derive(Debug)]
+pub struct DerivationEventRecorder {
+    /// The path of the events file.
+    path: PathBuf,
+    /// The channel to send the events to the writer thread.
+    events: Option<SyncSender<DerivationEvent>>,
+    /// The writer thread.
+    writer: Option<JoinHandle<()>>,
+}
+
+impl DerivationEventRecorder {
+    /// Opens the events file at the given path, creating it if it does not exist. Events are
+    /// appended to the existing contents of the file.
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, std::io::Error> {
+        let path = path.as_ref().to_path_buf();
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+
+        let (events, events_rx) = mpsc::sync_channel(EVENT_BUFFER);
+        let writer = EventWriter { path: path.clone(), writer: BufWriter::new(file) };
+        let writer = std::thread::Builder::new()
+            .name("derivation-events".to_string())
+            .spawn(move || writer.run(events_rx))?;
+
+        Ok(Self { path, events: Some(events), writer: Some(writer) })
+    }
+
+    /// Returns the path of the events file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl DerivationEventSink for DerivationEventRecorder {
+    fn on_event(&self, event: DerivationEvent) {
+        let Some(events) = &self.events else {
+            return;
+        };
+
+        if let Err(TrySendError::Full(_)) = events.try_send(event) {
+            warn!(
+                target: "derivation",
+                path = %self.path.display(),
+                "Derivation event buffer is full, dropping event"
+            );
+        }
+    }
+}
+
+impl Drop for DerivationEventRecorder {
+    fn drop(&mut self) {
+        // Closing the channel stops the writer thread once it flushed the remaining events.
+        self.events.take();
+        if let Some(writer) = self.writer.take() {
+            writer.join().ok();
+        }
+    }
+}
+
+/// Writes the events received from a [`DerivationEventRecorder`] to the events file.
+#[derive(Debug)]
+struct EventWriter {
+    /// The path of the events file.
+    path: PathBuf,
+    /// The writer of the events file.
+    writer: BufWriter<File>,
+}
+
+impl EventWriter {
+    /// Writes the received events until the channel is closed, flushing them every
+    /// [`FLUSH_INTERVAL`].
+    fn run(mut self, events: mpsc::Receiver<DerivationEvent>) {
+        let mut last_flush = Instant::now();
+        loop {
+            match events.recv_timeout(FLUSH_INTERVAL.saturating_sub(last_flush.elapsed())) {
+                Ok(event) => {
+                    if let Err(err) = self.write(&event) {
+                        self.warn(err, "Failed to record derivation event");
+                    }
+                }
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+
+            if last_flush.elapsed() >= FLUSH_INTERVAL {
+                if let Err(err) = self.writer.flush() {
+                    self.warn(err, "Failed to flush derivation events");
+                }
+                last_flush = Instant::now();
+            }
+        }
+
+        if let Err(err) = self.writer.flush() {
+            self.warn(err, "Failed to flush derivation events");
+        }
+    }
+
+    fn write(&mut self, event: &DerivationEvent) -> Result<(), std::io::Error> {
+        serde_json::to_writer(&mut self.writer, event)?;
+        self.writer.write_all(b"\n")
+    }
+
+    fn warn(&self, err: std::io::Error, message: &str) {
+        warn!(target: "derivation", path = %self.path.display(), ?err, "{message}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B128;
+    use kona_derive::FrameDropReason;
+
+    #[test]
+    fn test_recorder_appends_json_lines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+
+        let origin = BlockNumHash { number: 10, ..Default::default() };
+        let events = [
+            DerivationEvent::ChannelOpened { origin, channel_id: B128::with_last_byte(1) },
+            DerivationEvent::FrameDropped {
+                origin,
+                channel_id: B128::with_last_byte(2),
+                frame_number: 3,
+                reason: FrameDropReason::NoOpenChannel,
+            },
+        ];
+
+        let recorder = DerivationEventRecorder::create(&path).unwrap();
+        recorder.on_event(events[0].clone());
+        drop(recorder);
+        let recorder = DerivationEventRecorder::create(&path).unwrap();
+        recorder.on_event(events[1].clone());
+        drop(recorder);
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines = contents.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains(r#""event":"frameDropped""#));
+        assert!(lines[1].contains(r#""reason":"noOpenChannel""#));
+        let recorded = lines
+            .iter()
+            .map(|line| serde_json::from_str::<DerivationEvent>(line).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(recorded, events);
+    }
+
+    #[test]
+    fn test_recorder_flushes_on_interval() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.jsonl");
+
+        let origin = BlockNumHash { number: 10, ..Default::default() };
+        let recorder = DerivationEventRecorder::create(&path).unwrap();
+        recorder.on_event(DerivationEvent::ChannelOpened {
+            origin,
+            channel_id: B128::with_last_byte(1),
+        });
+
+        // The event is flushed while the recorder is still running.
+        std::thread::sleep(FLUSH_INTERVAL * 2);
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(contents.lines().count(), 1);
+    }
+}
@@ -0,0 +1,340 @@
+//! History of the system config updates applied by the derivation pipeline, and the server of
+//! the [`DerivationQueries`] answered from it.
+
+use alloy_eips::BlockNumHash;
+use alloy_provider::RootProvider;
+use kona_derive::{DerivationEvent, DerivationEventSink, L2ChainProvider};
+use kona_genesis::{RollupConfig, SystemConfig, SystemConfigUpdate};
+use kona_protocol::L2BlockInfo;
+use kona_providers_alloy::AlloyL2ChainProvider;
+use kona_rpc::{DerivationQueries, SystemConfigHistoryEntry};
+use op_alloy_network::Optimism;
+use std::sync::{Arc, Mutex, MutexGuard};
+use tokio::{select, sync::mpsc};
+use tokio_util::sync::CancellationToken;
+
+/// The maximum number of entries retained by a [`SystemConfigHistory`].
+///
+/// System config updates are rare, so this covers months of history on public chains. The oldest
+/// entries are dropped past it, and the blocks preceding the history are answered from the L2
+/// chain instead.
+const MAX_HISTORY_ENTRIES: usize = 1024;
+
+/// The history of the system config updates applied by the derivation pipeline, indexed by the L1
+/// block they were included in and the L2 block they take effect at.
+///
+/// The history is shared between the [`DerivationEventSink`] returned by
+/// [`SystemConfigHistory::sink`], which records the updates as the pipeline traverses L1, and the
+/// derivation actor, which resolves the L2 block of the updates as attributes are derived.
+///
+/// The history is kept in memory, bounded to the last [`MAX_HISTORY_ENTRIES`] entries, and is
+/// rebuilt from the L2 safe head the pipeline is reset to on restart.
+#[derive(Debug, Clone, Default)]
+pub struct SystemConfigHistory {
+    /// The entries of the history, in L1 order.
+    entries: Arc<Mutex<Vec<SystemConfigHistoryEntry>>>,
+}
+
+impl SystemConfigHistory {
+    /// Creates a new, empty [`SystemConfigHistory`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a [`DerivationEventSink`] recording the system config updates into the history,
+    /// and forwarding every event to the given sink, if any.
+    pub fn sink(
+        &self,
+        inner: Option<Arc<dyn DerivationEventSink>>,
+    ) -> Arc<dyn DerivationEventSink> {
+        Arc::new(SystemConfigHistorySink { history: self.clone(), inner })
+    }
+
+    /// Returns the entries of the history, in L1 order.
+    pub fn entries(&self) -> Vec<SystemConfigHistoryEntry> {
+        self.lock().clone()
+    }
+
+    /// Records the updates applied by the receipts of the given L1 origin.
+    ///
+    /// Entries at or after the L1 origin are replaced, as the pipeline is traversing them again.
+    /// The L2 block of a replaced entry at the same L1 origin is kept.
+    pub fn record(
+        &self,
+        l1_origin: BlockNumHash,
+        updates: Vec<SystemConfigUpdate>,
+        system_config: SystemConfig,
+    ) {
+        let mut entries = self.lock();
+        let l2_block = entries
+            .iter()
+            .find(|entry| entry.l1_origin == l1_origin)
+            .and_then(|entry| entry.l2_block);
+        entries.retain(|entry| entry.l1_origin.number < l1_origin.number);
+        entries.push(SystemConfigHistoryEntry { l1_origin, l2_block, updates, system_config });
+
+        let excess = entries.len().saturating_sub(MAX_HISTORY_ENTRIES);
+        entries.drain(..excess);
+    }
+
+    /// Resolves the L2 block of the updates that take effect at the given L2 block, derived with
+    /// the given epoch.
+    pub fn resolve(&self, epoch: u64, l2_block: u64) {
+        let mut entries = self.lock();
+        for entry in entries.iter_mut().rev() {
+            if entry.l2_block.is_some() {
+                break;
+            }
+            if entry.l1_origin.number <= epoch {
+                entry.l2_block = Some(l2_block);
+            }
+        }
+    }
+
+    /// Rewinds the history to the L2 safe head the pipeline was reset to.
+    ///
+    /// The updates after the L1 origin of the L2 safe head are dropped, as the pipeline traverses
+    /// them again. If the history is left empty, the system config at the L2 safe head is recorded
+    /// as the base of the history.
+    pub fn reset(&self, l2_safe_head: L2BlockInfo, system_config: Option<SystemConfig>) {
+        let mut entries = self.lock();
+        entries.retain(|entry| entry.l1_origin.number <= l2_safe_head.l1_origin.number);
+        for entry in entries.iter_mut() {
+            if entry.l2_block.is_some_and(|block| block > l2_safe_head.block_info.number) {
+                entry.l2_block = None;
+            }
+        }
+
+        if let Some(system_config) = system_config.filter(|_| entries.is_empty()) {
+            entries.push(SystemConfigHistoryEntry {
+                l1_origin: l2_safe_head.l1_origin,
+                l2_block: Some(l2_safe_head.block_info.number),
+                updates: Vec::new(),
+                system_config,
+            });
+        }
+    }
+
+    /// Returns the system config in effect at the given L2 block, or `None` if the block precedes
+    /// the history.
+    pub fn effective_at(&self, l2_block: u64) -> Option<SystemConfig> {
+        self.lock()
+            .iter()
+            .rev()
+            .find(|entry| entry.l2_block.is_some_and(|block| block <= l2_block))
+            .map(|entry| entry.system_config)
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<SystemConfigHistoryEntry>> {
+        self.entries.lock().unwrap_or_else(|err| err.into_inner())
+    }
+}
+
+/// Serves the [`DerivationQueries`] from a [`SystemConfigHistory`], off the derivation actor.
+///
+/// Blocks preceding the history are answered from the L1 info deposit of the L2 block, fetched
+/// from the L2 chain in a task of their own.
+#[derive(Debug, Clone)]
+pub struct SystemConfigQueryServer {
+    /// The history the queries are answered from.
+    history: SystemConfigHistory,
+    /// The L2 provider the blocks preceding the history are fetched from.
+    l2_provider: RootProvider<Optimism>,
+    /// The rollup configuration.
+    rollup_config: Arc<RollupConfig>,
+}
+
+impl SystemConfigQueryServer {
+    /// Creates a new [`SystemConfigQueryServer`] answering from the given history.
+    pub const fn new(
+        history: SystemConfigHistory,
+        l2_provider: RootProvider<Optimism>,
+        rollup_config: Arc<RollupConfig>,
+    ) -> Self {
+        Self { history, l2_provider, rollup_config }
+    }
+
+    /// Serves the queries received on the given channel, until it is closed or the cancellation
+    /// token is cancelled.
+    pub async fn run(
+        self,
+        mut queries: mpsc::Receiver<DerivationQueries>,
+        cancellation: CancellationToken,
+    ) {
+        loop {
+            let query = select! {
+                _ = cancellation.cancelled() => return,
+                query = queries.recv() => query,
+            };
+            let Some(query) = query else {
+                return;
+            };
+            self.handle(query);
+        }
+    }
+
+    /// Answers a [`DerivationQueries`].
+    fn handle(&self, query: DerivationQueries) {
+        match query {
+            DerivationQueries::SystemConfigHistory(sender) => {
+                if sender.send(self.history.entries()).is_err() {
+                    warn!(target: "derivation", "Failed to send system config history");
+                }
+            }
+            DerivationQueries::SystemConfigAt { block, sender } => {
+                if let Some(system_config) = self.history.effective_at(block) {
+                    if sender.send(Some(system_config)).is_err() {
+                        warn!(target: "derivation", block, "Failed to send system config");
+                    }
+                    return;
+                }
+
+                // Fall back to the L2 chain for blocks that precede the history.
+                let mut provider = AlloyL2ChainProvider::new(
+                    self.l2_provider.clone(),
+                    Arc::clone(&self.rollup_config),
+                    1,
+                );
+                let rollup_config = Arc::clone(&self.rollup_config);
+                tokio::spawn(async move {
+                    let system_config =
+                        provider.system_config_by_number(block, rollup_config).await.ok();
+                    if sender.send(system_config).is_err() {
+                        warn!(target: "derivation", block, "Failed to send system config");
+                    }
+                });
+            }
+        }
+    }
+}
+
+/// A [`DerivationEventSink`] recording the system config updates into a [`SystemConfigHistory`].
+#[derive(Debug)]
+struct SystemConfigHistorySink {
+    /// The history the updates are recorded into.
+    history: SystemConfigHistory,
+    /// The sink the events are forwarded to.
+    inner: Option<Arc<dyn DerivationEventSink>>,
+}
+
+impl DerivationEventSink for SystemConfigHistorySink {
+    fn on_event(&self, event: DerivationEvent) {
+        if let DerivationEvent::SystemConfigUpdated { origin, updates, system_config } = &event {
+            self.history.record(*origin, updates.clone(), *system_config);
+        }
+        if let Some(inner) = &self.inner {
+            inner.on_event(event);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Address;
+    use kona_genesis::BatcherUpdate;
+    use kona_protocol::BlockInfo;
+
+    fn batcher_update(origin: u64) -> DerivationEvent {
+        let batcher_address = Address::with_last_byte(origin as u8);
+        DerivationEvent::SystemConfigUpdated {
+            origin: BlockNumHash { number: origin, ..Default::default() },
+            updates: vec![SystemConfigUpdate::Batcher(BatcherUpdate { batcher_address })],
+            system_config: SystemConfig { batcher_address, ..Default::default() },
+        }
+    }
+
+    fn safe_head(number: u64, epoch: u64) -> L2BlockInfo {
+        L2BlockInfo {
+            block_info: BlockInfo { number, ..Default::default() },
+            l1_origin: BlockNumHash { number: epoch, ..Default::default() },
+            seq_num: 0,
+        }
+    }
+
+    fn batcher_at(history: &SystemConfigHistory, l2_block: u64) -> Option<Address> {
+        history.effective_at(l2_block).map(|config| config.batcher_address)
+    }
+
+    #[test]
+    fn test_history_resolves_effective_blocks() {
+        let history = SystemConfigHistory::new();
+        history.reset(safe_head(10, 4), Some(SystemConfig::default()));
+
+        let sink = history.sink(None);
+        sink.on_event(batcher_update(5));
+        sink.on_event(DerivationEvent::ChannelOpened {
+            origin: BlockNumHash::default(),
+            channel_id: Default::default(),
+        });
+        sink.on_event(batcher_update(7));
+
+        // L2 blocks 11 and 12 are derived with epoch 4, 13 with epoch 6 and 14 with epoch 7.
+        for (l2_block, epoch) in [(11, 4), (12, 4), (13, 6), (14, 7)] {
+            history.resolve(epoch, l2_block);
+        }
+
+        let entries = history.entries();
+        assert_eq!(entries.len(), 3);
+        assert_eq!(
+            entries.iter().map(|e| e.l2_block).collect::<Vec<_>>(),
+            [Some(10), Some(13), Some(14)]
+        );
+
+        assert_eq!(batcher_at(&history, 9), None);
+        assert_eq!(batcher_at(&history, 12), Some(Address::ZERO));
+        assert_eq!(batcher_at(&history, 13), Some(Address::with_last_byte(5)));
+        assert_eq!(batcher_at(&history, 100), Some(Address::with_last_byte(7)));
+    }
+
+    #[test]
+    fn test_history_reset_rewinds() {
+        let history = SystemConfigHistory::new();
+        history.reset(safe_head(10, 4), Some(SystemConfig::default()));
+        history.record(
+            BlockNumHash { number: 5, ..Default::default() },
+            vec![],
+            SystemConfig { gas_limit: 5, ..Default::default() },
+        );
+        history.resolve(5, 12);
+        history.record(
+            BlockNumHash { number: 7, ..Default::default() },
+            vec![],
+            SystemConfig { gas_limit: 7, ..Default::default() },
+        );
+        history.resolve(7, 14);
+
+        // Reset to L2 block 11, derived with epoch 5: the update at L1 block 7 is dropped, and the
+        // update at L1 block 5 must be resolved again.
+        history.reset(safe_head(11, 5), Some(SystemConfig::default()));
+        let entries = history.entries();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[1].l2_block, None);
+        assert_eq!(history.effective_at(14).map(|c| c.gas_limit), Some(0));
+
+        history.resolve(5, 12);
+        assert_eq!(history.effective_at(14).map(|c| c.gas_limit), Some(5));
+    }
+
+    #[test]
+    fn test_history_is_bounded() {
+        let history = SystemConfigHistory::new();
+        history.reset(safe_head(10, 4), Some(SystemConfig::default()));
+        for origin in 5..5 + MAX_HISTORY_ENTRIES as u64 {
+            history.record(
+                BlockNumHash { number: origin, ..Default::default() },
+                vec![],
+                SystemConfig { gas_limit: origin, ..Default::default() },
+            );
+            history.resolve(origin, origin + 10);
+        }
+
+        // The entry of the L2 safe head is dropped, and the blocks preceding the oldest retained
+        // entry fall back to the L2 chain.
+        let entries = history.entries();
+        assert_eq!(entries.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(entries[0].l1_origin.number, 5);
+        assert_eq!(history.effective_at(14), None);
+        assert_eq!(history.effective_at(15).map(|c| c.gas_limit), Some(5));
+    }
+}
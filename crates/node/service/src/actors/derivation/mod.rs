@@ -0,0 +1,19 @@
+//! The `DerivationActor` and its components.
+
+mod checkpoint;
+pub use checkpoint::{
+    DerivationCheckpointConfig, DerivationCheckpointError, DerivationCheckpointStore,
+    DerivationCheckpointer,
+};
+
+mod events;
+pub use events::DerivationEventRecorder;
+
+mod history;
+pub use history::{SystemConfigHistory, SystemConfigQueryServer};
+
+mod actor;
+pub use actor::{
+    DerivationActor, DerivationBuilder, DerivationContext, DerivationError,
+    DerivationInboundChannels, DerivationState, InboundDerivationMessage, PipelineBuilder,
+};
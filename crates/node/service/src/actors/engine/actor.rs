@@ -1,28 +1,42 @@
 //! The [`EngineActor`].
 
-use super::{EngineError, L2Finalizer};
+use super::{EngineError, FinalityIndex, L2Finalizer};
 use alloy_rpc_types_engine::JwtSecret;
 use async_trait::async_trait;
+use backon::{ExponentialBuilder, Retryable};
 use futures::future::OptionFuture;
 use kona_derive::{ResetSignal, Signal};
 use kona_engine::{
-    BuildTask, ConsolidateTask, Engine, EngineClient, EngineQueries,
-    EngineState as InnerEngineState, EngineTask, EngineTaskError, EngineTaskErrorSeverity,
-    InsertTask,
+    BuildTask, ConsolidateTask, Engine, EngineCapabilitiesError, EngineCapabilityReport,
+    EngineClient, EngineQueries, EngineState as InnerEngineState, EngineTask, EngineTaskError,
+    EngineTaskErrorSeverity, InsertTask, SecondaryEngine, SecondaryEngineConfig,
 };
 use kona_genesis::RollupConfig;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tokio::{
     sync::{mpsc, oneshot, watch},
     task::JoinHandle,
+    time::{Instant, interval_at},
 };
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 use url::Url;
 
 use crate::{NodeActor, NodeMode, actors::CancellableContext};
 
+/// The interval at which the engine api capabilities of the execution layer are checked against
+/// the active and scheduled hardforks.
+const CAPABILITIES_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// The number of times the engine api capabilities exchange is retried at startup while the
+/// execution layer is unreachable, with an exponential backoff starting at one second.
+const CAPABILITIES_STARTUP_RETRIES: usize = 6;
+
 /// The [`EngineActor`] is responsible for managing the operations sent to the execution layer's
 /// Engine API. To accomplish this, it uses the [`Engine`] task queue to order Engine API
 /// interactions based off of the [`Ord`] implementation of [`EngineTask`].
@@ -36,6 +50,8 @@ pub struct EngineActor {
     unsafe_block_rx: mpsc::Receiver<OpExecutionPayloadEnvelope>,
     /// A channel to receive reset requests.
     reset_request_rx: mpsc::Receiver<()>,
+    /// A channel to send reset requests, used by the engine query handler.
+    reset_request_tx: mpsc::Sender<()>,
     /// Handler for inbound queries to the engine.
     inbound_queries: mpsc::Receiver<EngineQueries>,
     /// A channel to receive build requests from the sequencer actor.
@@ -94,6 +110,12 @@ pub struct EngineBuilder {
     /// When the node is in sequencer mode, the engine actor will receive requests to build blocks
     /// from the sequencer actor.
     pub mode: NodeMode,
+    /// The optional secondary execution layer, to which the `engine_newPayload` and
+    /// `engine_forkchoiceUpdated` calls made to the engine are mirrored.
+    pub secondary: Option<SecondaryEngineConfig>,
+    /// The optional path of the [`FinalityIndex`] file, persisting the derived L2 blocks awaiting
+    /// finalization across restarts.
+    pub finality_index: Option<PathBuf>,
 }
 
 impl EngineBuilder {
@@ -113,15 +135,42 @@ impl EngineBuilder {
     }
 
     /// Returns the [`EngineClient`].
+    ///
+    /// If a secondary execution layer is configured, the task mirroring calls to it is spawned,
+    /// so this must be called from within a tokio runtime.
     pub fn client(&self) -> Arc<EngineClient> {
-        EngineClient::new_http(
+        let client = EngineClient::new_http(
             self.engine_url.clone(),
             self.l2_rpc_url.clone(),
             self.l1_rpc_url.clone(),
             self.config.clone(),
             self.jwt_secret,
-        )
-        .into()
+        );
+
+        match self.secondary.clone() {
+            Some(secondary) => client.with_secondary(SecondaryEngine::spawn(secondary)).into(),
+            None => client.into(),
+        }
+    }
+
+    /// Returns the [`FinalityIndex`], loaded from its file if one is configured.
+    ///
+    /// The index starts empty if its file cannot be read.
+    pub fn finality_index(&self) -> FinalityIndex {
+        let Some(path) = self.finality_index.clone() else {
+            return FinalityIndex::default();
+        };
+
+        match FinalityIndex::load(path) {
+            Ok(index) => {
+                info!(target: "engine", awaiting_finalization = index.len(), "Loaded finality index");
+                index
+            }
+            Err(err) => {
+                warn!(target: "engine", ?err, "Failed to load the finality index, starting empty");
+                FinalityIndex::default()
+            }
+        }
     }
 }
 
@@ -177,14 +226,16 @@ impl EngineActor {
             (None, None)
         };
 
+        let finalizer = L2Finalizer::new(finalized_l1_block_rx, config.finality_index());
         let actor = Self {
             builder: config,
             attributes_rx,
             unsafe_block_rx,
             reset_request_rx,
+            reset_request_tx: reset_request_tx.clone(),
             inbound_queries: inbound_queries_rx,
             build_request_rx,
-            finalizer: L2Finalizer::new(finalized_l1_block_rx),
+            finalizer,
         };
 
         let outbound_data = EngineInboundData {
@@ -205,9 +256,11 @@ impl EngineActorState {
     fn start_query_task(
         &self,
         mut inbound_query_channel: tokio::sync::mpsc::Receiver<EngineQueries>,
+        reset_request_tx: mpsc::Sender<()>,
     ) -> JoinHandle<()> {
         let state_recv = self.engine.state_subscribe();
         let queue_length_recv = self.engine.queue_length_subscribe();
+        let task_queue = self.engine.task_queue_handle();
         let engine_client = self.client.clone();
         let rollup_config = self.rollup.clone();
 
@@ -217,7 +270,14 @@ impl EngineActorState {
                     trace!(target: "engine", ?req, "Received engine query request.");
 
                     if let Err(e) = req
-                        .handle(&state_recv, &queue_length_recv, &engine_client, &rollup_config)
+                        .handle(
+                            &state_recv,
+                            &queue_length_recv,
+                            &task_queue,
+                            &reset_request_tx,
+                            &engine_client,
+                            &rollup_config,
+                        )
                         .await
                     {
                         warn!(target: "engine", err = ?e, "Failed to handle engine query request.");
@@ -227,6 +287,69 @@ impl EngineActorState {
         })
     }
 
+    /// Checks that the execution layer supports the engine api methods required by the active and
+    /// scheduled hardforks, before the engine starts processing tasks.
+    ///
+    /// The exchange is retried while the execution layer is unreachable, which is common while
+    /// both clients start up. Fails if the capabilities still cannot be exchanged, or if a method
+    /// required by an active hardfork is not supported.
+    pub(super) async fn check_capabilities_at_startup(&self) -> Result<(), EngineError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let result = (|| self.client.check_capabilities(now))
+            .retry(ExponentialBuilder::default().with_max_times(CAPABILITIES_STARTUP_RETRIES))
+            .when(|err| {
+                matches!(err, EngineCapabilitiesError::Rpc(err) if err.is_transport_error())
+            })
+            .notify(|err, dur| {
+                warn!(target: "engine", %err, ?dur, "Failed to exchange engine api capabilities, retrying");
+            })
+            .await;
+        Self::handle_capability_report(result, now)
+    }
+
+    /// Periodically checks that the execution layer supports the engine api methods required by
+    /// the active and scheduled hardforks.
+    ///
+    /// The check is skipped if the capabilities cannot be exchanged with the execution layer, as
+    /// it was already checked at startup.
+    pub(super) async fn check_capabilities(&self) -> Result<(), EngineError> {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        match self.client.check_capabilities(now).await {
+            Err(EngineCapabilitiesError::Rpc(err)) => {
+                warn!(target: "engine", ?err, "Failed to exchange engine api capabilities, skipping the check");
+                Ok(())
+            }
+            result => Self::handle_capability_report(result, now),
+        }
+    }
+
+    /// Fails if a method required by an active hardfork is not supported, and warns about the
+    /// methods missing for scheduled hardforks.
+    fn handle_capability_report(
+        result: Result<EngineCapabilityReport, EngineCapabilitiesError>,
+        now: u64,
+    ) -> Result<(), EngineError> {
+        match result {
+            Ok(report) => {
+                for missing in report.missing_upcoming {
+                    warn!(
+                        target: "engine",
+                        fork = missing.fork,
+                        activation = missing.activation,
+                        hours_until_activation = missing.activation.saturating_sub(now) / 3600,
+                        methods = ?missing.methods,
+                        "The execution layer does not support the engine api methods required by an upcoming hardfork. Upgrade the execution client before the hardfork activates"
+                    );
+                }
+                Ok(())
+            }
+            Err(err) => {
+                error!(target: "engine", %err, "Execution layer capability check failed");
+                Err(err.into())
+            }
+        }
+    }
+
     /// Resets the inner [`Engine`] and propagates the reset to the derivation actor.
     pub(super) async fn reset(
         &mut self,
@@ -251,8 +374,8 @@ impl EngineActorState {
         // Attempt to update the safe head following the reset.
         self.maybe_update_safe_head(engine_l2_safe_head_tx);
 
-        // Clear the queue of L2 blocks awaiting finalization.
-        finalizer.clear();
+        // Drop the L2 blocks awaiting finalization that are derived again after the reset.
+        finalizer.reset(&l2_safe_head);
 
         Ok(())
     }
@@ -329,14 +452,18 @@ impl EngineActorState {
 
             // Only reset the engine if the sync state does not already know about a finalized
             // block.
-            if self.engine.state().sync_state.finalized_head() != L2BlockInfo::default() {
-                return Ok(());
+            if self.engine.state().sync_state.finalized_head() == L2BlockInfo::default() {
+                // If the sync status is finished, we can reset the engine and start derivation.
+                info!(target: "engine", "Performing initial engine reset");
+                self.reset(derivation_signal_tx, engine_l2_safe_head_tx, finalizer).await?;
+                sync_complete_tx.send(()).ok();
+            } else {
+                finalizer.reset(&self.engine.state().sync_state.safe_head());
             }
 
-            // If the sync status is finished, we can reset the engine and start derivation.
-            info!(target: "engine", "Performing initial engine reset");
-            self.reset(derivation_signal_tx, engine_l2_safe_head_tx, finalizer).await?;
-            sync_complete_tx.send(()).ok();
+            // Finalize the L2 blocks restored from the finality index right away if the finalized
+            // L1 chain already contains their L1 origins.
+            finalizer.try_finalize_next(self).await;
         }
 
         Ok(())
@@ -380,8 +507,14 @@ impl NodeActor for EngineActor {
     ) -> Result<(), Self::Error> {
         let mut state = self.builder.build_state();
 
+        // Fail fast if the execution layer does not support the engine api methods required by the
+        // active hardforks, and keep checking periodically to catch scheduled hardforks.
+        state.check_capabilities_at_startup().await?;
+        let mut capabilities_check =
+            interval_at(Instant::now() + CAPABILITIES_CHECK_INTERVAL, CAPABILITIES_CHECK_INTERVAL);
+
         // Start the engine query server in a separate task to avoid blocking the main task.
-        let handle = state.start_query_task(self.inbound_queries);
+        let handle = state.start_query_task(self.inbound_queries, self.reset_request_tx);
 
         // The sync complete tx is consumed after the first successful send. Hence we need to wrap
         // it in an `Option` to ensure we satisfy the borrow checker.
@@ -484,6 +617,9 @@ impl NodeActor for EngineActor {
                     // chain.
                     self.finalizer.try_finalize_next(&mut state).await;
                 }
+                _ = capabilities_check.tick() => {
+                    state.check_capabilities().await?;
+                }
             }
         }
     }
@@ -2,7 +2,7 @@
 //!
 //! [`EngineActor`]: super::EngineActor
 
-use kona_engine::{EngineResetError, EngineTaskErrors};
+use kona_engine::{EngineCapabilitiesError, EngineResetError, EngineTaskErrors};
 
 /// An error from the [`EngineActor`].
 ///
@@ -18,4 +18,7 @@ pub enum EngineError {
     /// Engine task error.
     #[error(transparent)]
     EngineTask(#[from] EngineTaskErrors),
+    /// The execution layer does not support the required engine api methods.
+    #[error(transparent)]
+    Capabilities(#[from] EngineCapabilitiesError),
 }
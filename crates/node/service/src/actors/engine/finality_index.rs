@@ -0,0 +1,158 @@
+//! The [`FinalityIndex`].
+
+use crate::fs::write_atomic;
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// An internal type alias for L1 block numbers.
+type L1BlockNumber = u64;
+
+/// An internal type alias for L2 block numbers.
+type L2BlockNumber = u64;
+
+/// An error produced when reading or writing the [`FinalityIndex`].
+#[derive(Debug, thiserror::Error)]
+pub enum FinalityIndexError {
+    /// The index file could not be read or written.
+    #[error("Failed to access the finality index file {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    /// The index could not be encoded, or the index file is malformed.
+    #[error("Invalid finality index file {0}: {1}")]
+    Json(PathBuf, serde_json::Error),
+}
+
+/// An index of `L1 origin number -> highest derived L2 block number`, tracking the derived L2
+/// blocks awaiting finalization.
+///
+/// When backed by a file, the index survives restarts, so that L2 blocks that became safe before
+/// a restart are still finalized once their L1 origin is finalized.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FinalityIndex {
+    /// The derived L2 blocks awaiting finalization, keyed by L1 origin number.
+    entries: BTreeMap<L1BlockNumber, L2BlockNumber>,
+    /// The optional path of the index file.
+    path: Option<PathBuf>,
+}
+
+impl FinalityIndex {
+    /// Loads the [`FinalityIndex`] backed by the file at the given path. The index is empty if the
+    /// file does not exist.
+    pub fn load(path: PathBuf) -> Result<Self, FinalityIndexError> {
+        let entries = match std::fs::read(&path) {
+            Ok(contents) => serde_json::from_slice(&contents)
+                .map_err(|err| FinalityIndexError::Json(path.clone(), err))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(err) => return Err(FinalityIndexError::Io(path, err)),
+        };
+
+        Ok(Self { entries, path: Some(path) })
+    }
+
+    /// Returns the number of L1 origins awaiting finalization.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns whether no L2 block is awaiting finalization.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records that the given L2 block was derived from the given L1 origin. Returns whether the
+    /// index changed.
+    pub fn insert(&mut self, l1_origin: L1BlockNumber, l2_block: L2BlockNumber) -> bool {
+        match self.entries.get(&l1_origin) {
+            Some(&highest) if highest >= l2_block => false,
+            _ => {
+                self.entries.insert(l1_origin, l2_block);
+                true
+            }
+        }
+    }
+
+    /// Returns the highest L2 block derived from an L1 origin contained within the finalized L1
+    /// chain.
+    pub fn finalizable(&self, finalized_l1: L1BlockNumber) -> Option<L2BlockNumber> {
+        self.entries.range(..=finalized_l1).next_back().map(|(_, l2_block)| *l2_block)
+    }
+
+    /// Removes the L2 blocks derived from L1 origins contained within the finalized L1 chain,
+    /// returning the highest of them.
+    pub fn finalize(&mut self, finalized_l1: L1BlockNumber) -> Option<L2BlockNumber> {
+        let finalizable = self.finalizable(finalized_l1)?;
+        self.entries.retain(|&l1_origin, _| l1_origin > finalized_l1);
+        Some(finalizable)
+    }
+
+    /// Removes the L2 blocks above the given safe head, which are derived again after a reset.
+    /// Returns whether the index changed.
+    pub fn prune(&mut self, l2_safe_head: L2BlockNumber) -> bool {
+        let len = self.entries.len();
+        self.entries.retain(|_, &mut l2_block| l2_block <= l2_safe_head);
+        len != self.entries.len()
+    }
+
+    /// Writes the index to its file, if any, atomically replacing the previous one.
+    pub fn store(&self) -> Result<(), FinalityIndexError> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let contents = serde_json::to_vec(&self.entries)
+            .map_err(|err| FinalityIndexError::Json(path.clone(), err))?;
+        write_atomic(path, &contents).map_err(|err| FinalityIndexError::Io(path.clone(), err))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finality_index_finalize() {
+        let mut index = FinalityIndex::default();
+        assert!(index.insert(10, 100));
+        assert!(index.insert(10, 102));
+        assert!(!index.insert(10, 101));
+        assert!(index.insert(11, 104));
+        assert!(index.insert(13, 110));
+
+        assert_eq!(index.finalizable(9), None);
+        assert_eq!(index.finalizable(12), Some(104));
+        assert_eq!(index.finalize(12), Some(104));
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.finalize(12), None);
+        assert_eq!(index.finalize(13), Some(110));
+        assert!(index.is_empty());
+    }
+
+    #[test]
+    fn test_finality_index_prune() {
+        let mut index = FinalityIndex::default();
+        index.insert(10, 100);
+        index.insert(11, 104);
+        index.insert(12, 108);
+
+        assert!(index.prune(105));
+        assert!(!index.prune(105));
+        assert_eq!(index.finalize(12), Some(104));
+    }
+
+    #[test]
+    fn test_finality_index_persistence() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("finality.json");
+
+        let mut index = FinalityIndex::load(path.clone()).unwrap();
+        assert!(index.is_empty());
+        index.insert(10, 100);
+        index.insert(11, 104);
+        index.store().unwrap();
+
+        let mut loaded = FinalityIndex::load(path.clone()).unwrap();
+        assert_eq!(loaded, index);
+        assert_eq!(loaded.finalize(11), Some(104));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(FinalityIndex::load(path), Err(FinalityIndexError::Json(..))));
+    }
+}
@@ -1,56 +1,71 @@
 //! The [`L2Finalizer`].
 
+use futures::future::OptionFuture;
 use kona_engine::{EngineTask, FinalizeTask};
-use kona_protocol::{BlockInfo, OpAttributesWithParent};
-use std::collections::BTreeMap;
-use tokio::sync::watch;
+use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
+use tokio::{select, sync::watch, task::JoinHandle};
 
-use crate::actors::engine::actor::EngineActorState;
-
-/// An internal type alias for L1 block numbers.
-type L1BlockNumber = u64;
-
-/// An internal type alias for L2 block numbers.
-type L2BlockNumber = u64;
+use crate::actors::engine::{FinalityIndex, actor::EngineActorState};
 
 /// The [`L2Finalizer`] is responsible for finalizing L2 blocks derived from finalized L1 blocks.
-/// It maintains a queue of derived L2 blocks that are awaiting finalization, and finalizes them
+/// It maintains an index of derived L2 blocks that are awaiting finalization, and finalizes them
 /// as new finalized L1 blocks are received.
 #[derive(Debug)]
 pub struct L2Finalizer {
     /// A channel that receives new finalized L1 blocks intermittently.
     finalized_l1_block_rx: watch::Receiver<Option<BlockInfo>>,
-    /// A map of `L1 block number -> highest derived L2 block number` within the L1 epoch, used to
-    /// track derived [`OpAttributesWithParent`] awaiting finalization. When a new finalized L1
-    /// block is received, the highest L2 block whose inputs are contained within the finalized
-    /// L1 chain is finalized.
-    awaiting_finalization: BTreeMap<L1BlockNumber, L2BlockNumber>,
+    /// The [`FinalityIndex`] of derived [`OpAttributesWithParent`] awaiting finalization. When a
+    /// new finalized L1 block is received, the highest L2 block whose inputs are contained within
+    /// the finalized L1 chain is finalized.
+    awaiting_finalization: FinalityIndex,
+    /// The write of the [`FinalityIndex`] in progress, if any.
+    pending_store: Option<JoinHandle<()>>,
+    /// Whether the [`FinalityIndex`] changed since the last write was started.
+    dirty: bool,
 }
 
 impl L2Finalizer {
-    /// Creates a new [`L2Finalizer`] with the given channel receiver for finalized L1 blocks.
-    pub const fn new(finalized_l1_block_rx: watch::Receiver<Option<BlockInfo>>) -> Self {
-        Self { finalized_l1_block_rx, awaiting_finalization: BTreeMap::new() }
+    /// Creates a new [`L2Finalizer`] with the given channel receiver for finalized L1 blocks and
+    /// the given [`FinalityIndex`].
+    pub const fn new(
+        finalized_l1_block_rx: watch::Receiver<Option<BlockInfo>>,
+        awaiting_finalization: FinalityIndex,
+    ) -> Self {
+        Self { finalized_l1_block_rx, awaiting_finalization, pending_store: None, dirty: false }
     }
 
     /// Enqueues a derived [`OpAttributesWithParent`] for finalization. When a new finalized L1
     /// block is observed that is `>=` the height of [`OpAttributesWithParent::l1_origin`], the L2
     /// block associated with the payload attributes will be finalized.
     pub fn enqueue_for_finalization(&mut self, attributes: &OpAttributesWithParent) {
-        self.awaiting_finalization
-            .entry(attributes.l1_origin.number)
-            .and_modify(|n| *n = (*n).max(attributes.block_number()))
-            .or_insert(attributes.block_number());
+        self.awaiting_finalization.insert(attributes.l1_origin.number, attributes.block_number());
+        self.persist();
     }
 
-    /// Clears the finalization queue.
-    pub fn clear(&mut self) {
-        self.awaiting_finalization.clear();
+    /// Removes the L2 blocks above the given safe head from the finalization queue, as they are
+    /// derived again after a reset.
+    pub fn reset(&mut self, l2_safe_head: &L2BlockInfo) {
+        if self.awaiting_finalization.prune(l2_safe_head.block_info.number) {
+            self.persist();
+        }
     }
 
     /// Receives a new finalized L1 block from the channel.
+    ///
+    /// Meanwhile, the changes made to the [`FinalityIndex`] while a write was in progress are
+    /// written once it completes. This is cancel safe.
     pub async fn new_finalized_block(&mut self) -> Result<(), watch::error::RecvError> {
-        self.finalized_l1_block_rx.changed().await
+        loop {
+            select! {
+                biased;
+
+                res = self.finalized_l1_block_rx.changed() => return res,
+                Some(_) = OptionFuture::from(self.pending_store.as_mut()), if self.dirty => {
+                    self.pending_store = None;
+                    self.spawn_store();
+                }
+            }
+        }
     }
 
     /// Attempts to finalize any L2 blocks that the finalizer knows about and are contained within
@@ -61,21 +76,124 @@ impl L2Finalizer {
             return;
         };
 
-        // Find the highest safe L2 block that is contained within the finalized chain,
-        // that the finalizer is aware of.
-        let highest_safe = self.awaiting_finalization.range(..=new_finalized_l1.number).next_back();
-
-        // If the highest safe block is found, enqueue a finalization task and drain the
-        // queue of all L1 blocks not contained in the finalized L1 chain.
-        if let Some((_, highest_safe_number)) = highest_safe {
-            let task = EngineTask::Finalize(FinalizeTask::new(
-                engine_state.client.clone(),
-                engine_state.rollup.clone(),
-                *highest_safe_number,
-            ));
-            engine_state.engine.enqueue(task);
-
-            self.awaiting_finalization.retain(|&number, _| number > new_finalized_l1.number);
+        let Some(highest_safe_number) = self.advance(new_finalized_l1.number) else {
+            return;
+        };
+
+        // Blocks restored from the index may already be finalized, never move finality backwards.
+        let finalized_number =
+            engine_state.engine.state().sync_state.finalized_head().block_info.number;
+        if highest_safe_number <= finalized_number {
+            return;
         }
+
+        let task = EngineTask::Finalize(FinalizeTask::new(
+            engine_state.client.clone(),
+            engine_state.rollup.clone(),
+            highest_safe_number,
+        ));
+        engine_state.engine.enqueue(task);
+    }
+
+    /// Moves the finalized boundary to the given finalized L1 block, returning the highest safe
+    /// L2 block contained within the finalized chain, if any.
+    ///
+    /// The queue is drained of all L1 blocks contained in the finalized L1 chain, and the
+    /// [`FinalityIndex`] is persisted when the boundary moves.
+    fn advance(&mut self, finalized_l1: u64) -> Option<u64> {
+        let highest_safe_number = self.awaiting_finalization.finalize(finalized_l1)?;
+        self.persist();
+        Some(highest_safe_number)
+    }
+
+    /// Marks the [`FinalityIndex`] as changed, and writes it unless a write is in progress.
+    ///
+    /// The changes made while a write is in progress are coalesced into a single write once it
+    /// completes, see [`L2Finalizer::new_finalized_block`], so that writes land in order and the
+    /// engine actor never waits on the disk.
+    fn persist(&mut self) {
+        self.dirty = true;
+        if self.pending_store.as_ref().is_none_or(JoinHandle::is_finished) {
+            self.spawn_store();
+        }
+    }
+
+    /// Writes a snapshot of the [`FinalityIndex`] to its file, if any, on the blocking thread
+    /// pool.
+    fn spawn_store(&mut self) {
+        self.dirty = false;
+        let index = self.awaiting_finalization.clone();
+        self.pending_store = Some(tokio::task::spawn_blocking(move || {
+            if let Err(err) = index.store() {
+                warn!(target: "engine", ?err, "Failed to store the finality index");
+            }
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attributes(l1_origin: u64, l2_block: u64) -> OpAttributesWithParent {
+        let mut parent = L2BlockInfo::default();
+        parent.block_info.number = l2_block - 1;
+        let l1_origin = BlockInfo { number: l1_origin, ..Default::default() };
+        OpAttributesWithParent::new(Default::default(), parent, l1_origin, false)
+    }
+
+    async fn restart(mut finalizer: L2Finalizer, path: &std::path::Path) -> L2Finalizer {
+        while let Some(pending_store) = finalizer.pending_store.take() {
+            pending_store.await.unwrap();
+            if finalizer.dirty {
+                finalizer.spawn_store();
+            }
+        }
+        let (_, finalized_l1_block_rx) = watch::channel(None);
+        L2Finalizer::new(finalized_l1_block_rx, FinalityIndex::load(path.to_path_buf()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_finalizer_restart() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("finality.json");
+        let (_, finalized_l1_block_rx) = watch::channel(None);
+        let mut finalizer =
+            L2Finalizer::new(finalized_l1_block_rx, FinalityIndex::load(path.clone()).unwrap());
+
+        finalizer.enqueue_for_finalization(&attributes(10, 100));
+        finalizer.enqueue_for_finalization(&attributes(11, 104));
+        finalizer.enqueue_for_finalization(&attributes(12, 108));
+        assert_eq!(finalizer.advance(9), None);
+        assert_eq!(finalizer.advance(10), Some(100));
+
+        // The blocks awaiting finalization are restored after a restart.
+        let mut finalizer = restart(finalizer, &path).await;
+        assert_eq!(finalizer.awaiting_finalization.len(), 2);
+
+        // Blocks derived again after a reset are dropped from the persisted index.
+        finalizer.reset(&attributes(11, 104).parent);
+        let mut finalizer = restart(finalizer, &path).await;
+        assert_eq!(finalizer.awaiting_finalization.len(), 0);
+        assert_eq!(finalizer.advance(12), None);
+    }
+
+    #[tokio::test]
+    async fn test_finalizer_restart_before_finalization() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("finality.json");
+        let (_, finalized_l1_block_rx) = watch::channel(None);
+        let mut finalizer =
+            L2Finalizer::new(finalized_l1_block_rx, FinalityIndex::load(path.clone()).unwrap());
+
+        // Safe blocks are persisted as they are enqueued, without the finalized boundary moving.
+        finalizer.enqueue_for_finalization(&attributes(10, 100));
+        assert!(finalizer.pending_store.is_some());
+        finalizer.enqueue_for_finalization(&attributes(11, 104));
+        finalizer.enqueue_for_finalization(&attributes(12, 108));
+
+        let mut finalizer = restart(finalizer, &path).await;
+        assert_eq!(finalizer.awaiting_finalization.len(), 3);
+        assert_eq!(finalizer.advance(12), Some(108));
     }
 }
@@ -6,5 +6,8 @@ pub use actor::{EngineActor, EngineBuilder, EngineContext, EngineInboundData};
 mod error;
 pub use error::EngineError;
 
+mod finality_index;
+pub use finality_index::{FinalityIndex, FinalityIndexError};
+
 mod finalizer;
 pub use finalizer::L2Finalizer;
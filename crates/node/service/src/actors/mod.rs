@@ -7,7 +7,8 @@ pub use traits::{CancellableContext, NodeActor};
 
 mod engine;
 pub use engine::{
-    EngineActor, EngineBuilder, EngineContext, EngineError, EngineInboundData, L2Finalizer,
+    EngineActor, EngineBuilder, EngineContext, EngineError, EngineInboundData, FinalityIndex,
+    FinalityIndexError, L2Finalizer,
 };
 
 mod supervisor;
@@ -21,8 +22,10 @@ pub use rpc::{RpcActor, RpcActorError, RpcContext};
 
 mod derivation;
 pub use derivation::{
-    DerivationActor, DerivationBuilder, DerivationContext, DerivationError,
-    DerivationInboundChannels, DerivationState, InboundDerivationMessage, PipelineBuilder,
+    DerivationActor, DerivationBuilder, DerivationCheckpointConfig, DerivationCheckpointError,
+    DerivationCheckpointStore, DerivationCheckpointer, DerivationContext, DerivationError,
+    DerivationEventRecorder, DerivationInboundChannels, DerivationState, InboundDerivationMessage,
+    PipelineBuilder, SystemConfigHistory, SystemConfigQueryServer,
 };
 
 mod l1_watcher_rpc;
@@ -33,13 +36,19 @@ pub use l1_watcher_rpc::{
 
 mod network;
 pub use network::{
-    NetworkActor, NetworkActorError, NetworkBuilder, NetworkBuilderError, NetworkConfig,
-    NetworkContext, NetworkDriver, NetworkDriverError, NetworkHandler, NetworkInboundData,
+    BlockPayloadArgs, BlockSigner, BlockSignerError, ClientCert, DEFAULT_REMOTE_SIGNER_TIMEOUT,
+    LocalBlockSigner, NetworkActor, NetworkActorError, NetworkBuilder, NetworkBuilderError,
+    NetworkConfig, NetworkContext, NetworkDriver, NetworkDriverError, NetworkHandler,
+    NetworkInboundData, PublishRetryTicker, RemoteBlockSigner, RemoteSignerConfig,
 };
 
 mod sequencer;
 pub use sequencer::{
-    AttributesBuilderConfig, ConductorClient, ConductorError, L1OriginSelector,
-    L1OriginSelectorError, L1OriginSelectorProvider, SequencerActor, SequencerActorError,
+    AttributesBuilderConfig, ConductorClient, ConductorError, ForcedTransaction,
+    ForcedTransactionsHook, GasLimitHook, L1OriginSelector, L1OriginSelectorError,
+    L1OriginSelectorProvider, PayloadAttributesContext, PayloadAttributesHook,
+    PayloadAttributesHookConfig, PayloadAttributesHookError, PayloadAttributesHookKind,
+    PersistedSequencerState, RecoveryModeHook, SequencerActor, SequencerActorError,
     SequencerBuilder, SequencerConfig, SequencerContext, SequencerInboundData,
+    SequencerPersistenceError, SequencerStatePersistence, SequencerStateWriter,
 };
@@ -1,17 +1,16 @@
 use alloy_primitives::Address;
-use alloy_signer::SignerSync;
-use alloy_signer_local::PrivateKeySigner;
 use async_trait::async_trait;
 use kona_p2p::P2pRpcRequest;
 use kona_rpc::NetworkAdminQuery;
 use libp2p::TransportError;
 use op_alloy_rpc_types_engine::{OpExecutionPayloadEnvelope, OpNetworkPayloadEnvelope};
+use std::sync::Arc;
 use thiserror::Error;
 use tokio::{self, select, sync::mpsc};
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
 
 use crate::{
-    CancellableContext, NodeActor,
+    BlockSigner, CancellableContext, NodeActor,
     actors::network::{
         builder::NetworkBuilder, driver::NetworkDriverError, error::NetworkBuilderError,
     },
@@ -95,29 +94,6 @@ impl NetworkActor {
         };
         (outbound_data, actor)
     }
-
-    /// Signs a payload with the given signer and chain id.
-    ///
-    /// Spec: <https://specs.optimism.io/protocol/rollup-node-p2p.html#block-signatures>
-    fn sign_payload(
-        block: OpExecutionPayloadEnvelope,
-        signer: &PrivateKeySigner,
-        chain_id: u64,
-    ) -> Result<OpNetworkPayloadEnvelope, NetworkActorError> {
-        // Computes the payload hash for a given block.
-        let payload_hash = block.payload_hash();
-
-        let Ok(signature) = signer.sign_hash_sync(&payload_hash.signature_message(chain_id)) else {
-            return Err(NetworkActorError::FailedToSignPayload);
-        };
-
-        Ok(OpNetworkPayloadEnvelope {
-            parent_beacon_block_root: block.parent_beacon_block_root,
-            payload: block.execution_payload,
-            signature,
-            payload_hash,
-        })
-    }
 }
 
 /// The communication context used by the network actor.
@@ -156,9 +132,6 @@ pub enum NetworkActorError {
     /// Channel closed unexpectedly.
     #[error("Channel closed unexpectedly")]
     ChannelClosed,
-    /// Failed to sign the payload.
-    #[error("Failed to sign the payload")]
-    FailedToSignPayload,
 }
 
 #[async_trait]
@@ -176,13 +149,24 @@ impl NodeActor for NetworkActor {
         mut self,
         NetworkContext { blocks, cancellation }: Self::OutboundData,
     ) -> Result<(), Self::Error> {
-        let local_signer = self.builder.local_signer.clone();
+        let block_signer = self.builder.block_signer.clone();
 
         let mut handler = self.builder.build()?.start().await?;
 
         // New unsafe block channel.
         let (unsafe_block_tx, mut unsafe_block_rx) = tokio::sync::mpsc::unbounded_channel();
 
+        // Sign the unsafe payloads to publish in a task of their own, as a remote signer is a
+        // round trip away and would otherwise stall the gossip.
+        let (signed_tx, mut signed_rx) = mpsc::channel(256);
+        tokio::spawn(sign_payloads(
+            block_signer,
+            handler.discovery.chain_id,
+            self.publish_rx,
+            signed_tx,
+            cancellation.clone(),
+        ));
+
         loop {
             select! {
                 _ = cancellation.cancelled() => {
@@ -218,24 +202,15 @@ impl NodeActor for NetworkActor {
                         );
                     }
                 }
-                Some(block) = self.publish_rx.recv(), if !self.publish_rx.is_closed() => {
-                    let timestamp = block.execution_payload.timestamp();
-                    let selector = |handler: &kona_p2p::BlockHandler| {
-                        handler.topic(timestamp)
-                    };
-                    let Some(signer) = local_signer.as_ref() else {
-                        warn!(target: "net", "No local signer available to sign the payload");
-                        continue;
-                    };
-
-                    let chain_id = handler.discovery.chain_id;
-                    let payload = Self::sign_payload(block, signer, chain_id)?;
-
-                    match handler.gossip.publish(selector, Some(payload)) {
-                        Ok(id) => info!("Published unsafe payload | {:?}", id),
-                        Err(e) => warn!("Failed to publish unsafe payload: {:?}", e),
+                Some(payload) = signed_rx.recv(), if !signed_rx.is_closed() => {
+                    match handler.publish_block(payload) {
+                        Ok(id) => info!(target: "net", ?id, "Published unsafe payload"),
+                        Err(err) => warn!(target: "net", ?err, "Failed to publish unsafe payload"),
                     }
                 }
+                _ = handler.publish_retry_ticker.tick(), if !handler.gossip.publish_queue.is_empty() => {
+                    handler.gossip.retry_publishes();
+                }
                 event = handler.gossip.next() => {
                     let Some(event) = event else {
                         error!(target: "node::p2p", "The gossip swarm stream has ended");
@@ -276,67 +251,45 @@ impl NodeActor for NetworkActor {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloy_primitives::B256;
-    use alloy_rpc_types_engine::{ExecutionPayloadV1, ExecutionPayloadV3};
-    use alloy_signer_local::PrivateKeySigner;
-    use arbitrary::Arbitrary;
-    use op_alloy_rpc_types_engine::OpExecutionPayload;
-    use rand::Rng;
-
-    #[test]
-    fn test_payload_signature_roundtrip_v1() {
-        let mut bytes = [0u8; 4096];
-        rand::rng().fill(bytes.as_mut_slice());
-
-        let pubkey = PrivateKeySigner::random();
-        let expected_address = pubkey.address();
-        const CHAIN_ID: u64 = 1337;
-
-        let block = OpExecutionPayloadEnvelope {
-            execution_payload: OpExecutionPayload::V1(
-                ExecutionPayloadV1::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap(),
-            ),
-            parent_beacon_block_root: None,
+/// Signs the unsafe payloads received on `payloads` in order, and sends the signed payloads to
+/// `signed` for the network actor to publish.
+///
+/// Payloads that cannot be signed are not gossipped.
+async fn sign_payloads(
+    signer: Option<Arc<dyn BlockSigner>>,
+    chain_id: u64,
+    mut payloads: mpsc::Receiver<OpExecutionPayloadEnvelope>,
+    signed: mpsc::Sender<OpNetworkPayloadEnvelope>,
+    cancellation: CancellationToken,
+) {
+    loop {
+        let block = select! {
+            _ = cancellation.cancelled() => return,
+            block = payloads.recv() => block,
         };
-
-        let payload = NetworkActor::sign_payload(block, &pubkey, CHAIN_ID).unwrap();
-        let encoded_payload = payload.encode_v1().unwrap();
-
-        let decoded_payload = OpNetworkPayloadEnvelope::decode_v1(&encoded_payload).unwrap();
-
-        let msg = decoded_payload.payload_hash.signature_message(CHAIN_ID);
-        let msg_signer = decoded_payload.signature.recover_address_from_prehash(&msg).unwrap();
-
-        assert_eq!(expected_address, msg_signer);
-    }
-
-    #[test]
-    fn test_payload_signature_roundtrip_v3() {
-        let mut bytes = [0u8; 4096];
-        rand::rng().fill(bytes.as_mut_slice());
-
-        let pubkey = PrivateKeySigner::random();
-        let expected_address = pubkey.address();
-        const CHAIN_ID: u64 = 1337;
-
-        let block = OpExecutionPayloadEnvelope {
-            execution_payload: OpExecutionPayload::V3(
-                ExecutionPayloadV3::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap(),
-            ),
-            parent_beacon_block_root: Some(B256::random()),
+        let Some(block) = block else {
+            return;
         };
 
-        let payload = NetworkActor::sign_payload(block, &pubkey, CHAIN_ID).unwrap();
-        let encoded_payload = payload.encode_v3().unwrap();
-
-        let decoded_payload = OpNetworkPayloadEnvelope::decode_v3(&encoded_payload).unwrap();
-
-        let msg = decoded_payload.payload_hash.signature_message(CHAIN_ID);
-        let msg_signer = decoded_payload.signature.recover_address_from_prehash(&msg).unwrap();
+        let Some(signer) = signer.as_ref() else {
+            warn!(target: "net", "No block signer available to sign the payload");
+            continue;
+        };
 
-        assert_eq!(expected_address, msg_signer);
+        let payload = select! {
+            _ = cancellation.cancelled() => return,
+            payload = signer.sign_payload(block, chain_id) => payload,
+        };
+        match payload {
+            Ok(payload) => {
+                if signed.send(payload).await.is_err() {
+                    return;
+                }
+            }
+            Err(err) => {
+                error!(target: "net", ?err, "Failed to sign unsafe payload, it will not be gossipped");
+                kona_macros::inc!(gauge, crate::Metrics::UNSAFE_BLOCK_SIGNING_ERROR);
+            }
+        }
     }
 }
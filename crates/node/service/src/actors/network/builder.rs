@@ -1,17 +1,18 @@
 //! Network Builder Module.
 
 use alloy_primitives::Address;
-use alloy_signer_local::PrivateKeySigner;
 use discv5::{Config as Discv5Config, Enr};
 use kona_genesis::RollupConfig;
-use kona_p2p::{Discv5Builder, GaterConfig, GossipDriverBuilder, LocalNode};
+use kona_p2p::{
+    Discv5Builder, GaterConfig, GossipDriverBuilder, LocalNode, PublishRetryConfig, TransportConfig,
+};
 use kona_peers::{PeerMonitoring, PeerScoreLevel};
 use libp2p::{Multiaddr, identity::Keypair};
-use std::{path::PathBuf, time::Duration};
+use std::{path::PathBuf, sync::Arc, time::Duration};
 
 use crate::{
     NetworkBuilderError,
-    actors::network::{NetworkConfig, NetworkDriver},
+    actors::network::{BlockSigner, NetworkConfig, NetworkDriver},
 };
 
 /// Constructs a [`NetworkDriver`] for the OP Stack Consensus Layer.
@@ -21,8 +22,8 @@ pub struct NetworkBuilder {
     pub(super) discovery: Discv5Builder,
     /// The gossip driver.
     pub(super) gossip: GossipDriverBuilder,
-    /// A local signer for payloads.
-    pub(super) local_signer: Option<PrivateKeySigner>,
+    /// The signer for unsafe blocks gossipped by the sequencer.
+    pub(super) block_signer: Option<Arc<dyn BlockSigner>>,
 }
 
 impl From<NetworkConfig> for NetworkBuilder {
@@ -44,7 +45,9 @@ impl From<NetworkConfig> for NetworkBuilder {
         .with_peer_monitoring(config.monitor_peers)
         .with_topic_scoring(config.topic_scoring)
         .with_gater_config(config.gater_config)
-        .with_local_signer(config.local_signer)
+        .with_publish_retry(config.publish_retry)
+        .with_transport(config.transport)
+        .with_block_signer(config.block_signer)
     }
 }
 
@@ -70,7 +73,7 @@ impl NetworkBuilder {
                 gossip_addr,
                 keypair,
             ),
-            local_signer: None,
+            block_signer: None,
         }
     }
 
@@ -79,9 +82,19 @@ impl NetworkBuilder {
         Self { gossip: self.gossip.with_gater_config(config), ..self }
     }
 
-    /// Sets the local signer for the [`NetworkBuilder`].
-    pub fn with_local_signer(self, local_signer: Option<PrivateKeySigner>) -> Self {
-        Self { local_signer, ..self }
+    /// Sets the [`BlockSigner`] for the [`NetworkBuilder`].
+    pub fn with_block_signer(self, block_signer: Option<Arc<dyn BlockSigner>>) -> Self {
+        Self { block_signer, ..self }
+    }
+
+    /// Sets the [`PublishRetryConfig`] for the [`GossipDriverBuilder`].
+    pub fn with_publish_retry(self, config: PublishRetryConfig) -> Self {
+        Self { gossip: self.gossip.with_publish_retry(config), ..self }
+    }
+
+    /// Sets the [`TransportConfig`] for the [`GossipDriverBuilder`].
+    pub fn with_transport(self, transport: TransportConfig) -> Self {
+        Self { gossip: self.gossip.with_transport(transport), ..self }
     }
 
     /// Sets the bootstore path for the [`Discv5Builder`].
@@ -1,15 +1,16 @@
 //! Configuration for the `Network`.
 
 use alloy_primitives::Address;
-use alloy_signer_local::PrivateKeySigner;
 use discv5::Enr;
 use kona_genesis::RollupConfig;
-use kona_p2p::{GaterConfig, LocalNode};
+use kona_p2p::{GaterConfig, LocalNode, PublishRetryConfig, TransportConfig};
 use kona_peers::{PeerMonitoring, PeerScoreLevel};
 use libp2p::{Multiaddr, identity::Keypair};
-use std::path::PathBuf;
+use std::{path::PathBuf, sync::Arc};
 use tokio::time::Duration;
 
+use crate::actors::network::BlockSigner;
+
 /// Configuration for kona's P2P stack.
 #[derive(Debug, Clone)]
 pub struct NetworkConfig {
@@ -24,6 +25,8 @@ pub struct NetworkConfig {
     pub discovery_randomize: Option<Duration>,
     /// The gossip address.
     pub gossip_address: libp2p::Multiaddr,
+    /// The transports of the gossip swarm: QUIC, dual-stack listening and connection security.
+    pub transport: TransportConfig,
     /// The unsafe block signer.
     pub unsafe_block_signer: Address,
     /// The keypair.
@@ -44,8 +47,10 @@ pub struct NetworkConfig {
     pub bootnodes: Vec<Enr>,
     /// The [`RollupConfig`].
     pub rollup_config: RollupConfig,
-    /// A local signer for payloads.
-    pub local_signer: Option<PrivateKeySigner>,
+    /// The signer for unsafe blocks gossipped by the sequencer.
+    pub block_signer: Option<Arc<dyn BlockSigner>>,
+    /// The configuration for retrying failed unsafe block publications.
+    pub publish_retry: PublishRetryConfig,
 }
 
 impl NetworkConfig {
@@ -67,6 +72,7 @@ impl NetworkConfig {
             discovery_interval: Self::DEFAULT_DISCOVERY_INTERVAL,
             discovery_randomize: Self::DEFAULT_DISCOVERY_RANDOMIZE,
             gossip_address,
+            transport: Default::default(),
             unsafe_block_signer,
             keypair: Keypair::generate_secp256k1(),
             bootnodes: Default::default(),
@@ -76,7 +82,8 @@ impl NetworkConfig {
             scoring: Default::default(),
             topic_scoring: Default::default(),
             monitor_peers: Default::default(),
-            local_signer: Default::default(),
+            block_signer: Default::default(),
+            publish_retry: Default::default(),
         }
     }
 }
@@ -3,7 +3,7 @@ use kona_p2p::{ConnectionGater, Discv5Driver, GossipDriver, PEER_SCORE_INSPECT_F
 use libp2p::TransportError;
 use tokio::sync::watch;
 
-use crate::actors::network::handler::NetworkHandler;
+use crate::actors::network::handler::{NetworkHandler, PublishRetryTicker};
 
 /// A network driver. This is the driver that is used to start the network.
 #[derive(Debug)]
@@ -36,12 +36,17 @@ impl NetworkDriver {
         // We are checking the peer scores every [`PEER_SCORE_INSPECT_FREQUENCY`] seconds.
         let peer_score_inspector = tokio::time::interval(*PEER_SCORE_INSPECT_FREQUENCY);
 
+        // Failed unsafe block publications are retried at the configured interval.
+        let publish_retry_ticker =
+            PublishRetryTicker::new(self.gossip.publish_queue.config.retry_interval);
+
         Ok(NetworkHandler {
             gossip: self.gossip,
             discovery: handler,
             enr_receiver,
             unsafe_block_signer_sender: self.unsafe_block_signer_sender,
             peer_score_inspector,
+            publish_retry_ticker,
         })
     }
 }
@@ -1,9 +1,14 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
 use alloy_primitives::Address;
 use discv5::Enr;
-use kona_p2p::{ConnectionGater, Discv5Handler, GossipDriver, HandlerRequest};
-use tokio::sync::{mpsc, watch};
+use kona_p2p::{ConnectionGater, Discv5Handler, GossipDriver, HandlerRequest, PublishError};
+use libp2p::gossipsub::MessageId;
+use op_alloy_rpc_types_engine::OpNetworkPayloadEnvelope;
+use tokio::{
+    sync::{mpsc, watch},
+    time::{Instant, Interval, MissedTickBehavior},
+};
 
 /// A network handler used to communicate with the network once it is started.
 #[derive(Debug)]
@@ -18,9 +23,27 @@ pub struct NetworkHandler {
     pub unsafe_block_signer_sender: watch::Sender<Address>,
     /// The peer score inspector. Is used to ban peers that are below a given threshold.
     pub peer_score_inspector: tokio::time::Interval,
+    /// The ticker used to retry failed unsafe block publications.
+    pub publish_retry_ticker: PublishRetryTicker,
 }
 
 impl NetworkHandler {
+    /// Publishes an unsafe block.
+    ///
+    /// If the publication is queued for a retry while no other publication is, the retry ticker
+    /// is restarted so that the first retry happens a full retry interval later.
+    pub(super) fn publish_block(
+        &mut self,
+        payload: OpNetworkPayloadEnvelope,
+    ) -> Result<Option<MessageId>, PublishError> {
+        let was_empty = self.gossip.publish_queue.is_empty();
+        let result = self.gossip.publish_block(payload);
+        if was_empty && !self.gossip.publish_queue.is_empty() {
+            self.publish_retry_ticker.restart();
+        }
+        result
+    }
+
     pub(super) async fn handle_peer_monitoring(&mut self) {
         // Inspect peer scores and ban peers that are below the threshold.
         let Some(ban_peers) = self.gossip.peer_monitoring.as_ref() else {
@@ -99,3 +122,64 @@ impl NetworkHandler {
         }
     }
 }
+
+/// The ticker used to retry failed unsafe block publications.
+///
+/// The ticker is only polled while publications are waiting for a retry, so missed ticks are
+/// delayed rather than fired back to back, and the ticker is restarted when the first publication
+/// is queued.
+#[derive(Debug)]
+pub struct PublishRetryTicker {
+    /// The underlying interval.
+    interval: Interval,
+}
+
+impl PublishRetryTicker {
+    /// Creates a new [`PublishRetryTicker`] ticking every `retry_interval`, starting one interval
+    /// from now.
+    pub fn new(retry_interval: Duration) -> Self {
+        let mut interval =
+            tokio::time::interval_at(Instant::now() + retry_interval, retry_interval);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        Self { interval }
+    }
+
+    /// Restarts the ticker, so that the next tick happens one retry interval from now.
+    pub fn restart(&mut self) {
+        self.interval.reset();
+    }
+
+    /// Completes when the next retry is due.
+    pub async fn tick(&mut self) {
+        self.interval.tick().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test(start_paused = true)]
+    async fn test_publish_retry_ticker_spacing() {
+        let retry_interval = Duration::from_secs(2);
+        let mut ticker = PublishRetryTicker::new(retry_interval);
+
+        // The queue stays empty for a while, so the ticker is not polled.
+        tokio::time::advance(retry_interval * 10).await;
+
+        // A publication is queued: the first retry happens a full interval later.
+        ticker.restart();
+        let start = Instant::now();
+        for i in 1..=3 {
+            ticker.tick().await;
+            assert_eq!(start.elapsed(), retry_interval * i);
+        }
+
+        // Ticks missed while the queue was empty again do not fire back to back.
+        tokio::time::advance(retry_interval * 5).await;
+        ticker.tick().await;
+        let last = Instant::now();
+        ticker.tick().await;
+        assert_eq!(last.elapsed(), retry_interval);
+    }
+}
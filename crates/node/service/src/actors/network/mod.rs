@@ -13,7 +13,13 @@ mod error;
 pub use error::NetworkBuilderError;
 
 mod handler;
-pub use handler::NetworkHandler;
+pub use handler::{NetworkHandler, PublishRetryTicker};
 
 mod config;
 pub use config::NetworkConfig;
+
+mod signer;
+pub use signer::{
+    BlockPayloadArgs, BlockSigner, BlockSignerError, ClientCert, DEFAULT_REMOTE_SIGNER_TIMEOUT,
+    LocalBlockSigner, RemoteBlockSigner, RemoteSignerConfig,
+};
@@ -0,0 +1,109 @@
+//! A [`BlockSigner`] backed by a local private key.
+
+use super::{BlockSigner, BlockSignerError};
+use alloy_primitives::{Address, Signature};
+use alloy_signer::SignerSync;
+use alloy_signer_local::PrivateKeySigner;
+use async_trait::async_trait;
+use op_alloy_rpc_types_engine::PayloadHash;
+
+/// A [`BlockSigner`] backed by a local private key.
+#[derive(Debug, Clone)]
+pub struct LocalBlockSigner {
+    /// The inner private key signer.
+    signer: PrivateKeySigner,
+}
+
+impl LocalBlockSigner {
+    /// Creates a new [`LocalBlockSigner`] from the given [`PrivateKeySigner`].
+    pub const fn new(signer: PrivateKeySigner) -> Self {
+        Self { signer }
+    }
+}
+
+impl From<PrivateKeySigner> for LocalBlockSigner {
+    fn from(signer: PrivateKeySigner) -> Self {
+        Self::new(signer)
+    }
+}
+
+#[async_trait]
+impl BlockSigner for LocalBlockSigner {
+    fn address(&self) -> Option<Address> {
+        Some(self.signer.address())
+    }
+
+    async fn sign_block(
+        &self,
+        payload_hash: PayloadHash,
+        chain_id: u64,
+    ) -> Result<Signature, BlockSignerError> {
+        Ok(self.signer.sign_hash_sync(&payload_hash.signature_message(chain_id))?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::B256;
+    use alloy_rpc_types_engine::{ExecutionPayloadV1, ExecutionPayloadV3};
+    use arbitrary::Arbitrary;
+    use op_alloy_rpc_types_engine::{
+        OpExecutionPayload, OpExecutionPayloadEnvelope, OpNetworkPayloadEnvelope,
+    };
+    use rand::Rng;
+
+    #[tokio::test]
+    async fn test_payload_signature_roundtrip_v1() {
+        let mut bytes = [0u8; 4096];
+        rand::rng().fill(bytes.as_mut_slice());
+
+        let signer = LocalBlockSigner::new(PrivateKeySigner::random());
+        let expected_address = signer.address().unwrap();
+        const CHAIN_ID: u64 = 1337;
+
+        let block = OpExecutionPayloadEnvelope {
+            execution_payload: OpExecutionPayload::V1(
+                ExecutionPayloadV1::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap(),
+            ),
+            parent_beacon_block_root: None,
+        };
+
+        let payload = signer.sign_payload(block, CHAIN_ID).await.unwrap();
+        let encoded_payload = payload.encode_v1().unwrap();
+
+        let decoded_payload = OpNetworkPayloadEnvelope::decode_v1(&encoded_payload).unwrap();
+
+        let msg = decoded_payload.payload_hash.signature_message(CHAIN_ID);
+        let msg_signer = decoded_payload.signature.recover_address_from_prehash(&msg).unwrap();
+
+        assert_eq!(expected_address, msg_signer);
+    }
+
+    #[tokio::test]
+    async fn test_payload_signature_roundtrip_v3() {
+        let mut bytes = [0u8; 4096];
+        rand::rng().fill(bytes.as_mut_slice());
+
+        let signer = LocalBlockSigner::new(PrivateKeySigner::random());
+        let expected_address = signer.address().unwrap();
+        const CHAIN_ID: u64 = 1337;
+
+        let block = OpExecutionPayloadEnvelope {
+            execution_payload: OpExecutionPayload::V3(
+                ExecutionPayloadV3::arbitrary(&mut arbitrary::Unstructured::new(&bytes)).unwrap(),
+            ),
+            parent_beacon_block_root: Some(B256::random()),
+        };
+
+        let payload = signer.sign_payload(block, CHAIN_ID).await.unwrap();
+        let encoded_payload = payload.encode_v3().unwrap();
+
+        let decoded_payload = OpNetworkPayloadEnvelope::decode_v3(&encoded_payload).unwrap();
+
+        let msg = decoded_payload.payload_hash.signature_message(CHAIN_ID);
+        let msg_signer = decoded_payload.signature.recover_address_from_prehash(&msg).unwrap();
+
+        assert_eq!(expected_address, msg_signer);
+    }
+}
@@ -0,0 +1,89 @@
+//! Signers for unsafe blocks gossipped by the sequencer.
+
+use alloy_primitives::{Address, Signature, SignatureError};
+use alloy_transport::{RpcError, TransportErrorKind};
+use async_trait::async_trait;
+use op_alloy_rpc_types_engine::{
+    OpExecutionPayloadEnvelope, OpNetworkPayloadEnvelope, PayloadHash,
+};
+use std::{fmt::Debug, path::PathBuf};
+
+mod local;
+pub use local::LocalBlockSigner;
+
+mod remote;
+pub use remote::{
+    BlockPayloadArgs, ClientCert, DEFAULT_REMOTE_SIGNER_TIMEOUT, RemoteBlockSigner,
+    RemoteSignerConfig,
+};
+
+/// An error produced by a [`BlockSigner`].
+#[derive(Debug, thiserror::Error)]
+pub enum BlockSignerError {
+    /// The local signer failed to sign the payload hash.
+    #[error("Failed to sign the payload with the local signer: {0}")]
+    Local(#[from] alloy_signer::Error),
+    /// The request to the remote signer failed.
+    #[error("Remote signer request failed: {0}")]
+    Remote(#[from] RpcError<TransportErrorKind>),
+    /// The remote signer returned a malformed signature.
+    #[error("Remote signer returned an invalid signature: {0}")]
+    InvalidSignature(#[from] SignatureError),
+    /// The signature returned by the remote signer does not recover to the expected address.
+    #[error("Remote signer signed with {recovered}, expected {expected}")]
+    UnexpectedSigner {
+        /// The configured address of the signer.
+        expected: Address,
+        /// The address recovered from the returned signature.
+        recovered: Address,
+    },
+    /// A TLS certificate or key for the remote signer could not be read.
+    #[error("Failed to read {0}: {1}")]
+    ReadCertificate(PathBuf, std::io::Error),
+    /// The HTTP client for the remote signer could not be built.
+    #[error("Failed to build the remote signer client: {0}")]
+    Client(#[from] alloy_transport_http::reqwest::Error),
+}
+
+/// A signer for unsafe blocks, used by the sequencer to sign blocks before they are gossipped.
+///
+/// Spec: <https://specs.optimism.io/protocol/rollup-node-p2p.html#block-signatures>
+#[async_trait]
+pub trait BlockSigner: Debug + Send + Sync {
+    /// Returns the address of the signer, if it is known ahead of signing.
+    fn address(&self) -> Option<Address>;
+
+    /// Checks that the signer is able to sign blocks.
+    ///
+    /// The sequencer refuses to build blocks while this check fails, since the blocks it would
+    /// produce could not be gossipped to the network.
+    async fn health_check(&self) -> Result<(), BlockSignerError> {
+        Ok(())
+    }
+
+    /// Signs the [`PayloadHash`] of an unsafe block for the given L2 chain id.
+    async fn sign_block(
+        &self,
+        payload_hash: PayloadHash,
+        chain_id: u64,
+    ) -> Result<Signature, BlockSignerError>;
+
+    /// Signs an [`OpExecutionPayloadEnvelope`], producing the [`OpNetworkPayloadEnvelope`] to
+    /// gossip.
+    async fn sign_payload(
+        &self,
+        block: OpExecutionPayloadEnvelope,
+        chain_id: u64,
+    ) -> Result<OpNetworkPayloadEnvelope, BlockSignerError> {
+        // Computes the payload hash for a given block.
+        let payload_hash = block.payload_hash();
+        let signature = self.sign_block(payload_hash, chain_id).await?;
+
+        Ok(OpNetworkPayloadEnvelope {
+            parent_beacon_block_root: block.parent_beacon_block_root,
+            payload: block.execution_payload,
+            signature,
+            payload_hash,
+        })
+    }
+}
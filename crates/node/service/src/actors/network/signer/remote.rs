@@ -0,0 +1,263 @@
+//! A [`BlockSigner`] backed by a remote [op-signer] service.
+//!
+//! [op-signer]: https://github.com/ethereum-optimism/infra/tree/main/op-signer
+
+use super::{BlockSigner, BlockSignerError};
+use alloy_primitives::{Address, B256, Bytes, Signature};
+use alloy_rpc_client::RpcClient;
+use alloy_transport_http::{
+    Http,
+    reqwest::{Certificate, Client, Identity},
+};
+use async_trait::async_trait;
+use op_alloy_rpc_types_engine::PayloadHash;
+use serde::{Deserialize, Serialize};
+use std::{path::PathBuf, time::Duration};
+use url::Url;
+
+/// The default timeout for requests to the remote signer.
+pub const DEFAULT_REMOTE_SIGNER_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// The op-signer method used to sign unsafe block payloads.
+const SIGN_BLOCK_PAYLOAD_METHOD: &str = "opsigner_signBlockPayload";
+
+/// The op-signer method used to check that the service is reachable.
+const HEALTH_STATUS_METHOD: &str = "health_status";
+
+/// The signing domain of unsafe blocks.
+///
+/// Spec: <https://specs.optimism.io/protocol/rollup-node-p2p.html#block-signatures>
+const SIGNING_DOMAIN_BLOCKS_V1: B256 = B256::ZERO;
+
+/// The arguments of the `opsigner_signBlockPayload` request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockPayloadArgs {
+    /// The signing domain.
+    pub domain: B256,
+    /// The L2 chain id.
+    pub chain_id: u64,
+    /// The hash of the payload to sign.
+    pub payload_hash: B256,
+    /// The address the payload is expected to be signed with.
+    pub sender_address: Address,
+}
+
+/// A TLS client certificate, used to authenticate against the remote signer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClientCert {
+    /// Path to the PEM-encoded client certificate.
+    pub cert: PathBuf,
+    /// Path to the PEM-encoded private key of the client certificate.
+    pub key: PathBuf,
+}
+
+/// Configuration for the [`RemoteBlockSigner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RemoteSignerConfig {
+    /// The op-signer endpoint.
+    pub endpoint: Url,
+    /// The address of the key used by the remote signer.
+    pub address: Address,
+    /// Path to a PEM-encoded CA certificate used to verify the remote signer. If [`None`], the
+    /// system roots are used.
+    pub ca_cert: Option<PathBuf>,
+    /// The client certificate used for mutual TLS, if any.
+    pub client_cert: Option<ClientCert>,
+    /// The timeout of requests to the remote signer.
+    pub timeout: Duration,
+}
+
+impl RemoteSignerConfig {
+    /// Creates a new [`RemoteSignerConfig`] for the given endpoint and signer address, without
+    /// custom TLS settings.
+    pub const fn new(endpoint: Url, address: Address) -> Self {
+        Self {
+            endpoint,
+            address,
+            ca_cert: None,
+            client_cert: None,
+            timeout: DEFAULT_REMOTE_SIGNER_TIMEOUT,
+        }
+    }
+}
+
+/// A [`BlockSigner`] that delegates signing to an op-signer service over JSON-RPC.
+///
+/// Signatures returned by the remote signer are checked against the configured address before
+/// being used, so that a misconfigured signer cannot produce blocks that peers would reject.
+#[derive(Debug, Clone)]
+pub struct RemoteBlockSigner {
+    /// The inner RPC client.
+    rpc: RpcClient,
+    /// The address of the remote key.
+    address: Address,
+}
+
+impl RemoteBlockSigner {
+    /// Creates a new [`RemoteBlockSigner`] from the given [`RemoteSignerConfig`].
+    pub fn new(config: RemoteSignerConfig) -> Result<Self, BlockSignerError> {
+        let read = |path: &PathBuf| {
+            std::fs::read(path).map_err(|e| BlockSignerError::ReadCertificate(path.clone(), e))
+        };
+
+        let mut builder = Client::builder().use_rustls_tls().timeout(config.timeout);
+        if let Some(ca_cert) = &config.ca_cert {
+            builder = builder.add_root_certificate(Certificate::from_pem(&read(ca_cert)?)?);
+        }
+        if let Some(ClientCert { cert, key }) = &config.client_cert {
+            let mut pem = read(cert)?;
+            pem.extend(read(key)?);
+            builder = builder.identity(Identity::from_pem(&pem)?);
+        }
+
+        let rpc = RpcClient::new(Http::with_client(builder.build()?, config.endpoint), false);
+        Ok(Self { rpc, address: config.address })
+    }
+}
+
+#[async_trait]
+impl BlockSigner for RemoteBlockSigner {
+    fn address(&self) -> Option<Address> {
+        Some(self.address)
+    }
+
+    async fn health_check(&self) -> Result<(), BlockSignerError> {
+        let version: String = self.rpc.request_noparams(HEALTH_STATUS_METHOD).await?;
+        trace!(target: "signer", version, "Remote signer is healthy");
+        Ok(())
+    }
+
+    async fn sign_block(
+        &self,
+        payload_hash: PayloadHash,
+        chain_id: u64,
+    ) -> Result<Signature, BlockSignerError> {
+        let args = BlockPayloadArgs {
+            domain: SIGNING_DOMAIN_BLOCKS_V1,
+            chain_id,
+            payload_hash: payload_hash.0,
+            sender_address: self.address,
+        };
+        let signature: Bytes = self.rpc.request(SIGN_BLOCK_PAYLOAD_METHOD, (args,)).await?;
+        let signature = Signature::try_from(signature.as_ref())?;
+
+        let recovered =
+            signature.recover_address_from_prehash(&payload_hash.signature_message(chain_id))?;
+        if recovered != self.address {
+            return Err(BlockSignerError::UnexpectedSigner { expected: self.address, recovered });
+        }
+
+        Ok(signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_signer::SignerSync;
+    use alloy_signer_local::PrivateKeySigner;
+    use jsonrpsee::{
+        RpcModule,
+        server::{Server, ServerHandle},
+        types::ErrorObjectOwned,
+    };
+    use std::net::SocketAddr;
+
+    /// Spawns a mock op-signer server signing block payloads with the given key.
+    async fn mock_signer(key: PrivateKeySigner) -> (SocketAddr, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let mut module = RpcModule::new(key);
+        module
+            .register_method(HEALTH_STATUS_METHOD, |_, _, _| {
+                Ok::<_, ErrorObjectOwned>("v1.0.0".to_string())
+            })
+            .unwrap();
+        module
+            .register_method(SIGN_BLOCK_PAYLOAD_METHOD, |params, key, _| {
+                let args: BlockPayloadArgs = params.one()?;
+                let msg = PayloadHash(args.payload_hash).signature_message(args.chain_id);
+                let signature = key.sign_hash_sync(&msg).unwrap();
+                Ok::<_, ErrorObjectOwned>(Bytes::copy_from_slice(&signature.as_bytes()))
+            })
+            .unwrap();
+
+        (addr, server.start(module))
+    }
+
+    fn config(addr: SocketAddr, address: Address) -> RemoteSignerConfig {
+        RemoteSignerConfig::new(format!("http://{addr}").parse().unwrap(), address)
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_signs_block() {
+        let key = PrivateKeySigner::random();
+        let address = key.address();
+        let (addr, _handle) = mock_signer(key).await;
+
+        let signer = RemoteBlockSigner::new(config(addr, address)).unwrap();
+        signer.health_check().await.unwrap();
+
+        let payload_hash = PayloadHash(B256::random());
+        let signature = signer.sign_block(payload_hash, 10).await.unwrap();
+        let recovered =
+            signature.recover_address_from_prehash(&payload_hash.signature_message(10)).unwrap();
+        assert_eq!(recovered, address);
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_rejects_unexpected_signer() {
+        let (addr, _handle) = mock_signer(PrivateKeySigner::random()).await;
+
+        let expected = Address::random();
+        let signer = RemoteBlockSigner::new(config(addr, expected)).unwrap();
+
+        let err = signer.sign_block(PayloadHash(B256::random()), 10).await.unwrap_err();
+        assert!(
+            matches!(err, BlockSignerError::UnexpectedSigner { expected: e, .. } if e == expected)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_remote_signer_unreachable() {
+        let (addr, handle) = mock_signer(PrivateKeySigner::random()).await;
+        handle.stop().unwrap();
+        handle.stopped().await;
+
+        let signer = RemoteBlockSigner::new(config(addr, Address::random())).unwrap();
+        assert!(matches!(signer.health_check().await, Err(BlockSignerError::Remote(_))));
+        assert!(matches!(
+            signer.sign_block(PayloadHash(B256::random()), 10).await,
+            Err(BlockSignerError::Remote(_))
+        ));
+    }
+
+    #[test]
+    fn test_remote_signer_missing_ca_cert() {
+        let config = RemoteSignerConfig {
+            ca_cert: Some(PathBuf::from("/nonexistent/ca.crt")),
+            ..RemoteSignerConfig::new("https://localhost:8080".parse().unwrap(), Address::ZERO)
+        };
+        assert!(matches!(
+            RemoteBlockSigner::new(config),
+            Err(BlockSignerError::ReadCertificate(..))
+        ));
+    }
+
+    #[test]
+    fn test_block_payload_args_serde() {
+        let args = BlockPayloadArgs {
+            domain: B256::ZERO,
+            chain_id: 10,
+            payload_hash: B256::repeat_byte(0xaa),
+            sender_address: Address::repeat_byte(0xbb),
+        };
+        let json = serde_json::to_value(args).unwrap();
+        assert_eq!(json["domain"], B256::ZERO.to_string());
+        assert_eq!(json["chainId"], 10);
+        assert_eq!(json["payloadHash"], B256::repeat_byte(0xaa).to_string());
+        assert_eq!(serde_json::from_value::<BlockPayloadArgs>(json).unwrap(), args);
+    }
+}
@@ -4,8 +4,9 @@ use crate::{NodeActor, actors::CancellableContext};
 use async_trait::async_trait;
 use kona_p2p::P2pRpcRequest;
 use kona_rpc::{
-    AdminApiServer, AdminRpc, DevEngineApiServer, DevEngineRpc, HealthzResponse, NetworkAdminQuery,
-    OpP2PApiServer, RollupNodeApiServer, SequencerAdminQuery, WsRPC, WsServer,
+    AdminApiServer, AdminRpc, DevEngineAdminApiServer, DevEngineApiServer, DevEngineRpc,
+    HealthApiServer, HealthRpc, HealthzResponse, NetworkAdminQuery, OpP2PApiServer, ProbeLayer,
+    RollupNodeApiServer, SequencerAdminQuery, WsRPC, WsServer,
 };
 
 use jsonrpsee::{
@@ -14,9 +15,10 @@ use jsonrpsee::{
     server::{Server, ServerHandle},
 };
 use kona_engine::EngineQueries;
-use kona_rpc::{L1WatcherQueries, P2pRpc, RollupRpc, RpcBuilder};
+use kona_rpc::{DerivationQueries, L1WatcherQueries, P2pRpc, RollupRpc, RpcBuilder};
 use tokio::sync::mpsc;
 use tokio_util::sync::{CancellationToken, WaitForCancellationFuture};
+use tower::ServiceBuilder;
 
 /// An error returned by the [`RpcActor`].
 #[derive(Debug, thiserror::Error)]
@@ -60,6 +62,8 @@ pub struct RpcContext {
     pub sequencer_admin: Option<mpsc::Sender<SequencerAdminQuery>>,
     /// The l1 watcher queries sender.
     pub l1_watcher_queries: mpsc::Sender<L1WatcherQueries>,
+    /// The derivation queries sender.
+    pub derivation_queries: mpsc::Sender<DerivationQueries>,
     /// The engine query sender.
     pub engine_query: mpsc::Sender<EngineQueries>,
     /// The cancellation token, shared between all tasks.
@@ -72,9 +76,8 @@ impl CancellableContext for RpcContext {
     }
 }
 
-/// Launches the jsonrpsee [`Server`].
-///
-/// If the RPC server is disabled, this will return `Ok(None)`.
+/// Launches the jsonrpsee [`Server`], serving the liveness and readiness probes through the given
+/// [`ProbeLayer`], if any.
 ///
 /// ## Errors
 ///
@@ -82,8 +85,12 @@ impl CancellableContext for RpcContext {
 async fn launch(
     config: &RpcBuilder,
     module: RpcModule<()>,
+    probes: Option<ProbeLayer>,
 ) -> Result<ServerHandle, std::io::Error> {
-    let server = Server::builder().build(config.socket).await?;
+    let server = Server::builder()
+        .set_http_middleware(ServiceBuilder::new().option_layer(probes))
+        .build(config.socket)
+        .await?;
 
     if let Ok(addr) = server.local_addr() {
         info!(target: "rpc", addr = ?addr, "RPC server bound to address");
@@ -111,6 +118,7 @@ impl NodeActor for RpcActor {
             cancellation,
             p2p_network,
             l1_watcher_queries,
+            derivation_queries,
             engine_query,
             network_admin,
             sequencer_admin,
@@ -123,6 +131,22 @@ impl NodeActor for RpcActor {
             jsonrpsee::core::RpcResult::Ok(response)
         })?;
 
+        // Serve the liveness and readiness probes alongside the RPC methods.
+        let probes = self.config.probes.map(|readiness| {
+            ProbeLayer::new(
+                engine_query.clone(),
+                l1_watcher_queries.clone(),
+                p2p_network.clone(),
+                readiness,
+            )
+        });
+
+        // Build the sequencer health module, polled by op-conductor.
+        modules.merge(
+            HealthRpc::new(engine_query.clone(), p2p_network.clone(), self.config.health)
+                .into_rpc(),
+        )?;
+
         // Build the p2p rpc module.
         modules.merge(P2pRpc::new(p2p_network).into_rpc())?;
 
@@ -133,13 +157,20 @@ impl NodeActor for RpcActor {
         )?;
 
         // Create context for communication between actors.
-        let rollup_rpc = RollupRpc::new(engine_query.clone(), l1_watcher_queries);
+        let rollup_rpc =
+            RollupRpc::new(engine_query.clone(), l1_watcher_queries, derivation_queries);
         modules.merge(rollup_rpc.into_rpc())?;
 
         // Add development RPC module for engine state introspection if enabled
         if self.config.dev_enabled() {
             let dev_rpc = DevEngineRpc::new(engine_query.clone());
-            modules.merge(dev_rpc.into_rpc())?;
+            modules.merge(DevEngineApiServer::into_rpc(dev_rpc))?;
+        }
+
+        // Add development RPC module to recover a stalled engine task queue if enabled
+        if self.config.dev_admin_enabled() {
+            let dev_admin_rpc = DevEngineRpc::new(engine_query.clone());
+            modules.merge(DevEngineAdminApiServer::into_rpc(dev_admin_rpc))?;
         }
 
         if self.config.ws_enabled() {
@@ -148,12 +179,12 @@ impl NodeActor for RpcActor {
 
         let restarts = self.config.restart_count();
 
-        let mut handle = launch(&self.config, modules.clone()).await?;
+        let mut handle = launch(&self.config, modules.clone(), probes.clone()).await?;
 
         for _ in 0..=restarts {
             tokio::select! {
                 _ = handle.clone().stopped() => {
-                    match launch(&self.config, modules.clone()).await {
+                    match launch(&self.config, modules.clone(), probes.clone()).await {
                         Ok(h) => handle = h,
                         Err(err) => {
                             error!(target: "rpc", ?err, "Failed to launch rpc server");
@@ -192,8 +223,11 @@ mod tests {
             admin_persistence: None,
             ws_enabled: false,
             dev_enabled: false,
+            dev_admin_enabled: false,
+            health: Default::default(),
+            probes: None,
         };
-        let result = launch(&launcher, RpcModule::new(())).await;
+        let result = launch(&launcher, RpcModule::new(()), None).await;
         assert!(result.is_ok());
     }
 
@@ -206,6 +240,9 @@ mod tests {
             admin_persistence: None,
             ws_enabled: false,
             dev_enabled: false,
+            dev_admin_enabled: false,
+            health: Default::default(),
+            probes: None,
         };
         let mut modules = RpcModule::new(());
 
@@ -213,7 +250,7 @@ mod tests {
         modules.merge(RpcModule::new(())).expect("module merge");
         modules.merge(RpcModule::new(())).expect("module merge");
 
-        let result = launch(&launcher, modules).await;
+        let result = launch(&launcher, modules, None).await;
         assert!(result.is_ok());
     }
 }
@@ -1,9 +1,21 @@
 //! The [`SequencerActor`].
 
-use super::{L1OriginSelector, L1OriginSelectorError, SequencerConfig};
-use crate::{CancellableContext, NodeActor, actors::sequencer::conductor::ConductorClient};
+use super::{
+    L1OriginSelector, L1OriginSelectorError, PayloadAttributesContext, PayloadAttributesHook,
+    PayloadAttributesHookError, PayloadAttributesHookKind, PersistedSequencerState,
+    SequencerConfig, SequencerPersistenceError, SequencerStatePersistence, SequencerStateWriter,
+    signer_health::{SIGNER_HEALTH_CHECK_INTERVAL, SignerHealth},
+};
+use crate::{
+    BlockSigner, CancellableContext, NodeActor,
+    actors::sequencer::conductor::{
+        CONDUCTOR_LEADERSHIP_POLL_INTERVAL, ConductorClient, spawn_leadership_poller,
+    },
+};
+use alloy_primitives::B256;
 use alloy_provider::RootProvider;
 use async_trait::async_trait;
+use futures::future::OptionFuture;
 use kona_derive::{AttributesBuilder, PipelineErrorKind, StatefulAttributesBuilder};
 use kona_genesis::RollupConfig;
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
@@ -45,6 +57,9 @@ pub(super) struct SequencerActorState<AB: AttributesBuilder> {
     pub origin_selector: L1OriginSelector<RootProvider>,
     /// The conductor RPC client.
     pub conductor: Option<ConductorClient>,
+    /// The health of the [`BlockSigner`] used by the network actor to sign the built blocks, if
+    /// any. The sequencer refuses to build blocks while the signer is unhealthy.
+    pub signer_health: Option<SignerHealth>,
     /// Whether the sequencer is active. This is used inside communications between the sequencer
     /// and the op-conductor to activate/deactivate the sequencer when leader election occurs.
     ///
@@ -56,6 +71,16 @@ pub(super) struct SequencerActorState<AB: AttributesBuilder> {
     /// ## Default value
     /// At startup, the sequencer is _NOT_ in recovery mode.
     pub is_recovery_mode: bool,
+    /// The maximum number of L2 blocks the unsafe head may be ahead of the safe head before block
+    /// production stalls. Disabled if `0`.
+    pub max_safe_lag: u64,
+    /// Persists the active state and the last built block across restarts, if configured.
+    pub persistence: Option<SequencerStateWriter>,
+    /// The hash of the last block built by the sequencer, restored from the persisted state at
+    /// startup.
+    pub last_built_hash: Option<B256>,
+    /// The [`PayloadAttributesHook`]s applied in order to the attributes of every built block.
+    pub hooks: Vec<Arc<dyn PayloadAttributesHook>>,
 }
 
 /// A trait for building [`AttributesBuilder`]s.
@@ -71,24 +96,77 @@ impl From<SequencerBuilder>
     for SequencerActorState<StatefulAttributesBuilder<AlloyChainProvider, AlloyL2ChainProvider>>
 {
     fn from(seq_builder: SequencerBuilder) -> Self {
-        let SequencerConfig { sequencer_stopped, sequencer_recovery_mode, conductor_rpc_url } =
-            seq_builder.seq_cfg.clone();
+        let SequencerConfig {
+            sequencer_stopped,
+            sequencer_recovery_mode,
+            conductor_rpc_url,
+            max_safe_lag,
+            l1_confs,
+            admin_state_path,
+            hooks: hook_configs,
+        } = seq_builder.seq_cfg.clone();
 
         let cfg = seq_builder.rollup_cfg.clone();
         let l1_provider = seq_builder.l1_provider.clone();
         let conductor = conductor_rpc_url.map(ConductorClient::new_http);
 
+        // The built-in hooks are applied before the custom ones.
+        if sequencer_recovery_mode &&
+            !hook_configs.iter().any(|hook| hook.kind() == PayloadAttributesHookKind::Recovery)
+        {
+            warn!(target: "sequencer", "Recovery mode is enabled, but the recovery hook is not configured");
+        }
+        let hooks = hook_configs
+            .into_iter()
+            .map(|hook| hook.build())
+            .chain(seq_builder.hooks.iter().cloned())
+            .collect::<Vec<_>>();
+
         let builder = seq_builder.build();
 
-        let origin_selector = L1OriginSelector::new(cfg.clone(), l1_provider);
+        let origin_selector =
+            L1OriginSelector::new(cfg.clone(), l1_provider).with_l1_confs(l1_confs);
+
+        // The persisted state, if any, takes precedence over the startup flag. If the state file
+        // cannot be read, start stopped rather than risk running two active sequencers.
+        let persistence = admin_state_path.map(SequencerStatePersistence::new);
+        let persisted = persistence.as_ref().and_then(|p| match p.load() {
+            Ok(state) => state,
+            Err(err) => {
+                error!(target: "sequencer", ?err, "Failed to load persisted sequencer state, starting stopped");
+                Some(PersistedSequencerState::default())
+            }
+        });
+        if let Some(state) = persisted {
+            info!(
+                target: "sequencer",
+                active = state.sequencer_started,
+                last_built_hash = ?state.last_built_hash,
+                "Restoring persisted sequencer state"
+            );
+        }
+
+        let is_active = persisted.map_or(!sequencer_stopped, |state| state.sequencer_started);
+        let last_built_hash = persisted.and_then(|state| state.last_built_hash);
+        let persistence = persistence.map(|persistence| {
+            SequencerStateWriter::spawn(
+                persistence,
+                PersistedSequencerState { sequencer_started: is_active, last_built_hash },
+            )
+        });
 
         Self {
             cfg,
             builder,
             origin_selector,
             conductor,
-            is_active: !sequencer_stopped,
+            signer_health: None,
+            is_active,
             is_recovery_mode: sequencer_recovery_mode,
+            max_safe_lag,
+            persistence,
+            last_built_hash,
+            hooks,
         }
     }
 }
@@ -106,6 +184,10 @@ pub struct SequencerBuilder {
     pub l1_provider: RootProvider,
     /// The L2 provider.
     pub l2_provider: RootProvider<Optimism>,
+    /// The [`BlockSigner`] used to sign the built blocks, if any.
+    pub block_signer: Option<Arc<dyn BlockSigner>>,
+    /// Custom [`PayloadAttributesHook`]s, applied after the hooks of the [`SequencerConfig`].
+    pub hooks: Vec<Arc<dyn PayloadAttributesHook>>,
 }
 
 impl AttributesBuilderConfig for SequencerBuilder {
@@ -151,6 +233,8 @@ pub struct SequencerContext {
     /// A sender to asynchronously sign and gossip built [`OpExecutionPayloadEnvelope`]s to the
     /// network actor.
     pub gossip_payload_tx: mpsc::Sender<OpExecutionPayloadEnvelope>,
+    /// Watch channel to observe the safe head of the engine.
+    pub safe_head_rx: watch::Receiver<L2BlockInfo>,
 }
 
 impl CancellableContext for SequencerContext {
@@ -168,6 +252,9 @@ pub enum SequencerActorError {
     /// An error occurred while selecting the next L1 origin.
     #[error(transparent)]
     L1OriginSelector(#[from] L1OriginSelectorError),
+    /// A payload attributes hook failed critically.
+    #[error("Payload attributes hook {0} failed: {1}")]
+    PayloadAttributesHook(&'static str, PayloadAttributesHookError),
     /// A channel was unexpectedly closed.
     #[error("Channel closed unexpectedly")]
     ChannelClosed,
@@ -191,7 +278,33 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
         ctx: &mut SequencerContext,
         unsafe_head_rx: &mut watch::Receiver<L2BlockInfo>,
     ) -> Result<(), SequencerActorError> {
+        // Do not build blocks that could not be signed and gossipped.
+        if !self.signer_available() {
+            warn!(target: "sequencer", "Block signer is unavailable. Re-attempting on next tick.");
+            return Ok(());
+        }
+
         let unsafe_head = *unsafe_head_rx.borrow();
+
+        // Do not extend the unsafe chain too far beyond the safe head.
+        let safe_head = *ctx.safe_head_rx.borrow();
+        kona_macros::set!(
+            gauge,
+            crate::Metrics::SEQUENCER_SAFE_LAG,
+            safe_lag(&unsafe_head, &safe_head) as f64
+        );
+        if self.safe_lag_exceeded(&unsafe_head, &safe_head) {
+            warn!(
+                target: "sequencer",
+                unsafe_head = unsafe_head.block_info.number,
+                safe_head = safe_head.block_info.number,
+                max_safe_lag = self.max_safe_lag,
+                "Max safe lag reached, stalling block production. Re-attempting on next tick."
+            );
+            kona_macros::inc!(counter, crate::Metrics::SEQUENCER_SAFE_LAG_STALLS);
+            return Ok(());
+        }
+
         let l1_origin = match self.origin_selector.next_l1_origin(unsafe_head).await {
             Ok(l1_origin) => l1_origin,
             Err(err) => {
@@ -300,6 +413,41 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
             attributes.no_tx_pool = Some(true);
         }
 
+        // Apply the payload attributes hooks. They cannot re-enable the transaction pool of a block
+        // that the protocol requires to be empty.
+        let empty_block_required = attributes.no_tx_pool == Some(true);
+        let hook_ctx = PayloadAttributesContext {
+            cfg: &self.cfg,
+            parent: unsafe_head,
+            l1_origin,
+            recovery_mode: self.is_recovery_mode,
+            empty_block_required,
+            // The attributes builder sets the gas limit of the system config at the parent.
+            system_gas_limit: attributes.gas_limit,
+        };
+        for hook in &self.hooks {
+            match hook.apply(&hook_ctx, &mut attributes).await {
+                Ok(()) => {}
+                Err(err @ PayloadAttributesHookError::Temporary(_)) => {
+                    warn!(
+                        target: "sequencer",
+                        hook = hook.name(),
+                        %err,
+                        "Payload attributes hook failed. Re-attempting on next tick."
+                    );
+                    return Ok(());
+                }
+                Err(err @ PayloadAttributesHookError::Critical(_)) => {
+                    error!(target: "sequencer", hook = hook.name(), %err, "Payload attributes hook failed");
+                    ctx.cancellation.cancel();
+                    return Err(SequencerActorError::PayloadAttributesHook(hook.name(), err));
+                }
+            }
+        }
+        if empty_block_required {
+            attributes.no_tx_pool = Some(true);
+        }
+
         // TODO: L1 origin in this type must be optional, to account for attributes that weren't
         // derived.
         let attrs_with_parent =
@@ -325,6 +473,13 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
 
         let payload = self.try_wait_for_payload(ctx, payload_rx).await?;
 
+        // Record the last built block, persisted in the background.
+        let block_hash = payload.execution_payload.block_hash();
+        self.last_built_hash = Some(block_hash);
+        if let Some(persistence) = &self.persistence {
+            persistence.record_built(block_hash);
+        }
+
         // Log the block building job duration, if metrics are enabled.
         kona_macros::set!(
             gauge,
@@ -349,6 +504,51 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
         self.schedule_gossip(ctx, payload).await
     }
 
+    /// Returns `true` if the [`BlockSigner`] was healthy at its last health check, or if no
+    /// signer is configured.
+    pub(super) fn signer_available(&self) -> bool {
+        if self.signer_health.as_ref().is_none_or(SignerHealth::is_healthy) {
+            return true;
+        }
+        kona_macros::inc!(counter, crate::Metrics::SEQUENCER_SIGNER_UNAVAILABLE);
+        false
+    }
+
+    /// Persists the sequencer state with the given active flag and the last built block, if
+    /// persistence is enabled.
+    pub(super) fn persist(&self, active: bool) -> Result<(), SequencerPersistenceError> {
+        self.persistence.as_ref().map_or(Ok(()), |persistence| persistence.store_active(active))
+    }
+
+    /// Stops the sequencer resuming from the persisted state if the unsafe head is not the last
+    /// block it built, as another sequencer may have taken over while the node was down.
+    pub(super) fn check_resume(&mut self, unsafe_head: &L2BlockInfo) {
+        let Some(last_built_hash) = self.last_built_hash else {
+            return;
+        };
+        if !self.is_active || unsafe_head.block_info.hash == last_built_hash {
+            return;
+        }
+
+        error!(
+            target: "sequencer",
+            %last_built_hash,
+            unsafe_head = %unsafe_head.block_info.hash,
+            "Unsafe head is not the last built block, not resuming the sequencer"
+        );
+        self.is_active = false;
+    }
+
+    /// Returns `true` if the max safe lag is enabled and the unsafe head is at least
+    /// `max_safe_lag` blocks ahead of the safe head.
+    pub(super) fn safe_lag_exceeded(
+        &self,
+        unsafe_head: &L2BlockInfo,
+        safe_head: &L2BlockInfo,
+    ) -> bool {
+        self.max_safe_lag > 0 && safe_lag(unsafe_head, safe_head) >= self.max_safe_lag
+    }
+
     /// Waits for the next payload to be built and returns it, if there is a payload receiver
     /// present.
     async fn try_wait_for_payload(
@@ -417,6 +617,11 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
     }
 }
 
+/// Returns the number of L2 blocks the unsafe head is ahead of the safe head.
+const fn safe_lag(unsafe_head: &L2BlockInfo, safe_head: &L2BlockInfo) -> u64 {
+    unsafe_head.block_info.number.saturating_sub(safe_head.block_info.number)
+}
+
 #[async_trait]
 impl NodeActor for SequencerActor<SequencerBuilder> {
     type Error = SequencerActorError;
@@ -432,15 +637,30 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
         let block_time = self.builder.rollup_cfg.block_time;
         let mut build_ticker = tokio::time::interval(Duration::from_secs(block_time));
 
+        let block_signer = self.builder.block_signer.clone();
         let mut state = SequencerActorState::from(self.builder);
+        state.signer_health = block_signer.map(|signer| {
+            SignerHealth::spawn(signer, SIGNER_HEALTH_CHECK_INTERVAL, ctx.cancellation.clone())
+        });
+        let mut leadership = state.conductor.clone().map(|conductor| {
+            spawn_leadership_poller(
+                conductor,
+                CONDUCTOR_LEADERSHIP_POLL_INTERVAL,
+                ctx.cancellation.clone(),
+            )
+        });
+
+        // Reset the engine state prior to beginning block building.
+        state.schedule_initial_reset(&mut ctx, &mut self.unsafe_head_rx).await?;
+
+        // Only resume sequencing on top of the last built block.
+        let unsafe_head = *self.unsafe_head_rx.borrow();
+        state.check_resume(&unsafe_head);
 
         // Initialize metrics, if configured.
         #[cfg(feature = "metrics")]
         state.update_metrics();
 
-        // Reset the engine state prior to beginning block building.
-        state.schedule_initial_reset(&mut ctx, &mut self.unsafe_head_rx).await?;
-
         loop {
             select! {
                 // We are using a biased select here to ensure that the admin queries are given priority over the block building task.
@@ -457,7 +677,7 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
                 Some(admin_query) = self.admin_query_rx.recv(), if !self.admin_query_rx.is_closed() => {
                     let is_sequencer_active = state.is_active;
 
-                    if let Err(e) = state.handle_admin_query(admin_query, &mut self.unsafe_head_rx).await {
+                    if let Err(e) = state.handle_admin_query(admin_query, &mut self.unsafe_head_rx, &ctx.safe_head_rx).await {
                         error!(target: "sequencer", err = ?e, "Failed to handle admin query");
                     }
 
@@ -472,6 +692,15 @@ impl NodeActor for SequencerActor<SequencerBuilder> {
                     #[cfg(feature = "metrics")]
                     state.update_metrics();
                 }
+                // Stop the sequencer when the conductor transfers leadership away from the node.
+                Some(Ok(())) = OptionFuture::from(leadership.as_mut().map(|rx| rx.changed())) => {
+                    let is_leader = leadership.as_mut().and_then(|rx| *rx.borrow_and_update());
+                    if state.follow_conductor_leadership(is_leader) {
+                        // Update metrics, if configured.
+                        #[cfg(feature = "metrics")]
+                        state.update_metrics();
+                    }
+                }
                 // The sequencer must be active to build new blocks.
                 _ = build_ticker.tick(), if state.is_active => {
                     state.build_block(&mut ctx, &mut self.unsafe_head_rx).await?;
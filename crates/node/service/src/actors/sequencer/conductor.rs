@@ -1,8 +1,17 @@
 use alloy_rpc_client::ReqwestClient;
 use alloy_transport::{RpcError, TransportErrorKind};
+use kona_derive::AttributesBuilder;
 use op_alloy_rpc_types_engine::OpExecutionPayloadEnvelope;
+use std::time::Duration;
+use tokio::{sync::watch, time::MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
 use url::Url;
 
+use crate::actors::sequencer::actor::SequencerActorState;
+
+/// The interval at which the sequencer polls the conductor for leadership changes.
+pub(super) const CONDUCTOR_LEADERSHIP_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
 /// A client for communicating with the conductor service via RPC
 #[derive(Debug, Clone)]
 pub struct ConductorClient {
@@ -45,6 +54,83 @@ impl ConductorClient {
     }
 }
 
+/// Polls the conductor for the leadership of the node in a background task, so that a slow or
+/// unreachable conductor never stalls block building.
+///
+/// The leadership is published over a watch channel: `Some(is_leader)` while the conductor is
+/// active, and `None` while it is paused or cannot be reached.
+///
+/// The conductor is polled every `interval`, until cancelled.
+pub(super) fn spawn_leadership_poller(
+    conductor: ConductorClient,
+    interval: Duration,
+    cancellation: CancellationToken,
+) -> watch::Receiver<Option<bool>> {
+    let (leadership_tx, leadership_rx) = watch::channel(None);
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = cancellation.cancelled() => return,
+                _ = ticker.tick() => {}
+            }
+
+            let leadership = poll_leadership(&conductor).await;
+            leadership_tx
+                .send_if_modified(|current| std::mem::replace(current, leadership) != leadership);
+        }
+    });
+    leadership_rx
+}
+
+/// Queries the leadership of the node, if the conductor is active.
+async fn poll_leadership(conductor: &ConductorClient) -> Option<bool> {
+    match conductor.conductor_active().await {
+        Ok(true) => {}
+        Ok(false) => {
+            debug!(target: "sequencer", "Conductor is paused, not following leadership");
+            return None;
+        }
+        Err(err) => {
+            warn!(target: "sequencer", ?err, "Failed to query the conductor status");
+            return None;
+        }
+    }
+
+    conductor
+        .leader()
+        .await
+        .inspect_err(|err| {
+            warn!(target: "sequencer", ?err, "Failed to query the conductor leadership");
+        })
+        .ok()
+}
+
+impl<AB: AttributesBuilder> SequencerActorState<AB> {
+    /// Follows a leadership change published by [`spawn_leadership_poller`], stopping the sequencer
+    /// when the node loses leadership. Returns `true` if the sequencer was stopped.
+    ///
+    /// The sequencer is not started when the node gains leadership: op-conductor starts it
+    /// through `admin_startSequencer`, with the unsafe head it committed as the expected head.
+    pub(super) fn follow_conductor_leadership(&mut self, leadership: Option<bool>) -> bool {
+        match (leadership, self.is_active) {
+            (Some(false), true) => {
+                info!(target: "sequencer", "Lost conductor leadership, stopping sequencer");
+                self.stop_sequencer().is_ok()
+            }
+            (Some(true), false) => {
+                info!(
+                    target: "sequencer",
+                    "Gained conductor leadership, waiting for the conductor to start the sequencer"
+                );
+                false
+            }
+            _ => false,
+        }
+    }
+}
+
 /// Error type for conductor operations
 #[derive(Debug, thiserror::Error)]
 pub enum ConductorError {
@@ -52,3 +138,171 @@ pub enum ConductorError {
     #[error("RPC error: {0}")]
     Rpc(#[from] RpcError<TransportErrorKind>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::actors::sequencer::L1OriginSelector;
+    use alloy_eips::BlockNumHash;
+    use alloy_primitives::B256;
+    use alloy_provider::RootProvider;
+    use async_trait::async_trait;
+    use jsonrpsee::{
+        RpcModule,
+        server::{Server, ServerHandle},
+        types::ErrorObjectOwned,
+    };
+    use kona_derive::{PipelineError, PipelineResult};
+    use kona_genesis::RollupConfig;
+    use kona_protocol::{BlockInfo, L2BlockInfo};
+    use op_alloy_rpc_types_engine::OpPayloadAttributes;
+    use std::sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    };
+
+    /// An [`AttributesBuilder`] that never builds attributes, as none are needed when following
+    /// leadership.
+    #[derive(Debug)]
+    struct NoopAttributesBuilder;
+
+    #[async_trait]
+    impl AttributesBuilder for NoopAttributesBuilder {
+        async fn prepare_payload_attributes(
+            &mut self,
+            _: L2BlockInfo,
+            _: BlockNumHash,
+        ) -> PipelineResult<OpPayloadAttributes> {
+            Err(PipelineError::Eof.temp())
+        }
+    }
+
+    /// The leadership and status of a mock conductor.
+    #[derive(Debug, Default)]
+    struct MockConductor {
+        leader: AtomicBool,
+        active: AtomicBool,
+    }
+
+    /// Spawns a mock conductor, serving its leadership and status.
+    async fn spawn_conductor(conductor: Arc<MockConductor>) -> (Url, ServerHandle) {
+        let server = Server::builder().build("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+
+        let mut module = RpcModule::new(conductor);
+        module
+            .register_method("conductor_leader", |_, conductor, _| {
+                Ok::<_, ErrorObjectOwned>(conductor.leader.load(Ordering::SeqCst))
+            })
+            .unwrap();
+        module
+            .register_method("conductor_active", |_, conductor, _| {
+                Ok::<_, ErrorObjectOwned>(conductor.active.load(Ordering::SeqCst))
+            })
+            .unwrap();
+
+        let handle = server.start(module);
+        (format!("http://{addr}").parse().unwrap(), handle)
+    }
+
+    fn state(is_active: bool) -> SequencerActorState<NoopAttributesBuilder> {
+        let cfg = Arc::new(RollupConfig::default());
+        let l1_provider = RootProvider::new_http("http://127.0.0.1:1".parse().unwrap());
+        SequencerActorState {
+            cfg: cfg.clone(),
+            builder: NoopAttributesBuilder,
+            origin_selector: L1OriginSelector::new(cfg, l1_provider),
+            conductor: None,
+            signer_health: None,
+            is_active,
+            is_recovery_mode: false,
+            max_safe_lag: 0,
+            persistence: None,
+            last_built_hash: None,
+            hooks: Vec::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_conductor_leadership_polling() {
+        let conductor = Arc::new(MockConductor::default());
+        let (url, _handle) = spawn_conductor(conductor.clone()).await;
+        let cancellation = CancellationToken::new();
+        let mut leadership = spawn_leadership_poller(
+            ConductorClient::new_http(url),
+            Duration::from_millis(10),
+            cancellation.clone(),
+        );
+
+        // The conductor is paused, so the leadership is unknown.
+        assert_eq!(*leadership.borrow(), None);
+
+        conductor.active.store(true, Ordering::SeqCst);
+        leadership.changed().await.unwrap();
+        assert_eq!(*leadership.borrow_and_update(), Some(false));
+
+        conductor.leader.store(true, Ordering::SeqCst);
+        leadership.changed().await.unwrap();
+        assert_eq!(*leadership.borrow_and_update(), Some(true));
+
+        conductor.active.store(false, Ordering::SeqCst);
+        leadership.changed().await.unwrap();
+        assert_eq!(*leadership.borrow_and_update(), None);
+
+        cancellation.cancel();
+    }
+
+    #[tokio::test]
+    async fn test_conductor_leadership_unreachable() {
+        let cancellation = CancellationToken::new();
+        let leadership = spawn_leadership_poller(
+            ConductorClient::new_http("http://127.0.0.1:1".parse().unwrap()),
+            Duration::from_millis(10),
+            cancellation.clone(),
+        );
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(*leadership.borrow(), None);
+        cancellation.cancel();
+    }
+
+    #[test]
+    fn test_follow_leadership() {
+        let mut state = state(true);
+
+        // Unknown leadership is not followed.
+        assert!(!state.follow_conductor_leadership(None));
+        assert!(state.is_active);
+
+        // Leadership is lost.
+        assert!(state.follow_conductor_leadership(Some(false)));
+        assert!(!state.is_active);
+        assert!(!state.follow_conductor_leadership(Some(false)));
+
+        // Leadership is gained: the conductor starts the sequencer through the admin api.
+        assert!(!state.follow_conductor_leadership(Some(true)));
+        assert!(!state.is_active);
+    }
+
+    #[test]
+    fn test_check_resume() {
+        let head = |hash| L2BlockInfo {
+            block_info: BlockInfo { hash, ..Default::default() },
+            ..Default::default()
+        };
+
+        // Without a last built block, the persisted state is followed.
+        let mut state = state(true);
+        state.check_resume(&head(B256::repeat_byte(0x01)));
+        assert!(state.is_active);
+
+        // The sequencer resumes on top of the last built block.
+        state.last_built_hash = Some(B256::repeat_byte(0x01));
+        state.check_resume(&head(B256::repeat_byte(0x01)));
+        assert!(state.is_active);
+
+        // Another sequencer built on top of the last built block.
+        state.check_resume(&head(B256::repeat_byte(0x02)));
+        assert!(!state.is_active);
+    }
+}
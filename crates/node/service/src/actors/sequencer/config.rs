@@ -2,6 +2,8 @@
 //!
 //! [`SequencerActor`]: super::SequencerActor
 
+use super::PayloadAttributesHookConfig;
+use std::path::PathBuf;
 use url::Url;
 
 /// Configuration for the [`SequencerActor`].
@@ -14,5 +16,20 @@ pub struct SequencerConfig {
     /// Whether or not the sequencer is in recovery mode.
     pub sequencer_recovery_mode: bool,
     /// The [`Url`] for the conductor RPC endpoint. If [`Some`], enables the conductor service.
+    ///
+    /// The sequencer stops when the node loses the conductor leadership, but is not started when
+    /// it gains it: as with op-node, the conductor starts it through `admin_startSequencer`.
     pub conductor_rpc_url: Option<Url>,
+    /// The maximum number of L2 blocks the unsafe head may be ahead of the safe head. Block
+    /// production stalls once this distance is reached. Disabled if `0`.
+    pub max_safe_lag: u64,
+    /// The number of L1 blocks to keep between the L1 head and the L1 origins selected by the
+    /// sequencer.
+    pub l1_confs: u64,
+    /// The path of the file used to persist the sequencer state across restarts. If [`Some`],
+    /// the persisted state takes precedence over [`Self::sequencer_stopped`].
+    pub admin_state_path: Option<PathBuf>,
+    /// The built-in payload attributes hooks, applied in order to the attributes of every built
+    /// block.
+    pub hooks: Vec<PayloadAttributesHookConfig>,
 }
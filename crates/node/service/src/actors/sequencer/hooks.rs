@@ -0,0 +1,383 @@
+//! Hooks applied by the sequencer to the payload attributes of the blocks it builds.
+//!
+//! The [`SequencerActor`] prepares the payload attributes of each block with the
+//! [`AttributesBuilder`], and enforces the protocol rules (e.g. empty blocks at hardfork
+//! boundaries, or beyond the sequencer drift). [`PayloadAttributesHook`]s are then applied in
+//! order, letting operators implement transaction-inclusion policies without patching the actor.
+//!
+//! [`SequencerActor`]: super::SequencerActor
+//! [`AttributesBuilder`]: kona_derive::AttributesBuilder
+
+use alloy_primitives::Bytes;
+use async_trait::async_trait;
+use kona_genesis::RollupConfig;
+use kona_protocol::{BlockInfo, L2BlockInfo};
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
+use serde::{Deserialize, Serialize};
+use std::{fmt::Debug, sync::Arc};
+
+/// The EIP-2718 type of deposit transactions, which can only originate from L1.
+const DEPOSIT_TX_TYPE: u8 = 0x7e;
+
+/// The context in which a [`PayloadAttributesHook`] is applied.
+#[derive(Debug, Clone, Copy)]
+pub struct PayloadAttributesContext<'a> {
+    /// The [`RollupConfig`] of the chain being sequenced.
+    pub cfg: &'a RollupConfig,
+    /// The parent of the block being built.
+    pub parent: L2BlockInfo,
+    /// The L1 origin of the block being built.
+    pub l1_origin: BlockInfo,
+    /// Whether the sequencer is in recovery mode.
+    pub recovery_mode: bool,
+    /// Whether the protocol requires the block to be empty, because it is the first block of a
+    /// hardfork or because it is beyond the sequencer drift. User transactions included in such a
+    /// block would make its batch invalid.
+    pub empty_block_required: bool,
+    /// The gas limit of the system config at the parent block, which verifiers derive the block
+    /// with.
+    pub system_gas_limit: Option<u64>,
+}
+
+impl PayloadAttributesContext<'_> {
+    /// Returns the number of the block being built.
+    pub const fn block_number(&self) -> u64 {
+        self.parent.block_info.number + 1
+    }
+}
+
+/// An error returned by a [`PayloadAttributesHook`].
+#[derive(Debug, thiserror::Error)]
+pub enum PayloadAttributesHookError {
+    /// The block is not built, and building is re-attempted on the next tick.
+    #[error("Temporary payload attributes hook error: {0}")]
+    Temporary(String),
+    /// The sequencer is stopped.
+    #[error("Critical payload attributes hook error: {0}")]
+    Critical(String),
+}
+
+/// A hook modifying the payload attributes of the blocks built by the sequencer.
+///
+/// Hooks are applied after the protocol rules. If the protocol requires an empty block, the
+/// transaction pool stays disabled whatever the hooks do.
+#[async_trait]
+pub trait PayloadAttributesHook: Debug + Send + Sync {
+    /// Returns the name of the hook, used in logs.
+    fn name(&self) -> &'static str;
+
+    /// Applies the hook to the payload attributes of the next block.
+    async fn apply(
+        &self,
+        ctx: &PayloadAttributesContext<'_>,
+        attributes: &mut OpPayloadAttributes,
+    ) -> Result<(), PayloadAttributesHookError>;
+}
+
+/// The kinds of built-in [`PayloadAttributesHook`]s.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display, strum::EnumString, strum::EnumIter)]
+#[strum(serialize_all = "kebab-case")]
+pub enum PayloadAttributesHookKind {
+    /// The [`RecoveryModeHook`].
+    Recovery,
+    /// The [`GasLimitHook`].
+    GasLimit,
+    /// The [`ForcedTransactionsHook`].
+    ForcedTxs,
+}
+
+/// The configuration of a built-in [`PayloadAttributesHook`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PayloadAttributesHookConfig {
+    /// Builds empty blocks while the sequencer is in recovery mode.
+    Recovery,
+    /// Sets the gas limit of every block.
+    GasLimit(u64),
+    /// Includes scheduled transactions.
+    ForcedTransactions(Vec<ForcedTransaction>),
+}
+
+impl PayloadAttributesHookConfig {
+    /// Returns the [`PayloadAttributesHookKind`] of the configured hook.
+    pub const fn kind(&self) -> PayloadAttributesHookKind {
+        match self {
+            Self::Recovery => PayloadAttributesHookKind::Recovery,
+            Self::GasLimit(_) => PayloadAttributesHookKind::GasLimit,
+            Self::ForcedTransactions(_) => PayloadAttributesHookKind::ForcedTxs,
+        }
+    }
+
+    /// Builds the configured [`PayloadAttributesHook`].
+    pub fn build(self) -> Arc<dyn PayloadAttributesHook> {
+        match self {
+            Self::Recovery => Arc::new(RecoveryModeHook),
+            Self::GasLimit(gas_limit) => Arc::new(GasLimitHook::new(gas_limit)),
+            Self::ForcedTransactions(transactions) => {
+                Arc::new(ForcedTransactionsHook::new(transactions))
+            }
+        }
+    }
+}
+
+/// Disables the transaction pool while the sequencer is in recovery mode, so that only empty
+/// blocks are built until the sequencer catches up with the L1 origin.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RecoveryModeHook;
+
+#[async_trait]
+impl PayloadAttributesHook for RecoveryModeHook {
+    fn name(&self) -> &'static str {
+        "recovery"
+    }
+
+    async fn apply(
+        &self,
+        ctx: &PayloadAttributesContext<'_>,
+        attributes: &mut OpPayloadAttributes,
+    ) -> Result<(), PayloadAttributesHookError> {
+        if ctx.recovery_mode {
+            attributes.no_tx_pool = Some(true);
+        }
+        Ok(())
+    }
+}
+
+/// Sets the gas limit of every block.
+///
+/// Verifiers derive the gas limit of each block from the system config, so blocks built with a
+/// different gas limit would be replaced once their batches are derived, reorging the unsafe
+/// chain. The override is skipped for the blocks whose system config gas limit differs from the
+/// configured one.
+#[derive(Debug, Clone, Copy)]
+pub struct GasLimitHook {
+    /// The gas limit of the built blocks.
+    gas_limit: u64,
+}
+
+impl GasLimitHook {
+    /// Creates a new [`GasLimitHook`] with the given gas limit.
+    pub const fn new(gas_limit: u64) -> Self {
+        Self { gas_limit }
+    }
+}
+
+#[async_trait]
+impl PayloadAttributesHook for GasLimitHook {
+    fn name(&self) -> &'static str {
+        "gas-limit"
+    }
+
+    async fn apply(
+        &self,
+        ctx: &PayloadAttributesContext<'_>,
+        attributes: &mut OpPayloadAttributes,
+    ) -> Result<(), PayloadAttributesHookError> {
+        if let Some(system_gas_limit) = ctx.system_gas_limit.filter(|gas| *gas != self.gas_limit) {
+            warn!(
+                target: "sequencer",
+                block = ctx.block_number(),
+                gas_limit = self.gas_limit,
+                system_gas_limit,
+                "Configured gas limit differs from the system config, not overriding it"
+            );
+            return Ok(());
+        }
+
+        attributes.gas_limit = Some(self.gas_limit);
+        Ok(())
+    }
+}
+
+/// A transaction forced into the block with the given number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForcedTransaction {
+    /// The number of the block the transaction is included in.
+    pub block: u64,
+    /// The EIP-2718 encoded signed transaction.
+    pub transaction: Bytes,
+}
+
+impl ForcedTransaction {
+    /// Returns `true` if the transaction is not empty, and is not a deposit transaction.
+    pub fn is_valid(&self) -> bool {
+        self.transaction.first().is_some_and(|ty| *ty != DEPOSIT_TX_TYPE)
+    }
+}
+
+/// Includes scheduled [`ForcedTransaction`]s in the payload attributes, after the deposit
+/// transactions.
+///
+/// Forced transactions cannot be included in blocks that the protocol requires to be empty. Rather
+/// than silently dropping them, the hook fails with a critical error in that case, stopping the
+/// sequencer.
+#[derive(Debug, Clone)]
+pub struct ForcedTransactionsHook {
+    /// The forced transactions, sorted by block number.
+    transactions: Vec<ForcedTransaction>,
+}
+
+impl ForcedTransactionsHook {
+    /// Creates a new [`ForcedTransactionsHook`] including the given transactions.
+    pub fn new(mut transactions: Vec<ForcedTransaction>) -> Self {
+        transactions.sort_by_key(|tx| tx.block);
+        Self { transactions }
+    }
+
+    /// Returns the transactions forced into the block with the given number.
+    fn transactions_at(&self, block: u64) -> &[ForcedTransaction] {
+        let start = self.transactions.partition_point(|tx| tx.block < block);
+        let end = self.transactions.partition_point(|tx| tx.block <= block);
+        &self.transactions[start..end]
+    }
+}
+
+#[async_trait]
+impl PayloadAttributesHook for ForcedTransactionsHook {
+    fn name(&self) -> &'static str {
+        "forced-txs"
+    }
+
+    async fn apply(
+        &self,
+        ctx: &PayloadAttributesContext<'_>,
+        attributes: &mut OpPayloadAttributes,
+    ) -> Result<(), PayloadAttributesHookError> {
+        let forced = self.transactions_at(ctx.block_number());
+        if forced.is_empty() {
+            return Ok(());
+        }
+
+        if ctx.empty_block_required {
+            return Err(PayloadAttributesHookError::Critical(format!(
+                "{} transactions are forced into block {}, which must be empty",
+                forced.len(),
+                ctx.block_number()
+            )));
+        }
+
+        info!(
+            target: "sequencer",
+            block = ctx.block_number(),
+            count = forced.len(),
+            "Including forced transactions"
+        );
+        attributes
+            .transactions
+            .get_or_insert_with(Vec::new)
+            .extend(forced.iter().map(|tx| tx.transaction.clone()));
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::bytes;
+    use strum::IntoEnumIterator;
+
+    fn ctx(cfg: &RollupConfig, parent: u64) -> PayloadAttributesContext<'_> {
+        PayloadAttributesContext {
+            cfg,
+            parent: L2BlockInfo {
+                block_info: BlockInfo { number: parent, ..Default::default() },
+                ..Default::default()
+            },
+            l1_origin: BlockInfo::default(),
+            recovery_mode: false,
+            empty_block_required: false,
+            system_gas_limit: None,
+        }
+    }
+
+    fn forced(block: u64, transaction: Bytes) -> ForcedTransaction {
+        ForcedTransaction { block, transaction }
+    }
+
+    #[test]
+    fn test_hook_kind_from_str() {
+        assert_eq!(
+            "gas-limit".parse::<PayloadAttributesHookKind>().unwrap(),
+            PayloadAttributesHookKind::GasLimit
+        );
+        for kind in PayloadAttributesHookKind::iter() {
+            assert_eq!(kind.to_string().parse::<PayloadAttributesHookKind>().unwrap(), kind);
+        }
+        assert!("unknown".parse::<PayloadAttributesHookKind>().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_recovery_mode_hook() {
+        let cfg = RollupConfig::default();
+        let mut attributes = OpPayloadAttributes::default();
+
+        RecoveryModeHook.apply(&ctx(&cfg, 0), &mut attributes).await.unwrap();
+        assert_eq!(attributes.no_tx_pool, None);
+
+        let ctx = PayloadAttributesContext { recovery_mode: true, ..ctx(&cfg, 0) };
+        RecoveryModeHook.apply(&ctx, &mut attributes).await.unwrap();
+        assert_eq!(attributes.no_tx_pool, Some(true));
+    }
+
+    #[tokio::test]
+    async fn test_gas_limit_hook() {
+        let cfg = RollupConfig::default();
+        let mut attributes =
+            OpPayloadAttributes { gas_limit: Some(30_000_000), ..Default::default() };
+
+        GasLimitHook::new(60_000_000).apply(&ctx(&cfg, 0), &mut attributes).await.unwrap();
+        assert_eq!(attributes.gas_limit, Some(60_000_000));
+
+        // The gas limit matches the system config.
+        let ctx = PayloadAttributesContext { system_gas_limit: Some(60_000_000), ..ctx(&cfg, 0) };
+        let mut attributes =
+            OpPayloadAttributes { gas_limit: Some(60_000_000), ..Default::default() };
+        GasLimitHook::new(60_000_000).apply(&ctx, &mut attributes).await.unwrap();
+        assert_eq!(attributes.gas_limit, Some(60_000_000));
+
+        // The gas limit differs from the system config, which verifiers derive the block with.
+        let mut attributes =
+            OpPayloadAttributes { gas_limit: Some(60_000_000), ..Default::default() };
+        GasLimitHook::new(30_000_000).apply(&ctx, &mut attributes).await.unwrap();
+        assert_eq!(attributes.gas_limit, Some(60_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_forced_transactions_hook() {
+        let cfg = RollupConfig::default();
+        let deposit = bytes!("7e01");
+        let hook = ForcedTransactionsHook::new(vec![
+            forced(11, bytes!("02aa")),
+            forced(10, bytes!("02bb")),
+            forced(11, bytes!("02cc")),
+        ]);
+
+        let mut attributes =
+            OpPayloadAttributes { transactions: Some(vec![deposit.clone()]), ..Default::default() };
+        hook.apply(&ctx(&cfg, 10), &mut attributes).await.unwrap();
+        assert_eq!(attributes.transactions, Some(vec![deposit, bytes!("02aa"), bytes!("02cc")]));
+
+        let mut attributes = OpPayloadAttributes::default();
+        hook.apply(&ctx(&cfg, 11), &mut attributes).await.unwrap();
+        assert_eq!(attributes.transactions, None);
+    }
+
+    #[tokio::test]
+    async fn test_forced_transactions_hook_empty_block_required() {
+        let cfg = RollupConfig::default();
+        let hook = ForcedTransactionsHook::new(vec![forced(1, bytes!("02aa"))]);
+
+        let ctx = PayloadAttributesContext { empty_block_required: true, ..ctx(&cfg, 0) };
+        let mut attributes = OpPayloadAttributes::default();
+        assert!(matches!(
+            hook.apply(&ctx, &mut attributes).await,
+            Err(PayloadAttributesHookError::Critical(_))
+        ));
+    }
+
+    #[test]
+    fn test_forced_transaction_validity() {
+        assert!(forced(1, bytes!("02aa")).is_valid());
+        assert!(!forced(1, bytes!("7eaa")).is_valid());
+        assert!(!forced(1, Bytes::new()).is_valid());
+    }
+}
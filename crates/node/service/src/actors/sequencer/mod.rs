@@ -3,6 +3,19 @@
 mod config;
 pub use config::SequencerConfig;
 
+mod persistence;
+pub use persistence::{
+    PersistedSequencerState, SequencerPersistenceError, SequencerStatePersistence,
+    SequencerStateWriter,
+};
+
+mod hooks;
+pub use hooks::{
+    ForcedTransaction, ForcedTransactionsHook, GasLimitHook, PayloadAttributesContext,
+    PayloadAttributesHook, PayloadAttributesHookConfig, PayloadAttributesHookError,
+    PayloadAttributesHookKind, RecoveryModeHook,
+};
+
 mod origin_selector;
 pub use origin_selector::{L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider};
 
@@ -14,5 +27,7 @@ pub use actor::{
 
 mod rpc;
 
+mod signer_health;
+
 mod conductor;
 pub use conductor::{ConductorClient, ConductorError};
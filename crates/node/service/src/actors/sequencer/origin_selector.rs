@@ -20,12 +20,26 @@ pub struct L1OriginSelector<P: L1OriginSelectorProvider> {
     current: Option<BlockInfo>,
     /// The next L1 origin.
     next: Option<BlockInfo>,
+    /// The number of L1 blocks to keep between the L1 head and the selected L1 origins.
+    l1_confs: u64,
 }
 
 impl<P: L1OriginSelectorProvider> L1OriginSelector<P> {
     /// Creates a new [`L1OriginSelector`].
     pub const fn new(cfg: Arc<RollupConfig>, l1: P) -> Self {
-        Self { cfg, l1, current: None, next: None }
+        Self { cfg, l1, current: None, next: None, l1_confs: 0 }
+    }
+
+    /// Sets the number of confirmations an L1 block needs before it can be adopted as the next
+    /// L1 origin. A block is confirmed once it is at least `l1_confs` blocks behind the L1 head.
+    pub const fn with_l1_confs(mut self, l1_confs: u64) -> Self {
+        self.l1_confs = l1_confs;
+        self
+    }
+
+    /// Returns the number of confirmations required for the next L1 origin.
+    pub const fn l1_confs(&self) -> u64 {
+        self.l1_confs
     }
 
     /// Returns the current L1 origin.
@@ -126,11 +140,29 @@ impl<P: L1OriginSelectorProvider> L1OriginSelector<P> {
                 return Ok(());
             }
 
+            // Only adopt the next L1 origin once it has enough confirmations, to avoid building
+            // on L1 blocks that are likely to be reorged out.
+            let next_number = current.number + 1;
+            if self.l1_confs > 0 {
+                let l1_head = self.l1.get_latest_block_number().await?;
+                if next_number.saturating_add(self.l1_confs) > l1_head {
+                    debug!(
+                        target: "l1_origin_selector",
+                        next_number,
+                        l1_head,
+                        l1_confs = self.l1_confs,
+                        "Next L1 origin does not have enough confirmations"
+                    );
+                    kona_macros::inc!(counter, crate::Metrics::SEQUENCER_L1_ORIGIN_UNCONFIRMED);
+                    return Ok(());
+                }
+            }
+
             // If the next L1 origin is a logical extension of the current L1 chain, set it.
             //
             // Ignore the eventuality that the block is not found, as the next L1 origin fetch is
             // performed on a best-effort basis.
-            let next = self.l1.get_block_by_number(current.number + 1).await?;
+            let next = self.l1.get_block_by_number(next_number).await?;
             if next.map(|n| n.parent_hash == current.hash).unwrap_or(false) {
                 self.next = next;
             }
@@ -167,6 +199,9 @@ pub trait L1OriginSelectorProvider {
         &self,
         number: u64,
     ) -> Result<Option<BlockInfo>, L1OriginSelectorError>;
+
+    /// Returns the number of the latest L1 block.
+    async fn get_latest_block_number(&self) -> Result<u64, L1OriginSelectorError>;
 }
 
 #[async_trait]
@@ -184,6 +219,10 @@ impl L1OriginSelectorProvider for RootProvider {
     ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
         Ok(Provider::get_block_by_number(self, number.into()).await?.map(Into::into))
     }
+
+    async fn get_latest_block_number(&self) -> Result<u64, L1OriginSelectorError> {
+        Ok(Provider::get_block_number(self).await?)
+    }
 }
 
 #[cfg(test)]
@@ -221,6 +260,10 @@ mod test {
         ) -> Result<Option<BlockInfo>, L1OriginSelectorError> {
             Ok(self.blocks.iter().find(|b| b.number == number).copied())
         }
+
+        async fn get_latest_block_number(&self) -> Result<u64, L1OriginSelectorError> {
+            Ok(self.blocks.iter().map(|b| b.number).max().unwrap_or_default())
+        }
     }
 
     #[tokio::test]
@@ -422,4 +465,49 @@ mod test {
             assert!(matches!(next_err, L1OriginSelectorError::NotEnoughData(_)));
         }
     }
+
+    #[tokio::test]
+    #[rstest]
+    #[case::no_confs(0, 1, true)]
+    #[case::not_enough_confs(2, 2, false)]
+    #[case::enough_confs(2, 3, true)]
+    async fn test_next_l1_origin_l1_confs(
+        #[case] l1_confs: u64,
+        #[case] l1_head: u64,
+        #[case] next_adopted: bool,
+    ) {
+        // Assume an L1 slot time of 12 seconds.
+        const L1_SLOT_TIME: u64 = 12;
+
+        let cfg = Arc::new(RollupConfig {
+            block_time: 2,
+            max_sequencer_drift: 600,
+            ..Default::default()
+        });
+
+        // Initialize the provider with a chain of L1 blocks up to the L1 head.
+        let mut provider = MockOriginSelectorProvider::default();
+        for i in 0..=l1_head {
+            provider.with_block(BlockInfo {
+                parent_hash: B256::with_last_byte(i.saturating_sub(1) as u8),
+                hash: B256::with_last_byte(i as u8),
+                number: i,
+                timestamp: i * L1_SLOT_TIME,
+            });
+        }
+
+        let mut selector = L1OriginSelector::new(cfg, provider).with_l1_confs(l1_confs);
+
+        // The next L2 block is past the timestamp of the next L1 origin.
+        let unsafe_head = L2BlockInfo {
+            block_info: BlockInfo { timestamp: L1_SLOT_TIME, ..Default::default() },
+            l1_origin: NumHash { number: 0, hash: B256::ZERO },
+            seq_num: 0,
+        };
+        let next = selector.next_l1_origin(unsafe_head).await.unwrap();
+
+        let expected_epoch = if next_adopted { 1 } else { 0 };
+        assert_eq!(next.number, expected_epoch);
+        assert_eq!(next.hash, B256::with_last_byte(expected_epoch as u8));
+    }
 }
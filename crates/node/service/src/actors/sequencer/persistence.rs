@@ -0,0 +1,222 @@
+//! Persistence of the sequencer state across restarts.
+
+use crate::fs::write_atomic;
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::{
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::watch;
+
+/// An error produced when reading or writing the [`PersistedSequencerState`].
+#[derive(Debug, thiserror::Error)]
+pub enum SequencerPersistenceError {
+    /// The state file could not be read or written.
+    #[error("Failed to access the sequencer state file {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    /// The state could not be encoded, or the state file is malformed.
+    #[error("Invalid sequencer state file {0}: {1}")]
+    Json(PathBuf, serde_json::Error),
+}
+
+/// The sequencer state persisted across restarts, so that a restarted node resumes sequencing in
+/// the mode it was last put in through the admin API.
+///
+/// The file format is compatible with the `op-node` admin state file.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedSequencerState {
+    /// Whether the sequencer was active.
+    pub sequencer_started: bool,
+    /// The hash of the last block built by the sequencer.
+    ///
+    /// A sequencer persisted as active only resumes on restart if its unsafe head is still this
+    /// block, so that it never builds on top of blocks gossipped by another sequencer meanwhile.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_built_hash: Option<B256>,
+}
+
+/// Reads and writes the [`PersistedSequencerState`] to a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SequencerStatePersistence {
+    /// The path of the state file.
+    path: PathBuf,
+}
+
+impl SequencerStatePersistence {
+    /// Creates a new [`SequencerStatePersistence`] backed by the file at the given path.
+    pub const fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    /// Loads the [`PersistedSequencerState`], returning [`None`] if the state file does not exist.
+    pub fn load(&self) -> Result<Option<PersistedSequencerState>, SequencerPersistenceError> {
+        let contents = match std::fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(SequencerPersistenceError::Io(self.path.clone(), err)),
+        };
+
+        serde_json::from_slice(&contents)
+            .map(Some)
+            .map_err(|err| SequencerPersistenceError::Json(self.path.clone(), err))
+    }
+
+    /// Stores the [`PersistedSequencerState`], atomically replacing the previous one.
+    pub fn store(&self, state: &PersistedSequencerState) -> Result<(), SequencerPersistenceError> {
+        let contents = serde_json::to_vec(state)
+            .map_err(|err| SequencerPersistenceError::Json(self.path.clone(), err))?;
+        write_atomic(&self.path, &contents)
+            .map_err(|err| SequencerPersistenceError::Io(self.path.clone(), err))
+    }
+}
+
+/// Writes the [`PersistedSequencerState`] as the sequencer changes it.
+///
+/// The active state is written before [`SequencerStateWriter::store_active`] returns, so that an
+/// admin request is only acknowledged once it is durable. The last built block is written by a
+/// background task on the blocking thread pool, coalescing the blocks built while a write is in
+/// progress. All writes are serialized and write the latest state, so that the file never goes
+/// back to an older state.
+#[derive(Debug)]
+pub struct SequencerStateWriter {
+    /// The state file.
+    persistence: SequencerStatePersistence,
+    /// The latest state, written by the background task when it changes.
+    state: watch::Sender<PersistedSequencerState>,
+    /// Serializes the writes of the state file.
+    write_lock: Arc<Mutex<()>>,
+}
+
+impl SequencerStateWriter {
+    /// Creates a new [`SequencerStateWriter`] starting from the given state, and spawns the task
+    /// writing the last built blocks. The task exits when the writer is dropped.
+    pub fn spawn(persistence: SequencerStatePersistence, state: PersistedSequencerState) -> Self {
+        let (state, state_rx) = watch::channel(state);
+        let write_lock = Arc::new(Mutex::new(()));
+        tokio::spawn(write_changes(persistence.clone(), state_rx, Arc::clone(&write_lock)));
+        Self { persistence, state, write_lock }
+    }
+
+    /// Records the last block built by the sequencer, written in the background.
+    pub fn record_built(&self, hash: B256) {
+        self.state.send_modify(|state| state.last_built_hash = Some(hash));
+    }
+
+    /// Writes the active state along with the last built block, returning once it is durable.
+    pub fn store_active(&self, active: bool) -> Result<(), SequencerPersistenceError> {
+        self.state.send_if_modified(|state| {
+            state.sequencer_started = active;
+            false
+        });
+        store_latest(&self.persistence, &self.state.subscribe(), &self.write_lock)
+    }
+}
+
+/// Writes the state each time it changes, until the [`SequencerStateWriter`] is dropped.
+async fn write_changes(
+    persistence: SequencerStatePersistence,
+    mut state: watch::Receiver<PersistedSequencerState>,
+    write_lock: Arc<Mutex<()>>,
+) {
+    while state.changed().await.is_ok() {
+        let persistence = persistence.clone();
+        let state = state.clone();
+        let write_lock = Arc::clone(&write_lock);
+        let stored =
+            tokio::task::spawn_blocking(move || store_latest(&persistence, &state, &write_lock));
+        if let Ok(Err(err)) = stored.await {
+            warn!(target: "sequencer", ?err, "Failed to persist the last built block");
+        }
+    }
+}
+
+/// Writes the latest state, holding the write lock.
+fn store_latest(
+    persistence: &SequencerStatePersistence,
+    state: &watch::Receiver<PersistedSequencerState>,
+    write_lock: &Mutex<()>,
+) -> Result<(), SequencerPersistenceError> {
+    let _guard = write_lock.lock().unwrap_or_else(|err| err.into_inner());
+    let latest = *state.borrow();
+    persistence.store(&latest)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_persisted_sequencer_state_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let persistence = SequencerStatePersistence::new(dir.path().join("admin-state.json"));
+        assert_eq!(persistence.load().unwrap(), None);
+
+        let state = PersistedSequencerState {
+            sequencer_started: true,
+            last_built_hash: Some(B256::repeat_byte(0xaa)),
+        };
+        persistence.store(&state).unwrap();
+        assert_eq!(persistence.load().unwrap(), Some(state));
+
+        let stopped = PersistedSequencerState { sequencer_started: false, ..state };
+        persistence.store(&stopped).unwrap();
+        assert_eq!(persistence.load().unwrap(), Some(stopped));
+    }
+
+    #[test]
+    fn test_persisted_sequencer_state_op_node_format() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin-state.json");
+        std::fs::write(&path, r#"{"sequencerStarted":true}"#).unwrap();
+
+        let state = SequencerStatePersistence::new(path).load().unwrap().unwrap();
+        assert!(state.sequencer_started);
+        assert_eq!(state.last_built_hash, None);
+    }
+
+    #[tokio::test]
+    async fn test_sequencer_state_writer() {
+        let dir = tempfile::tempdir().unwrap();
+        let persistence = SequencerStatePersistence::new(dir.path().join("admin-state.json"));
+        let writer = SequencerStateWriter::spawn(
+            persistence.clone(),
+            PersistedSequencerState { sequencer_started: true, last_built_hash: None },
+        );
+
+        // The built blocks are written in the background.
+        writer.record_built(B256::repeat_byte(0x01));
+        writer.record_built(B256::repeat_byte(0x02));
+        let expected = PersistedSequencerState {
+            sequencer_started: true,
+            last_built_hash: Some(B256::repeat_byte(0x02)),
+        };
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while persistence.load().ok().flatten() != Some(expected) {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .unwrap();
+
+        // The active state is written before returning, along with the last built block.
+        writer.store_active(false).unwrap();
+        assert_eq!(
+            persistence.load().unwrap(),
+            Some(PersistedSequencerState { sequencer_started: false, ..expected })
+        );
+    }
+
+    #[test]
+    fn test_persisted_sequencer_state_malformed() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("admin-state.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        assert!(matches!(
+            SequencerStatePersistence::new(path).load(),
+            Err(SequencerPersistenceError::Json(..))
+        ));
+    }
+}
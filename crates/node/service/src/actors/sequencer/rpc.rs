@@ -1,9 +1,10 @@
 //! The RPC server for the sequencer actor.
 //! Mostly handles queries from the admin rpc.
 
+use alloy_primitives::B256;
 use kona_derive::AttributesBuilder;
 use kona_protocol::L2BlockInfo;
-use kona_rpc::SequencerAdminQuery;
+use kona_rpc::{SequencerAdminError, SequencerAdminQuery, SequencerStatus};
 use tokio::sync::watch;
 
 use crate::actors::sequencer::actor::SequencerActorState;
@@ -23,20 +24,36 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
         &mut self,
         query: SequencerAdminQuery,
         unsafe_head: &mut watch::Receiver<L2BlockInfo>,
+        safe_head: &watch::Receiver<L2BlockInfo>,
     ) -> Result<(), SequencerRpcError> {
         match query {
             SequencerAdminQuery::SequencerActive(tx) => {
                 tx.send(self.is_active).map_err(|_| SequencerRpcError::SendResponse)?;
             }
-            SequencerAdminQuery::StartSequencer => {
-                info!(target: "sequencer", "Starting sequencer");
-                self.is_active = true;
+            SequencerAdminQuery::SequencerStatus(tx) => {
+                let unsafe_head = *unsafe_head.borrow();
+                let safe_head = *safe_head.borrow();
+                let status = SequencerStatus {
+                    active: self.is_active,
+                    recovery_mode: self.is_recovery_mode,
+                    conductor_enabled: self.conductor.is_some(),
+                    unsafe_head: unsafe_head.block_info.id(),
+                    safe_head: safe_head.block_info.id(),
+                    l1_origin: self.origin_selector.current().map(|origin| origin.id()),
+                    max_safe_lag: self.max_safe_lag,
+                    safe_lag_exceeded: self.safe_lag_exceeded(&unsafe_head, &safe_head),
+                    l1_confs: self.origin_selector.l1_confs(),
+                };
+                tx.send(status).map_err(|_| SequencerRpcError::SendResponse)?;
+            }
+            SequencerAdminQuery::StartSequencer(expected, tx) => {
+                let local = unsafe_head.borrow().hash();
+                tx.send(self.start_sequencer(expected, local).await)
+                    .map_err(|_| SequencerRpcError::SendResponse)?;
             }
             SequencerAdminQuery::StopSequencer(tx) => {
-                info!(target: "sequencer", "Stopping sequencer");
-                self.is_active = false;
-
-                tx.send(unsafe_head.borrow().hash())
+                let local = unsafe_head.borrow().hash();
+                tx.send(self.stop_sequencer().map(|_| local))
                     .map_err(|_| SequencerRpcError::SendResponse)?;
             }
             SequencerAdminQuery::ConductorEnabled(tx) => {
@@ -58,4 +75,51 @@ impl<AB: AttributesBuilder> SequencerActorState<AB> {
 
         Ok(())
     }
+
+    /// Starts the sequencer on top of the `expected` unsafe head, refusing to start if the
+    /// `local` unsafe head differs. The new state is persisted before the sequencer starts.
+    pub(super) async fn start_sequencer(
+        &mut self,
+        expected: B256,
+        local: B256,
+    ) -> Result<(), SequencerAdminError> {
+        if self.is_active {
+            warn!(target: "sequencer", "Sequencer already running");
+            return Err(SequencerAdminError::AlreadyActive);
+        }
+        if expected != local {
+            error!(target: "sequencer", %expected, %local, "Refusing to start sequencer, unsafe head mismatch");
+            return Err(SequencerAdminError::UnsafeHeadMismatch { expected, local });
+        }
+        if !self.signer_available() {
+            error!(target: "sequencer", "Refusing to start sequencer, block signer is unavailable");
+            return Err(SequencerAdminError::SignerUnavailable);
+        }
+
+        self.persist(true).map_err(|err| {
+            error!(target: "sequencer", ?err, "Failed to persist sequencer state");
+            SequencerAdminError::Persistence(err.to_string())
+        })?;
+
+        info!(target: "sequencer", unsafe_head = %local, "Starting sequencer");
+        self.is_active = true;
+        Ok(())
+    }
+
+    /// Stops the sequencer. The new state is persisted before the sequencer stops.
+    pub(super) fn stop_sequencer(&mut self) -> Result<(), SequencerAdminError> {
+        if !self.is_active {
+            warn!(target: "sequencer", "Sequencer not running");
+            return Err(SequencerAdminError::NotActive);
+        }
+
+        self.persist(false).map_err(|err| {
+            error!(target: "sequencer", ?err, "Failed to persist sequencer state");
+            SequencerAdminError::Persistence(err.to_string())
+        })?;
+
+        info!(target: "sequencer", "Stopping sequencer");
+        self.is_active = false;
+        Ok(())
+    }
 }
@@ -0,0 +1,135 @@
+//! Background health tracking of the sequencer's [`BlockSigner`].
+
+use crate::BlockSigner;
+use std::{sync::Arc, time::Duration};
+use tokio::{sync::watch, time::MissedTickBehavior};
+use tokio_util::sync::CancellationToken;
+
+/// The interval at which the health of the block signer is checked.
+pub(super) const SIGNER_HEALTH_CHECK_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The health of a [`BlockSigner`], checked periodically by a background task.
+///
+/// Remote signers are checked over RPC, so the sequencer reads the last known health rather than
+/// adding a round-trip to the signer to every block build. The signer is considered unhealthy
+/// until its first check succeeds.
+#[derive(Debug, Clone)]
+pub(super) struct SignerHealth {
+    /// The last known health of the signer.
+    healthy: watch::Receiver<bool>,
+}
+
+impl SignerHealth {
+    /// Spawns a task checking the health of the signer every `interval`, until cancelled.
+    pub(super) fn spawn(
+        signer: Arc<dyn BlockSigner>,
+        interval: Duration,
+        cancellation: CancellationToken,
+    ) -> Self {
+        let (healthy_tx, healthy) = watch::channel(false);
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = cancellation.cancelled() => return,
+                    _ = ticker.tick() => {}
+                }
+
+                let was_healthy = *healthy_tx.borrow();
+                let healthy = match signer.health_check().await {
+                    Ok(()) => {
+                        if !was_healthy {
+                            info!(target: "sequencer", "Block signer is healthy");
+                        }
+                        true
+                    }
+                    Err(err) => {
+                        if was_healthy {
+                            error!(target: "sequencer", ?err, "Block signer health check failed");
+                        } else {
+                            debug!(target: "sequencer", ?err, "Block signer is still unhealthy");
+                        }
+                        false
+                    }
+                };
+                healthy_tx
+                    .send_if_modified(|current| std::mem::replace(current, healthy) != healthy);
+            }
+        });
+        Self { healthy }
+    }
+
+    /// Returns the last known health of the signer.
+    pub(super) fn is_healthy(&self) -> bool {
+        *self.healthy.borrow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BlockSignerError;
+    use alloy_primitives::{Address, Signature, U256};
+    use async_trait::async_trait;
+    use op_alloy_rpc_types_engine::PayloadHash;
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+    /// A signer whose health is toggled by the test.
+    #[derive(Debug, Default)]
+    struct ToggleSigner {
+        healthy: AtomicBool,
+        checks: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BlockSigner for ToggleSigner {
+        fn address(&self) -> Option<Address> {
+            None
+        }
+
+        async fn health_check(&self) -> Result<(), BlockSignerError> {
+            self.checks.fetch_add(1, Ordering::SeqCst);
+            if self.healthy.load(Ordering::SeqCst) {
+                Ok(())
+            } else {
+                Err(BlockSignerError::InvalidSignature(
+                    alloy_primitives::SignatureError::InvalidParity(0),
+                ))
+            }
+        }
+
+        async fn sign_block(&self, _: PayloadHash, _: u64) -> Result<Signature, BlockSignerError> {
+            Ok(Signature::new(U256::ZERO, U256::ZERO, false))
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_signer_health_is_cached() {
+        let signer = Arc::new(ToggleSigner::default());
+        signer.healthy.store(true, Ordering::SeqCst);
+        let cancellation = CancellationToken::new();
+        let interval = Duration::from_secs(2);
+        let health = SignerHealth::spawn(signer.clone(), interval, cancellation.clone());
+
+        // Unhealthy until the first check completes.
+        assert!(!health.is_healthy());
+        let mut healthy = health.healthy.clone();
+        healthy.changed().await.unwrap();
+        assert!(health.is_healthy());
+
+        // Reading the health does not check the signer.
+        let checks = signer.checks.load(Ordering::SeqCst);
+        for _ in 0..10 {
+            assert!(health.is_healthy());
+        }
+        assert_eq!(signer.checks.load(Ordering::SeqCst), checks);
+
+        // The signer becomes unhealthy, which is picked up by the next check.
+        signer.healthy.store(false, Ordering::SeqCst);
+        healthy.changed().await.unwrap();
+        assert!(!health.is_healthy());
+
+        cancellation.cancel();
+    }
+}
@@ -0,0 +1,57 @@
+//! Durable writes of the files persisting the node state across restarts.
+
+use std::{fs::File, io::Write, path::Path};
+
+/// Atomically replaces the contents of the file at `path`.
+///
+/// The contents are written and synced to a temporary file next to `path`, which is then renamed
+/// over `path`. The parent directory is synced afterwards, so that the rename itself survives a
+/// crash. A crash at any point leaves either the previous or the new contents behind, never a
+/// truncated file.
+pub(crate) fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let tmp_path = path.with_extension("tmp");
+    let mut file = File::create(&tmp_path)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    drop(file);
+
+    std::fs::rename(&tmp_path, path)?;
+    sync_parent_dir(path)
+}
+
+/// Syncs the directory containing `path`, persisting the directory entries changed by a rename.
+#[cfg(unix)]
+fn sync_parent_dir(path: &Path) -> std::io::Result<()> {
+    let parent = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    File::open(parent)?.sync_all()
+}
+
+/// Directories cannot be opened as files outside of unix, where the rename is left to the
+/// filesystem to persist.
+#[cfg(not(unix))]
+const fn sync_parent_dir(_: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomic() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("state.json");
+
+        write_atomic(&path, b"first").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"first");
+
+        write_atomic(&path, b"second").unwrap();
+        assert_eq!(std::fs::read(&path).unwrap(), b"second");
+
+        // The temporary file is renamed over the target.
+        assert!(!path.with_extension("tmp").exists());
+    }
+}
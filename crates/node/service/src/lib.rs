@@ -9,22 +9,33 @@
 #[macro_use]
 extern crate tracing;
 
+mod fs;
+
 mod service;
 pub use service::{InteropMode, NodeMode, RollupNode, RollupNodeBuilder, RollupNodeService};
 
 mod actors;
 pub use actors::{
-    AttributesBuilderConfig, CancellableContext, ConductorClient, ConductorError, DerivationActor,
-    DerivationBuilder, DerivationContext, DerivationError, DerivationInboundChannels,
-    DerivationState, EngineActor, EngineBuilder, EngineContext, EngineError, EngineInboundData,
+    AttributesBuilderConfig, BlockPayloadArgs, BlockSigner, BlockSignerError, CancellableContext,
+    ClientCert, ConductorClient, ConductorError, DEFAULT_REMOTE_SIGNER_TIMEOUT, DerivationActor,
+    DerivationBuilder, DerivationCheckpointConfig, DerivationCheckpointError,
+    DerivationCheckpointStore, DerivationCheckpointer, DerivationContext, DerivationError,
+    DerivationEventRecorder, DerivationInboundChannels, DerivationState, EngineActor,
+    EngineBuilder, EngineContext, EngineError, EngineInboundData, FinalityIndex,
+    FinalityIndexError, ForcedTransaction, ForcedTransactionsHook, GasLimitHook,
     InboundDerivationMessage, L1OriginSelector, L1OriginSelectorError, L1OriginSelectorProvider,
     L1WatcherRpc, L1WatcherRpcContext, L1WatcherRpcError, L1WatcherRpcInboundChannels,
-    L1WatcherRpcState, L2Finalizer, NetworkActor, NetworkActorError, NetworkBuilder,
-    NetworkBuilderError, NetworkConfig, NetworkContext, NetworkDriver, NetworkDriverError,
-    NetworkHandler, NetworkInboundData, NodeActor, PipelineBuilder, RpcActor, RpcActorError,
-    RpcContext, SequencerActor, SequencerActorError, SequencerBuilder, SequencerConfig,
-    SequencerContext, SequencerInboundData, SupervisorActor, SupervisorActorContext,
-    SupervisorActorError, SupervisorExt, SupervisorInboundData, SupervisorRpcServerExt,
+    L1WatcherRpcState, L2Finalizer, LocalBlockSigner, NetworkActor, NetworkActorError,
+    NetworkBuilder, NetworkBuilderError, NetworkConfig, NetworkContext, NetworkDriver,
+    NetworkDriverError, NetworkHandler, NetworkInboundData, NodeActor, PayloadAttributesContext,
+    PayloadAttributesHook, PayloadAttributesHookConfig, PayloadAttributesHookError,
+    PayloadAttributesHookKind, PersistedSequencerState, PipelineBuilder, PublishRetryTicker,
+    RecoveryModeHook, RemoteBlockSigner, RemoteSignerConfig, RpcActor, RpcActorError, RpcContext,
+    SequencerActor, SequencerActorError, SequencerBuilder, SequencerConfig, SequencerContext,
+    SequencerInboundData, SequencerPersistenceError, SequencerStatePersistence,
+    SequencerStateWriter, SupervisorActor, SupervisorActorContext, SupervisorActorError,
+    SupervisorExt, SupervisorInboundData, SupervisorRpcServerExt, SystemConfigHistory,
+    SystemConfigQueryServer,
 };
 
 mod metrics;
@@ -29,6 +29,25 @@ impl Metrics {
     pub const SEQUENCER_CONDUCTOR_COMMITMENT_DURATION: &str =
         "kona_node_sequencer_conductor_commitment_duration";
 
+    /// Identifier for the counter of block building attempts skipped because the block signer was
+    /// unavailable.
+    pub const SEQUENCER_SIGNER_UNAVAILABLE: &str = "kona_node_sequencer_signer_unavailable";
+
+    /// Identifier for the gauge that tracks the number of L2 blocks between the unsafe and safe
+    /// heads, as seen by the sequencer.
+    pub const SEQUENCER_SAFE_LAG: &str = "kona_node_sequencer_safe_lag";
+
+    /// Identifier for the counter of block building attempts skipped because the unsafe head is
+    /// too far ahead of the safe head.
+    pub const SEQUENCER_SAFE_LAG_STALLS: &str = "kona_node_sequencer_safe_lag_stalls";
+
+    /// Identifier for the counter of times the next L1 origin was not adopted because it did not
+    /// have enough L1 confirmations.
+    pub const SEQUENCER_L1_ORIGIN_UNCONFIRMED: &str = "kona_node_sequencer_l1_origin_unconfirmed";
+
+    /// Identifier for the gauge that tracks failures to sign unsafe blocks before gossipping them.
+    pub const UNSAFE_BLOCK_SIGNING_ERROR: &str = "kona_node_unsafe_block_signing_errors";
+
     /// Initializes metrics for the node service.
     ///
     /// This does two things:
@@ -75,6 +94,39 @@ impl Metrics {
             Self::SEQUENCER_CONDUCTOR_COMMITMENT_DURATION,
             "Duration of the sequencer conductor commitment"
         );
+
+        // Sequencer signer unavailable
+        metrics::describe_counter!(
+            Self::SEQUENCER_SIGNER_UNAVAILABLE,
+            metrics::Unit::Count,
+            "Number of block building attempts skipped because the block signer was unavailable"
+        );
+
+        // Sequencer safe lag
+        metrics::describe_gauge!(
+            Self::SEQUENCER_SAFE_LAG,
+            "Number of L2 blocks between the unsafe and safe heads"
+        );
+
+        // Sequencer safe lag stalls
+        metrics::describe_counter!(
+            Self::SEQUENCER_SAFE_LAG_STALLS,
+            metrics::Unit::Count,
+            "Number of block building attempts skipped because the max safe lag was reached"
+        );
+
+        // Sequencer unconfirmed L1 origins
+        metrics::describe_counter!(
+            Self::SEQUENCER_L1_ORIGIN_UNCONFIRMED,
+            metrics::Unit::Count,
+            "Number of times the next L1 origin was not adopted for lack of L1 confirmations"
+        );
+
+        // Unsafe block signing errors
+        metrics::describe_gauge!(
+            Self::UNSAFE_BLOCK_SIGNING_ERROR,
+            "Number of unsafe blocks that could not be signed before being gossipped"
+        );
     }
 
     /// Initializes metrics to `0` so they can be queried immediately by consumers of prometheus
@@ -86,5 +138,20 @@ impl Metrics {
 
         // Derivation critical error
         kona_macros::set!(counter, Self::DERIVATION_CRITICAL_ERROR, 0);
+
+        // Sequencer signer unavailable
+        kona_macros::set!(counter, Self::SEQUENCER_SIGNER_UNAVAILABLE, 0);
+
+        // Sequencer safe lag
+        kona_macros::set!(gauge, Self::SEQUENCER_SAFE_LAG, 0);
+
+        // Sequencer safe lag stalls
+        kona_macros::set!(counter, Self::SEQUENCER_SAFE_LAG_STALLS, 0);
+
+        // Sequencer unconfirmed L1 origins
+        kona_macros::set!(counter, Self::SEQUENCER_L1_ORIGIN_UNCONFIRMED, 0);
+
+        // Unsafe block signing errors
+        kona_macros::set!(gauge, Self::UNSAFE_BLOCK_SIGNING_ERROR, 0);
     }
 }
@@ -10,7 +10,7 @@ use crate::{
     service::spawn_and_wait,
 };
 use async_trait::async_trait;
-use kona_derive::{AttributesBuilder, Pipeline, SignalReceiver};
+use kona_derive::{AttributesBuilder, Pipeline, SignalReceiver, StageCheckpoint};
 use std::fmt::Display;
 use tokio_util::sync::CancellationToken;
 
@@ -51,7 +51,7 @@ pub trait RollupNodeService {
         >;
 
     /// The type of derivation pipeline to use for the service.
-    type DerivationPipeline: Pipeline + SignalReceiver + Send + Sync + 'static;
+    type DerivationPipeline: Pipeline + SignalReceiver + StageCheckpoint + Send + Sync + 'static;
 
     /// The type of derivation actor to use for the service.
     type DerivationActor: NodeActor<
@@ -131,6 +131,7 @@ pub trait RollupNodeService {
                 l1_head_updates_tx,
                 engine_l2_safe_head_tx,
                 el_sync_complete_tx,
+                derivation_queries_tx,
             },
             derivation,
         ) = Self::DerivationActor::build(self.derivation_builder());
@@ -175,6 +176,9 @@ pub trait RollupNodeService {
             .then_some(Self::SequencerActor::build(self.sequencer_builder()))
             .unzip();
 
+        // The sequencer observes the safe head to enforce the max safe lag.
+        let sequencer_safe_head_rx = engine_l2_safe_head_tx.subscribe();
+
         spawn_and_wait!(
             cancellation,
             actors = [
@@ -186,6 +190,7 @@ pub trait RollupNodeService {
                         network_admin: net_admin_rpc,
                         sequencer_admin: sequencer_inbound_data.as_ref().map(|s| s.admin_query_tx.clone()),
                         l1_watcher_queries: da_watcher_rpc,
+                        derivation_queries: derivation_queries_tx,
                         engine_query: engine_rpc,
                     }
                 )),
@@ -197,6 +202,7 @@ pub trait RollupNodeService {
                             "`build_request_tx` not set while in sequencer mode. This should never happen.",
                         ),
                         gossip_payload_tx,
+                        safe_head_rx: sequencer_safe_head_rx,
                         cancellation: cancellation.clone(),
                     })
                 ),
@@ -1,6 +1,9 @@
 //! Contains the builder for the [`RollupNode`].
 
-use crate::{EngineBuilder, InteropMode, NetworkConfig, NodeMode, RollupNode, SequencerConfig};
+use crate::{
+    DerivationCheckpointConfig, EngineBuilder, InteropMode, NetworkConfig, NodeMode,
+    PayloadAttributesHook, RollupNode, SequencerConfig,
+};
 use alloy_primitives::Bytes;
 use alloy_provider::RootProvider;
 use alloy_rpc_client::RpcClient;
@@ -11,12 +14,14 @@ use alloy_transport_http::{
 };
 use http_body_util::Full;
 use op_alloy_network::Optimism;
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
 use tower::ServiceBuilder;
 use url::Url;
 
+use kona_derive::DerivationEventSink;
+use kona_engine::SecondaryEngineConfig;
 use kona_genesis::RollupConfig;
-use kona_providers_alloy::OnlineBeaconClient;
+use kona_providers_alloy::{OnlineBeaconClient, OnlineDataAvailabilityRegistry, PrefetchConfig};
 use kona_rpc::{RpcBuilder, SupervisorRpcConfig};
 
 /// The [`RollupNodeBuilder`] is used to construct a [`RollupNode`] service.
@@ -34,6 +39,8 @@ pub struct RollupNodeBuilder {
     l2_provider_rpc_url: Option<Url>,
     /// The JWT secret.
     jwt_secret: Option<JwtSecret>,
+    /// The optional secondary execution layer verifying the engine calls.
+    secondary_engine: Option<SecondaryEngineConfig>,
     /// The [`NetworkConfig`].
     p2p_config: Option<NetworkConfig>,
     /// An RPC Configuration.
@@ -42,10 +49,22 @@ pub struct RollupNodeBuilder {
     supervisor_rpc_config: SupervisorRpcConfig,
     /// The [`SequencerConfig`].
     sequencer_config: Option<SequencerConfig>,
+    /// Custom [`PayloadAttributesHook`]s applied by the sequencer.
+    payload_attributes_hooks: Vec<Arc<dyn PayloadAttributesHook>>,
     /// The mode to run the node in.
     mode: NodeMode,
     /// Whether to run the node in interop mode.
     interop_mode: InteropMode,
+    /// The optional derivation pipeline checkpoint configuration.
+    derivation_checkpoint: Option<DerivationCheckpointConfig>,
+    /// The optional sink of the derivation pipeline events.
+    derivation_event_sink: Option<Arc<dyn DerivationEventSink>>,
+    /// The configuration of the L1 data prefetching of the derivation pipeline.
+    l1_prefetch: PrefetchConfig,
+    /// The registry the data availability provider of the derivation pipeline is selected from.
+    da_registry: OnlineDataAvailabilityRegistry,
+    /// The optional path of the finality index file.
+    finality_index: Option<PathBuf>,
 }
 
 impl RollupNodeBuilder {
@@ -94,6 +113,11 @@ impl RollupNodeBuilder {
         Self { jwt_secret: Some(jwt_secret), ..self }
     }
 
+    /// Sets the secondary execution layer to which the engine calls are mirrored.
+    pub fn with_secondary_engine(self, secondary_engine: Option<SecondaryEngineConfig>) -> Self {
+        Self { secondary_engine, ..self }
+    }
+
     /// Appends the P2P [`NetworkConfig`] to the builder.
     pub fn with_p2p_config(self, config: NetworkConfig) -> Self {
         Self { p2p_config: Some(config), ..self }
@@ -109,6 +133,48 @@ impl RollupNodeBuilder {
         Self { sequencer_config: Some(sequencer_config), ..self }
     }
 
+    /// Appends a custom [`PayloadAttributesHook`], applied by the sequencer after the hooks of
+    /// the [`SequencerConfig`].
+    pub fn with_payload_attributes_hook(mut self, hook: Arc<dyn PayloadAttributesHook>) -> Self {
+        self.payload_attributes_hooks.push(hook);
+        self
+    }
+
+    /// Sets the [`DerivationCheckpointConfig`], enabling derivation pipeline checkpoints.
+    pub fn with_derivation_checkpoint(
+        self,
+        derivation_checkpoint: Option<DerivationCheckpointConfig>,
+    ) -> Self {
+        Self { derivation_checkpoint, ..self }
+    }
+
+    /// Sets the [`DerivationEventSink`] receiving the events emitted by the derivation pipeline.
+    pub fn with_derivation_event_sink(self, event_sink: Arc<dyn DerivationEventSink>) -> Self {
+        Self { derivation_event_sink: Some(event_sink), ..self }
+    }
+
+    /// Sets the [`PrefetchConfig`] of the L1 data read by the derivation pipeline. Prefetching is
+    /// disabled by default.
+    pub fn with_l1_prefetch(self, l1_prefetch: PrefetchConfig) -> Self {
+        Self { l1_prefetch, ..self }
+    }
+
+    /// Sets the [`OnlineDataAvailabilityRegistry`] the data availability provider of the
+    /// derivation pipeline is selected from. Rollups matching none of its entries read their
+    /// batches from L1, as does the default registry.
+    pub fn with_data_availability_registry(
+        self,
+        da_registry: OnlineDataAvailabilityRegistry,
+    ) -> Self {
+        Self { da_registry, ..self }
+    }
+
+    /// Sets the path of the finality index file, persisting the derived L2 blocks awaiting
+    /// finalization across restarts.
+    pub fn with_finality_index(self, finality_index: Option<PathBuf>) -> Self {
+        Self { finality_index, ..self }
+    }
+
     /// Assembles the [`RollupNode`] service.
     ///
     /// By default, the supervisor RPC is disabled.
@@ -150,6 +216,8 @@ impl RollupNodeBuilder {
             engine_url: self.l2_engine_rpc_url.expect("missing l2 engine rpc url"),
             jwt_secret,
             mode: self.mode,
+            secondary: self.secondary_engine,
+            finality_index: self.finality_index,
         };
 
         let p2p_config = self.p2p_config.expect("P2P config not set");
@@ -170,8 +238,13 @@ impl RollupNodeBuilder {
             rpc_builder: self.rpc_config,
             p2p_config,
             sequencer_config,
+            payload_attributes_hooks: self.payload_attributes_hooks,
             // By default, the supervisor rpc config is disabled.
             supervisor_rpc: self.supervisor_rpc_config,
+            derivation_checkpoint: self.derivation_checkpoint,
+            derivation_event_sink: self.derivation_event_sink,
+            l1_prefetch: self.l1_prefetch,
+            da_registry: self.da_registry,
         }
     }
 }
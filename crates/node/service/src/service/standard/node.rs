@@ -1,19 +1,21 @@
 //! Contains the [`RollupNode`] implementation.
 use crate::{
-    DerivationActor, DerivationBuilder, EngineActor, EngineBuilder, InteropMode, L1WatcherRpc,
-    L1WatcherRpcState, NetworkActor, NetworkBuilder, NetworkConfig, NodeMode, RollupNodeBuilder,
-    RollupNodeService, RpcActor, SequencerConfig, SupervisorActor, SupervisorRpcServerExt,
+    DerivationActor, DerivationBuilder, DerivationCheckpointConfig, EngineActor, EngineBuilder,
+    InteropMode, L1WatcherRpc, L1WatcherRpcState, NetworkActor, NetworkBuilder, NetworkConfig,
+    NodeMode, PayloadAttributesHook, RollupNodeBuilder, RollupNodeService, RpcActor,
+    SequencerConfig, SupervisorActor, SupervisorRpcServerExt,
     actors::{SequencerActor, SequencerBuilder},
 };
 use alloy_provider::RootProvider;
 use async_trait::async_trait;
-use kona_derive::StatefulAttributesBuilder;
+use kona_derive::{DerivationEventSink, StatefulAttributesBuilder};
 use op_alloy_network::Optimism;
 use std::sync::Arc;
 
 use kona_genesis::RollupConfig;
 use kona_providers_alloy::{
-    AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlinePipeline,
+    AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlineDataAvailabilityRegistry,
+    OnlinePipeline, PrefetchConfig,
 };
 use kona_rpc::{RpcBuilder, SupervisorRpcConfig, SupervisorRpcServer};
 
@@ -39,8 +41,18 @@ pub struct RollupNode {
     pub(crate) p2p_config: NetworkConfig,
     /// The [`SequencerConfig`] for the node.
     pub(crate) sequencer_config: SequencerConfig,
+    /// Custom [`PayloadAttributesHook`]s applied by the sequencer.
+    pub(crate) payload_attributes_hooks: Vec<Arc<dyn PayloadAttributesHook>>,
     /// The supervisor rpc server config.
     pub(crate) supervisor_rpc: SupervisorRpcConfig,
+    /// The optional derivation pipeline checkpoint configuration.
+    pub(crate) derivation_checkpoint: Option<DerivationCheckpointConfig>,
+    /// The optional sink of the derivation pipeline events.
+    pub(crate) derivation_event_sink: Option<Arc<dyn DerivationEventSink>>,
+    /// The configuration of the L1 data prefetching of the derivation pipeline.
+    pub(crate) l1_prefetch: PrefetchConfig,
+    /// The registry the data availability provider of the derivation pipeline is selected from.
+    pub(crate) da_registry: OnlineDataAvailabilityRegistry,
 }
 
 impl RollupNode {
@@ -103,6 +115,8 @@ impl RollupNodeService for RollupNode {
             rollup_cfg: self.config.clone(),
             l1_provider: self.l1_provider.clone(),
             l2_provider: self.l2_provider.clone(),
+            block_signer: self.p2p_config.block_signer.clone(),
+            hooks: self.payload_attributes_hooks.clone(),
         }
     }
 
@@ -121,6 +135,10 @@ impl RollupNodeService for RollupNode {
             l2_provider: self.l2_provider.clone(),
             rollup_config: self.config.clone(),
             interop_mode: self.interop_mode,
+            checkpoint: self.derivation_checkpoint.clone(),
+            event_sink: self.derivation_event_sink.clone(),
+            prefetch: self.l1_prefetch,
+            da_registry: self.da_registry.clone(),
         }
     }
 }
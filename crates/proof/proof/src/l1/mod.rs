@@ -1,7 +1,10 @@
 //! Contains the L1 constructs of the proof, backed by the preimage oracle ABI as a data source.
 
 mod pipeline;
-pub use pipeline::{OraclePipeline, ProviderAttributesBuilder, ProviderDerivationPipeline};
+pub use pipeline::{
+    OracleDataAvailabilityRegistry, OraclePipeline, ProviderAttributesBuilder,
+    ProviderDerivationPipeline,
+};
 
 mod blob_provider;
 pub use blob_provider::{OracleBlobProvider, ROOTS_OF_UNITY};
@@ -1,13 +1,17 @@
 //! Contains an oracle-backed pipeline.
 
-use crate::FlushableCache;
+use crate::{
+    FlushableCache,
+    l1::{OracleBlobProvider, OracleL1ChainProvider},
+};
 use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_derive::{
-    ChainProvider, DataAvailabilityProvider, DerivationPipeline, L2ChainProvider, OriginProvider,
-    Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult, PolledAttributesQueueStage,
-    ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder, StepResult,
+    ChainProvider, DataAvailabilityProvider, DataAvailabilityRegistry, DerivationPipeline,
+    L2ChainProvider, OriginProvider, Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult,
+    PolledAttributesQueueStage, ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder,
+    StepResult,
 };
 use kona_driver::{DriverPipeline, PipelineCursor};
 use kona_genesis::{RollupConfig, SystemConfig};
@@ -25,6 +29,10 @@ pub type ProviderDerivationPipeline<L1, L2, DA> = DerivationPipeline<
 /// pipeline.
 pub type ProviderAttributesBuilder<L1, L2> = StatefulAttributesBuilder<L1, L2>;
 
+/// A registry of the data availability providers of the oracle-backed pipeline.
+pub type OracleDataAvailabilityRegistry<O> =
+    DataAvailabilityRegistry<OracleL1ChainProvider<O>, OracleBlobProvider<O>>;
+
 /// An oracle-backed derivation pipeline.
 #[derive(Debug)]
 pub struct OraclePipeline<O, L1, L2, DA>
@@ -32,7 +40,7 @@ where
     O: CommsClient + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     /// The internal derivation pipeline.
     pub pipeline: ProviderDerivationPipeline<L1, L2, DA>,
@@ -45,7 +53,7 @@ where
     O: CommsClient + FlushableCache + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     /// Constructs a new oracle-backed derivation pipeline.
     pub async fn new(
@@ -97,7 +105,7 @@ where
     O: CommsClient + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     /// Flushes the cache on re-org.
     fn flush(&mut self) {
@@ -111,7 +119,7 @@ where
     O: CommsClient + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     /// Receives a signal from the driver.
     async fn signal(&mut self, signal: Signal) -> PipelineResult<()> {
@@ -124,7 +132,7 @@ where
     O: CommsClient + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     /// Returns the optional L1 [BlockInfo] origin.
     fn origin(&self) -> Option<BlockInfo> {
@@ -137,7 +145,7 @@ where
     O: CommsClient + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     type Item = OpAttributesWithParent;
 
@@ -152,7 +160,7 @@ where
     O: CommsClient + FlushableCache + Send + Sync + Debug,
     L1: ChainProvider + Send + Sync + Debug + Clone,
     L2: L2ChainProvider + Send + Sync + Debug + Clone,
-    DA: DataAvailabilityProvider + Send + Sync + Debug + Clone,
+    DA: DataAvailabilityProvider + Send + Sync + Debug,
 {
     /// Peeks at the next [OpAttributesWithParent] from the pipeline.
     fn peek(&self) -> Option<&OpAttributesWithParent> {
@@ -120,6 +120,7 @@ pub enum PipelineErrorKind {
 /// - [`Self::SystemConfigUpdate`]: System configuration update failures
 /// - [`Self::AttributesBuilder`]: Block attribute construction failures
 /// - [`Self::Provider`]: External provider communication failures
+/// - [`Self::InvalidCheckpoint`]: Pipeline checkpoint encoding or decoding failures
 #[derive(Error, Debug, PartialEq, Eq)]
 pub enum PipelineError {
     /// End of file: no more data available from the channel bank.
@@ -270,6 +271,18 @@ pub enum PipelineError {
     /// It indicates a protocol version mismatch or configuration issue.
     #[error("Unsupported signal")]
     UnsupportedSignal,
+    /// A pipeline checkpoint could not be taken or restored.
+    ///
+    /// This error occurs when the buffered data of a stage cannot be encoded into a
+    /// [`PipelineCheckpoint`], or when a checkpoint contains data that cannot be decoded.
+    ///
+    /// # Recovery
+    /// The checkpoint should be discarded, and the pipeline reset to derive from the L1 origin
+    /// of the safe head.
+    ///
+    /// [`PipelineCheckpoint`]: crate::PipelineCheckpoint
+    #[error("Invalid pipeline checkpoint: {0}")]
+    InvalidCheckpoint(String),
 }
 
 impl PipelineError {
@@ -29,7 +29,10 @@ pub use pipeline::{
 };
 
 mod sources;
-pub use sources::{BlobData, BlobSource, CalldataSource, EthereumDataSource};
+pub use sources::{
+    BlobData, BlobSource, CalldataSource, DataAvailabilityKey, DataAvailabilityRegistry,
+    DynDataAvailabilityProvider, ETHEREUM_DATA_AVAILABILITY, EthereumDataSource,
+};
 
 mod stages;
 pub use stages::{
@@ -42,12 +45,16 @@ pub use stages::{
 mod traits;
 pub use traits::{
     AttributesBuilder, AttributesProvider, BatchValidationProviderDerive, BlobProvider,
-    ChainProvider, DataAvailabilityProvider, L2ChainProvider, NextAttributes, OriginAdvancer,
-    OriginProvider, Pipeline, ResetProvider, SignalReceiver,
+    ChainProvider, DataAvailabilityProvider, DerivationEventSink, L2ChainProvider, NextAttributes,
+    OriginAdvancer, OriginProvider, Pipeline, ResetProvider, SignalReceiver, StageCheckpoint,
 };
 
 mod types;
-pub use types::{ActivationSignal, PipelineResult, ResetSignal, Signal, StepResult};
+pub use types::{
+    ActivationSignal, BatchCheckpoint, BatchReaderCheckpoint, ChannelCheckpoint, ChannelDropReason,
+    DerivationEvent, FrameDropReason, PipelineCheckpoint, PipelineResult, ResetSignal, Signal,
+    StepResult,
+};
 
 mod metrics;
 pub use metrics::Metrics;
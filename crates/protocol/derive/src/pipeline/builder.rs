@@ -2,9 +2,9 @@
 
 use crate::{
     AttributesBuilder, AttributesQueue, BatchProvider, BatchStream, ChainProvider, ChannelProvider,
-    ChannelReader, DataAvailabilityProvider, DerivationPipeline, FrameQueue,
+    ChannelReader, DataAvailabilityProvider, DerivationEventSink, DerivationPipeline, FrameQueue,
     IndexedAttributesQueueStage, IndexedTraversal, L1Retrieval, L2ChainProvider,
-    PolledAttributesQueueStage, PollingTraversal,
+    PolledAttributesQueueStage, PollingTraversal, types::EventEmitter,
 };
 use alloc::sync::Arc;
 use core::fmt::Debug;
@@ -26,6 +26,7 @@ where
     builder: Option<B>,
     origin: Option<BlockInfo>,
     rollup_config: Option<Arc<RollupConfig>>,
+    event_sink: Option<Arc<dyn DerivationEventSink>>,
 }
 
 impl<B, P, T, D> Default for PipelineBuilder<B, P, T, D>
@@ -43,6 +44,7 @@ where
             builder: None,
             origin: None,
             rollup_config: None,
+            event_sink: None,
         }
     }
 }
@@ -95,6 +97,14 @@ where
         self
     }
 
+    /// Sets the sink receiving the [`DerivationEvent`]s emitted by the pipeline stages.
+    ///
+    /// [`DerivationEvent`]: crate::DerivationEvent
+    pub fn event_sink(mut self, event_sink: Arc<dyn DerivationEventSink>) -> Self {
+        self.event_sink = Some(event_sink);
+        self
+    }
+
     /// Builds a derivation pipeline with the [`PolledAttributesQueueStage`].
     pub fn build_polled(self) -> DerivationPipeline<PolledAttributesQueueStage<D, P, T, B>, T> {
         self.into()
@@ -121,18 +131,24 @@ where
         let l2_chain_provider = builder.l2_chain_provider.expect("chain_provider must be set");
         let dap_source = builder.dap_source.expect("dap_source must be set");
         let attributes_builder = builder.builder.expect("builder must be set");
+        let events = EventEmitter::new(builder.event_sink);
 
         // Compose the stage stack.
-        let mut l1_traversal = PollingTraversal::new(chain_provider, Arc::clone(&rollup_config));
+        let mut l1_traversal = PollingTraversal::new(chain_provider, Arc::clone(&rollup_config))
+            .with_events(events.clone());
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source);
-        let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
-        let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
+        let frame_queue =
+            FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config)).with_events(events.clone());
+        let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue)
+            .with_events(events.clone());
         let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
         let batch_stream =
-            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
+            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone())
+                .with_events(events.clone());
         let batch_provider =
-            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone());
+            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone())
+                .with_events(events);
         let attributes =
             AttributesQueue::new(rollup_config.clone(), batch_provider, attributes_builder);
 
@@ -156,18 +172,24 @@ where
         let l2_chain_provider = builder.l2_chain_provider.expect("l2_chain_provider must be set");
         let dap_source = builder.dap_source.expect("dap_source must be set");
         let attributes_builder = builder.builder.expect("builder must be set");
+        let events = EventEmitter::new(builder.event_sink);
 
         // Compose the stage stack.
-        let mut l1_traversal = IndexedTraversal::new(chain_provider, Arc::clone(&rollup_config));
+        let mut l1_traversal = IndexedTraversal::new(chain_provider, Arc::clone(&rollup_config))
+            .with_events(events.clone());
         l1_traversal.block = Some(builder.origin.expect("origin must be set"));
         let l1_retrieval = L1Retrieval::new(l1_traversal, dap_source);
-        let frame_queue = FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config));
-        let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue);
+        let frame_queue =
+            FrameQueue::new(l1_retrieval, Arc::clone(&rollup_config)).with_events(events.clone());
+        let channel_provider = ChannelProvider::new(Arc::clone(&rollup_config), frame_queue)
+            .with_events(events.clone());
         let channel_reader = ChannelReader::new(channel_provider, Arc::clone(&rollup_config));
         let batch_stream =
-            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone());
+            BatchStream::new(channel_reader, rollup_config.clone(), l2_chain_provider.clone())
+                .with_events(events.clone());
         let batch_provider =
-            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone());
+            BatchProvider::new(rollup_config.clone(), batch_stream, l2_chain_provider.clone())
+                .with_events(events);
         let attributes =
             AttributesQueue::new(rollup_config.clone(), batch_provider, attributes_builder);
 
@@ -2,8 +2,8 @@
 
 use crate::{
     ActivationSignal, L2ChainProvider, NextAttributes, OriginAdvancer, OriginProvider, Pipeline,
-    PipelineError, PipelineErrorKind, PipelineResult, ResetSignal, Signal, SignalReceiver,
-    StepResult,
+    PipelineCheckpoint, PipelineError, PipelineErrorKind, PipelineResult, ResetSignal, Signal,
+    SignalReceiver, StageCheckpoint, StepResult,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -224,6 +224,37 @@ where
     }
 }
 
+#[async_trait]
+impl<S, P> StageCheckpoint for DerivationPipeline<S, P>
+where
+    S: NextAttributes
+        + SignalReceiver
+        + OriginProvider
+        + OriginAdvancer
+        + StageCheckpoint
+        + Debug
+        + Send
+        + Sync,
+    P: L2ChainProvider + Send + Sync + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        // Prepared attributes are derived from the current L2 safe head, so they cannot be
+        // replayed on top of the checkpointed safe head.
+        if !self.prepared.is_empty() {
+            return Err(
+                PipelineError::InvalidCheckpoint("pipeline has prepared attributes".into()).crit()
+            );
+        }
+        self.attributes.checkpoint(checkpoint)
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.attributes.restore(checkpoint).await?;
+        self.prepared.clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -24,9 +24,14 @@ pub struct BlobData {
 }
 
 impl BlobData {
+    /// Creates a new [`BlobData`] holding the given blob.
+    pub fn from_blob(blob: &Blob) -> Self {
+        Self { data: Some(Bytes::copy_from_slice(blob.as_slice())), calldata: None }
+    }
+
     /// Decodes the blob into raw byte data.
     /// Returns a [`BlobDecodingError`] if the blob is invalid.
-    pub(crate) fn decode(&self) -> Result<Bytes, BlobDecodingError> {
+    pub fn decode(&self) -> Result<Bytes, BlobDecodingError> {
         let data = self.data.as_ref().ok_or(BlobDecodingError::MissingData)?;
 
         // Validate the blob encoding version
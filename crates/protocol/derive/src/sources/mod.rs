@@ -18,3 +18,9 @@ pub use blobs::BlobSource;
 
 mod calldata;
 pub use calldata::CalldataSource;
+
+mod registry;
+pub use registry::{
+    DataAvailabilityKey, DataAvailabilityRegistry, DynDataAvailabilityProvider,
+    ETHEREUM_DATA_AVAILABILITY,
+};
@@ -0,0 +1,271 @@
+//! Contains the [DataAvailabilityRegistry], which selects the [DataAvailabilityProvider] of a
+//! rollup at runtime, based on its [RollupConfig].
+
+use crate::{
+    BlobProvider, ChainProvider, DataAvailabilityProvider, EthereumDataSource, PipelineResult,
+};
+use alloc::{boxed::Box, string::String, sync::Arc, vec::Vec};
+use alloy_primitives::{Address, Bytes};
+use async_trait::async_trait;
+use core::fmt::{self, Debug};
+use kona_genesis::RollupConfig;
+use kona_protocol::BlockInfo;
+
+/// The name of the default [EthereumDataSource] provider.
+pub const ETHEREUM_DATA_AVAILABILITY: &str = "ethereum";
+
+/// A type-erased [DataAvailabilityProvider], so that the provider of a pipeline can be selected at
+/// runtime.
+pub struct DynDataAvailabilityProvider {
+    /// The name of the provider.
+    name: String,
+    /// The inner provider.
+    inner: Box<dyn DataAvailabilityProvider<Item = Bytes> + Send + Sync>,
+}
+
+impl DynDataAvailabilityProvider {
+    /// Creates a new [DynDataAvailabilityProvider] with the given name, wrapping the given
+    /// provider.
+    pub fn new<D>(name: impl Into<String>, provider: D) -> Self
+    where
+        D: DataAvailabilityProvider<Item = Bytes> + Send + Sync + 'static,
+    {
+        Self { name: name.into(), inner: Box::new(provider) }
+    }
+
+    /// Returns the name of the provider.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Debug for DynDataAvailabilityProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DynDataAvailabilityProvider").field("name", &self.name).finish()
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityProvider for DynDataAvailabilityProvider {
+    type Item = Bytes;
+
+    async fn next(
+        &mut self,
+        block_ref: &BlockInfo,
+        batcher_address: Address,
+    ) -> PipelineResult<Self::Item> {
+        self.inner.next(block_ref, batcher_address).await
+    }
+
+    fn clear(&mut self) {
+        self.inner.clear();
+    }
+}
+
+/// The [RollupConfig] field a [DataAvailabilityRegistry] entry is keyed by.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataAvailabilityKey {
+    /// Matches the rollups with the given L2 chain ID.
+    L2ChainId(u64),
+    /// Matches the rollups with the given batch inbox address.
+    BatchInboxAddress(Address),
+    /// Matches the alt-DA rollups with the given DA commitment type.
+    AltDaCommitmentType(String),
+}
+
+impl DataAvailabilityKey {
+    /// Returns whether the key matches the given [RollupConfig].
+    pub fn matches(&self, cfg: &RollupConfig) -> bool {
+        match self {
+            Self::L2ChainId(id) => cfg.l2_chain_id.id() == *id,
+            Self::BatchInboxAddress(address) => cfg.batch_inbox_address == *address,
+            Self::AltDaCommitmentType(commitment_type) => cfg
+                .alt_da_config
+                .as_ref()
+                .and_then(|alt_da| alt_da.da_commitment_type.as_ref())
+                .is_some_and(|t| t == commitment_type),
+        }
+    }
+}
+
+/// A factory of [DynDataAvailabilityProvider]s.
+type DataAvailabilityFactory<C, B> =
+    Arc<dyn Fn(C, B, &RollupConfig) -> DynDataAvailabilityProvider + Send + Sync>;
+
+/// An entry of the [DataAvailabilityRegistry].
+struct RegistryEntry<C, B> {
+    /// The key matched against the rollup config.
+    key: DataAvailabilityKey,
+    /// The name of the provider.
+    name: String,
+    /// The factory of the provider.
+    factory: DataAvailabilityFactory<C, B>,
+}
+
+impl<C, B> Clone for RegistryEntry<C, B> {
+    fn clone(&self) -> Self {
+        Self { key: self.key.clone(), name: self.name.clone(), factory: self.factory.clone() }
+    }
+}
+
+/// A registry of [DataAvailabilityProvider] factories, keyed by [RollupConfig] fields.
+///
+/// The registry lets rollups with a custom batch inbox logic or a custom DA layer plug their own
+/// provider into the pipelines of the node and of the fault proof program, which are otherwise
+/// built with the [EthereumDataSource]. Entries are matched in registration order, and the
+/// [EthereumDataSource] is used if no entry matches the rollup config.
+pub struct DataAvailabilityRegistry<C, B> {
+    /// The registered entries.
+    entries: Vec<RegistryEntry<C, B>>,
+}
+
+impl<C, B> DataAvailabilityRegistry<C, B>
+where
+    C: ChainProvider + Send + Sync + Clone + Debug + 'static,
+    B: BlobProvider + Send + Sync + Clone + Debug + 'static,
+{
+    /// Creates a new, empty [DataAvailabilityRegistry].
+    pub const fn new() -> Self {
+        Self { entries: Vec::new() }
+    }
+
+    /// Registers a provider factory for the rollups matching the given key.
+    ///
+    /// The factory is called with the L1 chain and blob providers of the pipeline.
+    pub fn register<F, D>(
+        mut self,
+        key: DataAvailabilityKey,
+        name: impl Into<String>,
+        factory: F,
+    ) -> Self
+    where
+        F: Fn(C, B, &RollupConfig) -> D + Send + Sync + 'static,
+        D: DataAvailabilityProvider<Item = Bytes> + Send + Sync + 'static,
+    {
+        let name = name.into();
+        let provider_name = name.clone();
+        let boxed: DataAvailabilityFactory<C, B> =
+            Arc::new(move |chain_provider: C, blob_provider: B, cfg: &RollupConfig| {
+                DynDataAvailabilityProvider::new(
+                    provider_name.clone(),
+                    factory(chain_provider, blob_provider, cfg),
+                )
+            });
+        self.entries.push(RegistryEntry { key, name, factory: boxed });
+        self
+    }
+
+    /// Returns the name of the provider selected for the given [RollupConfig].
+    pub fn select(&self, cfg: &RollupConfig) -> &str {
+        self.entries
+            .iter()
+            .find(|entry| entry.key.matches(cfg))
+            .map_or(ETHEREUM_DATA_AVAILABILITY, |entry| entry.name.as_str())
+    }
+
+    /// Creates the provider selected for the given [RollupConfig], from the given L1 chain and
+    /// blob providers.
+    pub fn create(
+        &self,
+        chain_provider: C,
+        blob_provider: B,
+        cfg: &RollupConfig,
+    ) -> DynDataAvailabilityProvider {
+        let Some(entry) = self.entries.iter().find(|entry| entry.key.matches(cfg)) else {
+            return DynDataAvailabilityProvider::new(
+                ETHEREUM_DATA_AVAILABILITY,
+                EthereumDataSource::new_from_parts(chain_provider, blob_provider, cfg),
+            );
+        };
+        (entry.factory)(chain_provider, blob_provider, cfg)
+    }
+}
+
+impl<C, B> Default for DataAvailabilityRegistry<C, B>
+where
+    C: ChainProvider + Send + Sync + Clone + Debug + 'static,
+    B: BlobProvider + Send + Sync + Clone + Debug + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, B> Clone for DataAvailabilityRegistry<C, B> {
+    fn clone(&self) -> Self {
+        Self { entries: self.entries.clone() }
+    }
+}
+
+impl<C, B> Debug for DataAvailabilityRegistry<C, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.entries.iter().map(|entry| (&entry.key, &entry.name))).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{TestBlobProvider, TestChainProvider, TestDAP};
+    use alloc::{string::ToString, vec};
+    use kona_genesis::AltDAConfig;
+
+    type TestRegistry = DataAvailabilityRegistry<TestChainProvider, TestBlobProvider>;
+
+    fn test_dap(_: TestChainProvider, _: TestBlobProvider, _: &RollupConfig) -> TestDAP {
+        TestDAP { results: vec![Ok(Bytes::from_static(&[0xFF]))] }
+    }
+
+    #[test]
+    fn test_key_matches() {
+        let cfg = RollupConfig {
+            l2_chain_id: 10u64.into(),
+            batch_inbox_address: Address::with_last_byte(1),
+            alt_da_config: Some(AltDAConfig {
+                da_commitment_type: Some("GenericCommitment".to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert!(DataAvailabilityKey::L2ChainId(10).matches(&cfg));
+        assert!(!DataAvailabilityKey::L2ChainId(8453).matches(&cfg));
+        assert!(DataAvailabilityKey::BatchInboxAddress(Address::with_last_byte(1)).matches(&cfg));
+        assert!(!DataAvailabilityKey::BatchInboxAddress(Address::ZERO).matches(&cfg));
+        assert!(
+            DataAvailabilityKey::AltDaCommitmentType("GenericCommitment".to_string()).matches(&cfg)
+        );
+        assert!(
+            !DataAvailabilityKey::AltDaCommitmentType("GenericCommitment".to_string())
+                .matches(&RollupConfig::default())
+        );
+    }
+
+    #[test]
+    fn test_registry_defaults_to_ethereum() {
+        let registry = TestRegistry::default().register(
+            DataAvailabilityKey::L2ChainId(8453),
+            "custom",
+            test_dap,
+        );
+        let cfg = RollupConfig { l2_chain_id: 10u64.into(), ..Default::default() };
+        assert_eq!(registry.select(&cfg), ETHEREUM_DATA_AVAILABILITY);
+        let provider =
+            registry.create(TestChainProvider::default(), TestBlobProvider::default(), &cfg);
+        assert_eq!(provider.name(), ETHEREUM_DATA_AVAILABILITY);
+    }
+
+    #[tokio::test]
+    async fn test_registry_selects_first_match() {
+        let registry = TestRegistry::new()
+            .register(DataAvailabilityKey::L2ChainId(10), "custom", test_dap)
+            .register(DataAvailabilityKey::L2ChainId(10), "shadowed", test_dap);
+        let cfg = RollupConfig { l2_chain_id: 10u64.into(), ..Default::default() };
+        assert_eq!(registry.select(&cfg), "custom");
+
+        let mut provider =
+            registry.create(TestChainProvider::default(), TestBlobProvider::default(), &cfg);
+        assert_eq!(provider.name(), "custom");
+        let data = provider.next(&BlockInfo::default(), Address::ZERO).await.unwrap();
+        assert_eq!(data, Bytes::from_static(&[0xFF]));
+    }
+}
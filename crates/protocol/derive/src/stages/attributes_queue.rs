@@ -4,9 +4,9 @@ use crate::{
     errors::{PipelineError, ResetError},
     traits::{
         AttributesBuilder, AttributesProvider, NextAttributes, OriginAdvancer, OriginProvider,
-        SignalReceiver,
+        SignalReceiver, StageCheckpoint,
     },
-    types::{PipelineResult, Signal},
+    types::{PipelineCheckpoint, PipelineResult, Signal, decode_single_batch, encode_single_batch},
 };
 use alloc::{boxed::Box, sync::Arc};
 use async_trait::async_trait;
@@ -204,6 +204,33 @@ where
     }
 }
 
+#[async_trait]
+impl<P, AB> StageCheckpoint for AttributesQueue<P, AB>
+where
+    P: AttributesProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+    AB: AttributesBuilder + Send + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        checkpoint.batch = self.batch.as_ref().map(encode_single_batch);
+        checkpoint.last_in_span = self.is_last_in_span;
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        self.batch = checkpoint.batch.as_ref().map(decode_single_batch).transpose()?;
+        self.is_last_in_span = checkpoint.last_in_span;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -3,8 +3,14 @@
 use super::NextBatchProvider;
 use crate::{
     errors::{PipelineEncodingError, PipelineError, PipelineErrorKind, ResetError},
-    traits::{AttributesProvider, L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, ResetSignal, Signal},
+    traits::{
+        AttributesProvider, L2ChainProvider, OriginAdvancer, OriginProvider, SignalReceiver,
+        StageCheckpoint,
+    },
+    types::{
+        BatchCheckpoint, EventEmitter, PipelineCheckpoint, PipelineResult, ResetSignal, Signal,
+        decode_single_batch, encode_single_batch,
+    },
 };
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use async_trait::async_trait;
@@ -55,6 +61,8 @@ where
     pub(crate) next_spans: Vec<SingleBatch>,
     /// Used to validate the batches.
     pub(crate) fetcher: BF,
+    /// Emits the validity of the batches.
+    pub(crate) events: EventEmitter,
 }
 
 impl<P, BF> BatchQueue<P, BF>
@@ -73,9 +81,16 @@ where
             batches: Default::default(),
             next_spans: Default::default(),
             fetcher,
+            events: Default::default(),
         }
     }
 
+    /// Sets the [`EventEmitter`] of the [`BatchQueue`].
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
+    }
+
     /// Pops the next batch from the current queued up span-batch cache.
     /// The parent is used to set the parent hash of the batch.
     /// The parent is verified when the batch is later validated.
@@ -132,6 +147,13 @@ where
             let batch = &self.batches[i];
             let validity =
                 batch.check_batch(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher).await;
+            // Report the batches leaving the queue. Undecided batches, and future batches before
+            // Holocene, are kept and checked again on the next derivation.
+            let kept = validity == BatchValidity::Undecided ||
+                (validity.is_future() && !self.cfg.is_holocene_active(origin.timestamp));
+            if !kept {
+                self.events.batch_checked(&origin, &batch.batch, validity);
+            }
             match validity {
                 BatchValidity::Future => {
                     // Drop Future batches post-holocene.
@@ -244,6 +266,7 @@ where
         // If we drop the batch, validation logs the drop reason with WARN level.
         let validity =
             data.check_batch(&self.cfg, &self.l1_blocks, parent, &mut self.fetcher).await;
+        self.events.batch_checked(&origin, &data.batch, validity);
         // Post-Holocene, future batches are dropped due to prevent gaps.
         let drop = validity.is_drop() ||
             (self.cfg.is_holocene_active(origin.timestamp) && validity.is_future());
@@ -460,6 +483,65 @@ where
     }
 }
 
+impl<P, BF> BatchQueue<P, BF>
+where
+    P: NextBatchProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+    BF: L2ChainProvider + Debug,
+{
+    /// Records the buffered batches into the [`PipelineCheckpoint`].
+    pub(crate) fn checkpoint_batches(
+        &self,
+        checkpoint: &mut PipelineCheckpoint,
+    ) -> PipelineResult<()> {
+        checkpoint.batch_origin = self.origin;
+        checkpoint.l1_blocks = self.l1_blocks.clone();
+        checkpoint.batches =
+            self.batches.iter().map(BatchCheckpoint::new).collect::<PipelineResult<_>>()?;
+        checkpoint.next_spans = self.next_spans.iter().map(encode_single_batch).collect();
+        Ok(())
+    }
+
+    /// Replaces the buffered batches with the batches of the [`PipelineCheckpoint`].
+    pub(crate) fn restore_batches(
+        &mut self,
+        checkpoint: &PipelineCheckpoint,
+    ) -> PipelineResult<()> {
+        self.origin = checkpoint.batch_origin;
+        self.l1_blocks = checkpoint.l1_blocks.clone();
+        self.batches = checkpoint
+            .batches
+            .iter()
+            .map(|batch| batch.batch(&self.cfg))
+            .collect::<PipelineResult<_>>()?;
+        self.next_spans =
+            checkpoint.next_spans.iter().map(decode_single_batch).collect::<PipelineResult<_>>()?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P, BF> StageCheckpoint for BatchQueue<P, BF>
+where
+    P: NextBatchProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+    BF: L2ChainProvider + Send + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        self.checkpoint_batches(checkpoint)
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        self.restore_batches(checkpoint)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
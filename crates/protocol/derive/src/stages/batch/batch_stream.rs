@@ -1,8 +1,13 @@
 //! This module contains the `BatchStream` stage.
 
 use crate::{
-    L2ChainProvider, NextBatchProvider, OriginAdvancer, OriginProvider, PipelineEncodingError,
-    PipelineError, PipelineResult, Signal, SignalReceiver,
+    DerivationEvent, L2ChainProvider, NextBatchProvider, OriginAdvancer, OriginProvider,
+    PipelineCheckpoint, PipelineEncodingError, PipelineError, PipelineResult, Signal,
+    SignalReceiver, StageCheckpoint,
+    types::{
+        EventEmitter, decode_single_batch, decode_span_batch, encode_single_batch,
+        encode_span_batch,
+    },
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use async_trait::async_trait;
@@ -48,6 +53,8 @@ where
     config: Arc<RollupConfig>,
     /// Used to validate the batches.
     fetcher: BF,
+    /// Emits the validity of the span batches.
+    events: EventEmitter,
 }
 
 impl<P, BF> BatchStream<P, BF>
@@ -57,7 +64,20 @@ where
 {
     /// Create a new [`BatchStream`] stage.
     pub const fn new(prev: P, config: Arc<RollupConfig>, fetcher: BF) -> Self {
-        Self { prev, span: None, buffer: VecDeque::new(), config, fetcher }
+        Self {
+            prev,
+            span: None,
+            buffer: VecDeque::new(),
+            config,
+            fetcher,
+            events: EventEmitter::new(None),
+        }
+    }
+
+    /// Sets the [`EventEmitter`] of the [`BatchStream`].
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
     }
 
     /// Returns if the [`BatchStream`] stage is active based on the
@@ -168,6 +188,12 @@ where
                         crate::metrics::Metrics::PIPELINE_BATCH_VALIDITY,
                         "validity" => validity.to_string(),
                     );
+                    self.events.emit(|| DerivationEvent::BatchChecked {
+                        origin: batch_with_inclusion.inclusion_block.id(),
+                        timestamp: b.starting_timestamp(),
+                        is_span: true,
+                        validity,
+                    });
 
                     match validity {
                         BatchValidity::Accept => self.span = Some(b),
@@ -233,6 +259,41 @@ where
     }
 }
 
+#[async_trait]
+impl<P, BF> StageCheckpoint for BatchStream<P, BF>
+where
+    P: BatchStreamProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+    BF: L2ChainProvider + Send + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        checkpoint.span_batch = self.span.as_ref().map(encode_span_batch).transpose()?;
+        checkpoint.span_buffer = self.buffer.iter().map(encode_single_batch).collect();
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        self.span = checkpoint
+            .span_batch
+            .as_ref()
+            .map(|span| decode_span_batch(span, &self.config))
+            .transpose()?;
+        self.buffer = checkpoint
+            .span_buffer
+            .iter()
+            .map(decode_single_batch)
+            .collect::<PipelineResult<_>>()?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
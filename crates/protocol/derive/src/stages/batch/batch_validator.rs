@@ -2,9 +2,10 @@
 
 use super::NextBatchProvider;
 use crate::{
+    DerivationEvent,
     errors::{PipelineError, PipelineErrorKind, ResetError},
-    traits::{AttributesProvider, OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, ResetSignal, Signal},
+    traits::{AttributesProvider, OriginAdvancer, OriginProvider, SignalReceiver, StageCheckpoint},
+    types::{EventEmitter, PipelineCheckpoint, PipelineResult, ResetSignal, Signal},
 };
 use alloc::{boxed::Box, sync::Arc, vec::Vec};
 use async_trait::async_trait;
@@ -35,6 +36,8 @@ where
     /// If new L2 Block's L1 origin is not included in this list, fetch and
     /// push it to the list.
     pub(crate) l1_blocks: Vec<BlockInfo>,
+    /// Emits the validity of the batches.
+    pub(crate) events: EventEmitter,
 }
 
 impl<P> BatchValidator<P>
@@ -43,7 +46,13 @@ where
 {
     /// Create a new [`BatchValidator`] stage.
     pub const fn new(cfg: Arc<RollupConfig>, prev: P) -> Self {
-        Self { cfg, prev, origin: None, l1_blocks: Vec::new() }
+        Self { cfg, prev, origin: None, l1_blocks: Vec::new(), events: EventEmitter::new(None) }
+    }
+
+    /// Sets the [`EventEmitter`] of the [`BatchValidator`].
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
     }
 
     /// Returns `true` if the pipeline origin is behind the parent origin.
@@ -248,12 +257,21 @@ where
         next_batch.parent_hash = parent.block_info.hash;
 
         // Check the validity of the single batch before forwarding it.
-        match next_batch.check_batch(
+        let validity = next_batch.check_batch(
             self.cfg.as_ref(),
             self.l1_blocks.as_ref(),
             parent,
             &stage_origin,
-        ) {
+        );
+        if validity != BatchValidity::Undecided {
+            self.events.emit(|| DerivationEvent::BatchChecked {
+                origin: stage_origin.id(),
+                timestamp: next_batch.timestamp,
+                is_span: false,
+                validity,
+            });
+        }
+        match validity {
             BatchValidity::Accept => {
                 info!(target: "batch_validator", "Found next batch (epoch #{})", next_batch.epoch_num);
                 Ok(next_batch)
@@ -323,6 +341,47 @@ where
     }
 }
 
+impl<P> BatchValidator<P>
+where
+    P: NextBatchProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    /// Records the tracked L1 blocks into the [`PipelineCheckpoint`].
+    pub(crate) fn checkpoint_batches(&self, checkpoint: &mut PipelineCheckpoint) {
+        checkpoint.batch_origin = self.origin;
+        checkpoint.l1_blocks = self.l1_blocks.clone();
+    }
+
+    /// Replaces the tracked L1 blocks with the L1 blocks of the [`PipelineCheckpoint`].
+    pub(crate) fn restore_batches(&mut self, checkpoint: &PipelineCheckpoint) {
+        self.origin = checkpoint.batch_origin;
+        self.l1_blocks = checkpoint.l1_blocks.clone();
+    }
+}
+
+#[async_trait]
+impl<P> StageCheckpoint for BatchValidator<P>
+where
+    P: NextBatchProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        self.checkpoint_batches(checkpoint);
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        self.restore_batches(checkpoint);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
@@ -2,9 +2,11 @@
 
 use super::{ChannelReaderProvider, NextFrameProvider};
 use crate::{
+    ChannelCheckpoint, ChannelDropReason, DerivationEvent, FrameDropReason, PipelineCheckpoint,
+    StageCheckpoint,
     errors::PipelineError,
     traits::{OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    types::{EventEmitter, PipelineResult, Signal},
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::{Bytes, hex};
@@ -32,6 +34,8 @@ where
     pub(crate) prev: P,
     /// The current [`Channel`] being assembled.
     pub(crate) channel: Option<Channel>,
+    /// Emits the accepted and dropped frames and channels.
+    pub(crate) events: EventEmitter,
 }
 
 impl<P> ChannelAssembler<P>
@@ -40,7 +44,13 @@ where
 {
     /// Creates a new [`ChannelAssembler`] stage with the given configuration and previous stage.
     pub const fn new(cfg: Arc<RollupConfig>, prev: P) -> Self {
-        Self { cfg, prev, channel: None }
+        Self { cfg, prev, channel: None, events: EventEmitter::new(None) }
+    }
+
+    /// Sets the [`EventEmitter`] of the [`ChannelAssembler`].
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
     }
 
     /// Returns whether or not the channel currently being assembled has timed out.
@@ -76,6 +86,11 @@ where
                     origin.number,
                     channel.open_block_number()
                 );
+                self.events.emit(|| DerivationEvent::ChannelTimedOut {
+                    origin: origin.id(),
+                    channel_id: channel.id().into(),
+                    open_block: channel.open_block_number(),
+                });
                 self.channel = None;
             }
         }
@@ -91,6 +106,17 @@ where
                 hex::encode(next_frame.id),
                 origin.number
             );
+            if let Some(channel) = self.channel.as_ref() {
+                self.events.emit(|| DerivationEvent::ChannelDropped {
+                    origin: origin.id(),
+                    channel_id: channel.id().into(),
+                    reason: ChannelDropReason::Superseded,
+                });
+            }
+            self.events.emit(|| DerivationEvent::ChannelOpened {
+                origin: origin.id(),
+                channel_id: next_frame.id.into(),
+            });
             self.channel = Some(Channel::new(next_frame.id, origin));
         }
 
@@ -112,6 +138,7 @@ where
                 hex::encode(channel.id()),
                 origin.number
             );
+            let (frame_number, is_last) = (next_frame.number, next_frame.is_last);
             if channel.add_frame(next_frame, origin).is_err() {
                 error!(
                     target: "channel_assembler",
@@ -119,8 +146,20 @@ where
                     hex::encode(channel.id()),
                     origin.number
                 );
+                self.events.emit(|| DerivationEvent::FrameDropped {
+                    origin: origin.id(),
+                    channel_id: channel.id().into(),
+                    frame_number,
+                    reason: FrameDropReason::Rejected,
+                });
                 return Err(PipelineError::NotEnoughData.temp());
             }
+            self.events.emit(|| DerivationEvent::FrameAccepted {
+                origin: origin.id(),
+                channel_id: channel.id().into(),
+                frame_number,
+                is_last,
+            });
 
             let size = channel.size() as f64;
             kona_macros::set!(gauge, crate::metrics::Metrics::PIPELINE_CHANNEL_MEM, size);
@@ -142,6 +181,11 @@ where
                     hex::encode(channel.id()),
                     channel.size()
                 );
+                self.events.emit(|| DerivationEvent::ChannelDropped {
+                    origin: origin.id(),
+                    channel_id: channel.id().into(),
+                    reason: ChannelDropReason::TooLarge,
+                });
                 self.channel = None;
                 return Err(PipelineError::NotEnoughData.temp());
             }
@@ -156,11 +200,24 @@ where
                     "Channel (ID: {}) ready for decompression.",
                     hex::encode(channel.id()),
                 );
+                self.events.emit(|| DerivationEvent::ChannelCompleted {
+                    origin: origin.id(),
+                    channel_id: channel.id().into(),
+                    size: channel.size(),
+                });
 
                 // Reset the channel and return the compressed bytes.
                 self.channel = None;
                 return Ok(Some(channel_bytes));
             }
+        } else {
+            // Without an open channel, only the first frame of a channel can be accepted.
+            self.events.emit(|| DerivationEvent::FrameDropped {
+                origin: origin.id(),
+                channel_id: next_frame.id.into(),
+                frame_number: next_frame.number,
+                reason: FrameDropReason::NoOpenChannel,
+            });
         }
 
         kona_macros::set!(gauge, crate::metrics::Metrics::PIPELINE_CHANNEL_MEM, 0);
@@ -200,12 +257,64 @@ where
     }
 }
 
+impl<P> ChannelAssembler<P>
+where
+    P: NextFrameProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    /// Records the channel being assembled into the [`PipelineCheckpoint`].
+    pub(crate) fn checkpoint_channels(&self, checkpoint: &mut PipelineCheckpoint) {
+        checkpoint.channels = self.channel.iter().map(ChannelCheckpoint::from).collect();
+    }
+
+    /// Replaces the channel being assembled with the channel of the [`PipelineCheckpoint`].
+    pub(crate) fn restore_channels(
+        &mut self,
+        checkpoint: &PipelineCheckpoint,
+    ) -> PipelineResult<()> {
+        self.channel = match checkpoint.channels.as_slice() {
+            [] => None,
+            [channel] => Some(channel.channel()?),
+            _ => {
+                return Err(PipelineError::InvalidCheckpoint(
+                    "more than one channel buffered after Holocene".into(),
+                )
+                .crit());
+            }
+        };
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P> StageCheckpoint for ChannelAssembler<P>
+where
+    P: NextFrameProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        self.checkpoint_channels(checkpoint);
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        self.restore_channels(checkpoint)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::ChannelAssembler;
     use crate::{
-        ChannelReaderProvider, PipelineError,
-        test_utils::{CollectingLayer, TestNextFrameProvider, TraceStorage},
+        ChannelReaderProvider, DerivationEvent, FrameDropReason, PipelineError,
+        test_utils::{CollectingLayer, TestEventSink, TestNextFrameProvider, TraceStorage},
+        types::EventEmitter,
     };
     use alloc::{sync::Arc, vec};
     use kona_genesis::{
@@ -279,6 +388,53 @@ mod test {
         assert!(assembler.channel.is_none());
     }
 
+    #[tokio::test]
+    async fn test_assembler_events() {
+        let frames = [
+            crate::frame!(0xFF, 1, vec![0xDD; 50], true),
+            crate::frame!(0xFF, 0, vec![0xDD; 50], false),
+            crate::frame!(0xFF, 1, vec![0xDD; 50], true),
+        ];
+        let mock = TestNextFrameProvider::new(frames.into_iter().rev().map(Ok).collect());
+        let cfg = Arc::new(RollupConfig::default());
+        let sink = TestEventSink::default();
+        let mut assembler = ChannelAssembler::new(cfg, mock)
+            .with_events(EventEmitter::new(Some(Arc::new(sink.clone()))));
+
+        assert_eq!(assembler.next_data().await.unwrap_err(), PipelineError::NotEnoughData.temp());
+        assert_eq!(assembler.next_data().await.unwrap_err(), PipelineError::NotEnoughData.temp());
+        assert!(assembler.next_data().await.unwrap().is_some());
+
+        let origin = BlockInfo::default().id();
+        let channel_id = [0xFF; 16].into();
+        let size = 2 * (50 + kona_protocol::FRAME_OVERHEAD);
+        assert_eq!(
+            sink.events(),
+            vec![
+                DerivationEvent::FrameDropped {
+                    origin,
+                    channel_id,
+                    frame_number: 1,
+                    reason: FrameDropReason::NoOpenChannel,
+                },
+                DerivationEvent::ChannelOpened { origin, channel_id },
+                DerivationEvent::FrameAccepted {
+                    origin,
+                    channel_id,
+                    frame_number: 0,
+                    is_last: false,
+                },
+                DerivationEvent::FrameAccepted {
+                    origin,
+                    channel_id,
+                    frame_number: 1,
+                    is_last: true
+                },
+                DerivationEvent::ChannelCompleted { origin, channel_id, size },
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_assembler_already_built() {
         let trace_store: TraceStorage = Default::default();
@@ -1,8 +1,10 @@
 //! This module contains the `ChannelBank` struct.
 
 use crate::{
-    ChannelReaderProvider, NextFrameProvider, OriginAdvancer, OriginProvider, PipelineError,
-    PipelineErrorKind, PipelineResult, Signal, SignalReceiver,
+    ChannelCheckpoint, ChannelDropReason, ChannelReaderProvider, DerivationEvent, FrameDropReason,
+    NextFrameProvider, OriginAdvancer, OriginProvider, PipelineCheckpoint, PipelineError,
+    PipelineErrorKind, PipelineResult, Signal, SignalReceiver, StageCheckpoint,
+    types::EventEmitter,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use alloy_primitives::{Bytes, hex, map::HashMap};
@@ -41,6 +43,8 @@ where
     pub(crate) channel_queue: VecDeque<ChannelId>,
     /// The previous stage of the derivation pipeline.
     pub(crate) prev: P,
+    /// Emits the accepted and dropped frames and channels.
+    pub(crate) events: EventEmitter,
 }
 
 impl<P> ChannelBank<P>
@@ -49,7 +53,19 @@ where
 {
     /// Create a new [`ChannelBank`] stage.
     pub fn new(cfg: Arc<RollupConfig>, prev: P) -> Self {
-        Self { cfg, channels: HashMap::default(), channel_queue: VecDeque::new(), prev }
+        Self {
+            cfg,
+            channels: HashMap::default(),
+            channel_queue: VecDeque::new(),
+            prev,
+            events: EventEmitter::default(),
+        }
+    }
+
+    /// Sets the [`EventEmitter`] of the [`ChannelBank`].
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
     }
 
     /// Returns the size of the channel bank by accumulating over all channels.
@@ -72,6 +88,16 @@ where
                 self.channel_queue.pop_front().ok_or(PipelineError::ChannelProviderEmpty.crit())?;
             let channel = self.channels.remove(&id).ok_or(PipelineError::ChannelNotFound.crit())?;
             total_size -= channel.size();
+            self.events.emit(|| DerivationEvent::ChannelDropped {
+                origin: origin.id(),
+                channel_id: id.into(),
+                reason: ChannelDropReason::BankFull,
+            });
+            self.events.emit(|| DerivationEvent::ChannelDropped {
+                origin: origin.id(),
+                channel_id: id.into(),
+                reason: ChannelDropReason::BankFull,
+            });
         }
         Ok(())
     }
@@ -85,6 +111,10 @@ where
             Some(c) => c,
             None => {
                 let channel = Channel::new(frame.id, origin);
+                self.events.emit(|| DerivationEvent::ChannelOpened {
+                    origin: origin.id(),
+                    channel_id: frame.id.into(),
+                });
                 self.channel_queue.push_back(frame.id);
                 self.channels.insert(frame.id, channel);
                 self.channels.get_mut(&frame.id).expect("Channel must be in queue")
@@ -99,15 +129,33 @@ where
                 target: "channel_bank",
                 "Channel (ID: {}) timed out", hex::encode(frame.id)
             );
+            self.events.emit(|| DerivationEvent::FrameDropped {
+                origin: origin.id(),
+                channel_id: frame.id.into(),
+                frame_number: frame.number,
+                reason: FrameDropReason::ChannelTimedOut,
+            });
             return Ok(());
         }
 
         // Ingest the frame. If it fails, ignore the frame.
-        let frame_id = frame.id;
+        let (frame_id, frame_number, is_last) = (frame.id, frame.number, frame.is_last);
         if current_channel.add_frame(frame, origin).is_err() {
             warn!(target: "channel_bank", "Failed to add frame to channel: {:?}", frame_id);
+            self.events.emit(|| DerivationEvent::FrameDropped {
+                origin: origin.id(),
+                channel_id: frame_id.into(),
+                frame_number,
+                reason: FrameDropReason::Rejected,
+            });
             return Ok(());
         }
+        self.events.emit(|| DerivationEvent::FrameAccepted {
+            origin: origin.id(),
+            channel_id: frame_id.into(),
+            frame_number,
+            is_last,
+        });
 
         self.prune()
     }
@@ -134,6 +182,11 @@ where
                 target: "channel_bank",
                 "Channel (ID: {}) timed out", hex::encode(first)
             );
+            self.events.emit(|| DerivationEvent::ChannelTimedOut {
+                origin: origin.id(),
+                channel_id: first.into(),
+                open_block: channel.open_block_number(),
+            });
             self.channels.remove(&first);
             self.channel_queue.pop_front();
             return Ok(None);
@@ -170,6 +223,11 @@ where
         }
 
         let frame_data = channel.frame_data();
+        self.events.emit(|| DerivationEvent::ChannelCompleted {
+            origin: origin.id(),
+            channel_id: channel_id.into(),
+            size: channel.size(),
+        });
         self.channels.remove(&channel_id);
         self.channel_queue.remove(index);
 
@@ -237,6 +295,59 @@ where
     }
 }
 
+impl<P> ChannelBank<P>
+where
+    P: NextFrameProvider + OriginAdvancer + OriginProvider + SignalReceiver + Debug,
+{
+    /// Records the buffered channels into the [`PipelineCheckpoint`], in read order.
+    pub(crate) fn checkpoint_channels(&self, checkpoint: &mut PipelineCheckpoint) {
+        checkpoint.channels = self
+            .channel_queue
+            .iter()
+            .filter_map(|id| self.channels.get(id))
+            .map(ChannelCheckpoint::from)
+            .collect();
+    }
+
+    /// Replaces the buffered channels with the channels of the [`PipelineCheckpoint`].
+    pub(crate) fn restore_channels(
+        &mut self,
+        checkpoint: &PipelineCheckpoint,
+    ) -> PipelineResult<()> {
+        self.channels.clear();
+        self.channel_queue.clear();
+        for channel in &checkpoint.channels {
+            let channel = channel.channel()?;
+            self.channel_queue.push_back(channel.id());
+            self.channels.insert(channel.id(), channel);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<P> StageCheckpoint for ChannelBank<P>
+where
+    P: NextFrameProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        self.checkpoint_channels(checkpoint);
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        self.restore_channels(checkpoint)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,6 +527,28 @@ mod tests {
         assert!(channel_bank.prev.reset);
     }
 
+    #[tokio::test]
+    async fn test_checkpoint_roundtrip() {
+        let cfg = Arc::new(RollupConfig::default());
+        let mut channel_bank = ChannelBank::new(cfg.clone(), TestNextFrameProvider::new(vec![]));
+        channel_bank.ingest_frame(Frame::new([0xAA; 16], 0, vec![0x01; 10], false)).unwrap();
+        channel_bank.ingest_frame(Frame::new([0xBB; 16], 0, vec![0x02; 10], false)).unwrap();
+        channel_bank.ingest_frame(Frame::new([0xAA; 16], 1, vec![0x03; 10], false)).unwrap();
+
+        let mut checkpoint = PipelineCheckpoint::default();
+        channel_bank.checkpoint(&mut checkpoint).unwrap();
+        assert_eq!(checkpoint.channels.len(), 2);
+        assert_eq!(checkpoint.channels[0].frames.len(), 2);
+
+        let mut restored = ChannelBank::new(cfg, TestNextFrameProvider::new(vec![]));
+        restored.restore(&checkpoint).await.unwrap();
+        assert_eq!(restored.channel_queue, channel_bank.channel_queue);
+        assert_eq!(restored.size(), channel_bank.size());
+        for id in &channel_bank.channel_queue {
+            assert_eq!(restored.channels[id].frame_data(), channel_bank.channels[id].frame_data());
+        }
+    }
+
     #[test]
     fn test_ingest_invalid_frame() {
         let trace_store: TraceStorage = Default::default();
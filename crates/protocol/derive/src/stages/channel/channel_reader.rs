@@ -1,8 +1,8 @@
 //! This module contains the `ChannelReader` struct.
 
 use crate::{
-    BatchStreamProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult, Signal,
-    SignalReceiver,
+    BatchReaderCheckpoint, BatchStreamProvider, OriginAdvancer, OriginProvider, PipelineCheckpoint,
+    PipelineError, PipelineResult, Signal, SignalReceiver, StageCheckpoint,
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Bytes;
@@ -194,6 +194,39 @@ where
     }
 }
 
+#[async_trait]
+impl<P> StageCheckpoint for ChannelReader<P>
+where
+    P: ChannelReaderProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)?;
+        checkpoint.channel_reader = self.next_batch.as_ref().map(BatchReaderCheckpoint::from);
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+
+        let max_rlp_bytes_per_channel = if self.cfg.is_fjord_active(checkpoint.origin.timestamp) {
+            MAX_RLP_BYTES_PER_CHANNEL_FJORD
+        } else {
+            MAX_RLP_BYTES_PER_CHANNEL_BEDROCK
+        };
+        self.next_batch = checkpoint
+            .channel_reader
+            .as_ref()
+            .map(|reader| reader.reader(max_rlp_bytes_per_channel as usize));
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
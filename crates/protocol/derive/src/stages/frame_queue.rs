@@ -1,8 +1,9 @@
 //! This module contains the [FrameQueue] stage of the derivation pipeline.
 
 use crate::{
-    NextFrameProvider, OriginAdvancer, OriginProvider, PipelineError, PipelineResult, Signal,
-    SignalReceiver,
+    DerivationEvent, FrameDropReason, NextFrameProvider, OriginAdvancer, OriginProvider,
+    PipelineCheckpoint, PipelineError, PipelineResult, Signal, SignalReceiver, StageCheckpoint,
+    types::EventEmitter,
 };
 use alloc::{boxed::Box, collections::VecDeque, sync::Arc};
 use alloy_primitives::Bytes;
@@ -38,6 +39,8 @@ where
     queue: VecDeque<Frame>,
     /// The rollup config.
     rollup_config: Arc<RollupConfig>,
+    /// Emits the dropped frames.
+    events: EventEmitter,
 }
 
 impl<P> FrameQueue<P>
@@ -48,7 +51,25 @@ where
     ///
     /// [`L1Retrieval`]: crate::stages::L1Retrieval
     pub const fn new(prev: P, cfg: Arc<RollupConfig>) -> Self {
-        Self { prev, queue: VecDeque::new(), rollup_config: cfg }
+        Self { prev, queue: VecDeque::new(), rollup_config: cfg, events: EventEmitter::new(None) }
+    }
+
+    /// Sets the [`EventEmitter`] of the [`FrameQueue`].
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// Removes the frame at the given index from the queue, emitting the drop reason.
+    fn drop_frame(&mut self, index: usize, origin: BlockInfo, reason: FrameDropReason) {
+        if let Some(frame) = self.queue.remove(index) {
+            self.events.emit(|| DerivationEvent::FrameDropped {
+                origin: origin.id(),
+                channel_id: frame.id.into(),
+                frame_number: frame.number,
+                reason,
+            });
+        }
     }
 
     /// Returns if holocene is active.
@@ -71,19 +92,19 @@ where
             // If the frames are in the same channel, and the frame numbers are not sequential,
             // drop the next frame.
             if extends_channel && prev_frame.number + 1 != next_frame.number {
-                self.queue.remove(i + 1);
+                self.drop_frame(i + 1, origin, FrameDropReason::NonSequential);
                 continue;
             }
 
             // If the frames are in the same channel, and the previous is last, drop the next frame.
             if extends_channel && prev_frame.is_last {
-                self.queue.remove(i + 1);
+                self.drop_frame(i + 1, origin, FrameDropReason::AfterLastFrame);
                 continue;
             }
 
             // If the frames are in different channels, the next frame must be first.
             if !extends_channel && next_frame.number != 0 {
-                self.queue.remove(i + 1);
+                self.drop_frame(i + 1, origin, FrameDropReason::NotFirstFrame);
                 continue;
             }
 
@@ -98,6 +119,14 @@ where
                 // Drain all frames from the previous channel.
                 let drained = self.queue.drain(first_frame..=i);
                 i = i.saturating_sub(drained.len());
+                for frame in drained {
+                    self.events.emit(|| DerivationEvent::FrameDropped {
+                        origin: origin.id(),
+                        channel_id: frame.id.into(),
+                        frame_number: frame.number,
+                        reason: FrameDropReason::ChannelSuperseded,
+                    });
+                }
                 continue;
             }
 
@@ -125,6 +154,9 @@ where
             // There may be more frames in the queue for the
             // pipeline to advance, so don't return an error here.
             error!(target: "frame_queue", "Failed to parse frames from data.");
+            if let Some(origin) = self.origin() {
+                self.events.emit(|| DerivationEvent::InvalidFrameData { origin: origin.id() });
+            }
             return Ok(());
         };
 
@@ -197,11 +229,34 @@ where
     }
 }
 
+#[async_trait]
+impl<P> StageCheckpoint for FrameQueue<P>
+where
+    P: FrameQueueProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send
+        + Debug,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        // The frames of the checkpoint origin are parsed again.
+        self.queue.clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
     use crate::{test_utils::TestFrameQueueProvider, types::ResetSignal};
-    use alloc::vec;
+    use alloc::{vec, vec::Vec};
     use kona_genesis::HardForkConfig;
 
     #[tokio::test]
@@ -384,6 +439,41 @@ pub(crate) mod tests {
         assert.next_frames().await;
     }
 
+    #[tokio::test]
+    async fn test_holocene_dropped_frame_events() {
+        let frames = [
+            crate::frame!(0xEE, 0, vec![0xDD; 50], false),
+            crate::frame!(0xEE, 2, vec![0xDD; 50], true), // Dropped
+            crate::frame!(0xFF, 1, vec![0xDD; 50], true), // Dropped
+        ];
+        let cfg = Arc::new(RollupConfig {
+            hardforks: HardForkConfig { holocene_time: Some(0), ..Default::default() },
+            ..Default::default()
+        });
+        let data = frames.iter().flat_map(|frame| frame.encode()).collect::<Vec<_>>();
+        let data = [&[kona_protocol::DERIVATION_VERSION_0], data.as_slice()].concat();
+        let mut mock = TestFrameQueueProvider::new(vec![Ok(data.into())]);
+        mock.set_origin(BlockInfo::default());
+        let sink = crate::test_utils::TestEventSink::default();
+        let mut frame_queue =
+            FrameQueue::new(mock, cfg).with_events(EventEmitter::new(Some(Arc::new(sink.clone()))));
+
+        assert_eq!(frame_queue.next_frame().await.unwrap(), frames[0]);
+        let dropped = |frame: &Frame, reason| DerivationEvent::FrameDropped {
+            origin: BlockInfo::default().id(),
+            channel_id: frame.id.into(),
+            frame_number: frame.number,
+            reason,
+        };
+        assert_eq!(
+            sink.events(),
+            vec![
+                dropped(&frames[1], FrameDropReason::NonSequential),
+                dropped(&frames[2], FrameDropReason::NotFirstFrame),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_holocene_unclosed_channel() {
         let frames = [
@@ -2,7 +2,8 @@
 
 use crate::{
     ActivationSignal, DataAvailabilityProvider, FrameQueueProvider, OriginAdvancer, OriginProvider,
-    PipelineError, PipelineErrorKind, PipelineResult, ResetSignal, Signal, SignalReceiver,
+    PipelineCheckpoint, PipelineError, PipelineErrorKind, PipelineResult, ResetSignal, Signal,
+    SignalReceiver, StageCheckpoint,
 };
 use alloc::boxed::Box;
 use alloy_primitives::Address;
@@ -133,6 +134,30 @@ where
     }
 }
 
+#[async_trait]
+impl<DAP, P> StageCheckpoint for L1Retrieval<DAP, P>
+where
+    DAP: DataAvailabilityProvider + Send,
+    P: L1RetrievalProvider
+        + OriginAdvancer
+        + OriginProvider
+        + SignalReceiver
+        + StageCheckpoint
+        + Send,
+{
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.checkpoint(checkpoint)
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        self.prev.restore(checkpoint).await?;
+        // Read the data of the checkpoint origin again.
+        self.next = None;
+        self.provider.clear();
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
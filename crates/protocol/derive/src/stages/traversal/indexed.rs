@@ -1,8 +1,9 @@
 //! Contains the [`IndexedTraversal`] stage of the derivation pipeline.
 
 use crate::{
-    ActivationSignal, ChainProvider, L1RetrievalProvider, OriginAdvancer, OriginProvider,
-    PipelineError, PipelineResult, ResetError, ResetSignal, Signal, SignalReceiver,
+    ActivationSignal, ChainProvider, DerivationEvent, L1RetrievalProvider, OriginAdvancer,
+    OriginProvider, PipelineCheckpoint, PipelineError, PipelineResult, ResetError, ResetSignal,
+    Signal, SignalReceiver, StageCheckpoint, types::EventEmitter,
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Address;
@@ -29,6 +30,8 @@ pub struct IndexedTraversal<Provider: ChainProvider> {
     pub system_config: SystemConfig,
     /// A reference to the rollup config.
     pub rollup_config: Arc<RollupConfig>,
+    /// Emits the system config updates.
+    pub(crate) events: EventEmitter,
 }
 
 #[async_trait]
@@ -56,9 +59,16 @@ impl<F: ChainProvider> IndexedTraversal<F> {
             done: false,
             system_config: SystemConfig::default(),
             rollup_config: cfg,
+            events: EventEmitter::new(None),
         }
     }
 
+    /// Sets the [`EventEmitter`] of the traversal stage.
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
+    }
+
     /// Update the origin block in the traversal stage.
     fn update_origin(&mut self, block: BlockInfo) {
         self.done = false;
@@ -92,13 +102,19 @@ impl<F: ChainProvider> IndexedTraversal<F> {
 
         let addr = self.rollup_config.l1_system_config_address;
         let active = self.rollup_config.is_ecotone_active(block_info.timestamp);
-        match self.system_config.update_with_receipts(&receipts[..], addr, active) {
-            Ok(true) => {
+        match self.system_config.apply_receipts(&receipts[..], addr, active) {
+            Ok(updates) if !updates.is_empty() => {
                 let next = block_info.number as f64;
                 kona_macros::set!(gauge, crate::Metrics::PIPELINE_LATEST_SYS_CONFIG_UPDATE, next);
                 info!(target: "traversal", "System config updated at block {next}.");
+                let system_config = self.system_config;
+                self.events.emit(|| DerivationEvent::SystemConfigUpdated {
+                    origin: block_info.id(),
+                    updates,
+                    system_config,
+                });
             }
-            Ok(false) => { /* Ignore, no update applied */ }
+            Ok(_) => { /* Ignore, no update applied */ }
             Err(err) => {
                 error!(target: "traversal", ?err, "Failed to update system config at block {}", block_info.number);
                 kona_macros::set!(
@@ -151,6 +167,29 @@ impl<F: ChainProvider + Send> SignalReceiver for IndexedTraversal<F> {
     }
 }
 
+#[async_trait]
+impl<F: ChainProvider + Send> StageCheckpoint for IndexedTraversal<F> {
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        checkpoint.origin = self.block.ok_or(PipelineError::MissingOrigin.crit())?;
+        checkpoint.system_config = self.system_config;
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        // Reject checkpoints whose origin is no longer canonical.
+        let origin = checkpoint.origin;
+        let canonical =
+            self.data_source.block_info_by_number(origin.number).await.map_err(Into::into)?;
+        if canonical.hash != origin.hash {
+            return Err(ResetError::ReorgDetected(origin.hash, canonical.hash).into());
+        }
+
+        self.update_origin(origin);
+        self.system_config = checkpoint.system_config;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
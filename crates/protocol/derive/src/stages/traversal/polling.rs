@@ -1,8 +1,9 @@
 //! Contains the [`PollingTraversal`] stage of the derivation pipeline.
 
 use crate::{
-    ActivationSignal, ChainProvider, L1RetrievalProvider, OriginAdvancer, OriginProvider,
-    PipelineError, PipelineResult, ResetError, ResetSignal, Signal, SignalReceiver,
+    ActivationSignal, ChainProvider, DerivationEvent, L1RetrievalProvider, OriginAdvancer,
+    OriginProvider, PipelineCheckpoint, PipelineError, PipelineResult, ResetError, ResetSignal,
+    Signal, SignalReceiver, StageCheckpoint, types::EventEmitter,
 };
 use alloc::{boxed::Box, sync::Arc};
 use alloy_primitives::Address;
@@ -29,6 +30,8 @@ pub struct PollingTraversal<Provider: ChainProvider> {
     pub system_config: SystemConfig,
     /// A reference to the rollup config.
     pub rollup_config: Arc<RollupConfig>,
+    /// Emits the system config updates.
+    pub(crate) events: EventEmitter,
 }
 
 #[async_trait]
@@ -56,9 +59,16 @@ impl<F: ChainProvider> PollingTraversal<F> {
             done: false,
             system_config: SystemConfig::default(),
             rollup_config: cfg,
+            events: EventEmitter::new(None),
         }
     }
 
+    /// Sets the [`EventEmitter`] of the traversal stage.
+    pub(crate) fn with_events(mut self, events: EventEmitter) -> Self {
+        self.events = events;
+        self
+    }
+
     /// Update the origin block in the traversal stage.
     fn update_origin(&mut self, block: BlockInfo) {
         self.done = false;
@@ -100,13 +110,19 @@ impl<F: ChainProvider + Send> OriginAdvancer for PollingTraversal<F> {
 
         let addr = self.rollup_config.l1_system_config_address;
         let active = self.rollup_config.is_ecotone_active(next_l1_origin.timestamp);
-        match self.system_config.update_with_receipts(&receipts[..], addr, active) {
-            Ok(true) => {
+        match self.system_config.apply_receipts(&receipts[..], addr, active) {
+            Ok(updates) if !updates.is_empty() => {
                 let next = next_l1_origin.number as f64;
                 kona_macros::set!(gauge, crate::Metrics::PIPELINE_LATEST_SYS_CONFIG_UPDATE, next);
                 info!(target: "l1_traversal", "System config updated at block {next}.");
+                let system_config = self.system_config;
+                self.events.emit(|| DerivationEvent::SystemConfigUpdated {
+                    origin: next_l1_origin.id(),
+                    updates,
+                    system_config,
+                });
             }
-            Ok(false) => { /* Ignore, no update applied */ }
+            Ok(_) => { /* Ignore, no update applied */ }
             Err(err) => {
                 error!(target: "l1_traversal", ?err, "Failed to update system config at block {}", next_l1_origin.number);
                 kona_macros::set!(
@@ -172,12 +188,35 @@ impl<F: ChainProvider + Send> SignalReceiver for PollingTraversal<F> {
     }
 }
 
+#[async_trait]
+impl<F: ChainProvider + Send> StageCheckpoint for PollingTraversal<F> {
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        checkpoint.origin = self.block.ok_or(PipelineError::MissingOrigin.crit())?;
+        checkpoint.system_config = self.system_config;
+        Ok(())
+    }
+
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        // Reject checkpoints whose origin is no longer canonical.
+        let origin = checkpoint.origin;
+        let canonical =
+            self.data_source.block_info_by_number(origin.number).await.map_err(Into::into)?;
+        if canonical.hash != origin.hash {
+            return Err(ResetError::ReorgDetected(origin.hash, canonical.hash).into());
+        }
+
+        self.update_origin(origin);
+        self.system_config = checkpoint.system_config;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 pub(crate) mod tests {
     use super::*;
     use crate::{errors::PipelineErrorKind, test_utils::TraversalTestHelper};
     use alloc::vec;
-    use alloy_primitives::{address, b256};
+    use alloy_primitives::{B256, address, b256};
 
     #[test]
     fn test_l1_traversal_batcher_address() {
@@ -310,4 +349,65 @@ pub(crate) mod tests {
         let expected = address!("000000000000000000000000000000000000bEEF");
         assert_eq!(traversal.system_config.batcher_address, expected);
     }
+
+    #[tokio::test]
+    async fn test_l1_traversal_system_config_updated_event() {
+        use kona_genesis::{BatcherUpdate, SystemConfigUpdate};
+
+        let blocks = vec![BlockInfo::default(), BlockInfo::default()];
+        let receipts = TraversalTestHelper::new_receipts();
+        let sink = crate::test_utils::TestEventSink::default();
+        let mut traversal = TraversalTestHelper::new_from_blocks(blocks, receipts)
+            .with_events(EventEmitter::new(Some(Arc::new(sink.clone()))));
+        assert!(traversal.advance_origin().await.is_ok());
+
+        let update = SystemConfigUpdate::Batcher(BatcherUpdate {
+            batcher_address: address!("000000000000000000000000000000000000bEEF"),
+        });
+        assert_eq!(
+            sink.events(),
+            vec![DerivationEvent::SystemConfigUpdated {
+                origin: BlockInfo::default().id(),
+                updates: vec![update.clone(), update],
+                system_config: traversal.system_config,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_l1_traversal_checkpoint_restore() {
+        let blocks = vec![
+            BlockInfo { number: 0, hash: B256::with_last_byte(1), ..Default::default() },
+            BlockInfo { number: 1, hash: B256::with_last_byte(2), ..Default::default() },
+        ];
+        let mut traversal = TraversalTestHelper::new_from_blocks(blocks.clone(), vec![]);
+        let system_config =
+            SystemConfig { batcher_address: Address::with_last_byte(1), ..Default::default() };
+        let checkpoint =
+            PipelineCheckpoint { origin: blocks[1], system_config, ..Default::default() };
+
+        traversal.done = true;
+        traversal.restore(&checkpoint).await.unwrap();
+        assert_eq!(traversal.origin(), Some(blocks[1]));
+        assert_eq!(traversal.system_config, system_config);
+        assert!(!traversal.done);
+
+        let mut restored = PipelineCheckpoint::default();
+        traversal.checkpoint(&mut restored).unwrap();
+        assert_eq!(restored.origin, checkpoint.origin);
+        assert_eq!(restored.system_config, checkpoint.system_config);
+    }
+
+    #[tokio::test]
+    async fn test_l1_traversal_restore_reorged_origin() {
+        let blocks =
+            vec![BlockInfo { number: 0, hash: B256::with_last_byte(1), ..Default::default() }];
+        let mut traversal = TraversalTestHelper::new_from_blocks(blocks, vec![]);
+        let origin = BlockInfo { number: 0, hash: B256::with_last_byte(2), ..Default::default() };
+        let checkpoint = PipelineCheckpoint { origin, ..Default::default() };
+
+        let err = traversal.restore(&checkpoint).await.unwrap_err();
+        assert_eq!(err, ResetError::ReorgDetected(origin.hash, B256::with_last_byte(1)).into());
+        assert_eq!(traversal.origin(), Some(BlockInfo::default()));
+    }
 }
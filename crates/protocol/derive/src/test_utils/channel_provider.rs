@@ -5,8 +5,8 @@
 use crate::{
     errors::PipelineError,
     stages::NextFrameProvider,
-    traits::{OriginAdvancer, OriginProvider, SignalReceiver},
-    types::{PipelineResult, Signal},
+    traits::{OriginAdvancer, OriginProvider, SignalReceiver, StageCheckpoint},
+    types::{PipelineCheckpoint, PipelineResult, Signal},
 };
 use alloc::{boxed::Box, vec::Vec};
 use async_trait::async_trait;
@@ -63,3 +63,14 @@ impl SignalReceiver for TestNextFrameProvider {
         Ok(())
     }
 }
+
+#[async_trait]
+impl StageCheckpoint for TestNextFrameProvider {
+    fn checkpoint(&self, _: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        Ok(())
+    }
+
+    async fn restore(&mut self, _: &PipelineCheckpoint) -> PipelineResult<()> {
+        Ok(())
+    }
+}
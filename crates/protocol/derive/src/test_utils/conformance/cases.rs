@@ -0,0 +1,454 @@
+//! Seed [`DerivationFixture`]s for Holocene derivation.
+//!
+//! All seed fixtures share the same chains. Holocene is active from genesis, L1 blocks are four
+//! seconds apart and L2 blocks two seconds apart, so each epoch spans two L2 blocks. The batcher
+//! submits the batches of epoch `n` from L1 block `n + 1` onwards, and L1 block `0` carries no
+//! batcher data. Derivation starts from the L2 genesis block.
+
+use crate::{
+    ChannelDropReason, DerivationEvent, FrameDropReason,
+    test_utils::{
+        DerivationFixture, FixtureAttributes, FixtureL1Block, batcher_transaction, channel_frames,
+        compress_batches,
+    },
+};
+use alloc::{format, string::ToString, vec, vec::Vec};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{B128, B256, Bytes, FixedBytes, keccak256};
+use kona_genesis::{ChainGenesis, HardForkConfig, RollupConfig, SystemConfig};
+use kona_protocol::{
+    Batch, BatchValidity, BlockInfo, ChannelId, Frame, L2BlockInfo, SingleBatch, SpanBatch,
+};
+
+/// The L1 block time of the seed fixtures.
+const L1_BLOCK_TIME: u64 = 4;
+
+/// The L2 block time of the seed fixtures.
+const BLOCK_TIME: u64 = 2;
+
+/// The sequencing window size of the seed fixtures.
+const SEQ_WINDOW_SIZE: u64 = 10;
+
+/// The Granite channel timeout of the seed fixtures.
+const CHANNEL_TIMEOUT: u64 = 2;
+
+/// Returns all seed [`DerivationFixture`]s.
+pub fn seed_fixtures() -> Vec<DerivationFixture> {
+    vec![
+        steady_state_fixture(),
+        strict_ordering_fixture(),
+        span_batch_overlap_fixture(),
+        channel_timeout_fixture(),
+        invalid_frames_fixture(),
+    ]
+}
+
+/// Returns a [`DerivationFixture`] covering the Holocene steady state.
+///
+/// The batcher submits well-formed, strictly ordered data: a channel spanning two L1 blocks and
+/// single and span batches, without any frame, channel or batch to drop. Deriving it with the
+/// [`ChannelAssembler`] and [`BatchValidator`] selected at Holocene must produce the same
+/// attributes as the [`ChannelBank`] and [`BatchQueue`] used before Holocene.
+///
+/// [`ChannelAssembler`]: crate::ChannelAssembler
+/// [`BatchValidator`]: crate::BatchValidator
+/// [`ChannelBank`]: crate::ChannelBank
+/// [`BatchQueue`]: crate::BatchQueue
+pub fn steady_state_fixture() -> DerivationFixture {
+    let split = channel(2, &[single_batch(2), single_batch(3)], 3);
+    let l1_blocks = vec![
+        vec![],
+        vec![channel_transaction(1, &[single_batch(1)])],
+        vec![batcher_transaction(&split[..2])],
+        vec![
+            batcher_transaction(&split[2..]),
+            channel_transaction(3, &[Batch::Span(span_batch(4, 5))]),
+        ],
+        vec![channel_transaction(4, &[Batch::Span(span_batch(6, 7))])],
+        vec![channel_transaction(5, &[single_batch(8)])],
+    ];
+    let expected_attributes = vec![
+        single_attributes(1, 1),
+        single_attributes(2, 3),
+        single_attributes(3, 3),
+        span_attributes(4, 3, false),
+        span_attributes(5, 3, true),
+        span_attributes(6, 4, false),
+        span_attributes(7, 4, true),
+        single_attributes(8, 5),
+    ];
+    fixture("holocene_steady_state", l1_blocks, 8, expected_attributes, Vec::new())
+}
+
+/// Returns a [`DerivationFixture`] covering the Holocene strict batch ordering.
+///
+/// A batch from the future is dropped along with the remainder of its channel, and a batch from
+/// the past is dropped without affecting the following batches of its channel.
+pub fn strict_ordering_fixture() -> DerivationFixture {
+    let l1_blocks = vec![
+        vec![],
+        vec![channel_transaction(1, &[single_batch(1)])],
+        vec![channel_transaction(2, &[single_batch(3), single_batch(2)])],
+        vec![
+            channel_transaction(3, &[single_batch(2), single_batch(3)]),
+            channel_transaction(4, &[single_batch(3), single_batch(4)]),
+        ],
+    ];
+    let expected_attributes = vec![
+        single_attributes(1, 1),
+        single_attributes(2, 3),
+        single_attributes(3, 3),
+        single_attributes(4, 3),
+    ];
+    let expected_events = vec![
+        batch_checked(2, 3, false, BatchValidity::Drop),
+        batch_checked(3, 3, false, BatchValidity::Past),
+    ];
+    fixture("holocene_strict_ordering", l1_blocks, 4, expected_attributes, expected_events)
+}
+
+/// Returns a [`DerivationFixture`] covering span batches overlapping the L2 safe head.
+///
+/// The overlapping blocks of a span batch are skipped, a span batch entirely behind the L2 safe
+/// head is dropped as past, and an overlapping span batch with a mismatching parent is dropped.
+pub fn span_batch_overlap_fixture() -> DerivationFixture {
+    let mut bad_parent = span_batch(5, 6);
+    bad_parent.parent_check = FixedBytes::from_slice(&l2_hash(3)[..20]);
+
+    let l1_blocks = vec![
+        vec![],
+        vec![channel_transaction(1, &[single_batch(1)])],
+        vec![channel_transaction(2, &[Batch::Span(span_batch(1, 3))])],
+        vec![
+            channel_transaction(3, &[Batch::Span(span_batch(3, 5))]),
+            channel_transaction(4, &[Batch::Span(span_batch(2, 3))]),
+        ],
+        vec![channel_transaction(5, &[Batch::Span(bad_parent)])],
+    ];
+    let expected_attributes = vec![
+        single_attributes(1, 1),
+        span_attributes(2, 2, false),
+        span_attributes(3, 2, true),
+        span_attributes(4, 3, false),
+        span_attributes(5, 3, true),
+    ];
+    let expected_events = vec![
+        batch_checked(3, 2, true, BatchValidity::Past),
+        batch_checked(4, 5, true, BatchValidity::Drop),
+    ];
+    fixture("holocene_span_batch_overlap", l1_blocks, 6, expected_attributes, expected_events)
+}
+
+/// Returns a [`DerivationFixture`] covering channel timeouts.
+///
+/// A channel opened in L1 block `2` times out in L1 block `5`, so its last frame is dropped and
+/// its batches are only derived from the channel resubmitting them.
+pub fn channel_timeout_fixture() -> DerivationFixture {
+    let timed_out = channel(2, &[single_batch(2), single_batch(3)], 2);
+    let l1_blocks = vec![
+        vec![],
+        vec![channel_transaction(1, &[single_batch(1)])],
+        vec![batcher_transaction(&timed_out[..1])],
+        vec![],
+        vec![],
+        vec![
+            batcher_transaction(&timed_out[1..]),
+            channel_transaction(3, &[single_batch(2), single_batch(3), single_batch(4)]),
+        ],
+    ];
+    let expected_attributes = vec![
+        single_attributes(1, 1),
+        single_attributes(2, 5),
+        single_attributes(3, 5),
+        single_attributes(4, 5),
+    ];
+    let expected_events = vec![
+        DerivationEvent::ChannelTimedOut {
+            origin: l1_block(5).id(),
+            channel_id: B128::repeat_byte(2),
+            open_block: 2,
+        },
+        DerivationEvent::FrameDropped {
+            origin: l1_block(5).id(),
+            channel_id: B128::repeat_byte(2),
+            frame_number: 1,
+            reason: FrameDropReason::NoOpenChannel,
+        },
+    ];
+    fixture("holocene_channel_timeout", l1_blocks, 4, expected_attributes, expected_events)
+}
+
+/// Returns a [`DerivationFixture`] covering invalid frames.
+///
+/// Unparseable batcher data is dropped, non-sequential frames, frames after the last frame and
+/// frames not starting a channel are pruned, and an unfinished channel is superseded by the next
+/// channel.
+pub fn invalid_frames_fixture() -> DerivationFixture {
+    let mut first = channel(1, &[single_batch(1)], 2);
+    first.insert(1, Frame::new(channel_id(1), 2, vec![0xFF], false));
+    let superseded = channel(2, &[single_batch(2)], 2);
+    let mut complete = channel(4, &[single_batch(2), single_batch(3)], 1);
+    complete.push(Frame::new(channel_id(4), 1, vec![0xFF], true));
+
+    let l1_blocks = vec![
+        vec![],
+        vec![Bytes::from_static(&[0x01, 0xDE, 0xAD]), batcher_transaction(&first)],
+        vec![
+            batcher_transaction(&[
+                superseded[0].clone(),
+                Frame::new(channel_id(3), 1, vec![0xFF], true),
+            ]),
+            batcher_transaction(&complete),
+        ],
+    ];
+    let expected_attributes =
+        vec![single_attributes(1, 1), single_attributes(2, 2), single_attributes(3, 2)];
+    let expected_events = vec![
+        DerivationEvent::InvalidFrameData { origin: l1_block(1).id() },
+        frame_dropped(1, 1, 2, FrameDropReason::NonSequential),
+        frame_dropped(2, 3, 1, FrameDropReason::NotFirstFrame),
+        frame_dropped(2, 4, 1, FrameDropReason::AfterLastFrame),
+        DerivationEvent::ChannelDropped {
+            origin: l1_block(2).id(),
+            channel_id: B128::repeat_byte(2),
+            reason: ChannelDropReason::Superseded,
+        },
+    ];
+    fixture("holocene_invalid_frames", l1_blocks, 3, expected_attributes, expected_events)
+}
+
+/// Assembles a [`DerivationFixture`] from the batcher data of each L1 block, deriving from the
+/// L2 genesis block up to the L2 block with the given number.
+fn fixture(
+    name: &str,
+    batcher_data: Vec<Vec<Bytes>>,
+    l2_head: u64,
+    expected_attributes: Vec<FixtureAttributes>,
+    expected_events: Vec<DerivationEvent>,
+) -> DerivationFixture {
+    DerivationFixture {
+        name: name.to_string(),
+        rollup_config: rollup_config(),
+        system_config: SystemConfig::default(),
+        l1_blocks: batcher_data
+            .into_iter()
+            .enumerate()
+            .map(|(number, batcher_data)| FixtureL1Block {
+                info: l1_block(number as u64),
+                batcher_data,
+            })
+            .collect(),
+        l2_blocks: (0..=l2_head).map(l2_block).collect(),
+        safe_head: 0,
+        expected_attributes,
+        expected_events,
+    }
+}
+
+/// Returns the [`RollupConfig`] of the seed fixtures.
+fn rollup_config() -> RollupConfig {
+    RollupConfig {
+        genesis: ChainGenesis {
+            l1: l1_block(0).id(),
+            l2: l2_block(0).block_info.id(),
+            l2_time: 0,
+            system_config: Some(SystemConfig::default()),
+        },
+        block_time: BLOCK_TIME,
+        seq_window_size: SEQ_WINDOW_SIZE,
+        granite_channel_timeout: CHANNEL_TIMEOUT,
+        hardforks: HardForkConfig { holocene_time: Some(0), ..Default::default() },
+        ..Default::default()
+    }
+}
+
+/// Returns the hash of the L1 block with the given number.
+fn l1_hash(number: u64) -> B256 {
+    keccak256(format!("l1-{number}"))
+}
+
+/// Returns the hash of the L2 block with the given number.
+fn l2_hash(number: u64) -> B256 {
+    keccak256(format!("l2-{number}"))
+}
+
+/// Returns the L1 block with the given number.
+fn l1_block(number: u64) -> BlockInfo {
+    let parent_hash = number.checked_sub(1).map(l1_hash).unwrap_or_default();
+    BlockInfo::new(l1_hash(number), number, parent_hash, number * L1_BLOCK_TIME)
+}
+
+/// Returns the L2 block with the given number.
+fn l2_block(number: u64) -> L2BlockInfo {
+    let parent_hash = number.checked_sub(1).map(l2_hash).unwrap_or_default();
+    let timestamp = number * BLOCK_TIME;
+    let epoch = timestamp / L1_BLOCK_TIME;
+    L2BlockInfo::new(
+        BlockInfo::new(l2_hash(number), number, parent_hash, timestamp),
+        BlockNumHash { number: epoch, hash: l1_hash(epoch) },
+        number % (L1_BLOCK_TIME / BLOCK_TIME),
+    )
+}
+
+/// Returns the opaque batch transaction of the L2 block with the given number.
+fn transaction(number: u64) -> Bytes {
+    Bytes::from(vec![0x02, number as u8])
+}
+
+/// Returns the [`SingleBatch`] of the L2 block with the given number.
+fn single_batch(number: u64) -> Batch {
+    let block = l2_block(number);
+    Batch::Single(SingleBatch {
+        parent_hash: block.block_info.parent_hash,
+        epoch_num: block.l1_origin.number,
+        epoch_hash: block.l1_origin.hash,
+        timestamp: block.block_info.timestamp,
+        transactions: vec![transaction(number)],
+    })
+}
+
+/// Returns the [`SpanBatch`] of the L2 blocks in the given range, without transactions.
+fn span_batch(first: u64, last: u64) -> SpanBatch {
+    let cfg = rollup_config();
+    let mut span = SpanBatch {
+        genesis_timestamp: cfg.genesis.l2_time,
+        chain_id: cfg.l2_chain_id.id(),
+        ..Default::default()
+    };
+    for number in first..=last {
+        let block = l2_block(number);
+        let batch = SingleBatch {
+            parent_hash: block.block_info.parent_hash,
+            epoch_num: block.l1_origin.number,
+            epoch_hash: block.l1_origin.hash,
+            timestamp: block.block_info.timestamp,
+            transactions: Vec::new(),
+        };
+        span.append_singular_batch(batch, block.seq_num).expect("span batch must be appendable");
+    }
+    span
+}
+
+/// Returns the [`ChannelId`] with all bytes set to the given byte.
+const fn channel_id(id: u8) -> ChannelId {
+    [id; 16]
+}
+
+/// Returns the [`Frame`]s of a channel carrying the given batches.
+fn channel(id: u8, batches: &[Batch], frames: usize) -> Vec<Frame> {
+    channel_frames(channel_id(id), &compress_batches(batches), frames)
+}
+
+/// Returns a batcher transaction carrying a single frame channel with the given batches.
+fn channel_transaction(id: u8, batches: &[Batch]) -> Bytes {
+    batcher_transaction(&channel(id, batches, 1))
+}
+
+/// Returns the attributes derived from the [`SingleBatch`] of the L2 block with the given
+/// number, in the given L1 block.
+fn single_attributes(number: u64, derived_from: u64) -> FixtureAttributes {
+    let block = l2_block(number);
+    FixtureAttributes {
+        parent: number - 1,
+        timestamp: block.block_info.timestamp,
+        epoch: block.l1_origin.number,
+        transactions: vec![transaction(number)],
+        derived_from,
+        is_last_in_span: true,
+    }
+}
+
+/// Returns the attributes derived from a [`SpanBatch`] for the L2 block with the given number,
+/// in the given L1 block.
+fn span_attributes(number: u64, derived_from: u64, is_last_in_span: bool) -> FixtureAttributes {
+    FixtureAttributes {
+        transactions: Vec::new(),
+        is_last_in_span,
+        ..single_attributes(number, derived_from)
+    }
+}
+
+/// Returns the [`DerivationEvent::BatchChecked`] event for the batch starting at the L2 block
+/// with the given number, checked in the given L1 block.
+fn batch_checked(
+    origin: u64,
+    number: u64,
+    is_span: bool,
+    validity: BatchValidity,
+) -> DerivationEvent {
+    DerivationEvent::BatchChecked {
+        origin: l1_block(origin).id(),
+        timestamp: l2_block(number).block_info.timestamp,
+        is_span,
+        validity,
+    }
+}
+
+/// Returns the [`DerivationEvent::FrameDropped`] event for the given frame, dropped in the given
+/// L1 block.
+fn frame_dropped(
+    origin: u64,
+    id: u8,
+    frame_number: u16,
+    reason: FrameDropReason,
+) -> DerivationEvent {
+    DerivationEvent::FrameDropped {
+        origin: l1_block(origin).id(),
+        channel_id: B128::repeat_byte(id),
+        frame_number,
+        reason,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_steady_state_fixture() {
+        steady_state_fixture().run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_steady_state_matches_pre_holocene_stages() {
+        // Before Holocene, the same data is derived by the channel bank and the batch queue.
+        let mut fixture = steady_state_fixture();
+        fixture.rollup_config.hardforks =
+            HardForkConfig { granite_time: Some(0), ..Default::default() };
+        assert!(!fixture.rollup_config.is_holocene_active(0));
+
+        let pre_holocene = fixture.run().await.unwrap();
+        let holocene = steady_state_fixture().run().await.unwrap();
+        assert_eq!(pre_holocene, holocene);
+    }
+
+    #[tokio::test]
+    async fn test_strict_ordering_fixture() {
+        strict_ordering_fixture().run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_span_batch_overlap_fixture() {
+        span_batch_overlap_fixture().run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_channel_timeout_fixture() {
+        channel_timeout_fixture().run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_invalid_frames_fixture() {
+        invalid_frames_fixture().run().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_fixture_verify_mismatch() {
+        let mut fixture = strict_ordering_fixture();
+        fixture.expected_events.pop();
+        assert!(matches!(
+            fixture.run().await,
+            Err(crate::test_utils::FixtureError::EventMismatch { index: 1, .. })
+        ));
+    }
+}
@@ -0,0 +1,39 @@
+//! Batcher data encoding for derivation conformance fixtures.
+
+use alloc::vec::Vec;
+use alloy_primitives::Bytes;
+use alloy_rlp::Encodable;
+use kona_protocol::{Batch, ChannelId, DERIVATION_VERSION_0, Frame};
+
+/// The zlib compression level used for the channel data.
+const ZLIB_COMPRESSION_LEVEL: u8 = 9;
+
+/// Encodes the [`Batch`]es into zlib compressed channel data.
+pub fn compress_batches(batches: &[Batch]) -> Vec<u8> {
+    let mut rlp = Vec::new();
+    for batch in batches {
+        let mut encoded = Vec::new();
+        batch.encode(&mut encoded).expect("batch must be encodable");
+        Bytes::from(encoded).encode(&mut rlp);
+    }
+    miniz_oxide::deflate::compress_to_vec_zlib(&rlp, ZLIB_COMPRESSION_LEVEL)
+}
+
+/// Splits the channel data into at most `count` [`Frame`]s of the channel with the given ID.
+pub fn channel_frames(id: ChannelId, data: &[u8], count: usize) -> Vec<Frame> {
+    let chunks = data.chunks(data.len().div_ceil(count.max(1)).max(1)).collect::<Vec<_>>();
+    chunks
+        .iter()
+        .enumerate()
+        .map(|(i, chunk)| Frame::new(id, i as u16, chunk.to_vec(), i + 1 == chunks.len()))
+        .collect()
+}
+
+/// Encodes the [`Frame`]s into the data of a batcher transaction.
+pub fn batcher_transaction(frames: &[Frame]) -> Bytes {
+    let mut data = Vec::from([DERIVATION_VERSION_0]);
+    for frame in frames {
+        data.extend_from_slice(&frame.encode());
+    }
+    data.into()
+}
@@ -0,0 +1,82 @@
+//! The derivation conformance fixture format.
+
+use crate::DerivationEvent;
+use alloc::{string::String, vec::Vec};
+use alloy_primitives::Bytes;
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_protocol::{BlockInfo, L2BlockInfo};
+
+/// A derivation conformance fixture.
+///
+/// The fixture describes the L1 chain with the batcher data included in each block, the canonical
+/// L2 chain, and the payload attributes and dropped frames, channels and batches that deriving
+/// the L2 chain from the L1 chain must produce.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DerivationFixture {
+    /// The name of the fixture.
+    pub name: String,
+    /// The rollup config of the L2 chain.
+    pub rollup_config: RollupConfig,
+    /// The system config at the L2 safe head.
+    pub system_config: SystemConfig,
+    /// The L1 chain, starting at the L1 origin of the L2 safe head.
+    pub l1_blocks: Vec<FixtureL1Block>,
+    /// The canonical L2 chain, including the L2 safe head and the blocks derived on top of it.
+    pub l2_blocks: Vec<L2BlockInfo>,
+    /// The number of the L2 safe head the pipeline is reset to.
+    pub safe_head: u64,
+    /// The expected payload attributes, in derivation order.
+    pub expected_attributes: Vec<FixtureAttributes>,
+    /// The expected dropped frames, channels and batches, in emission order.
+    ///
+    /// Only the events for which [`DerivationFixture::is_dropped`] holds are compared.
+    pub expected_events: Vec<DerivationEvent>,
+}
+
+impl DerivationFixture {
+    /// Returns whether the [`DerivationEvent`] reports data that was dropped by the pipeline.
+    pub const fn is_dropped(event: &DerivationEvent) -> bool {
+        match event {
+            DerivationEvent::InvalidFrameData { .. } |
+            DerivationEvent::FrameDropped { .. } |
+            DerivationEvent::ChannelTimedOut { .. } |
+            DerivationEvent::ChannelDropped { .. } => true,
+            DerivationEvent::BatchChecked { validity, .. } => {
+                validity.is_drop() || validity.is_outdated()
+            }
+            _ => false,
+        }
+    }
+}
+
+/// An L1 block of a [`DerivationFixture`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct FixtureL1Block {
+    /// The block info.
+    pub info: BlockInfo,
+    /// The data of the batcher transactions included in the block, in order.
+    pub batcher_data: Vec<Bytes>,
+}
+
+/// The payload attributes derived by the pipeline, reduced to the fields a fixture asserts on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct FixtureAttributes {
+    /// The number of the L2 parent block.
+    pub parent: u64,
+    /// The timestamp of the L2 block.
+    pub timestamp: u64,
+    /// The number of the L1 origin of the L2 block.
+    pub epoch: u64,
+    /// The batch transactions of the L2 block.
+    pub transactions: Vec<Bytes>,
+    /// The number of the L1 block the attributes were derived from.
+    pub derived_from: u64,
+    /// Whether the attributes are the last derived from a span batch.
+    pub is_last_in_span: bool,
+}
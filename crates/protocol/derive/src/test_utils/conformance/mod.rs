@@ -0,0 +1,23 @@
+//! Derivation conformance fixtures.
+//!
+//! A [`DerivationFixture`] pins the payload attributes and the dropped frames, channels and
+//! batches that the [`DerivationPipeline`] must produce for a given L1 chain and rollup config.
+//! Fixtures are executed against the pipeline with [`DerivationFixture::run`], and the seeded
+//! Holocene fixtures are returned by [`seed_fixtures`].
+//!
+//! [`DerivationPipeline`]: crate::DerivationPipeline
+
+mod fixture;
+pub use fixture::{DerivationFixture, FixtureAttributes, FixtureL1Block};
+
+mod runner;
+pub use runner::{FixtureAttributesBuilder, FixtureDataSource, FixtureError, FixtureOutcome};
+
+mod encoding;
+pub use encoding::{batcher_transaction, channel_frames, compress_batches};
+
+mod cases;
+pub use cases::{
+    channel_timeout_fixture, invalid_frames_fixture, seed_fixtures, span_batch_overlap_fixture,
+    steady_state_fixture, strict_ordering_fixture,
+};
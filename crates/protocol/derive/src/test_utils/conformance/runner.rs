@@ -0,0 +1,325 @@
+//! Executes a [`DerivationFixture`] against the [`DerivationPipeline`].
+//!
+//! [`DerivationPipeline`]: crate::DerivationPipeline
+
+use crate::{
+    ActivationSignal, AttributesBuilder, DataAvailabilityProvider, DerivationEvent, OriginProvider,
+    Pipeline, PipelineBuilder, PipelineError, PipelineErrorKind, PipelineResult, ResetError,
+    ResetSignal, SignalReceiver, StepResult,
+    test_utils::{
+        DerivationFixture, FixtureAttributes, FixtureL1Block, TestChainProvider, TestEventSink,
+        TestL2ChainProvider,
+    },
+};
+use alloc::{boxed::Box, collections::VecDeque, sync::Arc, vec::Vec};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::{Address, Bytes};
+use alloy_rpc_types_engine::PayloadAttributes;
+use async_trait::async_trait;
+use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
+use op_alloy_rpc_types_engine::OpPayloadAttributes;
+use thiserror::Error;
+
+/// The maximum number of pipeline steps a [`DerivationFixture`] may take.
+const MAX_STEPS: usize = 10_000;
+
+/// An error executing or verifying a [`DerivationFixture`].
+#[derive(Error, Debug)]
+pub enum FixtureError {
+    /// The fixture is missing an L1 block.
+    #[error("fixture is missing L1 block #{0}")]
+    MissingL1Block(u64),
+    /// The fixture is missing an L2 block.
+    #[error("fixture is missing L2 block #{0}")]
+    MissingL2Block(u64),
+    /// The derived L2 block differs from the canonical L2 block of the fixture.
+    #[error("derived L2 block #{0} differs from the canonical L2 block")]
+    Diverged(u64),
+    /// The pipeline returned an unexpected error.
+    #[error("pipeline error: {0}")]
+    Pipeline(PipelineErrorKind),
+    /// The pipeline did not exhaust the L1 chain within the step limit.
+    #[error("pipeline did not exhaust the L1 chain within {0} steps")]
+    StepLimit(usize),
+    /// The derived attributes differ from the expected attributes.
+    #[error("attributes #{index} mismatch: expected {expected:?}, got {actual:?}")]
+    AttributesMismatch {
+        /// The index of the attributes.
+        index: usize,
+        /// The expected attributes.
+        expected: Option<FixtureAttributes>,
+        /// The derived attributes.
+        actual: Option<FixtureAttributes>,
+    },
+    /// The emitted events differ from the expected events.
+    #[error("event #{index} mismatch: expected {expected:?}, got {actual:?}")]
+    EventMismatch {
+        /// The index of the event.
+        index: usize,
+        /// The expected event.
+        expected: Option<DerivationEvent>,
+        /// The emitted event.
+        actual: Option<DerivationEvent>,
+    },
+}
+
+/// The outcome of executing a [`DerivationFixture`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FixtureOutcome {
+    /// The derived attributes, in derivation order.
+    pub attributes: Vec<FixtureAttributes>,
+    /// The dropped frames, channels and batches, in emission order.
+    pub events: Vec<DerivationEvent>,
+}
+
+impl FixtureOutcome {
+    /// Verifies the outcome against the expectations of the [`DerivationFixture`].
+    pub fn verify(&self, fixture: &DerivationFixture) -> Result<(), FixtureError> {
+        let len = self.attributes.len().max(fixture.expected_attributes.len());
+        for index in 0..len {
+            let expected = fixture.expected_attributes.get(index);
+            let actual = self.attributes.get(index);
+            if expected != actual {
+                return Err(FixtureError::AttributesMismatch {
+                    index,
+                    expected: expected.cloned(),
+                    actual: actual.cloned(),
+                });
+            }
+        }
+
+        let len = self.events.len().max(fixture.expected_events.len());
+        for index in 0..len {
+            let expected = fixture.expected_events.get(index);
+            let actual = self.events.get(index);
+            if expected != actual {
+                return Err(FixtureError::EventMismatch {
+                    index,
+                    expected: expected.cloned(),
+                    actual: actual.cloned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DerivationFixture {
+    /// Executes the fixture and verifies the outcome against its expectations.
+    pub async fn run(&self) -> Result<FixtureOutcome, FixtureError> {
+        let outcome = self.execute().await?;
+        outcome.verify(self)?;
+        Ok(outcome)
+    }
+
+    /// Executes the fixture against the [`DerivationPipeline`], driving it from the L2 safe head
+    /// until the L1 chain is exhausted.
+    ///
+    /// The L2 safe head is advanced to the canonical L2 block of the fixture after each derived
+    /// payload, in place of executing it.
+    ///
+    /// [`DerivationPipeline`]: crate::DerivationPipeline
+    pub async fn execute(&self) -> Result<FixtureOutcome, FixtureError> {
+        let rollup_config = Arc::new(self.rollup_config.clone());
+        let mut safe_head = self.l2_block(self.safe_head)?;
+        let origin = self.l1_block(safe_head.l1_origin.number)?.info;
+        let last = self.l1_blocks.last().map(|block| block.info.number).unwrap_or_default();
+
+        let mut chain_provider = TestChainProvider::default();
+        for block in &self.l1_blocks {
+            chain_provider.insert_block(block.info.number, block.info);
+            chain_provider.insert_receipts(block.info.hash, Vec::new());
+        }
+        let system_configs = self
+            .l2_blocks
+            .iter()
+            .map(|block| (block.block_info.number, self.system_config))
+            .collect();
+        let l2_chain_provider =
+            TestL2ChainProvider::new(self.l2_blocks.clone(), Vec::new(), system_configs);
+        let sink = TestEventSink::default();
+
+        let mut pipeline = PipelineBuilder::new()
+            .rollup_config(Arc::clone(&rollup_config))
+            .origin(origin)
+            .dap_source(FixtureDataSource::new(self.l1_blocks.clone()))
+            .builder(FixtureAttributesBuilder::new(rollup_config.block_time))
+            .chain_provider(chain_provider)
+            .l2_chain_provider(l2_chain_provider)
+            .event_sink(Arc::new(sink.clone()))
+            .build_polled();
+
+        let reset = ResetSignal { l2_safe_head: safe_head, l1_origin: origin, system_config: None };
+        pipeline.signal(reset.signal()).await.map_err(FixtureError::Pipeline)?;
+
+        let mut attributes = Vec::new();
+        for _ in 0..MAX_STEPS {
+            match pipeline.step(safe_head).await {
+                StepResult::PreparedAttributes => {
+                    let Some(prepared) = pipeline.next() else { continue };
+                    let derived = self.fixture_attributes(&prepared)?;
+                    safe_head = self.l2_block(derived.parent + 1)?;
+                    if safe_head.block_info.timestamp != derived.timestamp ||
+                        safe_head.l1_origin.number != derived.epoch
+                    {
+                        return Err(FixtureError::Diverged(safe_head.block_info.number));
+                    }
+                    attributes.push(derived);
+                }
+                StepResult::AdvancedOrigin |
+                StepResult::StepFailed(PipelineErrorKind::Temporary(_)) => {}
+                StepResult::OriginAdvanceErr(PipelineErrorKind::Reset(
+                    ResetError::HoloceneActivation,
+                )) |
+                StepResult::StepFailed(PipelineErrorKind::Reset(
+                    ResetError::HoloceneActivation,
+                )) => {
+                    let l1_origin = pipeline
+                        .origin()
+                        .ok_or(FixtureError::Pipeline(PipelineError::MissingOrigin.crit()))?;
+                    let activation = ActivationSignal {
+                        l2_safe_head: safe_head,
+                        l1_origin,
+                        system_config: None,
+                    };
+                    pipeline.signal(activation.signal()).await.map_err(FixtureError::Pipeline)?;
+                }
+                StepResult::OriginAdvanceErr(PipelineErrorKind::Temporary(_))
+                    if pipeline.origin().is_some_and(|origin| origin.number == last) =>
+                {
+                    let events =
+                        sink.events().into_iter().filter(Self::is_dropped).collect::<Vec<_>>();
+                    return Ok(FixtureOutcome { attributes, events });
+                }
+                StepResult::OriginAdvanceErr(err) | StepResult::StepFailed(err) => {
+                    return Err(FixtureError::Pipeline(err));
+                }
+            }
+        }
+
+        Err(FixtureError::StepLimit(MAX_STEPS))
+    }
+
+    /// Returns the L1 block with the given number.
+    fn l1_block(&self, number: u64) -> Result<&FixtureL1Block, FixtureError> {
+        self.l1_blocks
+            .iter()
+            .find(|block| block.info.number == number)
+            .ok_or(FixtureError::MissingL1Block(number))
+    }
+
+    /// Returns the canonical L2 block with the given number.
+    fn l2_block(&self, number: u64) -> Result<L2BlockInfo, FixtureError> {
+        self.l2_blocks
+            .iter()
+            .find(|block| block.block_info.number == number)
+            .copied()
+            .ok_or(FixtureError::MissingL2Block(number))
+    }
+
+    /// Reduces the [`OpAttributesWithParent`] to [`FixtureAttributes`].
+    ///
+    /// The L1 origin is recovered from the `prev_randao` set by the [`FixtureAttributesBuilder`].
+    fn fixture_attributes(
+        &self,
+        attributes: &OpAttributesWithParent,
+    ) -> Result<FixtureAttributes, FixtureError> {
+        let epoch = self
+            .l1_blocks
+            .iter()
+            .find(|block| block.info.hash == attributes.inner.payload_attributes.prev_randao)
+            .map(|block| block.info.number)
+            .ok_or(FixtureError::Diverged(attributes.parent.block_info.number + 1))?;
+
+        Ok(FixtureAttributes {
+            parent: attributes.parent.block_info.number,
+            timestamp: attributes.inner.payload_attributes.timestamp,
+            epoch,
+            transactions: attributes.inner.transactions.clone().unwrap_or_default(),
+            derived_from: attributes.l1_origin.number,
+            is_last_in_span: attributes.is_last_in_span,
+        })
+    }
+}
+
+/// A [`DataAvailabilityProvider`] serving the batcher data of the [`FixtureL1Block`]s.
+#[derive(Debug, Clone, Default)]
+pub struct FixtureDataSource {
+    /// The L1 blocks of the fixture.
+    blocks: Vec<FixtureL1Block>,
+    /// The remaining batcher data of the open L1 block.
+    data: VecDeque<Bytes>,
+    /// Whether the batcher data of an L1 block has been loaded.
+    open: bool,
+}
+
+impl FixtureDataSource {
+    /// Creates a new [`FixtureDataSource`] serving the batcher data of the given L1 blocks.
+    pub const fn new(blocks: Vec<FixtureL1Block>) -> Self {
+        Self { blocks, data: VecDeque::new(), open: false }
+    }
+}
+
+#[async_trait]
+impl DataAvailabilityProvider for FixtureDataSource {
+    type Item = Bytes;
+
+    async fn next(&mut self, block_ref: &BlockInfo, _: Address) -> PipelineResult<Self::Item> {
+        if !self.open {
+            self.data = self
+                .blocks
+                .iter()
+                .find(|block| block.info.hash == block_ref.hash)
+                .map(|block| block.batcher_data.iter().cloned().collect())
+                .unwrap_or_default();
+            self.open = true;
+        }
+        self.data.pop_front().ok_or(PipelineError::Eof.temp())
+    }
+
+    fn clear(&mut self) {
+        self.data.clear();
+        self.open = false;
+    }
+}
+
+/// An [`AttributesBuilder`] preparing deposit-free payload attributes.
+///
+/// The hash of the L1 origin is set as the `prev_randao`, standing in for the mix hash of the L1
+/// origin, so that the L1 origin of derived attributes can be recovered.
+#[derive(Debug, Clone, Copy)]
+pub struct FixtureAttributesBuilder {
+    /// The L2 block time.
+    block_time: u64,
+}
+
+impl FixtureAttributesBuilder {
+    /// Creates a new [`FixtureAttributesBuilder`] with the given L2 block time.
+    pub const fn new(block_time: u64) -> Self {
+        Self { block_time }
+    }
+}
+
+#[async_trait]
+impl AttributesBuilder for FixtureAttributesBuilder {
+    async fn prepare_payload_attributes(
+        &mut self,
+        l2_parent: L2BlockInfo,
+        epoch: BlockNumHash,
+    ) -> PipelineResult<OpPayloadAttributes> {
+        Ok(OpPayloadAttributes {
+            payload_attributes: PayloadAttributes {
+                timestamp: l2_parent.block_info.timestamp + self.block_time,
+                prev_randao: epoch.hash,
+                suggested_fee_recipient: Address::ZERO,
+                withdrawals: None,
+                parent_beacon_block_root: None,
+            },
+            transactions: None,
+            no_tx_pool: Some(true),
+            gas_limit: None,
+            eip_1559_params: None,
+        })
+    }
+}
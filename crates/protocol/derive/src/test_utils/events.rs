@@ -0,0 +1,22 @@
+//! A [`DerivationEventSink`] collecting the emitted events.
+
+use crate::{DerivationEvent, DerivationEventSink};
+use alloc::{sync::Arc, vec::Vec};
+use spin::Mutex;
+
+/// A [`DerivationEventSink`] that collects the emitted [`DerivationEvent`]s.
+#[derive(Debug, Default, Clone)]
+pub struct TestEventSink(pub Arc<Mutex<Vec<DerivationEvent>>>);
+
+impl TestEventSink {
+    /// Returns the collected events.
+    pub fn events(&self) -> Vec<DerivationEvent> {
+        self.0.lock().clone()
+    }
+}
+
+impl DerivationEventSink for TestEventSink {
+    fn on_event(&self, event: DerivationEvent) {
+        self.0.lock().push(event);
+    }
+}
@@ -40,6 +40,9 @@ pub use channel_reader::TestChannelReaderProvider;
 mod frame_queue;
 pub use frame_queue::TestFrameQueueProvider;
 
+mod events;
+pub use events::TestEventSink;
+
 mod tracing;
 pub use tracing::{CollectingLayer, TraceStorage};
 
@@ -49,4 +52,12 @@ pub use sys_config_fetcher::{TestSystemConfigL2Fetcher, TestSystemConfigL2Fetche
 mod frames;
 pub use frames::{FrameQueueAsserter, FrameQueueBuilder};
 
+mod conformance;
+pub use conformance::{
+    DerivationFixture, FixtureAttributes, FixtureAttributesBuilder, FixtureDataSource,
+    FixtureError, FixtureL1Block, FixtureOutcome, batcher_transaction, channel_frames,
+    channel_timeout_fixture, compress_batches, invalid_frames_fixture, seed_fixtures,
+    span_batch_overlap_fixture, steady_state_fixture, strict_ordering_fixture,
+};
+
 mod macros;
@@ -0,0 +1,13 @@
+//! Contains the [`DerivationEventSink`] trait.
+
+use crate::DerivationEvent;
+use core::fmt::Debug;
+
+/// Receives the [`DerivationEvent`]s emitted by the derivation pipeline stages.
+///
+/// The sink is called synchronously from within the pipeline steps, so implementations should
+/// hand the events off quickly rather than perform blocking work.
+pub trait DerivationEventSink: Debug + Send + Sync {
+    /// Receives a [`DerivationEvent`].
+    fn on_event(&self, event: DerivationEvent);
+}
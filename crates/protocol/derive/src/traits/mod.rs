@@ -13,8 +13,11 @@ pub use attributes::{AttributesBuilder, AttributesProvider, NextAttributes};
 mod data_sources;
 pub use data_sources::{BlobProvider, DataAvailabilityProvider};
 
+mod events;
+pub use events::DerivationEventSink;
+
 mod reset;
 pub use reset::ResetProvider;
 
 mod stages;
-pub use stages::{OriginAdvancer, OriginProvider, SignalReceiver};
+pub use stages::{OriginAdvancer, OriginProvider, SignalReceiver, StageCheckpoint};
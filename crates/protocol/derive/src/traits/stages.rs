@@ -4,7 +4,7 @@ use alloc::boxed::Box;
 use async_trait::async_trait;
 use kona_protocol::BlockInfo;
 
-use crate::{PipelineResult, Signal};
+use crate::{PipelineCheckpoint, PipelineResult, Signal};
 
 /// Providers a way for the pipeline to accept a signal from the driver.
 #[async_trait]
@@ -26,3 +26,17 @@ pub trait OriginAdvancer {
     /// This method is the equivalent of the reference implementation `advance_l1_block`.
     async fn advance_origin(&mut self) -> PipelineResult<()>;
 }
+
+/// Records and restores the data buffered by a pipeline stage, along with the data buffered by the
+/// stages it reads from.
+#[async_trait]
+pub trait StageCheckpoint {
+    /// Records the data buffered by the stage into the [`PipelineCheckpoint`].
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()>;
+
+    /// Restores the data buffered by the stage from the [`PipelineCheckpoint`].
+    ///
+    /// The stages are restored from the bottom of the pipeline up, so that a checkpoint that is no
+    /// longer consistent with L1 is rejected before any stage is modified.
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()>;
+}
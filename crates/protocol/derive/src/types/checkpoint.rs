@@ -0,0 +1,260 @@
+//! Checkpoint types for the `kona-derive` pipeline.
+//!
+//! A [`PipelineCheckpoint`] captures the data buffered by the pipeline stages, so that derivation
+//! can resume from the checkpointed L1 origin instead of re-walking L1 from the safe head's origin.
+
+use crate::{PipelineError, PipelineErrorKind, PipelineResult};
+use alloc::{format, vec::Vec};
+use alloy_primitives::{B128, Bytes};
+use alloy_rlp::{Decodable, Encodable};
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_protocol::{
+    Batch, BatchReader, BatchWithInclusionBlock, BlockInfo, Channel, Frame, L2BlockInfo,
+    SingleBatch, SpanBatch,
+};
+
+/// A snapshot of the data buffered by the derivation pipeline.
+///
+/// The data of the checkpoint [`origin`](Self::origin) is read again after the checkpoint is
+/// restored. Frames that were already buffered are then ignored as duplicates, and batches that
+/// were already derived are dropped as past batches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PipelineCheckpoint {
+    /// The L2 safe head the pipeline was stepped on when the checkpoint was taken.
+    pub l2_safe_head: L2BlockInfo,
+    /// The L1 origin of the traversal stage.
+    pub origin: BlockInfo,
+    /// The system config of the traversal stage.
+    pub system_config: SystemConfig,
+    /// The channels buffered by the channel bank or the channel assembler, in read order.
+    pub channels: Vec<ChannelCheckpoint>,
+    /// The channel being read by the channel reader.
+    pub channel_reader: Option<BatchReaderCheckpoint>,
+    /// The span batch staged by the batch stream, encoded as a [`Batch`].
+    pub span_batch: Option<Bytes>,
+    /// The RLP encoded single batches buffered by the batch stream.
+    pub span_buffer: Vec<Bytes>,
+    /// The L1 origin of the batch queue or the batch validator.
+    pub batch_origin: Option<BlockInfo>,
+    /// The L1 blocks tracked by the batch queue or the batch validator.
+    pub l1_blocks: Vec<BlockInfo>,
+    /// The batches buffered by the batch queue.
+    pub batches: Vec<BatchCheckpoint>,
+    /// The RLP encoded single batches cached by the batch queue.
+    pub next_spans: Vec<Bytes>,
+    /// The RLP encoded single batch held by the attributes queue.
+    pub batch: Option<Bytes>,
+    /// Whether the batch held by the attributes queue is the last of its span.
+    pub last_in_span: bool,
+}
+
+/// A [`Channel`] buffered by the pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ChannelCheckpoint {
+    /// The channel ID.
+    pub id: B128,
+    /// The L1 block that contained the first frame of the channel.
+    pub open_block: BlockInfo,
+    /// The highest L1 block that contained a frame of the channel.
+    pub inclusion_block: BlockInfo,
+    /// The encoded frames of the channel, ordered by frame number.
+    pub frames: Vec<Bytes>,
+}
+
+impl ChannelCheckpoint {
+    /// Rebuilds the [`Channel`] from its checkpoint.
+    pub fn channel(&self) -> PipelineResult<Channel> {
+        let mut channel = Channel::new(self.id.0, self.open_block);
+        for encoded in &self.frames {
+            let (_, frame) = Frame::decode(encoded).map_err(invalid_checkpoint)?;
+            channel.add_frame(frame, self.inclusion_block).map_err(invalid_checkpoint)?;
+        }
+        Ok(channel)
+    }
+}
+
+impl From<&Channel> for ChannelCheckpoint {
+    fn from(channel: &Channel) -> Self {
+        Self {
+            id: B128::from(channel.id()),
+            open_block: channel.open_block(),
+            inclusion_block: channel.highest_l1_inclusion_block(),
+            frames: channel.frames().into_iter().map(|frame| frame.encode().into()).collect(),
+        }
+    }
+}
+
+/// The unread data of a [`BatchReader`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BatchReaderCheckpoint {
+    /// The compressed channel data, or the decompressed data that has not been read yet.
+    pub data: Bytes,
+    /// Whether the data is decompressed.
+    pub decompressed: bool,
+    /// Whether the channel was compressed with brotli.
+    pub brotli_used: bool,
+}
+
+impl BatchReaderCheckpoint {
+    /// Rebuilds the [`BatchReader`] from its checkpoint.
+    pub fn reader(&self, max_rlp_bytes_per_channel: usize) -> BatchReader {
+        if self.decompressed {
+            BatchReader::from_decompressed(
+                self.data.to_vec(),
+                max_rlp_bytes_per_channel,
+                self.brotli_used,
+            )
+        } else {
+            BatchReader::new(&self.data[..], max_rlp_bytes_per_channel)
+        }
+    }
+}
+
+impl From<&BatchReader> for BatchReaderCheckpoint {
+    fn from(reader: &BatchReader) -> Self {
+        reader.compressed().map_or_else(
+            || Self {
+                data: Bytes::copy_from_slice(reader.remaining()),
+                decompressed: true,
+                brotli_used: reader.brotli_used,
+            },
+            |data| Self {
+                data: Bytes::copy_from_slice(data),
+                decompressed: false,
+                brotli_used: false,
+            },
+        )
+    }
+}
+
+/// A [`BatchWithInclusionBlock`] buffered by the batch queue.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BatchCheckpoint {
+    /// The L1 block that included the batch.
+    pub inclusion_block: BlockInfo,
+    /// The encoded batch.
+    pub batch: Bytes,
+}
+
+impl BatchCheckpoint {
+    /// Encodes a [`BatchWithInclusionBlock`].
+    pub fn new(batch: &BatchWithInclusionBlock) -> PipelineResult<Self> {
+        Ok(Self { inclusion_block: batch.inclusion_block, batch: encode_batch(&batch.batch)? })
+    }
+
+    /// Decodes the [`BatchWithInclusionBlock`].
+    pub fn batch(&self, cfg: &RollupConfig) -> PipelineResult<BatchWithInclusionBlock> {
+        Ok(BatchWithInclusionBlock::new(self.inclusion_block, decode_batch(&self.batch, cfg)?))
+    }
+}
+
+/// Encodes a [`Batch`], prefixed with its type.
+pub(crate) fn encode_batch(batch: &Batch) -> PipelineResult<Bytes> {
+    let mut out = Vec::new();
+    batch.encode(&mut out).map_err(invalid_checkpoint)?;
+    Ok(out.into())
+}
+
+/// Decodes a [`Batch`], prefixed with its type.
+pub(crate) fn decode_batch(data: &Bytes, cfg: &RollupConfig) -> PipelineResult<Batch> {
+    Batch::decode(&mut data.as_ref(), cfg).map_err(invalid_checkpoint)
+}
+
+/// Encodes a [`SpanBatch`] as a [`Batch`].
+pub(crate) fn encode_span_batch(span: &SpanBatch) -> PipelineResult<Bytes> {
+    encode_batch(&Batch::Span(span.clone()))
+}
+
+/// Decodes a [`SpanBatch`] encoded as a [`Batch`].
+pub(crate) fn decode_span_batch(data: &Bytes, cfg: &RollupConfig) -> PipelineResult<SpanBatch> {
+    match decode_batch(data, cfg)? {
+        Batch::Span(span) => Ok(span),
+        Batch::Single(_) => Err(invalid_checkpoint("expected a span batch")),
+    }
+}
+
+/// RLP encodes a [`SingleBatch`].
+pub(crate) fn encode_single_batch(batch: &SingleBatch) -> Bytes {
+    let mut out = Vec::new();
+    batch.encode(&mut out);
+    out.into()
+}
+
+/// RLP decodes a [`SingleBatch`].
+pub(crate) fn decode_single_batch(data: &Bytes) -> PipelineResult<SingleBatch> {
+    SingleBatch::decode(&mut data.as_ref()).map_err(invalid_checkpoint)
+}
+
+/// Wraps an error as a critical [`PipelineError::InvalidCheckpoint`].
+fn invalid_checkpoint(err: impl core::fmt::Display) -> PipelineErrorKind {
+    PipelineError::InvalidCheckpoint(format!("{err}")).crit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_channel_checkpoint_roundtrip() {
+        let id = [0xAA; 16];
+        let open = BlockInfo { number: 10, ..Default::default() };
+        let inclusion = BlockInfo { number: 12, ..Default::default() };
+        let mut channel = Channel::new(id, open);
+        channel.add_frame(Frame::new(id, 0, vec![1, 2, 3], false), open).unwrap();
+        channel.add_frame(Frame::new(id, 1, vec![4, 5], true), inclusion).unwrap();
+
+        let checkpoint = ChannelCheckpoint::from(&channel);
+        assert_eq!(checkpoint.frames.len(), 2);
+
+        let restored = checkpoint.channel().unwrap();
+        assert_eq!(restored.id(), id);
+        assert_eq!(restored.open_block(), open);
+        assert_eq!(restored.highest_l1_inclusion_block(), inclusion);
+        assert!(restored.is_ready());
+        assert_eq!(restored.frame_data(), channel.frame_data());
+    }
+
+    #[test]
+    fn test_channel_checkpoint_invalid_frame() {
+        let checkpoint =
+            ChannelCheckpoint { frames: vec![Bytes::from_static(&[0x00])], ..Default::default() };
+        assert!(matches!(
+            checkpoint.channel(),
+            Err(PipelineErrorKind::Critical(PipelineError::InvalidCheckpoint(_)))
+        ));
+    }
+
+    #[test]
+    fn test_batch_checkpoint_roundtrip() {
+        let cfg = RollupConfig::default();
+        let batch = BatchWithInclusionBlock::new(
+            BlockInfo { number: 5, ..Default::default() },
+            Batch::Single(SingleBatch { timestamp: 10, ..Default::default() }),
+        );
+
+        let checkpoint = BatchCheckpoint::new(&batch).unwrap();
+        assert_eq!(checkpoint.batch(&cfg).unwrap(), batch);
+    }
+
+    #[test]
+    fn test_single_batch_roundtrip() {
+        let batch = SingleBatch { timestamp: 10, epoch_num: 3, ..Default::default() };
+        assert_eq!(decode_single_batch(&encode_single_batch(&batch)).unwrap(), batch);
+        assert!(
+            decode_span_batch(
+                &encode_batch(&Batch::Single(batch)).unwrap(),
+                &RollupConfig::default()
+            )
+            .is_err()
+        );
+    }
+}
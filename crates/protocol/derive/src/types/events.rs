@@ -0,0 +1,173 @@
+//! Events emitted by the `kona-derive` pipeline stages.
+//!
+//! The stages report every frame, channel and batch they accept or drop, as well as every system
+//! config update, as a [`DerivationEvent`] to the [`DerivationEventSink`] configured on the
+//! [`PipelineBuilder`], so that the fate of a batcher submission can be audited after the fact.
+//!
+//! [`PipelineBuilder`]: crate::PipelineBuilder
+
+use crate::DerivationEventSink;
+use alloc::{sync::Arc, vec::Vec};
+use alloy_eips::BlockNumHash;
+use alloy_primitives::B128;
+use kona_genesis::{SystemConfig, SystemConfigUpdate};
+use kona_protocol::{Batch, BatchValidity, BlockInfo};
+
+/// The reason a frame was dropped by the pipeline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum FrameDropReason {
+    /// The frame number does not follow the previous frame of its channel.
+    NonSequential,
+    /// The frame follows the last frame of its channel.
+    AfterLastFrame,
+    /// The frame belongs to a new channel, but is not its first frame.
+    NotFirstFrame,
+    /// The channel of the frame was superseded by a new channel before it was closed.
+    ChannelSuperseded,
+    /// No channel is open for the frame.
+    NoOpenChannel,
+    /// The channel of the frame timed out.
+    ChannelTimedOut,
+    /// The channel rejected the frame, because it is a duplicate or the channel is closed.
+    Rejected,
+}
+
+/// The reason a channel was dropped by the pipeline, other than timing out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum ChannelDropReason {
+    /// The channel exceeded the maximum RLP bytes per channel.
+    TooLarge,
+    /// The channel was pruned to keep the channel bank within its maximum size.
+    BankFull,
+    /// A new channel was opened before the channel was closed.
+    Superseded,
+}
+
+/// An event emitted by a derivation pipeline stage.
+///
+/// Every event carries the L1 origin of the stage that emitted it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "event", rename_all = "camelCase", rename_all_fields = "camelCase")
+)]
+pub enum DerivationEvent {
+    /// The L1 data could not be parsed into frames, and was dropped.
+    InvalidFrameData {
+        /// The L1 origin.
+        origin: BlockNumHash,
+    },
+    /// A frame was added to its channel.
+    FrameAccepted {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The channel ID.
+        channel_id: B128,
+        /// The frame number.
+        frame_number: u16,
+        /// Whether the frame is the last frame of its channel.
+        is_last: bool,
+    },
+    /// A frame was dropped.
+    FrameDropped {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The channel ID.
+        channel_id: B128,
+        /// The frame number.
+        frame_number: u16,
+        /// The reason the frame was dropped.
+        reason: FrameDropReason,
+    },
+    /// A channel was opened.
+    ChannelOpened {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The channel ID.
+        channel_id: B128,
+    },
+    /// A channel timed out before it was completed, and was dropped.
+    ChannelTimedOut {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The channel ID.
+        channel_id: B128,
+        /// The number of the L1 block the channel was opened in.
+        open_block: u64,
+    },
+    /// A channel was dropped.
+    ChannelDropped {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The channel ID.
+        channel_id: B128,
+        /// The reason the channel was dropped.
+        reason: ChannelDropReason,
+    },
+    /// A channel was completed and forwarded to the channel reader.
+    ChannelCompleted {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The channel ID.
+        channel_id: B128,
+        /// The size of the channel, in bytes.
+        size: usize,
+    },
+    /// A batch was checked against the L2 safe head.
+    ///
+    /// Batches with the [`BatchValidity::Future`] validity are dropped once Holocene is active.
+    BatchChecked {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The timestamp of the batch, or of the first block of a span batch.
+        timestamp: u64,
+        /// Whether the batch is a span batch.
+        is_span: bool,
+        /// The validity of the batch.
+        validity: BatchValidity,
+    },
+    /// The system config was updated by the receipts of the L1 origin.
+    ///
+    /// The updates take effect from the first L2 block with the L1 origin as its epoch.
+    SystemConfigUpdated {
+        /// The L1 origin.
+        origin: BlockNumHash,
+        /// The updates applied, in log order.
+        updates: Vec<SystemConfigUpdate>,
+        /// The system config after the updates were applied.
+        system_config: SystemConfig,
+    },
+}
+
+/// Emits [`DerivationEvent`]s to an optional [`DerivationEventSink`].
+#[derive(Debug, Clone, Default)]
+pub(crate) struct EventEmitter(Option<Arc<dyn DerivationEventSink>>);
+
+impl EventEmitter {
+    /// Creates a new [`EventEmitter`] emitting to the given sink, if any.
+    pub(crate) const fn new(sink: Option<Arc<dyn DerivationEventSink>>) -> Self {
+        Self(sink)
+    }
+
+    /// Emits the event built by the given closure. The event is only built if a sink is set.
+    pub(crate) fn emit(&self, event: impl FnOnce() -> DerivationEvent) {
+        if let Some(sink) = &self.0 {
+            sink.on_event(event());
+        }
+    }
+
+    /// Emits the validity of the given batch, checked at the given L1 origin.
+    pub(crate) fn batch_checked(&self, origin: &BlockInfo, batch: &Batch, validity: BatchValidity) {
+        self.emit(|| DerivationEvent::BatchChecked {
+            origin: origin.id(),
+            timestamp: batch.timestamp(),
+            is_span: matches!(batch, Batch::Span(_)),
+            validity,
+        });
+    }
+}
@@ -5,3 +5,16 @@ pub use results::{PipelineResult, StepResult};
 
 mod signals;
 pub use signals::{ActivationSignal, ResetSignal, Signal};
+
+mod events;
+pub(crate) use events::EventEmitter;
+pub use events::{ChannelDropReason, DerivationEvent, FrameDropReason};
+
+mod checkpoint;
+pub use checkpoint::{
+    BatchCheckpoint, BatchReaderCheckpoint, ChannelCheckpoint, PipelineCheckpoint,
+};
+pub(crate) use checkpoint::{
+    decode_batch, decode_single_batch, decode_span_batch, encode_batch, encode_single_batch,
+    encode_span_batch,
+};
@@ -62,3 +62,6 @@ pub use rollup::{
     DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW, FJORD_MAX_SEQUENCER_DRIFT, GRANITE_CHANNEL_TIMEOUT,
     MAX_RLP_BYTES_PER_CHANNEL_BEDROCK, MAX_RLP_BYTES_PER_CHANNEL_FJORD, RollupConfig,
 };
+
+mod validation;
+pub use validation::{RollupConfigError, RollupConfigReport, RollupConfigWarning};
@@ -1,9 +1,9 @@
 //! Contains the [`SystemConfig`] type.
 
 use crate::{
-    CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigUpdateError,
-    SystemConfigUpdateKind,
+    CONFIG_UPDATE_TOPIC, RollupConfig, SystemConfigLog, SystemConfigUpdate, SystemConfigUpdateError,
 };
+use alloc::vec::Vec;
 use alloy_consensus::{Eip658Value, Receipt};
 use alloy_primitives::{Address, B64, Log, U256};
 
@@ -113,7 +113,20 @@ impl SystemConfig {
         l1_system_config_address: Address,
         ecotone_active: bool,
     ) -> Result<bool, SystemConfigUpdateError> {
-        let mut updated = false;
+        self.apply_receipts(receipts, l1_system_config_address, ecotone_active)
+            .map(|updates| !updates.is_empty())
+    }
+
+    /// Filters all L1 receipts to find config updates and applies the config updates.
+    ///
+    /// Returns the [`SystemConfigUpdate`]s that were applied, in log order.
+    pub fn apply_receipts(
+        &mut self,
+        receipts: &[Receipt],
+        l1_system_config_address: Address,
+        ecotone_active: bool,
+    ) -> Result<Vec<SystemConfigUpdate>, SystemConfigUpdateError> {
+        let mut updates = Vec::new();
         for receipt in receipts {
             if Eip658Value::Eip658(false) == receipt.status {
                 continue;
@@ -126,13 +139,12 @@ impl SystemConfig {
                     topics[0] == CONFIG_UPDATE_TOPIC
                 {
                     // Safety: Error is bubbled up by the trailing `?`
-                    self.process_config_update_log(log, ecotone_active)?;
-                    updated = true;
+                    updates.push(self.process_config_update_log(log, ecotone_active)?);
                 }
                 Ok::<(), SystemConfigUpdateError>(())
             })?;
         }
-        Ok(updated)
+        Ok(updates)
     }
 
     /// Returns the eip1559 parameters from a [SystemConfig] encoded as a [B64].
@@ -176,7 +188,7 @@ impl SystemConfig {
         &mut self,
         log: &Log,
         ecotone_active: bool,
-    ) -> Result<SystemConfigUpdateKind, SystemConfigUpdateError> {
+    ) -> Result<SystemConfigUpdate, SystemConfigUpdateError> {
         // Construct the system config log from the log.
         let log = SystemConfigLog::new(log.clone(), ecotone_active);
 
@@ -186,8 +198,8 @@ impl SystemConfig {
         // Apply the update to the system config.
         update.apply(self);
 
-        // Return the update type.
-        Ok(update.kind())
+        // Return the applied update.
+        Ok(update)
     }
 }
 
@@ -400,6 +412,49 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_system_config_apply_receipts_skips_failed_receipts() {
+        const UPDATE_TYPE: B256 =
+            b256!("0000000000000000000000000000000000000000000000000000000000000000");
+        let mut system_config = SystemConfig::default();
+
+        let update_log = Log {
+            address: Address::ZERO,
+            data: LogData::new_unchecked(
+                vec![
+                    CONFIG_UPDATE_TOPIC,
+                    CONFIG_UPDATE_EVENT_VERSION_0,
+                    UPDATE_TYPE,
+                ],
+                hex!("00000000000000000000000000000000000000000000000000000000000000200000000000000000000000000000000000000000000000000000000000000020000000000000000000000000000000000000000000000000000000000000beef").into()
+            )
+        };
+
+        let failed = Receipt {
+            logs: vec![update_log.clone()],
+            status: Eip658Value::Eip658(false),
+            cumulative_gas_used: 0,
+        };
+        let succeeded = Receipt {
+            logs: vec![update_log],
+            status: Eip658Value::Eip658(true),
+            cumulative_gas_used: 0,
+        };
+
+        let updates =
+            system_config.apply_receipts(&[failed, succeeded], Address::ZERO, false).unwrap();
+        assert_eq!(
+            updates,
+            vec![SystemConfigUpdate::Batcher(crate::BatcherUpdate {
+                batcher_address: address!("000000000000000000000000000000000000bEEF"),
+            })]
+        );
+        assert_eq!(
+            system_config.batcher_address,
+            address!("000000000000000000000000000000000000bEEF"),
+        );
+    }
+
     #[test]
     fn test_system_config_update_batcher_log() {
         const UPDATE_TYPE: B256 =
@@ -0,0 +1,356 @@
+//! Validation of the [`RollupConfig`].
+
+use crate::RollupConfig;
+use alloc::vec::Vec;
+use alloy_primitives::Address;
+
+/// A warning raised by [`RollupConfig::validate`].
+///
+/// Warnings flag configurations that are accepted by the node, but are likely unintended.
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum RollupConfigWarning {
+    /// A hardfork is scheduled while the prior hardfork is not, although an earlier hardfork is.
+    /// The prior hardfork is activated along with the scheduled one.
+    #[error("{fork} is scheduled at {time}, but the prior hardfork {prior} is not scheduled")]
+    MissingPriorHardfork {
+        /// The name of the scheduled hardfork.
+        fork: &'static str,
+        /// The activation time of the scheduled hardfork.
+        time: u64,
+        /// The name of the prior hardfork that is not scheduled.
+        prior: &'static str,
+    },
+    /// A hardfork activation time is not the timestamp of an L2 block, so the hardfork activates
+    /// at the first L2 block after it.
+    #[error("{fork} activation time {time} is not on an L2 block boundary")]
+    HardforkNotOnBlockBoundary {
+        /// The name of the hardfork.
+        fork: &'static str,
+        /// The activation time of the hardfork.
+        time: u64,
+    },
+}
+
+/// An error raised by [`RollupConfig::validate`].
+#[derive(Debug, thiserror::Error, Clone, Copy, PartialEq, Eq)]
+pub enum RollupConfigError {
+    /// The block time is zero.
+    #[error("Block time cannot be 0")]
+    ZeroBlockTime,
+    /// The sequencing window size is zero.
+    #[error("Sequencing window size cannot be 0")]
+    ZeroSequencerWindowSize,
+    /// The channel timeout is zero.
+    #[error("Channel timeout cannot be 0")]
+    ZeroChannelTimeout,
+    /// A required address is the zero address.
+    #[error("Missing {0} address")]
+    MissingAddress(&'static str),
+    /// A hardfork is scheduled before a prior hardfork.
+    #[error("{fork} is scheduled at {time}, before the prior hardfork {prior} at {prior_time}")]
+    NonMonotonicHardfork {
+        /// The name of the hardfork.
+        fork: &'static str,
+        /// The activation time of the hardfork.
+        time: u64,
+        /// The name of the prior hardfork.
+        prior: &'static str,
+        /// The activation time of the prior hardfork.
+        prior_time: u64,
+    },
+    /// An address required by the Interop hardfork is missing.
+    #[error("Interop is scheduled, but the {0} address is missing")]
+    MissingInteropAddress(&'static str),
+    /// The Interop hardfork is scheduled with a zero message expiry window.
+    #[error("Interop is scheduled, but the message expiry window is 0")]
+    ZeroInteropMessageExpiryWindow,
+    /// The L2 chain ID does not match the chain ID of the same chain in the superchain registry.
+    #[error("L2 chain ID {config} does not match the registry chain ID {registry}")]
+    L2ChainIdMismatch {
+        /// The L2 chain ID of the config.
+        config: u64,
+        /// The L2 chain ID of the chain in the registry.
+        registry: u64,
+    },
+    /// The L1 chain ID does not match the L1 chain ID of the chain in the superchain registry.
+    #[error("L1 chain ID {config} does not match the registry L1 chain ID {registry}")]
+    L1ChainIdMismatch {
+        /// The L1 chain ID of the config.
+        config: u64,
+        /// The L1 chain ID of the chain in the registry.
+        registry: u64,
+    },
+}
+
+/// The result of validating a [`RollupConfig`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RollupConfigReport {
+    /// The warnings raised by the validation.
+    pub warnings: Vec<RollupConfigWarning>,
+    /// The errors raised by the validation.
+    pub errors: Vec<RollupConfigError>,
+}
+
+impl RollupConfigReport {
+    /// Returns true if the validation raised no errors.
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Returns true if the validation raised neither warnings nor errors.
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty() && self.errors.is_empty()
+    }
+}
+
+impl RollupConfig {
+    /// Validates the rollup config, returning the warnings and errors found.
+    ///
+    /// The hardfork schedule is linted for hardforks scheduled before a prior hardfork, skipped
+    /// hardforks, and activation times that are not on an L2 block boundary.
+    pub fn validate(&self) -> RollupConfigReport {
+        let mut report = RollupConfigReport::default();
+
+        if self.block_time == 0 {
+            report.errors.push(RollupConfigError::ZeroBlockTime);
+        }
+        if self.seq_window_size == 0 {
+            report.errors.push(RollupConfigError::ZeroSequencerWindowSize);
+        }
+        if self.channel_timeout == 0 {
+            report.errors.push(RollupConfigError::ZeroChannelTimeout);
+        }
+        for (name, address) in [
+            ("batch inbox", self.batch_inbox_address),
+            ("deposit contract", self.deposit_contract_address),
+            ("L1 system config", self.l1_system_config_address),
+        ] {
+            if address == Address::ZERO {
+                report.errors.push(RollupConfigError::MissingAddress(name));
+            }
+        }
+
+        self.validate_hardforks(&mut report);
+
+        if self.hardforks.interop_time.is_some() {
+            if self.superchain_config_address.is_none_or(|address| address == Address::ZERO) {
+                report.errors.push(RollupConfigError::MissingInteropAddress("superchain config"));
+            }
+            if self.interop_message_expiry_window == 0 {
+                report.errors.push(RollupConfigError::ZeroInteropMessageExpiryWindow);
+            }
+        }
+
+        report
+    }
+
+    /// Lints the hardfork schedule.
+    ///
+    /// Hardforks missing before the first scheduled hardfork are not reported, as the registry
+    /// leaves out the hardforks active at genesis. The Pectra Blob Schedule fix is not part of the
+    /// ordered hardfork sequence, so it is only checked for its block alignment.
+    fn validate_hardforks(&self, report: &mut RollupConfigReport) {
+        let mut prior: Option<(&'static str, Option<u64>)> = None;
+        let mut latest: Option<(&'static str, u64)> = None;
+        for (fork, time) in self.hardforks.iter() {
+            let Some(time) = time else {
+                if fork != "Pectra Blob Schedule" {
+                    prior = Some((fork, None));
+                }
+                continue;
+            };
+
+            if self.block_time != 0 &&
+                time > self.genesis.l2_time &&
+                (time - self.genesis.l2_time) % self.block_time != 0
+            {
+                report
+                    .warnings
+                    .push(RollupConfigWarning::HardforkNotOnBlockBoundary { fork, time });
+            }
+
+            if fork == "Pectra Blob Schedule" {
+                continue;
+            }
+            if let (Some((prior, None)), Some(_)) = (prior, latest) {
+                report.warnings.push(RollupConfigWarning::MissingPriorHardfork {
+                    fork,
+                    time,
+                    prior,
+                });
+            }
+            if let Some((prior, prior_time)) = latest.filter(|(_, prior_time)| time < *prior_time) {
+                report.errors.push(RollupConfigError::NonMonotonicHardfork {
+                    fork,
+                    time,
+                    prior,
+                    prior_time,
+                });
+            }
+            prior = Some((fork, Some(time)));
+            latest = Some((fork, time));
+        }
+    }
+
+    /// Validates the rollup config against the config of the same chain in the superchain
+    /// registry, checking that both refer to the same L1 and L2 chain IDs.
+    ///
+    /// Returns the report of [`RollupConfig::validate`], extended with the chain ID mismatches.
+    pub fn validate_against(&self, registered: &Self) -> RollupConfigReport {
+        let mut report = self.validate();
+        if self.l2_chain_id.id() != registered.l2_chain_id.id() {
+            report.errors.push(RollupConfigError::L2ChainIdMismatch {
+                config: self.l2_chain_id.id(),
+                registry: registered.l2_chain_id.id(),
+            });
+        }
+        if self.l1_chain_id != registered.l1_chain_id {
+            report.errors.push(RollupConfigError::L1ChainIdMismatch {
+                config: self.l1_chain_id,
+                registry: registered.l1_chain_id,
+            });
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW, HardForkConfig};
+    use alloy_chains::Chain;
+
+    fn valid_config() -> RollupConfig {
+        RollupConfig {
+            block_time: 2,
+            seq_window_size: 3600,
+            channel_timeout: 300,
+            batch_inbox_address: Address::with_last_byte(1),
+            deposit_contract_address: Address::with_last_byte(2),
+            l1_system_config_address: Address::with_last_byte(3),
+            hardforks: HardForkConfig {
+                regolith_time: Some(0),
+                canyon_time: Some(0),
+                delta_time: Some(0),
+                ecotone_time: Some(10),
+                fjord_time: Some(20),
+                ..Default::default()
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_validate_valid_config() {
+        assert!(valid_config().validate().is_clean());
+    }
+
+    #[test]
+    fn test_validate_required_fields() {
+        let config = RollupConfig {
+            block_time: 0,
+            seq_window_size: 0,
+            batch_inbox_address: Address::ZERO,
+            ..valid_config()
+        };
+        assert_eq!(
+            config.validate().errors,
+            [
+                RollupConfigError::ZeroBlockTime,
+                RollupConfigError::ZeroSequencerWindowSize,
+                RollupConfigError::MissingAddress("batch inbox"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_non_monotonic_hardforks() {
+        let mut config = valid_config();
+        config.hardforks.granite_time = Some(14);
+        let report = config.validate();
+        assert!(!report.is_valid());
+        assert_eq!(
+            report.errors,
+            [RollupConfigError::NonMonotonicHardfork {
+                fork: "Granite",
+                time: 14,
+                prior: "Fjord",
+                prior_time: 20,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_missing_prior_hardfork() {
+        let mut config = valid_config();
+        config.hardforks.regolith_time = None;
+        config.hardforks.holocene_time = Some(30);
+        let report = config.validate();
+        assert!(report.is_valid());
+        assert_eq!(
+            report.warnings,
+            [RollupConfigWarning::MissingPriorHardfork {
+                fork: "Holocene",
+                time: 30,
+                prior: "Granite",
+            }]
+        );
+    }
+
+    #[test]
+    fn test_validate_block_boundary() {
+        let mut config = valid_config();
+        config.genesis.l2_time = 1;
+        config.hardforks.pectra_blob_schedule_time = Some(15);
+        let report = config.validate();
+        assert!(report.is_valid());
+        assert_eq!(
+            report.warnings,
+            [
+                RollupConfigWarning::HardforkNotOnBlockBoundary { fork: "Ecotone", time: 10 },
+                RollupConfigWarning::HardforkNotOnBlockBoundary { fork: "Fjord", time: 20 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_interop_addresses() {
+        let mut config = valid_config();
+        config.hardforks.granite_time = Some(20);
+        config.hardforks.holocene_time = Some(20);
+        config.hardforks.isthmus_time = Some(20);
+        config.hardforks.interop_time = Some(40);
+        config.interop_message_expiry_window = 0;
+        assert_eq!(
+            config.validate().errors,
+            [
+                RollupConfigError::MissingInteropAddress("superchain config"),
+                RollupConfigError::ZeroInteropMessageExpiryWindow,
+            ]
+        );
+
+        config.superchain_config_address = Some(Address::with_last_byte(4));
+        config.interop_message_expiry_window = DEFAULT_INTEROP_MESSAGE_EXPIRY_WINDOW;
+        assert!(config.validate().is_clean());
+    }
+
+    #[test]
+    fn test_validate_against_registry() {
+        let registered =
+            RollupConfig { l1_chain_id: 1, l2_chain_id: Chain::from_id(10), ..valid_config() };
+        assert!(registered.validate_against(&registered).is_clean());
+
+        let config = RollupConfig {
+            l1_chain_id: 11155111,
+            l2_chain_id: Chain::from_id(11),
+            ..registered.clone()
+        };
+        assert_eq!(
+            config.validate_against(&registered).errors,
+            [
+                RollupConfigError::L2ChainIdMismatch { config: 11, registry: 10 },
+                RollupConfigError::L1ChainIdMismatch { config: 11155111, registry: 1 },
+            ]
+        );
+    }
+}
@@ -1,8 +1,9 @@
 //! Optimism Payload attributes that reference the parent L2 block.
 
-use crate::{BlockInfo, L2BlockInfo};
+use crate::{BlockInfo, L1BlockInfoTx, L2BlockInfo};
 use alloc::vec;
-use op_alloy_consensus::OpTxType;
+use alloy_eips::eip2718::Decodable2718;
+use op_alloy_consensus::{OpTxEnvelope, OpTxType};
 use op_alloy_rpc_types_engine::OpPayloadAttributes;
 
 /// Optimism Payload Attributes with parent block reference and the L1 origin block.
@@ -61,6 +62,16 @@ impl OpAttributesWithParent {
         self.is_last_in_span
     }
 
+    /// Decodes the [`L1BlockInfoTx`] from the L1 info deposit at the top of the payload.
+    ///
+    /// Returns `None` if the first transaction is missing or is not a valid L1 info deposit.
+    pub fn l1_block_info(&self) -> Option<L1BlockInfoTx> {
+        let tx = self.inner.transactions.as_ref()?.first()?;
+        let envelope = OpTxEnvelope::decode_2718(&mut tx.as_ref()).ok()?;
+        let deposit = envelope.as_deposit()?;
+        L1BlockInfoTx::decode_calldata(deposit.input.as_ref()).ok()
+    }
+
     /// Returns `true` if all transactions in the payload are deposits.
     pub fn is_deposits_only(&self) -> bool {
         self.inner
@@ -104,4 +115,32 @@ mod tests {
         assert_eq!(op_attributes_with_parent.parent(), &parent);
         assert_eq!(op_attributes_with_parent.is_last_in_span(), is_last_in_span);
     }
+
+    #[test]
+    fn test_op_attributes_l1_block_info() {
+        use crate::L1BlockInfoBedrock;
+        use alloy_eips::eip2718::Encodable2718;
+        use alloy_primitives::{B256, Sealable};
+        use op_alloy_consensus::TxDeposit;
+
+        let l1_info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            number: 5,
+            block_hash: B256::with_last_byte(5),
+            ..Default::default()
+        });
+        let deposit = TxDeposit { input: l1_info.encode_calldata(), ..Default::default() };
+        let encoded = OpTxEnvelope::Deposit(deposit.seal_slow()).encoded_2718();
+
+        let mut attributes = OpAttributesWithParent::new(
+            OpPayloadAttributes::default(),
+            L2BlockInfo::default(),
+            BlockInfo::default(),
+            false,
+        );
+        assert_eq!(attributes.l1_block_info(), None);
+
+        attributes.inner.transactions = Some(vec![encoded.into()]);
+        assert_eq!(attributes.l1_block_info(), Some(l1_info));
+        assert_eq!(attributes.as_deposits_only().l1_block_info(), None);
+    }
 }
@@ -70,6 +70,26 @@ impl BatchReader {
         }
     }
 
+    /// Creates a new [`BatchReader`] over already decompressed data, such as the
+    /// [remaining](Self::remaining) data of another reader.
+    pub const fn from_decompressed(
+        decompressed: Vec<u8>,
+        max_rlp_bytes_per_channel: usize,
+        brotli_used: bool,
+    ) -> Self {
+        Self { data: None, decompressed, cursor: 0, max_rlp_bytes_per_channel, brotli_used }
+    }
+
+    /// Returns the compressed data of the reader, if it has not been decompressed yet.
+    pub fn compressed(&self) -> Option<&[u8]> {
+        self.data.as_deref()
+    }
+
+    /// Returns the decompressed data that has not been read yet.
+    pub fn remaining(&self) -> &[u8] {
+        &self.decompressed[self.cursor..]
+    }
+
     /// Helper method to decompress the data contained in the reader.
     pub fn decompress(&mut self) -> Result<(), DecompressionError> {
         if let Some(data) = self.data.take() {
@@ -149,6 +169,25 @@ mod test {
         assert_eq!(reader.cursor, decompressed_len);
     }
 
+    #[test]
+    fn test_batch_reader_from_remaining() {
+        let raw = new_compressed_batch_data();
+        let cfg = RollupConfig::default();
+        let mut reader = BatchReader::new(raw.clone(), MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as usize);
+        assert_eq!(reader.compressed(), Some(raw.as_ref()));
+        reader.decompress().unwrap();
+        assert_eq!(reader.compressed(), None);
+
+        let mut resumed = BatchReader::from_decompressed(
+            reader.remaining().to_vec(),
+            MAX_RLP_BYTES_PER_CHANNEL_BEDROCK as usize,
+            reader.brotli_used,
+        );
+        assert_eq!(resumed.next_batch(&cfg), reader.next_batch(&cfg));
+        assert!(reader.remaining().is_empty());
+        assert!(resumed.remaining().is_empty());
+    }
+
     #[test]
     fn test_batch_reader_fjord() {
         let raw = new_compressed_batch_data();
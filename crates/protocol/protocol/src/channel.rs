@@ -146,6 +146,23 @@ impl Channel {
         self.open_block.number
     }
 
+    /// Returns the L1 block that contained the first [`Frame`] in this channel.
+    pub const fn open_block(&self) -> BlockInfo {
+        self.open_block
+    }
+
+    /// Returns the highest L1 block that contained a [`Frame`] of this channel.
+    pub const fn highest_l1_inclusion_block(&self) -> BlockInfo {
+        self.highest_l1_inclusion_block
+    }
+
+    /// Returns the buffered [`Frame`]s, ordered by frame number.
+    pub fn frames(&self) -> Vec<&Frame> {
+        let mut frames = self.inputs.values().collect::<Vec<_>>();
+        frames.sort_by_key(|frame| frame.number);
+        frames
+    }
+
     /// Returns the estimated size of the channel including [`Frame`] overhead.
     pub const fn size(&self) -> usize {
         self.estimated_size
@@ -247,12 +264,28 @@ mod test {
 
         assert_eq!(channel.id(), id);
         assert_eq!(channel.open_block_number(), block.number);
+        assert_eq!(channel.open_block(), block);
         assert_eq!(channel.size(), 0);
         assert_eq!(channel.len(), 0);
         assert!(channel.is_empty());
         assert!(!channel.is_ready());
     }
 
+    #[test]
+    fn test_channel_frames() {
+        let id = [0xFF; 16];
+        let open = BlockInfo { number: 1, ..Default::default() };
+        let inclusion = BlockInfo { number: 2, ..Default::default() };
+        let mut channel = Channel::new(id, open);
+
+        channel.add_frame(Frame::new(id, 1, vec![0xBB], true), inclusion).unwrap();
+        channel.add_frame(Frame::new(id, 0, vec![0xAA], false), open).unwrap();
+
+        let numbers = channel.frames().iter().map(|frame| frame.number).collect::<Vec<_>>();
+        assert_eq!(numbers, vec![0, 1]);
+        assert_eq!(channel.highest_l1_inclusion_block(), inclusion);
+    }
+
     #[test]
     fn test_frame_validity() {
         let id = [0xFF; 16];
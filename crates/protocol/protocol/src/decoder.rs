@@ -0,0 +1,360 @@
+//! Decoding of raw batcher transaction data into channels and batches.
+//!
+//! The [`ChannelReassembler`] parses the [`Frame`]s of batcher transactions, reassembles their
+//! channels across transactions, and decodes the batches of the complete channels into
+//! [`DecodedChannel`]s. Unlike the derivation pipeline, decoding errors are kept on the
+//! [`DecodedChannel`] rather than silently dropping the channel, which makes the reassembler
+//! suitable for debugging batcher submissions.
+
+use crate::{
+    Batch, BatchDecodingError, BatchReader, BlockInfo, Channel, ChannelError, ChannelId,
+    DecompressionError, Frame, FrameParseError, SingleBatch, SpanBatchElement,
+};
+use alloc::vec::Vec;
+use alloy_consensus::Transaction;
+use alloy_eips::eip2718::{Decodable2718, Typed2718};
+use alloy_primitives::{Bytes, hex};
+use alloy_rlp::Decodable;
+use core::fmt;
+use kona_genesis::RollupConfig;
+use op_alloy_consensus::OpTxEnvelope;
+
+/// The compression algorithm of a channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display)]
+pub enum ChannelCompression {
+    /// Zlib compression.
+    #[display("zlib")]
+    Zlib,
+    /// Brotli compression, introduced with the Fjord hardfork.
+    #[display("brotli")]
+    Brotli,
+}
+
+impl ChannelCompression {
+    /// Detects the compression algorithm of the given channel data.
+    pub fn detect(data: &[u8]) -> Option<Self> {
+        let first = *data.first()?;
+        if (first & 0x0F) == BatchReader::ZLIB_DEFLATE_COMPRESSION_METHOD ||
+            (first & 0x0F) == BatchReader::ZLIB_RESERVED_COMPRESSION_METHOD
+        {
+            Some(Self::Zlib)
+        } else if first == BatchReader::CHANNEL_VERSION_BROTLI {
+            Some(Self::Brotli)
+        } else {
+            None
+        }
+    }
+}
+
+/// An error that stopped the decoding of a channel.
+#[derive(Debug, thiserror::Error)]
+pub enum ChannelDecodingError {
+    /// The channel data could not be decompressed.
+    #[error("Failed to decompress the channel: {0}")]
+    Decompression(#[from] DecompressionError),
+    /// The decompressed channel data is not a valid RLP string.
+    #[error("Failed to decode the RLP of batch {0}: {1}")]
+    Rlp(usize, alloy_rlp::Error),
+    /// A batch could not be decoded.
+    #[error("Failed to decode batch {0}: {1}")]
+    Batch(usize, BatchDecodingError),
+}
+
+/// A channel reassembled from [`Frame`]s, and its decoded batches.
+#[derive(Debug)]
+pub struct DecodedChannel {
+    /// The channel ID.
+    pub id: ChannelId,
+    /// The number of frames of the channel.
+    pub frames: usize,
+    /// The size of the compressed channel data.
+    pub compressed_size: usize,
+    /// The compression algorithm of the channel, if it is known.
+    pub compression: Option<ChannelCompression>,
+    /// The size of the decompressed channel data.
+    pub decompressed_size: usize,
+    /// The batches decoded from the channel, in order.
+    pub batches: Vec<Batch>,
+    /// The error that stopped the decoding of the channel, if any.
+    pub error: Option<ChannelDecodingError>,
+}
+
+impl DecodedChannel {
+    /// Decompresses the given channel data and decodes its batches, reading at most
+    /// `max_rlp_bytes_per_channel` bytes of decompressed data.
+    ///
+    /// Decoding stops at the first error, which is kept along with the batches decoded before it.
+    pub fn decode(
+        id: ChannelId,
+        frames: usize,
+        data: &[u8],
+        max_rlp_bytes_per_channel: usize,
+        cfg: &RollupConfig,
+    ) -> Self {
+        let mut channel = Self {
+            id,
+            frames,
+            compressed_size: data.len(),
+            compression: ChannelCompression::detect(data),
+            decompressed_size: 0,
+            batches: Vec::new(),
+            error: None,
+        };
+
+        let mut reader = BatchReader::new(data, max_rlp_bytes_per_channel);
+        if let Err(e) = reader.decompress() {
+            channel.error = Some(e.into());
+            return channel;
+        }
+        channel.decompressed_size = reader.decompressed.len();
+
+        let mut remaining = reader.remaining();
+        while !remaining.is_empty() {
+            let index = channel.batches.len();
+            let bytes = match Bytes::decode(&mut remaining) {
+                Ok(bytes) => bytes,
+                Err(e) => {
+                    channel.error = Some(ChannelDecodingError::Rlp(index, e));
+                    break;
+                }
+            };
+            match Batch::decode(&mut bytes.as_ref(), cfg) {
+                Ok(batch) => channel.batches.push(batch),
+                Err(e) => {
+                    channel.error = Some(ChannelDecodingError::Batch(index, e));
+                    break;
+                }
+            }
+        }
+        channel
+    }
+}
+
+impl fmt::Display for DecodedChannel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Channel {}: {} frames, {} compressed bytes",
+            hex::encode_prefixed(self.id),
+            self.frames,
+            self.compressed_size
+        )?;
+        if let Some(compression) = self.compression {
+            write!(f, " ({compression})")?;
+        }
+        writeln!(f, ", {} decompressed bytes", self.decompressed_size)?;
+
+        for (index, batch) in self.batches.iter().enumerate() {
+            match batch {
+                Batch::Single(batch) => fmt_single_batch(f, index, batch)?,
+                Batch::Span(batch) => {
+                    writeln!(
+                        f,
+                        "  Batch {index}: span batch of {} blocks, parent check {}, L1 origin \
+                         check {}",
+                        batch.batches.len(),
+                        batch.parent_check,
+                        batch.l1_origin_check
+                    )?;
+                    for element in &batch.batches {
+                        fmt_span_batch_element(f, element)?;
+                    }
+                }
+            }
+        }
+
+        if let Some(error) = &self.error {
+            writeln!(f, "  Error: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+fn fmt_single_batch(f: &mut fmt::Formatter<'_>, index: usize, batch: &SingleBatch) -> fmt::Result {
+    writeln!(
+        f,
+        "  Batch {index}: single batch at timestamp {}, epoch {} ({}), parent {}, {} transactions",
+        batch.timestamp,
+        batch.epoch_num,
+        batch.epoch_hash,
+        batch.parent_hash,
+        batch.transactions.len()
+    )?;
+    fmt_transactions(f, &batch.transactions)
+}
+
+fn fmt_span_batch_element(f: &mut fmt::Formatter<'_>, element: &SpanBatchElement) -> fmt::Result {
+    writeln!(
+        f,
+        "    Block at timestamp {}, epoch {}, {} transactions",
+        element.timestamp,
+        element.epoch_num,
+        element.transactions.len()
+    )?;
+    fmt_transactions(f, &element.transactions)
+}
+
+fn fmt_transactions(f: &mut fmt::Formatter<'_>, transactions: &[Bytes]) -> fmt::Result {
+    for tx in transactions {
+        match OpTxEnvelope::decode_2718(&mut tx.as_ref()) {
+            Ok(tx) => {
+                write!(
+                    f,
+                    "      {} type {} nonce {} gas limit {}",
+                    tx.tx_hash(),
+                    tx.ty(),
+                    tx.nonce(),
+                    tx.gas_limit()
+                )?;
+                tx.to().map_or_else(|| write!(f, " create"), |to| write!(f, " to {to}"))?;
+                writeln!(f, " value {}", tx.value())?;
+            }
+            Err(e) => writeln!(f, "      Invalid transaction {}: {e}", hex::encode_prefixed(tx))?,
+        }
+    }
+    Ok(())
+}
+
+/// Reassembles channels from the [`Frame`]s of batcher transactions, and decodes their batches.
+///
+/// Frames of a channel may be spread over multiple transactions, and added in any order.
+#[derive(Debug, Default)]
+pub struct ChannelReassembler {
+    /// The channels being reassembled, in the order their first frame was added.
+    channels: Vec<Channel>,
+}
+
+impl ChannelReassembler {
+    /// Creates a new, empty [`ChannelReassembler`].
+    pub const fn new() -> Self {
+        Self { channels: Vec::new() }
+    }
+
+    /// Parses the frames of the given batcher transaction data, which is either the calldata of
+    /// the transaction or the data decoded from one of its blobs, and adds them to their
+    /// channels. Returns the number of parsed frames.
+    ///
+    /// The L1 block is the block that included the transaction.
+    pub fn add_data(&mut self, data: &[u8], l1_block: BlockInfo) -> Result<usize, FrameParseError> {
+        let frames = Frame::parse_frames(data)?;
+        let count = frames.len();
+        for frame in frames {
+            // Invalid frames are ignored, as they are by the derivation pipeline.
+            let _ = self.add_frame(frame, l1_block);
+        }
+        Ok(count)
+    }
+
+    /// Adds a [`Frame`] to its channel, opening the channel if needed.
+    pub fn add_frame(&mut self, frame: Frame, l1_block: BlockInfo) -> Result<(), ChannelError> {
+        let index = self.channels.iter().position(|c| c.id() == frame.id).unwrap_or_else(|| {
+            self.channels.push(Channel::new(frame.id, l1_block));
+            self.channels.len() - 1
+        });
+        self.channels[index].add_frame(frame, l1_block)
+    }
+
+    /// Returns the channels that are still missing frames.
+    pub fn pending(&self) -> impl Iterator<Item = &Channel> {
+        self.channels.iter().filter(|c| !c.is_ready())
+    }
+
+    /// Removes the complete channels and decodes their batches.
+    ///
+    /// The maximum size of the decompressed data of a channel depends on whether Fjord was active
+    /// in the L1 block that opened the channel.
+    pub fn decode_ready(&mut self, cfg: &RollupConfig) -> Vec<DecodedChannel> {
+        let (ready, pending) = core::mem::take(&mut self.channels)
+            .into_iter()
+            .partition::<Vec<_>, _>(|c| c.is_ready());
+        self.channels = pending;
+
+        ready
+            .into_iter()
+            .filter_map(|channel| {
+                let data = channel.frame_data()?;
+                let max_rlp_bytes =
+                    cfg.max_rlp_bytes_per_channel(channel.open_block().timestamp) as usize;
+                Some(DecodedChannel::decode(channel.id(), channel.len(), &data, max_rlp_bytes, cfg))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DERIVATION_VERSION_0;
+    use alloc::{string::ToString, vec};
+
+    fn compressed_batch_data() -> Vec<u8> {
+        let file_contents =
+            alloc::string::String::from_utf8_lossy(include_bytes!("../testdata/batch.hex"));
+        let file_contents = &(&*file_contents)[..file_contents.len() - 1];
+        hex::decode(file_contents).unwrap()
+    }
+
+    fn batcher_data(frames: &[Frame]) -> Vec<u8> {
+        let mut data = vec![DERIVATION_VERSION_0];
+        frames.iter().for_each(|frame| data.extend(frame.encode()));
+        data
+    }
+
+    #[test]
+    fn test_reassemble_across_transactions() {
+        let compressed = compressed_batch_data();
+        let (first, second) = compressed.split_at(compressed.len() / 2);
+        let id = [0xAA; 16];
+        let mut reassembler = ChannelReassembler::new();
+
+        // The last frame is submitted first.
+        let count = reassembler
+            .add_data(
+                &batcher_data(&[Frame::new(id, 1, second.to_vec(), true)]),
+                Default::default(),
+            )
+            .unwrap();
+        assert_eq!(count, 1);
+        assert!(reassembler.decode_ready(&RollupConfig::default()).is_empty());
+        assert_eq!(reassembler.pending().count(), 1);
+
+        reassembler
+            .add_data(
+                &batcher_data(&[Frame::new(id, 0, first.to_vec(), false)]),
+                Default::default(),
+            )
+            .unwrap();
+        let channels = reassembler.decode_ready(&RollupConfig::default());
+        assert_eq!(reassembler.pending().count(), 0);
+        assert_eq!(channels.len(), 1);
+
+        let channel = &channels[0];
+        assert_eq!(channel.id, id);
+        assert_eq!(channel.frames, 2);
+        assert_eq!(channel.compressed_size, compressed.len());
+        assert_eq!(channel.compression, Some(ChannelCompression::Zlib));
+        assert!(channel.error.is_none(), "{:?}", channel.error);
+        assert!(!channel.batches.is_empty());
+        assert!(channel.to_string().contains("Batch 0: "));
+    }
+
+    #[test]
+    fn test_decode_invalid_channel() {
+        let channel = DecodedChannel::decode([0; 16], 1, &[0xFF, 0x00], 1_000, &Default::default());
+        assert_eq!(channel.compression, None);
+        assert!(matches!(
+            channel.error,
+            Some(ChannelDecodingError::Decompression(DecompressionError::UnsupportedType(0xFF)))
+        ));
+        assert!(channel.to_string().contains("Error: Failed to decompress the channel"));
+    }
+
+    #[test]
+    fn test_add_data_invalid_version() {
+        let mut reassembler = ChannelReassembler::new();
+        assert_eq!(
+            reassembler.add_data(&[1, 2, 3], Default::default()),
+            Err(FrameParseError::UnsupportedVersion)
+        );
+    }
+}
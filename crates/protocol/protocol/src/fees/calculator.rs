@@ -0,0 +1,300 @@
+//! Contains the [`FeeCalculator`], computing the fees paid by an L2 transaction.
+
+use crate::{L1BlockInfoTx, flz_compress_len};
+use alloy_consensus::Transaction;
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::U256;
+use kona_genesis::RollupConfig;
+use op_alloy_consensus::OpTxType;
+
+/// The gas charged per zero byte of transaction data.
+const ZERO_BYTE_GAS: u64 = 4;
+
+/// The gas charged per non-zero byte of transaction data.
+const NON_ZERO_BYTE_GAS: u64 = 16;
+
+/// The gas added to the L1 gas used before Regolith, to account for the signature of the
+/// transaction.
+const PRE_REGOLITH_SIGNATURE_GAS: u64 = 68 * 16;
+
+/// The intercept of the Fjord linear regression of the transaction size, scaled by 1e6.
+const FJORD_L1_COST_INTERCEPT: u64 = 42_585_600;
+
+/// The FastLZ coefficient of the Fjord linear regression of the transaction size, scaled by 1e6.
+const FJORD_L1_COST_FASTLZ_COEF: u64 = 836_500;
+
+/// The minimum estimated size of a transaction since Fjord, scaled by 1e6.
+const FJORD_MIN_TRANSACTION_SIZE_SCALED: u64 = 100 * 1_000_000;
+
+/// The fees paid by an L2 transaction, split by the fee vault they are credited to.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct FeeBreakdown {
+    /// The L1 data fee, credited to the [`Predeploys::L1_FEE_VAULT`].
+    ///
+    /// [`Predeploys::L1_FEE_VAULT`]: crate::Predeploys::L1_FEE_VAULT
+    pub l1_fee: U256,
+    /// The L1 gas used by the transaction data, as reported in the transaction receipt.
+    pub l1_gas_used: u64,
+    /// The operator fee, credited to the [`Predeploys::OPERATOR_FEE_VAULT`].
+    ///
+    /// [`Predeploys::OPERATOR_FEE_VAULT`]: crate::Predeploys::OPERATOR_FEE_VAULT
+    pub operator_fee: U256,
+    /// The base fee, credited to the [`Predeploys::BASE_FEE_VAULT`].
+    ///
+    /// [`Predeploys::BASE_FEE_VAULT`]: crate::Predeploys::BASE_FEE_VAULT
+    pub base_fee: U256,
+    /// The priority fee, credited to the [`Predeploys::SEQUENCER_FEE_VAULT`].
+    ///
+    /// [`Predeploys::SEQUENCER_FEE_VAULT`]: crate::Predeploys::SEQUENCER_FEE_VAULT
+    pub priority_fee: U256,
+}
+
+impl FeeBreakdown {
+    /// Returns the total fee paid by the transaction.
+    pub fn total(&self) -> U256 {
+        self.l1_fee + self.operator_fee + self.base_fee + self.priority_fee
+    }
+}
+
+/// Computes the fees paid by the transactions of an L2 block.
+///
+/// The L1 data fee is computed with the cost function of the hardfork active at the L2 block, from
+/// the fee parameters of the block's [`L1BlockInfoTx`]:
+/// - Bedrock: the transaction data gas plus the fixed overhead, multiplied by the L1 base fee and
+///   the L1 fee scalar.
+/// - Ecotone: the transaction data gas, priced at a weighted sum of the L1 base fee and the L1 blob
+///   base fee.
+/// - Fjord: the same pricing as Ecotone, applied to the transaction size estimated from its FastLZ
+///   compressed size.
+///
+/// Since Isthmus, transactions also pay the operator fee. Deposit transactions pay no fees.
+#[derive(Debug, Clone, Copy)]
+pub struct FeeCalculator<'a> {
+    /// The rollup config.
+    rollup_config: &'a RollupConfig,
+    /// The L1 info of the L2 block.
+    l1_info: L1BlockInfoTx,
+    /// The timestamp of the L2 block.
+    timestamp: u64,
+    /// The base fee of the L2 block.
+    base_fee: u64,
+}
+
+impl<'a> FeeCalculator<'a> {
+    /// Creates a new [`FeeCalculator`] for the L2 block with the given L1 info, timestamp and base
+    /// fee.
+    pub const fn new(
+        rollup_config: &'a RollupConfig,
+        l1_info: L1BlockInfoTx,
+        timestamp: u64,
+        base_fee: u64,
+    ) -> Self {
+        Self { rollup_config, l1_info, timestamp, base_fee }
+    }
+
+    /// Returns the fees paid by the transaction, given the gas it used.
+    pub fn fees<T: Transaction + Encodable2718>(&self, tx: &T, gas_used: u64) -> FeeBreakdown {
+        if tx.ty() == OpTxType::Deposit as u8 {
+            return FeeBreakdown::default();
+        }
+
+        let (l1_fee, l1_gas_used) = self.l1_data_fee(&tx.encoded_2718());
+        let tip = tx.effective_tip_per_gas(self.base_fee).unwrap_or_default();
+        FeeBreakdown {
+            l1_fee,
+            l1_gas_used,
+            operator_fee: self.operator_fee(gas_used),
+            base_fee: U256::from(gas_used) * U256::from(self.base_fee),
+            priority_fee: U256::from(gas_used) * U256::from(tip),
+        }
+    }
+
+    /// Returns the L1 data fee and the L1 gas used of the EIP-2718 encoded transaction.
+    pub fn l1_data_fee(&self, encoded: &[u8]) -> (U256, u64) {
+        match self.l1_info {
+            L1BlockInfoTx::Bedrock(_) => self.l1_data_fee_bedrock(encoded),
+            L1BlockInfoTx::Ecotone(_) | L1BlockInfoTx::Isthmus(_)
+                if self.rollup_config.is_fjord_active(self.timestamp) =>
+            {
+                self.l1_data_fee_fjord(encoded)
+            }
+            L1BlockInfoTx::Ecotone(_) | L1BlockInfoTx::Isthmus(_) => {
+                self.l1_data_fee_ecotone(encoded)
+            }
+        }
+    }
+
+    /// Returns the operator fee paid for the given gas used.
+    ///
+    /// The operator fee is zero before Isthmus.
+    pub fn operator_fee(&self, gas_used: u64) -> U256 {
+        if !self.rollup_config.is_isthmus_active(self.timestamp) {
+            return U256::ZERO;
+        }
+
+        let scaled = U256::from(gas_used) * U256::from(self.l1_info.operator_fee_scalar());
+        scaled / U256::from(1_000_000) + U256::from(self.l1_info.operator_fee_constant())
+    }
+
+    /// Returns the Bedrock L1 data fee and L1 gas used.
+    fn l1_data_fee_bedrock(&self, encoded: &[u8]) -> (U256, u64) {
+        let mut gas = data_gas(encoded);
+        if !self.rollup_config.is_regolith_active(self.timestamp) {
+            gas += PRE_REGOLITH_SIGNATURE_GAS;
+        }
+        let gas = U256::from(gas) + self.l1_info.l1_fee_overhead();
+
+        let fee =
+            gas * self.l1_info.l1_base_fee() * self.l1_info.l1_fee_scalar() / U256::from(1_000_000);
+        (fee, gas.saturating_to())
+    }
+
+    /// Returns the Ecotone L1 data fee and L1 gas used.
+    fn l1_data_fee_ecotone(&self, encoded: &[u8]) -> (U256, u64) {
+        let gas = data_gas(encoded);
+        let fee = U256::from(gas) * self.scaled_l1_base_fee() / U256::from(16_000_000);
+        (fee, gas)
+    }
+
+    /// Returns the Fjord L1 data fee and L1 gas used.
+    fn l1_data_fee_fjord(&self, encoded: &[u8]) -> (U256, u64) {
+        let fastlz_size = u64::from(flz_compress_len(encoded));
+        let estimated_size = (FJORD_L1_COST_FASTLZ_COEF * fastlz_size)
+            .saturating_sub(FJORD_L1_COST_INTERCEPT)
+            .max(FJORD_MIN_TRANSACTION_SIZE_SCALED);
+
+        let fee = U256::from(estimated_size) * self.scaled_l1_base_fee() /
+            U256::from(1_000_000_000_000u64);
+        (fee, estimated_size * NON_ZERO_BYTE_GAS / 1_000_000)
+    }
+
+    /// Returns the L1 base fee and L1 blob base fee, weighted by their scalars, as priced per byte
+    /// of compressed data since Ecotone.
+    fn scaled_l1_base_fee(&self) -> U256 {
+        self.l1_info.l1_base_fee() * self.l1_info.l1_fee_scalar() * U256::from(16) +
+            self.l1_info.blob_base_fee() * self.l1_info.blob_base_fee_scalar()
+    }
+}
+
+/// Returns the calldata gas of the transaction data.
+fn data_gas(data: &[u8]) -> u64 {
+    data.iter().map(|byte| if *byte == 0 { ZERO_BYTE_GAS } else { NON_ZERO_BYTE_GAS }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{L1BlockInfoBedrock, L1BlockInfoEcotone, L1BlockInfoIsthmus};
+    use alloc::vec::Vec;
+    use alloy_consensus::{Signed, TxEip1559};
+    use alloy_primitives::{Sealable, Signature};
+    use kona_genesis::HardForkConfig;
+    use op_alloy_consensus::{OpTxEnvelope, TxDeposit};
+
+    const DATA: [u8; 100] = [0; 100];
+
+    fn ecotone_info() -> L1BlockInfoEcotone {
+        L1BlockInfoEcotone {
+            base_fee: 1_000_000_000,
+            base_fee_scalar: 1368,
+            blob_base_fee: 1,
+            blob_base_fee_scalar: 810949,
+            ..Default::default()
+        }
+    }
+
+    fn isthmus_info() -> L1BlockInfoIsthmus {
+        L1BlockInfoIsthmus {
+            base_fee: 1_000_000_000,
+            base_fee_scalar: 1368,
+            blob_base_fee: 1,
+            blob_base_fee_scalar: 810949,
+            operator_fee_scalar: 2_000_000,
+            operator_fee_constant: 500,
+            ..Default::default()
+        }
+    }
+
+    fn rollup_config(hardforks: HardForkConfig) -> RollupConfig {
+        RollupConfig { hardforks, ..Default::default() }
+    }
+
+    #[test]
+    fn test_l1_data_fee_bedrock() {
+        let info = L1BlockInfoTx::Bedrock(L1BlockInfoBedrock {
+            base_fee: 1_000_000_000,
+            l1_fee_overhead: U256::from(188),
+            l1_fee_scalar: U256::from(684_000),
+            ..Default::default()
+        });
+
+        let cfg = rollup_config(HardForkConfig { regolith_time: Some(0), ..Default::default() });
+        let calculator = FeeCalculator::new(&cfg, info, 0, 0);
+        assert_eq!(calculator.l1_data_fee(&DATA), (U256::from(402_192_000_000u64), 588));
+
+        let cfg = rollup_config(HardForkConfig::default());
+        let calculator = FeeCalculator::new(&cfg, info, 0, 0);
+        assert_eq!(calculator.l1_data_fee(&DATA), (U256::from(1_146_384_000_000u64), 1676));
+    }
+
+    #[test]
+    fn test_l1_data_fee_ecotone() {
+        let cfg = rollup_config(HardForkConfig { ecotone_time: Some(0), ..Default::default() });
+        let calculator = FeeCalculator::new(&cfg, L1BlockInfoTx::Ecotone(ecotone_info()), 0, 0);
+        assert_eq!(calculator.l1_data_fee(&DATA), (U256::from(547_200_020u64), 400));
+    }
+
+    #[test]
+    fn test_l1_data_fee_fjord() {
+        let cfg = rollup_config(HardForkConfig { fjord_time: Some(0), ..Default::default() });
+        let calculator = FeeCalculator::new(&cfg, L1BlockInfoTx::Ecotone(ecotone_info()), 0, 0);
+
+        // The data compresses below the minimum transaction size.
+        assert_eq!(calculator.l1_data_fee(&DATA), (U256::from(2_188_800_081u64), 1600));
+
+        // Incompressible data is estimated by its FastLZ size, here 12.6234 bytes, which is also
+        // below the minimum transaction size.
+        let data = (0..64).collect::<Vec<u8>>();
+        assert_eq!(calculator.l1_data_fee(&data), (U256::from(2_188_800_081u64), 1600));
+    }
+
+    #[test]
+    fn test_operator_fee() {
+        let info = L1BlockInfoTx::Isthmus(isthmus_info());
+
+        let cfg = rollup_config(HardForkConfig { isthmus_time: Some(10), ..Default::default() });
+        assert_eq!(FeeCalculator::new(&cfg, info, 9, 0).operator_fee(21_000), U256::ZERO);
+        assert_eq!(FeeCalculator::new(&cfg, info, 10, 0).operator_fee(21_000), U256::from(42_500));
+    }
+
+    #[test]
+    fn test_fees() {
+        let cfg = rollup_config(HardForkConfig {
+            fjord_time: Some(0),
+            isthmus_time: Some(0),
+            ..Default::default()
+        });
+        let calculator = FeeCalculator::new(&cfg, L1BlockInfoTx::Isthmus(isthmus_info()), 0, 99);
+
+        let tx =
+            TxEip1559 { max_fee_per_gas: 100, max_priority_fee_per_gas: 2, ..Default::default() };
+        let tx = OpTxEnvelope::Eip1559(Signed::new_unhashed(tx, Signature::test_signature()));
+        let fees = calculator.fees(&tx, 21_000);
+        assert_eq!(
+            fees,
+            FeeBreakdown {
+                l1_fee: U256::from(2_188_800_081u64),
+                l1_gas_used: 1600,
+                operator_fee: U256::from(42_500),
+                base_fee: U256::from(21_000 * 99),
+                priority_fee: U256::from(21_000),
+            }
+        );
+        assert_eq!(fees.total(), U256::from(2_188_800_081u64 + 42_500 + 21_000 * 100));
+
+        let deposit = OpTxEnvelope::Deposit(TxDeposit::default().seal_slow());
+        assert_eq!(calculator.fees(&deposit, 21_000), FeeBreakdown::default());
+    }
+}
@@ -0,0 +1,105 @@
+//! FastLZ compressed size estimation, used by the Fjord L1 data fee.
+
+use alloc::vec;
+
+/// Returns the length of the input once compressed with the FastLZ (level 1) algorithm.
+///
+/// The output is not produced, only its length is computed. This mirrors `FlzCompressLen` of
+/// op-geth and `LibZip.flzCompress` of solady, which are used to estimate the L1 data fee of a
+/// transaction since Fjord.
+pub fn flz_compress_len(input: &[u8]) -> u32 {
+    let u24 = |i: u32| -> u32 {
+        let i = i as usize;
+        u32::from(input[i]) | (u32::from(input[i + 1]) << 8) | (u32::from(input[i + 2]) << 16)
+    };
+    let cmp = |p: u32, q: u32, e: u32| -> u32 {
+        let mut l = 0;
+        let mut e = e - q;
+        while l < e {
+            if input[(p + l) as usize] != input[(q + l) as usize] {
+                e = 0;
+            }
+            l += 1;
+        }
+        l
+    };
+
+    let mut n = 0;
+    let mut ht = vec![0u32; 8192];
+    let mut a = 0;
+    let ip_limit = (input.len() as u32).saturating_sub(13);
+    let mut ip = a + 2;
+    while ip < ip_limit {
+        let mut r;
+        loop {
+            let s = u24(ip);
+            let h = flz_hash(s);
+            r = ht[h];
+            ht[h] = ip;
+            let d = ip.wrapping_sub(r);
+            if ip >= ip_limit {
+                break;
+            }
+            ip += 1;
+            if d <= 0x1fff && s == u24(r) {
+                break;
+            }
+        }
+        if ip >= ip_limit {
+            break;
+        }
+        ip -= 1;
+        if ip > a {
+            n += flz_literals_len(ip - a);
+        }
+        let l = cmp(r + 3, ip + 3, ip_limit + 9);
+        n += flz_match_len(l);
+
+        // Hash the two positions following the match.
+        ip += l;
+        ht[flz_hash(u24(ip))] = ip;
+        ip += 1;
+        ht[flz_hash(u24(ip))] = ip;
+        ip += 1;
+        a = ip;
+    }
+    n + flz_literals_len(input.len() as u32 - a)
+}
+
+/// Returns the index of the 3-byte sequence in the hash table.
+const fn flz_hash(v: u32) -> usize {
+    ((2654435769u32.wrapping_mul(v) >> 19) & 0x1fff) as usize
+}
+
+/// Returns the encoded length of a run of `r` literals.
+const fn flz_literals_len(r: u32) -> u32 {
+    let rem = r % 0x20;
+    0x21 * (r / 0x20) + if rem != 0 { rem + 1 } else { 0 }
+}
+
+/// Returns the encoded length of a match of length `l`.
+const fn flz_match_len(l: u32) -> u32 {
+    let l = l - 1;
+    3 * (l / 262) + if l % 262 >= 6 { 3 } else { 2 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flz_compress_len_literals() {
+        assert_eq!(flz_compress_len(&[]), 0);
+        assert_eq!(flz_compress_len(&[0xff]), 2);
+        assert_eq!(flz_compress_len(&[0u8; 12]), 13);
+        // Incompressible runs are split into chunks of 32 literals.
+        let input = (0..64).collect::<alloc::vec::Vec<u8>>();
+        assert_eq!(flz_compress_len(&input), 2 * 0x21);
+    }
+
+    #[test]
+    fn test_flz_compress_len_match() {
+        // 2 literals, a match of 91 bytes, and the 5 trailing literals.
+        assert_eq!(flz_compress_len(&[0u8; 100]), 3 + 3 + 6);
+    }
+}
@@ -0,0 +1,7 @@
+//! Module containing the fee accounting of L2 transactions.
+
+mod fastlz;
+pub use fastlz::flz_compress_len;
+
+mod calculator;
+pub use calculator::{FeeBreakdown, FeeCalculator};
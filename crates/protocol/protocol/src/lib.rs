@@ -49,6 +49,9 @@ pub use channel::{
     MAX_RLP_BYTES_PER_CHANNEL,
 };
 
+mod decoder;
+pub use decoder::{ChannelCompression, ChannelDecodingError, ChannelReassembler, DecodedChannel};
+
 mod deposits;
 pub use deposits::{
     DEPOSIT_EVENT_ABI, DEPOSIT_EVENT_ABI_HASH, DEPOSIT_EVENT_VERSION_0, DepositError,
@@ -61,6 +64,9 @@ pub use info::{
     L1BlockInfoTx,
 };
 
+mod fees;
+pub use fees::{FeeBreakdown, FeeCalculator, flz_compress_len};
+
 mod predeploys;
 pub use predeploys::Predeploys;
 
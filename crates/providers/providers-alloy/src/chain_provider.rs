@@ -134,16 +134,7 @@ impl ChainProvider for AlloyChainProvider {
             return Ok(receipts.clone());
         }
 
-        let receipts = self
-            .inner
-            .get_block_receipts(hash.into())
-            .await?
-            .ok_or(AlloyChainProviderError::BlockNotFound(hash.into()))?;
-        let consensus_receipts = receipts
-            .into_iter()
-            .map(|r| r.inner.into_primitives_receipt().as_receipt().cloned())
-            .collect::<Option<Vec<_>>>()
-            .ok_or(AlloyChainProviderError::ReceiptsConversion(hash))?;
+        let consensus_receipts = fetch_receipts(&self.inner, hash).await?;
 
         self.receipts_by_hash_cache.put(hash, consensus_receipts.clone());
         Ok(consensus_receipts)
@@ -180,3 +171,19 @@ impl ChainProvider for AlloyChainProvider {
         Ok((block_info, block.body.transactions))
     }
 }
+
+/// Fetches the receipts of the block with the given hash, without caching them.
+pub(crate) async fn fetch_receipts(
+    provider: &RootProvider,
+    hash: B256,
+) -> Result<Vec<Receipt>, AlloyChainProviderError> {
+    let receipts = provider
+        .get_block_receipts(hash.into())
+        .await?
+        .ok_or(AlloyChainProviderError::BlockNotFound(hash.into()))?;
+    receipts
+        .into_iter()
+        .map(|r| r.inner.into_primitives_receipt().as_receipt().cloned())
+        .collect::<Option<Vec<_>>>()
+        .ok_or(AlloyChainProviderError::ReceiptsConversion(hash))
+}
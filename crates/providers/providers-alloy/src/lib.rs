@@ -21,5 +21,19 @@ pub use chain_provider::{AlloyChainProvider, AlloyChainProviderError};
 mod l2_chain_provider;
 pub use l2_chain_provider::{AlloyL2ChainProvider, AlloyL2ChainProviderError};
 
+mod recording;
+pub use recording::{
+    DerivationRecording, RecordedBlobProvider, RecordedChainProvider, RecordedL1Block,
+    RecordedL2ChainProvider, RecordedProviderError, RecordingError, SharedRecording,
+};
+
+mod metrics;
+pub use metrics::Metrics;
+
+mod prefetch;
+pub use prefetch::{
+    L1Prefetcher, PrefetchConfig, PrefetchingBlobProvider, PrefetchingChainProvider,
+};
+
 mod pipeline;
-pub use pipeline::OnlinePipeline;
+pub use pipeline::{OnlineDataAvailabilityRegistry, OnlinePipeline};
@@ -0,0 +1,68 @@
+//! Metrics for the alloy-backed providers.
+
+/// Container for metrics.
+#[derive(Debug, Clone)]
+pub struct Metrics;
+
+impl Metrics {
+    /// Identifier for the counter of L1 data lookups in the prefetch cache, labeled by the kind of
+    /// data and whether the lookup was a hit or a miss.
+    pub const L1_PREFETCH_LOOKUPS: &str = "kona_providers_l1_prefetch_lookups";
+
+    /// Identifier for the gauge that tracks the number of L1 blocks held in the prefetch cache.
+    pub const L1_PREFETCH_BLOCKS: &str = "kona_providers_l1_prefetch_blocks";
+
+    /// Identifier for the gauge that tracks the estimated size of the prefetch cache, in bytes.
+    pub const L1_PREFETCH_BYTES: &str = "kona_providers_l1_prefetch_bytes";
+
+    /// Identifier for the counter of prefetch cache invalidations.
+    pub const L1_PREFETCH_INVALIDATIONS: &str = "kona_providers_l1_prefetch_invalidations";
+}
+
+impl Metrics {
+    /// Initializes metrics.
+    ///
+    /// This does two things:
+    /// * Describes various metrics.
+    /// * Initializes metrics to 0 so they can be queried immediately.
+    #[cfg(feature = "metrics")]
+    pub fn init() {
+        Self::describe();
+        Self::zero();
+    }
+
+    /// Describes metrics.
+    #[cfg(feature = "metrics")]
+    pub fn describe() {
+        metrics::describe_counter!(
+            Self::L1_PREFETCH_LOOKUPS,
+            "The number of L1 data lookups in the prefetch cache"
+        );
+        metrics::describe_gauge!(
+            Self::L1_PREFETCH_BLOCKS,
+            "The number of L1 blocks held in the prefetch cache"
+        );
+        metrics::describe_gauge!(
+            Self::L1_PREFETCH_BYTES,
+            "The estimated size of the prefetch cache, in bytes"
+        );
+        metrics::describe_counter!(
+            Self::L1_PREFETCH_INVALIDATIONS,
+            "The number of prefetch cache invalidations"
+        );
+    }
+
+    /// Initializes metrics to 0 so they can be queried immediately.
+    #[cfg(feature = "metrics")]
+    pub fn zero() {
+        for kind in ["block_info", "header", "transactions", "receipts", "blobs"] {
+            for result in ["hit", "miss"] {
+                metrics::counter!(Self::L1_PREFETCH_LOOKUPS, "kind" => kind, "result" => result)
+                    .absolute(0);
+            }
+        }
+        kona_macros::set!(gauge, Self::L1_PREFETCH_BLOCKS, 0);
+        kona_macros::set!(gauge, Self::L1_PREFETCH_BYTES, 0);
+        kona_macros::set!(counter, Self::L1_PREFETCH_INVALIDATIONS, 0);
+    }
+}
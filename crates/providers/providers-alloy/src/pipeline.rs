@@ -1,13 +1,16 @@
 //! Contains an online derivation pipeline.
 
-use crate::{AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlineBlobProvider};
+use crate::{
+    AlloyChainProvider, AlloyL2ChainProvider, L1Prefetcher, OnlineBeaconClient, OnlineBlobProvider,
+    PrefetchConfig, PrefetchingBlobProvider, PrefetchingChainProvider,
+};
 use async_trait::async_trait;
 use core::fmt::Debug;
 use kona_derive::{
-    DerivationPipeline, EthereumDataSource, IndexedAttributesQueueStage, L2ChainProvider,
-    OriginProvider, Pipeline, PipelineBuilder, PipelineErrorKind, PipelineResult,
-    PolledAttributesQueueStage, ResetSignal, Signal, SignalReceiver, StatefulAttributesBuilder,
-    StepResult,
+    DataAvailabilityRegistry, DerivationEventSink, DerivationPipeline, DynDataAvailabilityProvider,
+    IndexedAttributesQueueStage, L2ChainProvider, OriginProvider, Pipeline, PipelineBuilder,
+    PipelineCheckpoint, PipelineErrorKind, PipelineResult, PolledAttributesQueueStage, ResetSignal,
+    Signal, SignalReceiver, StageCheckpoint, StatefulAttributesBuilder, StepResult,
 };
 use kona_genesis::{RollupConfig, SystemConfig};
 use kona_protocol::{BlockInfo, L2BlockInfo, OpAttributesWithParent};
@@ -17,7 +20,7 @@ use std::sync::Arc;
 pub type OnlinePolledDerivationPipeline = DerivationPipeline<
     PolledAttributesQueueStage<
         OnlineDataProvider,
-        AlloyChainProvider,
+        PrefetchingChainProvider,
         AlloyL2ChainProvider,
         OnlineAttributesBuilder,
     >,
@@ -28,21 +31,25 @@ pub type OnlinePolledDerivationPipeline = DerivationPipeline<
 pub type OnlineManagedDerivationPipeline = DerivationPipeline<
     IndexedAttributesQueueStage<
         OnlineDataProvider,
-        AlloyChainProvider,
+        PrefetchingChainProvider,
         AlloyL2ChainProvider,
         OnlineAttributesBuilder,
     >,
     AlloyL2ChainProvider,
 >;
 
-/// An RPC-backed Ethereum data source.
-pub type OnlineDataProvider =
-    EthereumDataSource<AlloyChainProvider, OnlineBlobProvider<OnlineBeaconClient>>;
+/// An RPC-backed data availability provider, selected by an [`OnlineDataAvailabilityRegistry`]
+/// and reading the L1 data prefetched by an [`L1Prefetcher`].
+pub type OnlineDataProvider = DynDataAvailabilityProvider;
+
+/// A registry of the data availability providers of the online pipeline.
+pub type OnlineDataAvailabilityRegistry =
+    DataAvailabilityRegistry<PrefetchingChainProvider, PrefetchingBlobProvider>;
 
 /// An RPC-backed payload attributes builder for the `AttributesQueue` stage of the derivation
 /// pipeline.
 pub type OnlineAttributesBuilder =
-    StatefulAttributesBuilder<AlloyChainProvider, AlloyL2ChainProvider>;
+    StatefulAttributesBuilder<PrefetchingChainProvider, AlloyL2ChainProvider>;
 
 /// An online derivation pipeline.
 #[derive(Debug)]
@@ -63,8 +70,21 @@ impl OnlinePipeline {
         chain_provider: AlloyChainProvider,
         mut l2_chain_provider: AlloyL2ChainProvider,
     ) -> PipelineResult<Self> {
-        let mut pipeline =
-            Self::new_polled(cfg.clone(), blob_provider, chain_provider, l2_chain_provider.clone());
+        let prefetcher = L1Prefetcher::new(
+            PrefetchConfig::DISABLED,
+            chain_provider.inner.clone(),
+            blob_provider.clone(),
+            &cfg,
+        );
+        let mut pipeline = Self::new_polled(
+            cfg.clone(),
+            blob_provider,
+            chain_provider,
+            l2_chain_provider.clone(),
+            &OnlineDataAvailabilityRegistry::default(),
+            &prefetcher,
+            None,
+        );
 
         // Reset the pipeline to populate the initial L1/L2 cursor and system configuration in L1
         // Traversal.
@@ -87,7 +107,11 @@ impl OnlinePipeline {
 
     /// Constructs a new polled derivation pipeline that is uninitialized.
     ///
-    /// Uses online providers as specified by the arguments.
+    /// Uses online providers as specified by the arguments. The data availability provider is
+    /// selected from the registry, and the L1 data is prefetched ahead of the pipeline by the
+    /// given [`L1Prefetcher`], which must be invalidated when the pipeline is reset. The optional
+    /// event sink receives the [`DerivationEvent`](kona_derive::DerivationEvent)s emitted by the
+    /// pipeline stages.
     ///
     /// Before using the returned pipeline, a [`ResetSignal`] must be sent to
     /// instantiate the pipeline state. [`Self::new`] is a convenience method that
@@ -97,29 +121,42 @@ impl OnlinePipeline {
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         l2_chain_provider: AlloyL2ChainProvider,
+        da_registry: &OnlineDataAvailabilityRegistry,
+        prefetcher: &L1Prefetcher,
+        event_sink: Option<Arc<dyn DerivationEventSink>>,
     ) -> Self {
+        let chain_provider = prefetcher.chain_provider(chain_provider);
+        let blob_provider = prefetcher.blob_provider(blob_provider);
+
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
             l2_chain_provider.clone(),
             chain_provider.clone(),
         );
-        let dap = EthereumDataSource::new_from_parts(chain_provider.clone(), blob_provider, &cfg);
+        let dap = da_registry.create(chain_provider.clone(), blob_provider, &cfg);
 
-        let pipeline = PipelineBuilder::new()
+        let mut builder = PipelineBuilder::new()
             .rollup_config(cfg.clone())
             .dap_source(dap)
             .l2_chain_provider(l2_chain_provider.clone())
             .chain_provider(chain_provider)
             .builder(attributes)
-            .origin(BlockInfo::default())
-            .build_polled();
+            .origin(BlockInfo::default());
+        if let Some(event_sink) = event_sink {
+            builder = builder.event_sink(event_sink);
+        }
+        let pipeline = builder.build_polled();
 
         Self::Polled(pipeline)
     }
 
     /// Constructs a new indexed derivation pipeline that is uninitialized.
     ///
-    /// Uses online providers as specified by the arguments.
+    /// Uses online providers as specified by the arguments. The data availability provider is
+    /// selected from the registry, and the L1 data is prefetched ahead of the pipeline by the
+    /// given [`L1Prefetcher`], which must be invalidated when the pipeline is reset. The optional
+    /// event sink receives the [`DerivationEvent`](kona_derive::DerivationEvent)s emitted by the
+    /// pipeline stages.
     ///
     /// Before using the returned pipeline, a [`ResetSignal`] must be sent to
     /// instantiate the pipeline state. [`Self::new`] is a convenience method that
@@ -129,22 +166,31 @@ impl OnlinePipeline {
         blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
         chain_provider: AlloyChainProvider,
         l2_chain_provider: AlloyL2ChainProvider,
+        da_registry: &OnlineDataAvailabilityRegistry,
+        prefetcher: &L1Prefetcher,
+        event_sink: Option<Arc<dyn DerivationEventSink>>,
     ) -> Self {
+        let chain_provider = prefetcher.chain_provider(chain_provider);
+        let blob_provider = prefetcher.blob_provider(blob_provider);
+
         let attributes = StatefulAttributesBuilder::new(
             cfg.clone(),
             l2_chain_provider.clone(),
             chain_provider.clone(),
         );
-        let dap = EthereumDataSource::new_from_parts(chain_provider.clone(), blob_provider, &cfg);
+        let dap = da_registry.create(chain_provider.clone(), blob_provider, &cfg);
 
-        let pipeline = PipelineBuilder::new()
+        let mut builder = PipelineBuilder::new()
             .rollup_config(cfg.clone())
             .dap_source(dap)
             .l2_chain_provider(l2_chain_provider.clone())
             .chain_provider(chain_provider)
             .builder(attributes)
-            .origin(BlockInfo::default())
-            .build_indexed();
+            .origin(BlockInfo::default());
+        if let Some(event_sink) = event_sink {
+            builder = builder.event_sink(event_sink);
+        }
+        let pipeline = builder.build_indexed();
 
         Self::Managed(pipeline)
     }
@@ -182,6 +228,25 @@ impl Iterator for OnlinePipeline {
     }
 }
 
+#[async_trait]
+impl StageCheckpoint for OnlinePipeline {
+    /// Records the pipeline state into the [PipelineCheckpoint].
+    fn checkpoint(&self, checkpoint: &mut PipelineCheckpoint) -> PipelineResult<()> {
+        match self {
+            Self::Polled(pipeline) => pipeline.checkpoint(checkpoint),
+            Self::Managed(pipeline) => pipeline.checkpoint(checkpoint),
+        }
+    }
+
+    /// Restores the pipeline state from the [PipelineCheckpoint].
+    async fn restore(&mut self, checkpoint: &PipelineCheckpoint) -> PipelineResult<()> {
+        match self {
+            Self::Polled(pipeline) => pipeline.restore(checkpoint).await,
+            Self::Managed(pipeline) => pipeline.restore(checkpoint).await,
+        }
+    }
+}
+
 #[async_trait]
 impl Pipeline for OnlinePipeline {
     /// Peeks at the next [OpAttributesWithParent] from the pipeline.
@@ -0,0 +1,592 @@
+//! Speculative prefetching of the L1 data read by the derivation pipeline.
+//!
+//! The derivation pipeline reads the L1 chain one block at a time, fetching the block, its
+//! receipts and the blobs of its batcher transactions in sequence. When the pipeline is far behind
+//! the L1 head, it is bound by the round-trip latency of these requests. The [`L1Prefetcher`]
+//! fetches the next blocks ahead of the pipeline concurrently, and serves them to the pipeline
+//! through the [`PrefetchingChainProvider`] and [`PrefetchingBlobProvider`].
+
+use crate::{
+    AlloyChainProvider, AlloyChainProviderError, OnlineBeaconClient, OnlineBlobProvider,
+    chain_provider::fetch_receipts,
+};
+use alloy_consensus::{Header, Receipt, Transaction, TxEnvelope};
+use alloy_eips::{
+    eip2718::Encodable2718,
+    eip4844::{BYTES_PER_BLOB, Blob, IndexedBlobHash},
+};
+use alloy_primitives::{Address, B256, map::HashMap};
+use alloy_provider::{Provider, RootProvider};
+use async_trait::async_trait;
+use kona_derive::{BlobProvider, BlobProviderError, ChainProvider};
+use kona_genesis::RollupConfig;
+use kona_protocol::BlockInfo;
+use std::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    sync::{Arc, Mutex, MutexGuard},
+    vec::Vec,
+};
+
+/// The configuration of the [`L1Prefetcher`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PrefetchConfig {
+    /// The number of L1 blocks to prefetch ahead of the pipeline. Prefetching is disabled when
+    /// zero.
+    pub depth: u64,
+    /// The maximum estimated size of the prefetched data, in bytes. No block is prefetched while
+    /// the prefetched data exceeds it.
+    pub max_bytes: usize,
+}
+
+impl PrefetchConfig {
+    /// A [`PrefetchConfig`] disabling prefetching.
+    pub const DISABLED: Self = Self { depth: 0, max_bytes: 0 };
+
+    /// Returns whether prefetching is enabled.
+    pub const fn is_enabled(&self) -> bool {
+        self.depth > 0
+    }
+}
+
+impl Default for PrefetchConfig {
+    fn default() -> Self {
+        Self::DISABLED
+    }
+}
+
+/// An L1 block prefetched along with the data the pipeline reads from it.
+#[derive(Debug, Clone)]
+struct PrefetchedBlock {
+    /// The block header.
+    header: Header,
+    /// The block info.
+    info: BlockInfo,
+    /// The transactions of the block.
+    transactions: Vec<TxEnvelope>,
+    /// The receipts of the block.
+    receipts: Vec<Receipt>,
+    /// The blobs of the batcher transactions of the block, by versioned hash.
+    blobs: HashMap<B256, Box<Blob>>,
+    /// The estimated size of the block data, in bytes.
+    size: usize,
+}
+
+impl PrefetchedBlock {
+    /// Creates a new [`PrefetchedBlock`], estimating its size.
+    fn new(
+        header: Header,
+        transactions: Vec<TxEnvelope>,
+        receipts: Vec<Receipt>,
+        blobs: HashMap<B256, Box<Blob>>,
+    ) -> Self {
+        let info = BlockInfo {
+            hash: header.hash_slow(),
+            number: header.number,
+            parent_hash: header.parent_hash,
+            timestamp: header.timestamp,
+        };
+        let size = size_of::<Header>() +
+            transactions.iter().map(|tx| tx.encode_2718_len()).sum::<usize>() +
+            receipts
+                .iter()
+                .flat_map(|receipt| &receipt.logs)
+                .map(|log| size_of::<Address>() + log.topics().len() * 32 + log.data.data.len())
+                .sum::<usize>() +
+            blobs.len() * BYTES_PER_BLOB;
+        Self { header, info, transactions, receipts, blobs, size }
+    }
+}
+
+/// The blocks prefetched by the [`L1Prefetcher`].
+#[derive(Debug, Default)]
+struct PrefetchCache {
+    /// The generation of the cache, incremented on each invalidation so that the fetches started
+    /// before it are discarded.
+    epoch: u64,
+    /// The hashes of the prefetched blocks, by number.
+    numbers: BTreeMap<u64, B256>,
+    /// The prefetched blocks, by hash.
+    blocks: HashMap<B256, PrefetchedBlock>,
+    /// The numbers of the blocks being fetched.
+    in_flight: BTreeSet<u64>,
+    /// The last block served to the pipeline by number.
+    last_served: Option<BlockInfo>,
+    /// The estimated size of the prefetched blocks, in bytes.
+    size: usize,
+}
+
+impl PrefetchCache {
+    /// Returns the numbers of the blocks to fetch ahead of the given block number, and marks them
+    /// as in flight.
+    fn schedule(&mut self, from: u64, config: &PrefetchConfig) -> Vec<u64> {
+        let mut scheduled = Vec::new();
+        for number in from..from.saturating_add(config.depth) {
+            if self.size >= config.max_bytes {
+                break;
+            }
+            if !self.numbers.contains_key(&number) && self.in_flight.insert(number) {
+                scheduled.push(number);
+            }
+        }
+        scheduled
+    }
+
+    /// Completes the fetch of the block with the given number, started at the given epoch.
+    ///
+    /// The block is discarded if the cache was invalidated since, or if the pipeline already read
+    /// past it.
+    fn complete(&mut self, epoch: u64, number: u64, block: Option<PrefetchedBlock>) {
+        if epoch != self.epoch {
+            return;
+        }
+        self.in_flight.remove(&number);
+        let Some(block) = block else { return };
+        if self.last_served.is_some_and(|last| number <= last.number) {
+            return;
+        }
+        self.size += block.size;
+        self.numbers.insert(number, block.info.hash);
+        if let Some(replaced) = self.blocks.insert(block.info.hash, block) {
+            self.size -= replaced.size;
+        }
+    }
+
+    /// Returns the prefetched block with the given number.
+    ///
+    /// The block must extend the last block served by number, unless it is that block. Otherwise,
+    /// the L1 chain reorged or the pipeline was reset since the block was prefetched, and the
+    /// cache is invalidated.
+    fn block_by_number(&mut self, number: u64) -> Option<&PrefetchedBlock> {
+        let hash = *self.numbers.get(&number)?;
+        let info = self.blocks.get(&hash)?.info;
+        let consistent = self.last_served.is_none_or(|last| {
+            (last.number + 1 == number && last.hash == info.parent_hash) || last == info
+        });
+        if !consistent {
+            self.invalidate();
+            return None;
+        }
+        self.blocks.get(&hash)
+    }
+
+    /// Returns the prefetched block with the given hash.
+    fn block_by_hash(&self, hash: &B256) -> Option<&PrefetchedBlock> {
+        self.blocks.get(hash)
+    }
+
+    /// Records the block served to the pipeline by number, and drops the blocks before it.
+    fn serve(&mut self, info: BlockInfo) {
+        self.last_served = Some(info);
+        let kept = self.numbers.split_off(&info.number);
+        for hash in core::mem::replace(&mut self.numbers, kept).into_values() {
+            if let Some(block) = self.blocks.remove(&hash) {
+                self.size -= block.size;
+            }
+        }
+    }
+
+    /// Drops all prefetched blocks, and discards the fetches in flight.
+    fn invalidate(&mut self) {
+        *self = Self { epoch: self.epoch + 1, ..Default::default() };
+        kona_macros::inc!(counter, crate::Metrics::L1_PREFETCH_INVALIDATIONS);
+    }
+}
+
+/// Speculatively fetches the L1 blocks ahead of the derivation pipeline, along with their
+/// receipts and the blobs of their batcher transactions.
+///
+/// Whenever the pipeline reads an L1 block by number, the next [`PrefetchConfig::depth`] blocks
+/// are fetched concurrently in the background, as long as the prefetched data stays within
+/// [`PrefetchConfig::max_bytes`]. The prefetched data is served by the
+/// [`PrefetchingChainProvider`] and [`PrefetchingBlobProvider`] created from the prefetcher,
+/// which fall back to their inner providers on a miss.
+///
+/// Data looked up by hash cannot go stale. A block looked up by number is only served if it
+/// extends the last block served by number: otherwise the L1 chain reorged, or the pipeline was
+/// reset, and the whole cache is invalidated.
+#[derive(Debug, Clone)]
+pub struct L1Prefetcher {
+    /// The prefetch configuration.
+    config: PrefetchConfig,
+    /// The L1 execution client provider.
+    l1_provider: RootProvider,
+    /// The L1 blob provider.
+    blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
+    /// The batch inbox address, to which the batcher transactions are sent.
+    batch_inbox_address: Address,
+    /// The prefetched blocks.
+    cache: Arc<Mutex<PrefetchCache>>,
+}
+
+impl L1Prefetcher {
+    /// Creates a new [`L1Prefetcher`] fetching from the given providers.
+    pub fn new(
+        config: PrefetchConfig,
+        l1_provider: RootProvider,
+        blob_provider: OnlineBlobProvider<OnlineBeaconClient>,
+        rollup_config: &RollupConfig,
+    ) -> Self {
+        Self {
+            config,
+            l1_provider,
+            blob_provider,
+            batch_inbox_address: rollup_config.batch_inbox_address,
+            cache: Arc::new(Mutex::new(PrefetchCache::default())),
+        }
+    }
+
+    /// Returns the prefetch configuration.
+    pub const fn config(&self) -> &PrefetchConfig {
+        &self.config
+    }
+
+    /// Returns a [`PrefetchingChainProvider`] serving the prefetched data, and falling back to
+    /// the given provider.
+    pub fn chain_provider(&self, inner: AlloyChainProvider) -> PrefetchingChainProvider {
+        PrefetchingChainProvider { inner, prefetcher: self.clone() }
+    }
+
+    /// Returns a [`PrefetchingBlobProvider`] serving the prefetched blobs, and falling back to the
+    /// given provider.
+    pub fn blob_provider(
+        &self,
+        inner: OnlineBlobProvider<OnlineBeaconClient>,
+    ) -> PrefetchingBlobProvider {
+        PrefetchingBlobProvider { inner, prefetcher: self.clone() }
+    }
+
+    /// Drops all prefetched data, and discards the fetches in flight.
+    ///
+    /// Must be called when the pipeline is reset, or when an L1 reorg is detected, as the
+    /// prefetched blocks may no longer be canonical.
+    pub fn invalidate(&self) {
+        self.cache().invalidate();
+        self.update_gauges();
+    }
+
+    /// Locks the cache.
+    fn cache(&self) -> MutexGuard<'_, PrefetchCache> {
+        self.cache.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    /// Looks up data of the given kind in the cache, recording the hit or miss.
+    #[cfg_attr(not(feature = "metrics"), allow(unused_variables))]
+    fn lookup<T>(
+        &self,
+        kind: &'static str,
+        f: impl FnOnce(&mut PrefetchCache) -> Option<T>,
+    ) -> Option<T> {
+        if !self.config.is_enabled() {
+            return None;
+        }
+        let found = f(&mut self.cache());
+        kona_macros::inc!(
+            counter,
+            crate::Metrics::L1_PREFETCH_LOOKUPS,
+            "kind" => kind,
+            "result" => if found.is_some() { "hit" } else { "miss" }
+        );
+        found
+    }
+
+    /// Records the block served to the pipeline by number, and prefetches the blocks after it.
+    fn serve(&self, info: BlockInfo) {
+        if !self.config.is_enabled() {
+            return;
+        }
+        // Prefetching needs a runtime to spawn the fetches on.
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
+
+        let (epoch, scheduled) = {
+            let mut cache = self.cache();
+            cache.serve(info);
+            (cache.epoch, cache.schedule(info.number + 1, &self.config))
+        };
+        self.update_gauges();
+
+        for number in scheduled {
+            let prefetcher = self.clone();
+            runtime.spawn(async move {
+                let block = prefetcher.fetch(number).await;
+                prefetcher.cache().complete(epoch, number, block);
+                prefetcher.update_gauges();
+            });
+        }
+    }
+
+    /// Fetches the block with the given number, along with its receipts and the blobs of its
+    /// batcher transactions. Returns `None` if any of them cannot be fetched, notably if the
+    /// block does not exist yet.
+    async fn fetch(&self, number: u64) -> Option<PrefetchedBlock> {
+        let block = self
+            .l1_provider
+            .get_block_by_number(number.into())
+            .full()
+            .await
+            .ok()??
+            .into_consensus()
+            .map_transactions(|t| t.inner.into_inner());
+        let info = BlockInfo {
+            hash: block.header.hash_slow(),
+            number,
+            parent_hash: block.header.parent_hash,
+            timestamp: block.header.timestamp,
+        };
+
+        let receipts = fetch_receipts(&self.l1_provider, info.hash).await.ok()?;
+
+        let blob_hashes = self.batcher_blob_hashes(&block.body.transactions);
+        let blobs = if blob_hashes.is_empty() {
+            Vec::new()
+        } else {
+            self.blob_provider.clone().get_blobs(&info, &blob_hashes).await.ok()?
+        };
+        let blobs = blob_hashes.iter().map(|indexed| indexed.hash).zip(blobs).collect();
+
+        Some(PrefetchedBlock::new(block.header, block.body.transactions, receipts, blobs))
+    }
+
+    /// Returns the indexed hashes of the blobs of the transactions sent to the batch inbox.
+    ///
+    /// The sender of the transactions is not checked, so this may include blobs that the pipeline
+    /// does not read.
+    fn batcher_blob_hashes(&self, transactions: &[TxEnvelope]) -> Vec<IndexedBlobHash> {
+        let mut index = 0;
+        let mut hashes = Vec::new();
+        for tx in transactions {
+            let blob_hashes = tx.blob_versioned_hashes().unwrap_or_default();
+            if tx.to() == Some(self.batch_inbox_address) {
+                hashes.extend(
+                    blob_hashes
+                        .iter()
+                        .enumerate()
+                        .map(|(i, hash)| IndexedBlobHash { index: index + i as u64, hash: *hash }),
+                );
+            }
+            index += blob_hashes.len() as u64;
+        }
+        hashes
+    }
+
+    /// Updates the gauges of the prefetch cache.
+    fn update_gauges(&self) {
+        #[cfg(feature = "metrics")]
+        {
+            let cache = self.cache();
+            kona_macros::set!(gauge, crate::Metrics::L1_PREFETCH_BLOCKS, cache.blocks.len() as f64);
+            kona_macros::set!(gauge, crate::Metrics::L1_PREFETCH_BYTES, cache.size as f64);
+        }
+    }
+}
+
+/// A [`ChainProvider`] serving the L1 data prefetched by an [`L1Prefetcher`], and falling back to
+/// an [`AlloyChainProvider`].
+#[derive(Debug, Clone)]
+pub struct PrefetchingChainProvider {
+    /// The provider used on prefetch misses.
+    inner: AlloyChainProvider,
+    /// The prefetcher.
+    prefetcher: L1Prefetcher,
+}
+
+#[async_trait]
+impl ChainProvider for PrefetchingChainProvider {
+    type Error = AlloyChainProviderError;
+
+    async fn header_by_hash(&mut self, hash: B256) -> Result<Header, Self::Error> {
+        if let Some(header) = self
+            .prefetcher
+            .lookup("header", |cache| cache.block_by_hash(&hash).map(|b| b.header.clone()))
+        {
+            return Ok(header);
+        }
+        self.inner.header_by_hash(hash).await
+    }
+
+    async fn block_info_by_number(&mut self, number: u64) -> Result<BlockInfo, Self::Error> {
+        let info = match self
+            .prefetcher
+            .lookup("block_info", |cache| cache.block_by_number(number).map(|b| b.info))
+        {
+            Some(info) => info,
+            None => self.inner.block_info_by_number(number).await?,
+        };
+        self.prefetcher.serve(info);
+        Ok(info)
+    }
+
+    async fn receipts_by_hash(&mut self, hash: B256) -> Result<Vec<Receipt>, Self::Error> {
+        if let Some(receipts) = self
+            .prefetcher
+            .lookup("receipts", |cache| cache.block_by_hash(&hash).map(|b| b.receipts.clone()))
+        {
+            return Ok(receipts);
+        }
+        self.inner.receipts_by_hash(hash).await
+    }
+
+    async fn block_info_and_transactions_by_hash(
+        &mut self,
+        hash: B256,
+    ) -> Result<(BlockInfo, Vec<TxEnvelope>), Self::Error> {
+        if let Some(block) = self.prefetcher.lookup("transactions", |cache| {
+            cache.block_by_hash(&hash).map(|b| (b.info, b.transactions.clone()))
+        }) {
+            return Ok(block);
+        }
+        self.inner.block_info_and_transactions_by_hash(hash).await
+    }
+}
+
+/// A [`BlobProvider`] serving the blobs prefetched by an [`L1Prefetcher`], and falling back to an
+/// [`OnlineBlobProvider`].
+#[derive(Debug, Clone)]
+pub struct PrefetchingBlobProvider {
+    /// The provider used on prefetch misses.
+    inner: OnlineBlobProvider<OnlineBeaconClient>,
+    /// The prefetcher.
+    prefetcher: L1Prefetcher,
+}
+
+#[async_trait]
+impl BlobProvider for PrefetchingBlobProvider {
+    type Error = BlobProviderError;
+
+    async fn get_blobs(
+        &mut self,
+        block_ref: &BlockInfo,
+        blob_hashes: &[IndexedBlobHash],
+    ) -> Result<Vec<Box<Blob>>, Self::Error> {
+        if let Some(blobs) = self.prefetcher.lookup("blobs", |cache| {
+            let block = cache.block_by_hash(&block_ref.hash)?;
+            blob_hashes.iter().map(|indexed| block.blobs.get(&indexed.hash).cloned()).collect()
+        }) {
+            return Ok(blobs);
+        }
+        self.inner.get_blobs(block_ref, blob_hashes).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: PrefetchConfig = PrefetchConfig { depth: 4, max_bytes: 1 << 20 };
+
+    /// Returns a chain of prefetched blocks, starting at the given number.
+    fn chain(from: u64, len: usize) -> Vec<PrefetchedBlock> {
+        let mut parent_hash = B256::ZERO;
+        (from..from + len as u64)
+            .map(|number| {
+                let header = Header { number, parent_hash, ..Default::default() };
+                let block =
+                    PrefetchedBlock::new(header, Vec::new(), Vec::new(), HashMap::default());
+                parent_hash = block.info.hash;
+                block
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_schedule_skips_cached_and_in_flight_blocks() {
+        let mut cache = PrefetchCache::default();
+        assert_eq!(cache.schedule(10, &CONFIG), vec![10, 11, 12, 13]);
+        assert!(cache.schedule(10, &CONFIG).is_empty());
+
+        let block = chain(12, 1).remove(0);
+        cache.complete(0, 12, Some(block));
+        cache.complete(0, 13, None);
+        assert_eq!(cache.schedule(12, &CONFIG), vec![13, 14, 15]);
+    }
+
+    #[test]
+    fn test_schedule_bounded_by_size() {
+        let mut cache = PrefetchCache::default();
+        let block = chain(10, 1).remove(0);
+        let config = PrefetchConfig { depth: 4, max_bytes: block.size };
+        assert_eq!(cache.schedule(10, &config), vec![10, 11, 12, 13]);
+
+        cache.complete(0, 10, Some(block));
+        assert!(cache.schedule(14, &config).is_empty());
+    }
+
+    #[test]
+    fn test_serve_drops_older_blocks() {
+        let blocks = chain(10, 3);
+        let mut cache = PrefetchCache::default();
+        cache.schedule(10, &CONFIG);
+        for block in blocks.iter().cloned() {
+            cache.complete(0, block.info.number, Some(block));
+        }
+
+        let info = cache.block_by_number(11).unwrap().info;
+        cache.serve(info);
+        assert!(cache.block_by_hash(&blocks[0].info.hash).is_none());
+        assert_eq!(cache.block_by_number(11).unwrap().info, blocks[1].info);
+        assert_eq!(cache.block_by_number(12).unwrap().info, blocks[2].info);
+        assert_eq!(cache.size, blocks[1].size + blocks[2].size);
+
+        // Blocks the pipeline already read past are not cached.
+        cache.serve(blocks[2].info);
+        cache.complete(0, 10, Some(blocks[0].clone()));
+        assert!(cache.block_by_hash(&blocks[0].info.hash).is_none());
+    }
+
+    #[test]
+    fn test_inconsistent_block_invalidates() {
+        let blocks = chain(10, 2);
+        let mut cache = PrefetchCache::default();
+        cache.schedule(10, &CONFIG);
+        cache.complete(0, 11, Some(blocks[1].clone()));
+
+        // The served block 10 is not the parent of the prefetched block 11.
+        cache.serve(BlockInfo { number: 10, hash: B256::with_last_byte(1), ..Default::default() });
+        assert!(cache.block_by_number(11).is_none());
+        assert_eq!(cache.epoch, 1);
+        assert!(cache.numbers.is_empty());
+        assert!(cache.in_flight.is_empty());
+
+        // Fetches started before the invalidation are discarded.
+        cache.complete(0, 12, Some(chain(12, 1).remove(0)));
+        assert!(cache.numbers.is_empty());
+    }
+
+    #[test]
+    fn test_invalidate_drops_prefetched_blocks() {
+        let blob_provider = OnlineBlobProvider {
+            beacon_client: OnlineBeaconClient::new_http("http://127.0.0.1:1".to_string()),
+            genesis_time: 0,
+            slot_interval: 12,
+        };
+        let prefetcher = L1Prefetcher::new(
+            CONFIG,
+            RootProvider::new_http("http://127.0.0.1:1".parse().unwrap()),
+            blob_provider,
+            &RollupConfig::default(),
+        );
+
+        let blocks = chain(10, 2);
+        {
+            let mut cache = prefetcher.cache();
+            cache.schedule(10, &CONFIG);
+            cache.complete(0, 10, Some(blocks[0].clone()));
+        }
+        assert!(
+            prefetcher
+                .lookup("header", |cache| cache.block_by_hash(&blocks[0].info.hash).map(|_| ()))
+                .is_some()
+        );
+
+        // The pipeline is reset: the prefetched blocks are dropped, and the fetches started before
+        // the reset are discarded.
+        prefetcher.invalidate();
+        let mut cache = prefetcher.cache();
+        assert!(cache.blocks.is_empty());
+        assert_eq!(cache.size, 0);
+        cache.complete(0, 11, Some(blocks[1].clone()));
+        assert!(cache.block_by_number(11).is_none());
+    }
+}
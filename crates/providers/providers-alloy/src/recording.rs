@@ -0,0 +1,539 @@
+//! Providers serving the chain data read by the derivation pipeline from a recording.
+//!
+//! A [`DerivationRecording`] holds the L1 blocks, receipts and blobs, and the L2 blocks read by
+//! the derivation pipeline. The recorded providers serve the chain data from the recording and,
+//! when an upstream provider is set, fetch the missing data from it and record it. A recording
+//! captured against live RPCs can then be stored to a directory, and replayed offline.
+
+use crate::{AlloyChainProvider, AlloyL2ChainProvider, OnlineBeaconClient, OnlineBlobProvider};
+use alloy_consensus::{Header, Receipt, TxEnvelope};
+use alloy_eips::{
+    BlockId,
+    eip4844::{Blob, IndexedBlobHash},
+};
+use alloy_primitives::B256;
+use async_trait::async_trait;
+use kona_derive::{BlobProvider, ChainProvider, L2ChainProvider, PipelineError, PipelineErrorKind};
+use kona_genesis::{RollupConfig, SystemConfig};
+use kona_protocol::{BatchValidationProvider, BlockInfo, L2BlockInfo, to_system_config};
+use op_alloy_consensus::OpBlock;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, HashMap},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+/// The file of a recording directory holding the L1 blocks.
+const L1_BLOCKS_FILE: &str = "l1_blocks.json";
+
+/// The file of a recording directory holding the blobs.
+const BLOBS_FILE: &str = "blobs.json";
+
+/// The file of a recording directory holding the L2 blocks.
+const L2_BLOCKS_FILE: &str = "l2_blocks.json";
+
+/// An L1 block of a [`DerivationRecording`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedL1Block {
+    /// The block header.
+    pub header: Header,
+    /// The transactions of the block, if they were read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transactions: Option<Vec<TxEnvelope>>,
+    /// The receipts of the block, if they were read.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipts: Option<Vec<Receipt>>,
+}
+
+impl RecordedL1Block {
+    /// Returns the [`BlockInfo`] of the block.
+    pub fn block_info(&self) -> BlockInfo {
+        BlockInfo::new(
+            self.header.hash_slow(),
+            self.header.number,
+            self.header.parent_hash,
+            self.header.timestamp,
+        )
+    }
+}
+
+/// An error produced when loading or storing a [`DerivationRecording`].
+#[derive(Debug, thiserror::Error)]
+pub enum RecordingError {
+    /// A recording file could not be read or written.
+    #[error("Failed to access the recording file {0}: {1}")]
+    Io(PathBuf, std::io::Error),
+    /// A recording file could not be encoded, or is malformed.
+    #[error("Invalid recording file {0}: {1}")]
+    Json(PathBuf, serde_json::Error),
+}
+
+/// The chain data read by the derivation pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DerivationRecording {
+    /// The canonical L1 blocks, by number.
+    l1_blocks: BTreeMap<u64, RecordedL1Block>,
+    /// The numbers of the recorded L1 blocks, by hash.
+    l1_numbers: HashMap<B256, u64>,
+    /// The blobs, by versioned hash.
+    blobs: BTreeMap<B256, Box<Blob>>,
+    /// The L2 blocks, by number.
+    l2_blocks: BTreeMap<u64, OpBlock>,
+}
+
+impl DerivationRecording {
+    /// Loads a [`DerivationRecording`] from the given directory.
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, RecordingError> {
+        let dir = dir.as_ref();
+        let l1_blocks: BTreeMap<u64, RecordedL1Block> = read_json(&dir.join(L1_BLOCKS_FILE))?;
+        let l1_numbers = l1_blocks.iter().map(|(n, b)| (b.header.hash_slow(), *n)).collect();
+        Ok(Self {
+            l1_blocks,
+            l1_numbers,
+            blobs: read_json(&dir.join(BLOBS_FILE))?,
+            l2_blocks: read_json(&dir.join(L2_BLOCKS_FILE))?,
+        })
+    }
+
+    /// Stores the [`DerivationRecording`] to the given directory, creating it if needed.
+    pub fn store(&self, dir: impl AsRef<Path>) -> Result<(), RecordingError> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir).map_err(|err| RecordingError::Io(dir.to_path_buf(), err))?;
+        write_json(&dir.join(L1_BLOCKS_FILE), &self.l1_blocks)?;
+        write_json(&dir.join(BLOBS_FILE), &self.blobs)?;
+        write_json(&dir.join(L2_BLOCKS_FILE), &self.l2_blocks)
+    }
+
+    /// Returns the recorded L1 block with the given hash.
+    pub fn l1_block_by_hash(&self, hash: B256) -> Option<&RecordedL1Block> {
+        self.l1_numbers.get(&hash).and_then(|number| self.l1_blocks.get(number))
+    }
+
+    /// Returns the recorded L1 block with the given number.
+    pub fn l1_block_by_number(&self, number: u64) -> Option<&RecordedL1Block> {
+        self.l1_blocks.get(&number)
+    }
+
+    /// Returns the recorded blob with the given versioned hash.
+    pub fn blob(&self, hash: B256) -> Option<&Blob> {
+        self.blobs.get(&hash).map(|blob| blob.as_ref())
+    }
+
+    /// Returns the recorded L2 block with the given number.
+    pub fn l2_block(&self, number: u64) -> Option<&OpBlock> {
+        self.l2_blocks.get(&number)
+    }
+
+    /// Records an L1 block header, keeping the transactions and receipts recorded for it.
+    pub fn record_l1_header(&mut self, header: Header) {
+        let hash = header.hash_slow();
+        if self.l1_numbers.contains_key(&hash) {
+            return;
+        }
+        self.l1_numbers.insert(hash, header.number);
+        self.l1_blocks
+            .insert(header.number, RecordedL1Block { header, transactions: None, receipts: None });
+    }
+
+    /// Records the transactions of the L1 block with the given hash. The header of the block must
+    /// be recorded first.
+    pub fn record_l1_transactions(&mut self, hash: B256, transactions: Vec<TxEnvelope>) {
+        if let Some(block) = self.l1_block_mut(hash) {
+            block.transactions = Some(transactions);
+        }
+    }
+
+    /// Records the receipts of the L1 block with the given hash. The header of the block must be
+    /// recorded first.
+    pub fn record_l1_receipts(&mut self, hash: B256, receipts: Vec<Receipt>) {
+        if let Some(block) = self.l1_block_mut(hash) {
+            block.receipts = Some(receipts);
+        }
+    }
+
+    /// Records a blob.
+    pub fn record_blob(&mut self, hash: B256, blob: Box<Blob>) {
+        self.blobs.insert(hash, blob);
+    }
+
+    /// Records an L2 block.
+    pub fn record_l2_block(&mut self, block: OpBlock) {
+        self.l2_blocks.insert(block.header.number, block);
+    }
+
+    fn l1_block_mut(&mut self, hash: B256) -> Option<&mut RecordedL1Block> {
+        self.l1_numbers.get(&hash).and_then(|number| self.l1_blocks.get_mut(number))
+    }
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &Path) -> Result<T, RecordingError> {
+    let contents =
+        std::fs::read(path).map_err(|err| RecordingError::Io(path.to_path_buf(), err))?;
+    serde_json::from_slice(&contents).map_err(|err| RecordingError::Json(path.to_path_buf(), err))
+}
+
+fn write_json<T: Serialize>(path: &Path, value: &T) -> Result<(), RecordingError> {
+    let contents =
+        serde_json::to_vec(value).map_err(|err| RecordingError::Json(path.to_path_buf(), err))?;
+    std::fs::write(path, contents).map_err(|err| RecordingError::Io(path.to_path_buf(), err))
+}
+
+/// A [`DerivationRecording`] shared between the recorded providers.
+pub type SharedRecording = Arc<Mutex<DerivationRecording>>;
+
+/// Locks the shared recording. The recording is only ever accessed synchronously, so a poisoned
+/// lock still holds consistent data.
+fn lock(recording: &SharedRecording) -> MutexGuard<'_, DerivationRecording> {
+    recording.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+/// An error for the recorded providers.
+#[derive(Debug, thiserror::Error)]
+pub enum RecordedProviderError {
+    /// The L1 block is not recorded.
+    #[error("L1 block {0} is not recorded")]
+    L1BlockNotFound(BlockId),
+    /// The transactions of the L1 block are not recorded.
+    #[error("Transactions of L1 block {0} are not recorded")]
+    TransactionsNotFound(B256),
+    /// The receipts of the L1 block are not recorded.
+    #[error("Receipts of L1 block {0} are not recorded")]
+    ReceiptsNotFound(B256),
+    /// The blob is not recorded.
+    #[error("Blob {0} is not recorded")]
+    BlobNotFound(B256),
+    /// The L2 block is not recorded.
+    #[error("L2 block {0} is not recorded")]
+    L2BlockNotFound(u64),
+    /// Failed to construct the [`L2BlockInfo`] of the L2 block.
+    #[error("Failed to construct the L2 block info of block {0}")]
+    L2BlockInfoConstruction(u64),
+    /// Failed to convert the L2 block into a [`SystemConfig`].
+    #[error("Failed to convert the system config of L2 block {0}")]
+    SystemConfigConversion(u64),
+    /// The upstream provider failed.
+    #[error("Upstream provider error: {0}")]
+    Upstream(PipelineErrorKind),
+}
+
+impl From<RecordedProviderError> for PipelineErrorKind {
+    fn from(e: RecordedProviderError) -> Self {
+        match e {
+            RecordedProviderError::Upstream(e) => e,
+            e => Self::Temporary(PipelineError::Provider(e.to_string())),
+        }
+    }
+}
+
+/// A [`ChainProvider`] serving the L1 chain data from a [`DerivationRecording`], fetching and
+/// recording the missing data from an optional upstream provider.
+#[derive(Debug, Clone)]
+pub struct RecordedChainProvider<P = AlloyChainProvider> {
+    /// The recording.
+    recording: SharedRecording,
+    /// The optional upstream provider.
+    upstream: Option<P>,
+}
+
+impl<P> RecordedChainProvider<P> {
+    /// Creates a new [`RecordedChainProvider`].
+    pub const fn new(recording: SharedRecording, upstream: Option<P>) -> Self {
+        Self { recording, upstream }
+    }
+}
+
+#[async_trait]
+impl<P> ChainProvider for RecordedChainProvider<P>
+where
+    P: ChainProvider + Send,
+{
+    type Error = RecordedProviderError;
+
+    async fn header_by_hash(&mut self, hash: B256) -> Result<Header, Self::Error> {
+        if let Some(block) = lock(&self.recording).l1_block_by_hash(hash) {
+            return Ok(block.header.clone());
+        }
+        let upstream =
+            self.upstream.as_mut().ok_or(RecordedProviderError::L1BlockNotFound(hash.into()))?;
+
+        let header = upstream
+            .header_by_hash(hash)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        lock(&self.recording).record_l1_header(header.clone());
+        Ok(header)
+    }
+
+    async fn block_info_by_number(&mut self, number: u64) -> Result<BlockInfo, Self::Error> {
+        if let Some(block) = lock(&self.recording).l1_block_by_number(number) {
+            return Ok(block.block_info());
+        }
+        let upstream =
+            self.upstream.as_mut().ok_or(RecordedProviderError::L1BlockNotFound(number.into()))?;
+
+        let block_info = upstream
+            .block_info_by_number(number)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        let header = upstream
+            .header_by_hash(block_info.hash)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        lock(&self.recording).record_l1_header(header);
+        Ok(block_info)
+    }
+
+    async fn receipts_by_hash(&mut self, hash: B256) -> Result<Vec<Receipt>, Self::Error> {
+        if let Some(receipts) =
+            lock(&self.recording).l1_block_by_hash(hash).and_then(|b| b.receipts.clone())
+        {
+            return Ok(receipts);
+        }
+        if self.upstream.is_none() {
+            return Err(RecordedProviderError::ReceiptsNotFound(hash));
+        }
+
+        self.header_by_hash(hash).await?;
+        let upstream =
+            self.upstream.as_mut().ok_or(RecordedProviderError::ReceiptsNotFound(hash))?;
+        let receipts = upstream
+            .receipts_by_hash(hash)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        lock(&self.recording).record_l1_receipts(hash, receipts.clone());
+        Ok(receipts)
+    }
+
+    async fn block_info_and_transactions_by_hash(
+        &mut self,
+        hash: B256,
+    ) -> Result<(BlockInfo, Vec<TxEnvelope>), Self::Error> {
+        if let Some(block) = lock(&self.recording).l1_block_by_hash(hash) {
+            if let Some(transactions) = &block.transactions {
+                return Ok((block.block_info(), transactions.clone()));
+            }
+        }
+        if self.upstream.is_none() {
+            return Err(RecordedProviderError::TransactionsNotFound(hash));
+        }
+
+        self.header_by_hash(hash).await?;
+        let upstream =
+            self.upstream.as_mut().ok_or(RecordedProviderError::TransactionsNotFound(hash))?;
+        let (block_info, transactions) = upstream
+            .block_info_and_transactions_by_hash(hash)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        lock(&self.recording).record_l1_transactions(hash, transactions.clone());
+        Ok((block_info, transactions))
+    }
+}
+
+/// A [`BlobProvider`] serving the blobs from a [`DerivationRecording`], fetching and recording the
+/// missing blobs from an optional upstream provider.
+#[derive(Debug, Clone)]
+pub struct RecordedBlobProvider<B = OnlineBlobProvider<OnlineBeaconClient>> {
+    /// The recording.
+    recording: SharedRecording,
+    /// The optional upstream provider.
+    upstream: Option<B>,
+}
+
+impl<B> RecordedBlobProvider<B> {
+    /// Creates a new [`RecordedBlobProvider`].
+    pub const fn new(recording: SharedRecording, upstream: Option<B>) -> Self {
+        Self { recording, upstream }
+    }
+}
+
+#[async_trait]
+impl<B> BlobProvider for RecordedBlobProvider<B>
+where
+    B: BlobProvider + Send,
+{
+    type Error = RecordedProviderError;
+
+    async fn get_blobs(
+        &mut self,
+        block_ref: &BlockInfo,
+        blob_hashes: &[IndexedBlobHash],
+    ) -> Result<Vec<Box<Blob>>, Self::Error> {
+        let recorded = {
+            let recording = lock(&self.recording);
+            blob_hashes
+                .iter()
+                .map(|h| recording.blob(h.hash).map(|blob| Box::new(*blob)))
+                .collect::<Option<Vec<_>>>()
+        };
+        if let Some(blobs) = recorded {
+            return Ok(blobs);
+        }
+        let Some(upstream) = self.upstream.as_mut() else {
+            let recording = lock(&self.recording);
+            let missing = blob_hashes.iter().find(|h| recording.blob(h.hash).is_none());
+            return Err(RecordedProviderError::BlobNotFound(
+                missing.map(|h| h.hash).unwrap_or_default(),
+            ));
+        };
+
+        let blobs = upstream
+            .get_blobs(block_ref, blob_hashes)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        let mut recording = lock(&self.recording);
+        for (hash, blob) in blob_hashes.iter().zip(blobs.iter()) {
+            recording.record_blob(hash.hash, blob.clone());
+        }
+        Ok(blobs)
+    }
+}
+
+/// An [`L2ChainProvider`] serving the L2 blocks from a [`DerivationRecording`], fetching and
+/// recording the missing blocks from an optional upstream provider.
+#[derive(Debug, Clone)]
+pub struct RecordedL2ChainProvider<L = AlloyL2ChainProvider> {
+    /// The recording.
+    recording: SharedRecording,
+    /// The rollup configuration.
+    rollup_config: Arc<RollupConfig>,
+    /// The optional upstream provider.
+    upstream: Option<L>,
+}
+
+impl<L> RecordedL2ChainProvider<L> {
+    /// Creates a new [`RecordedL2ChainProvider`].
+    pub const fn new(
+        recording: SharedRecording,
+        rollup_config: Arc<RollupConfig>,
+        upstream: Option<L>,
+    ) -> Self {
+        Self { recording, rollup_config, upstream }
+    }
+}
+
+#[async_trait]
+impl<L> BatchValidationProvider for RecordedL2ChainProvider<L>
+where
+    L: BatchValidationProvider + Send,
+    <L as BatchValidationProvider>::Error: Into<PipelineErrorKind>,
+{
+    type Error = RecordedProviderError;
+
+    async fn l2_block_info_by_number(&mut self, number: u64) -> Result<L2BlockInfo, Self::Error> {
+        let block = self.block_by_number(number).await?;
+        L2BlockInfo::from_block_and_genesis(&block, &self.rollup_config.genesis)
+            .map_err(|_| RecordedProviderError::L2BlockInfoConstruction(number))
+    }
+
+    async fn block_by_number(&mut self, number: u64) -> Result<OpBlock, Self::Error> {
+        if let Some(block) = lock(&self.recording).l2_block(number) {
+            return Ok(block.clone());
+        }
+        let upstream =
+            self.upstream.as_mut().ok_or(RecordedProviderError::L2BlockNotFound(number))?;
+
+        let block = upstream
+            .block_by_number(number)
+            .await
+            .map_err(|e| RecordedProviderError::Upstream(e.into()))?;
+        lock(&self.recording).record_l2_block(block.clone());
+        Ok(block)
+    }
+}
+
+#[async_trait]
+impl<L> L2ChainProvider for RecordedL2ChainProvider<L>
+where
+    L: BatchValidationProvider + Send,
+    <L as BatchValidationProvider>::Error: Into<PipelineErrorKind>,
+{
+    type Error = RecordedProviderError;
+
+    async fn system_config_by_number(
+        &mut self,
+        number: u64,
+        rollup_config: Arc<RollupConfig>,
+    ) -> Result<SystemConfig, <Self as BatchValidationProvider>::Error> {
+        let block = self.block_by_number(number).await?;
+        to_system_config(&block, &rollup_config)
+            .map_err(|_| RecordedProviderError::SystemConfigConversion(number))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+
+    fn recording() -> DerivationRecording {
+        let mut recording = DerivationRecording::default();
+        let header = Header { number: 10, timestamp: 120, ..Default::default() };
+        let hash = header.hash_slow();
+        recording.record_l1_header(header);
+        recording.record_l1_receipts(hash, vec![Receipt::default()]);
+        recording.record_blob(B256::with_last_byte(1), Box::new(Blob::with_last_byte(2)));
+        recording.record_l2_block(OpBlock {
+            header: Header {
+                number: 5,
+                extra_data: Bytes::from_static(&[1]),
+                ..Default::default()
+            },
+            body: Default::default(),
+        });
+        recording
+    }
+
+    #[test]
+    fn test_recording_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        let recording = recording();
+
+        recording.store(dir.path()).unwrap();
+        assert_eq!(DerivationRecording::load(dir.path()).unwrap(), recording);
+    }
+
+    #[test]
+    fn test_recording_load_missing_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let err = DerivationRecording::load(dir.path().join("missing")).unwrap_err();
+        assert!(matches!(err, RecordingError::Io(..)));
+    }
+
+    #[tokio::test]
+    async fn test_replay_chain_provider() {
+        let recording = recording();
+        let block = recording.l1_block_by_number(10).unwrap().block_info();
+        let mut provider =
+            RecordedChainProvider::<AlloyChainProvider>::new(Arc::new(Mutex::new(recording)), None);
+
+        assert_eq!(provider.block_info_by_number(10).await.unwrap(), block);
+        assert_eq!(provider.header_by_hash(block.hash).await.unwrap().number, 10);
+        assert_eq!(provider.receipts_by_hash(block.hash).await.unwrap().len(), 1);
+        assert!(matches!(
+            provider.block_info_and_transactions_by_hash(block.hash).await,
+            Err(RecordedProviderError::TransactionsNotFound(hash)) if hash == block.hash
+        ));
+        assert!(matches!(
+            provider.block_info_by_number(11).await,
+            Err(RecordedProviderError::L1BlockNotFound(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_replay_blob_provider() {
+        let mut provider = RecordedBlobProvider::<OnlineBlobProvider<OnlineBeaconClient>>::new(
+            Arc::new(Mutex::new(recording())),
+            None,
+        );
+
+        let present = IndexedBlobHash { index: 0, hash: B256::with_last_byte(1) };
+        let missing = IndexedBlobHash { index: 1, hash: B256::with_last_byte(3) };
+        let blobs = provider.get_blobs(&BlockInfo::default(), &[present]).await.unwrap();
+        assert_eq!(*blobs[0], Blob::with_last_byte(2));
+        assert!(matches!(
+            provider.get_blobs(&BlockInfo::default(), &[present, missing]).await,
+            Err(RecordedProviderError::BlobNotFound(hash)) if hash == missing.hash
+        ));
+    }
+}
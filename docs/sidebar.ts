@@ -75,6 +75,10 @@ export const sidebar: SidebarItem[] = [
               {
                   text: "admin",
                   link: "/node/rpc/admin",
+              },
+              {
+                  text: "dev",
+                  link: "/node/rpc/dev",
               }
           ]
       },
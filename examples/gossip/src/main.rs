@@ -85,6 +85,7 @@ impl GossipCommand {
             NetworkConfig {
                 discovery_address: disc_addr,
                 gossip_address: gossip_addr,
+                transport: Default::default(),
                 unsafe_block_signer: signer,
                 discovery_config: discv5::ConfigBuilder::new(discv5::ListenConfig::Ipv4 {
                     ip: disc_ip,
@@ -102,7 +103,8 @@ impl GossipCommand {
                 gater_config: Default::default(),
                 bootnodes: Default::default(),
                 rollup_config: rollup_config.clone(),
-                local_signer: None,
+                block_signer: None,
+                publish_retry: Default::default(),
             }
             .into(),
         );